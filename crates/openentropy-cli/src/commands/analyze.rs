@@ -1,7 +1,15 @@
-use std::time::Instant;
+use std::io::Read;
+use std::path::Path;
+use std::time::{Duration, Instant};
 
+use indicatif::ProgressBar;
 use openentropy_core::analysis;
 use openentropy_core::conditioning::{ConditioningMode, condition, min_entropy_estimate};
+use openentropy_core::{CancellationToken, EntropyPool, EntropySource};
+use openentropy_tests::{BatteryReport, TestResult};
+
+/// Bytes collected per source call when streaming the analysis.
+const ANALYZE_CHUNK: usize = 1 << 16;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum AnalyzeView {
@@ -25,7 +33,10 @@ struct SourceInterpretation {
 
 pub struct AnalyzeCommandConfig<'a> {
     pub source_filter: Option<&'a str>,
+    pub exclude: Option<&'a str>,
+    pub sources_file: Option<&'a str>,
     pub output_path: Option<&'a str>,
+    pub csv_path: Option<&'a str>,
     pub samples: usize,
     pub cross_correlation: bool,
     pub entropy: bool,
@@ -33,19 +44,171 @@ pub struct AnalyzeCommandConfig<'a> {
     pub view: &'a str,
     pub include_telemetry: bool,
     pub report: bool,
+    pub repeat: usize,
+    pub timings: bool,
+    /// `--fail-under`: minimum quality score (0-100) for every source.
+    pub fail_under: Option<f64>,
+    pub input: Option<&'a str>,
+    pub stdin: bool,
+    pub compact: bool,
+}
+
+impl AnalyzeCommandConfig<'_> {
+    /// Apply `--sources`, `--exclude` and `--sources-file` to the detected sources.
+    fn select(&self, all_sources: Vec<Box<dyn EntropySource>>) -> Vec<Box<dyn EntropySource>> {
+        let profile = self.sources_file.map(super::SourceProfile::load);
+        super::filter_sources(
+            all_sources,
+            self.source_filter,
+            self.exclude,
+            profile.as_ref(),
+        )
+    }
+
+    /// Bytes from `--input` or `--stdin`, with a display name; `None` when
+    /// analyzing live sources.
+    fn read_input(&self) -> Option<(String, Vec<u8>)> {
+        let (name, data) = if self.stdin {
+            let mut buf = Vec::new();
+            let read = std::io::stdin().read_to_end(&mut buf).map(|_| buf);
+            ("stdin".to_string(), read)
+        } else {
+            let path = self.input?;
+            let name = Path::new(path)
+                .file_name()
+                .map_or_else(|| path.to_string(), |n| n.to_string_lossy().into_owned());
+            (name, std::fs::read(path))
+        };
+        match data {
+            Ok(data) if !data.is_empty() => Some((name, data)),
+            Ok(_) => {
+                eprintln!("Error: {name} is empty");
+                std::process::exit(2);
+            }
+            Err(e) => {
+                eprintln!("Error: cannot read {name}: {e}");
+                std::process::exit(2);
+            }
+        }
+    }
+
+    /// `(name, data, collection time)` for each input, collected lazily so
+    /// results interleave with the analysis. With `--input`/`--stdin` there is
+    /// a single entry and no collection time. `progress` advances by the
+    /// bytes collected and shows the current source.
+    fn inputs<'s>(
+        &self,
+        sources: &'s [Box<dyn EntropySource>],
+        input: Option<(String, Vec<u8>)>,
+        progress: &ProgressBar,
+        cancel: &CancellationToken,
+    ) -> Box<dyn Iterator<Item = (String, Vec<u8>, Option<Duration>)> + 's> {
+        if let Some((name, data)) = input {
+            return Box::new(std::iter::once((name, data, None)));
+        }
+        let samples = self.samples;
+        let progress = progress.clone();
+        let cancel = cancel.clone();
+        // Stops before the next source, and drops a collection that Ctrl+C
+        // interrupted rather than analyzing it.
+        Box::new(sources.iter().map_while(move |source| {
+            if cancel.is_cancelled() {
+                return None;
+            }
+            let name = source.name().to_string();
+            progress.set_message(name.clone());
+            let t0 = Instant::now();
+            let data = source.collect(samples);
+            progress.inc(samples as u64);
+            (!cancel.is_cancelled()).then(|| (name, data, Some(t0.elapsed())))
+        }))
+    }
+
+    /// Bar over every byte `--samples` will collect; hidden for `--input`.
+    fn progress(&self, sources: usize, input: bool) -> ProgressBar {
+        if input {
+            return ProgressBar::hidden();
+        }
+        super::progress_bar((sources * self.samples) as u64)
+    }
+
+    /// Like [`inputs`](Self::inputs) for live sources, but feeds each source
+    /// through [`analysis::OnlineAnalysis`] in [`ANALYZE_CHUNK`] chunks rather
+    /// than buffering `--samples` bytes. The duration covers collection only.
+    fn streamed(
+        &self,
+        sources: Vec<Box<dyn EntropySource>>,
+        progress: &ProgressBar,
+        cancel: &CancellationToken,
+    ) -> impl Iterator<Item = (String, analysis::SourceAnalysis, Duration)> {
+        let names: Vec<String> = sources.iter().map(|s| s.name().to_string()).collect();
+        let mut pool = EntropyPool::new(None);
+        for source in sources {
+            pool.add_source(source, 1.0);
+        }
+        let samples = self.samples;
+        let progress = progress.clone();
+        let cancel = cancel.clone();
+        names.into_iter().map_while(move |name| {
+            if cancel.is_cancelled() {
+                return None;
+            }
+            progress.set_message(name.clone());
+            let mut online = analysis::OnlineAnalysis::new(samples);
+            let mut elapsed = Duration::ZERO;
+            if let Some(mut chunks) = pool.collect_source_chunks(&name, ANALYZE_CHUNK, samples) {
+                while !cancel.is_cancelled() {
+                    let t0 = Instant::now();
+                    let Some(chunk) = chunks.next() else { break };
+                    elapsed += t0.elapsed();
+                    progress.inc(chunk.len() as u64);
+                    online.push(&chunk);
+                }
+            }
+            if cancel.is_cancelled() {
+                return None;
+            }
+            let result = online.finish(&name);
+            Some((name, result, elapsed))
+        })
+    }
+
+    /// Detected sources after filtering, or none when reading `--input`/`--stdin`.
+    fn sources_unless_input(&self, input: bool) -> Vec<Box<dyn EntropySource>> {
+        if input {
+            return Vec::new();
+        }
+        let sources = self.select(openentropy_core::platform::detect_available_sources());
+        if sources.is_empty() {
+            eprintln!("No sources matched filter.");
+            std::process::exit(1);
+        }
+        sources
+    }
 }
 
 pub fn run(cfg: AnalyzeCommandConfig<'_>) {
+    if cfg.repeat == 0 {
+        eprintln!("Error: --repeat must be at least 1");
+        std::process::exit(2);
+    }
+    super::check_fail_under(cfg.fail_under, 100.0);
+    if cfg.repeat > 1 && !cfg.report {
+        eprintln!("Note: --repeat only applies to --report and is ignored.");
+    }
+    if cfg.report && (cfg.entropy || cfg.cross_correlation || cfg.view != "summary") {
+        eprintln!(
+            "Note: --report mode runs the NIST test battery; \
+             --entropy, --cross-correlation, and --view are ignored."
+        );
+    }
+    // Ctrl+C stops collection between sources (or chunks) and exits nonzero.
+    let cancel = CancellationToken::new();
+    super::cancel_on_ctrlc(&cancel);
     if cfg.report {
-        if cfg.entropy || cfg.cross_correlation || cfg.view != "summary" {
-            eprintln!(
-                "Note: --report mode runs the NIST test battery; \
-                 --entropy, --cross-correlation, and --view are ignored."
-            );
-        }
-        run_report(&cfg);
+        run_report(&cfg, &cancel);
     } else {
-        run_analysis(&cfg);
+        run_analysis(&cfg, &cancel);
     }
 }
 
@@ -53,83 +216,112 @@ pub fn run(cfg: AnalyzeCommandConfig<'_>) {
 // Statistical analysis path (default)
 // ---------------------------------------------------------------------------
 
-fn run_analysis(cfg: &AnalyzeCommandConfig<'_>) {
+fn run_analysis(cfg: &AnalyzeCommandConfig<'_>, cancel: &CancellationToken) {
     let telemetry = super::telemetry::TelemetryCapture::start(cfg.include_telemetry);
-    let all_sources = openentropy_core::platform::detect_available_sources();
     let mode = super::parse_conditioning(cfg.conditioning);
     let view = AnalyzeView::parse(cfg.view);
 
-    let sources: Vec<_> = super::filter_sources(all_sources, cfg.source_filter);
+    let input = cfg.read_input();
+    let sources = cfg.sources_unless_input(input.is_some());
 
-    if sources.is_empty() {
-        eprintln!("No sources matched filter.");
-        std::process::exit(1);
+    if let Some((name, data)) = &input {
+        println!(
+            "Analyzing {name}, {} bytes (view: {})...\n",
+            data.len(),
+            view.as_str()
+        );
+    } else {
+        println!(
+            "Analyzing {} source(s), {} samples each (view: {})...\n",
+            sources.len(),
+            cfg.samples,
+            view.as_str()
+        );
     }
 
-    println!(
-        "Analyzing {} source(s), {} samples each (view: {})...\n",
-        sources.len(),
-        cfg.samples,
-        view.as_str()
-    );
-
     let mut all_results = Vec::new();
     let mut all_data: Vec<(String, Vec<u8>)> = Vec::new();
     let mut status_counts = [0usize; 3];
+    let mut csv_rows = Vec::new();
+
+    // Only --entropy and --cross-correlation need the bytes after analysis;
+    // otherwise live sources are analyzed as they stream in.
+    let keep_data = input.is_some() || cfg.entropy || cfg.cross_correlation;
+    let progress = cfg.progress(sources.len(), input.is_some());
+    type Analyzed = (String, analysis::SourceAnalysis, Vec<u8>, Option<Duration>);
+    let analyzed: Box<dyn Iterator<Item = Analyzed>> = if keep_data {
+        Box::new(
+            cfg.inputs(&sources, input, &progress, cancel)
+                .map(|(name, data, t)| {
+                    let result = openentropy_core::analyze_bytes(&name, &data);
+                    (name, result, data, t)
+                }),
+        )
+    } else {
+        Box::new(
+            cfg.streamed(sources, &progress, cancel)
+                .map(|(name, result, t)| (name, result, Vec::new(), Some(t))),
+        )
+    };
 
-    for source in &sources {
-        let name = source.name().to_string();
-        print!("  {name}...");
-        let t0 = Instant::now();
-        let data = source.collect(cfg.samples);
-        let collect_time = t0.elapsed();
-
-        if data.is_empty() {
-            println!(" (no data, skipped)");
-            continue;
-        }
+    for (name, result, data, collect_time) in analyzed {
+        progress.suspend(|| {
+            let n_bytes = result.sample_size;
+            if n_bytes == 0 {
+                println!("  {name}... (no data, skipped)");
+                return;
+            }
 
-        let result = analysis::full_analysis(&name, &data);
-        println!(" {:.2}s, {} bytes", collect_time.as_secs_f64(), data.len());
+            match collect_time {
+                Some(t) => println!("  {name}... {:.2}s, {n_bytes} bytes", t.as_secs_f64()),
+                None => println!("  {name}... {n_bytes} bytes"),
+            }
 
-        let interpretation = interpret_source(&result);
-        match interpretation.status {
-            AnalyzeStatus::Good => status_counts[0] += 1,
-            AnalyzeStatus::Warning => status_counts[1] += 1,
-            AnalyzeStatus::Critical => status_counts[2] += 1,
-        }
+            let interpretation = interpret_source(&result);
+            match interpretation.status {
+                AnalyzeStatus::Good => status_counts[0] += 1,
+                AnalyzeStatus::Warning => status_counts[1] += 1,
+                AnalyzeStatus::Critical => status_counts[2] += 1,
+            }
 
-        match view {
-            AnalyzeView::Summary => print_source_summary(&result, &interpretation),
-            AnalyzeView::Detailed => print_source_detailed(&result, &interpretation),
-        }
+            match view {
+                AnalyzeView::Summary => print_source_summary(&result, &interpretation),
+                AnalyzeView::Detailed => print_source_detailed(&result, &interpretation),
+            }
 
-        // Min-entropy breakdown (MCV primary + diagnostic estimators)
-        if cfg.entropy {
-            let entropy_input = if mode == ConditioningMode::Raw {
-                data.clone()
-            } else {
-                condition(&data, data.len(), mode)
-            };
-            let report = min_entropy_estimate(&entropy_input);
-            let report_str = format!("{report}");
-            println!(
-                "  ┌─ Min-Entropy Breakdown ({name}, conditioning: {}, {} bytes)",
-                cfg.conditioning,
-                entropy_input.len()
-            );
-            for line in report_str.lines() {
-                println!("  │ {line}");
+            // Min-entropy breakdown (MCV primary + diagnostic estimators)
+            if cfg.entropy {
+                let entropy_input = if mode == ConditioningMode::Raw {
+                    data.clone()
+                } else {
+                    condition(&data, data.len(), mode)
+                };
+                let report = min_entropy_estimate(&entropy_input);
+                let report_str = format!("{report}");
+                println!(
+                    "  ┌─ Min-Entropy Breakdown ({name}, conditioning: {}, {} bytes)",
+                    cfg.conditioning,
+                    entropy_input.len()
+                );
+                for line in report_str.lines() {
+                    println!("  │ {line}");
+                }
+                println!("  └─");
             }
-            println!("  └─");
-        }
 
-        all_results.push(result);
+            let throughput = collect_time
+                .filter(|t| !t.is_zero())
+                .map(|t| n_bytes as f64 / t.as_secs_f64());
+            csv_rows.push(analysis_csv_row(&result, &interpretation, throughput));
+            all_results.push(result);
 
-        if cfg.cross_correlation {
-            all_data.push((name, data));
-        }
+            if cfg.cross_correlation {
+                all_data.push((name, data));
+            }
+        });
     }
+    progress.finish_and_clear();
+    super::exit_if_interrupted(cancel);
 
     println!("\n{:=<68}", "");
     println!(
@@ -175,63 +367,128 @@ fn run_analysis(cfg: &AnalyzeCommandConfig<'_>) {
             json["telemetry_v1"] = serde_json::json!(window);
         }
 
-        super::write_json(&json, path, "Results");
+        super::write_json(&json, path, "Results", cfg.compact);
+    }
+    if let Some(path) = cfg.csv_path {
+        super::write_csv(&ANALYSIS_CSV_HEADER, &csv_rows, path, "Analysis");
     }
 }
 
+const ANALYSIS_CSV_HEADER: [&str; 10] = [
+    "source",
+    "samples",
+    "grade",
+    "min_entropy",
+    "shannon_entropy",
+    "throughput_bps",
+    "max_autocorrelation",
+    "bit_bias",
+    "stationary",
+    "status",
+];
+
+/// One CSV row of key scalars; `throughput_bps` is empty for `--input`/`--stdin`.
+fn analysis_csv_row(
+    r: &analysis::SourceAnalysis,
+    i: &SourceInterpretation,
+    throughput_bps: Option<f64>,
+) -> Vec<String> {
+    vec![
+        r.source_name.clone(),
+        r.sample_size.to_string(),
+        openentropy_core::grade_min_entropy(r.min_entropy.max(0.0)).to_string(),
+        format!("{:.4}", r.min_entropy),
+        format!("{:.4}", r.shannon_entropy),
+        throughput_bps.map_or_else(String::new, |t| format!("{t:.1}")),
+        format!("{:.4}", r.autocorrelation.max_abs_correlation),
+        format!("{:.4}", r.bit_bias.overall_bias),
+        r.stationarity.is_stationary.to_string(),
+        i.status.as_str().to_string(),
+    ]
+}
+
 // ---------------------------------------------------------------------------
 // NIST-inspired test battery path (--report)
 // ---------------------------------------------------------------------------
 
-fn run_report(cfg: &AnalyzeCommandConfig<'_>) {
+fn run_report(cfg: &AnalyzeCommandConfig<'_>, cancel: &CancellationToken) {
     let telemetry = super::telemetry::TelemetryCapture::start(cfg.include_telemetry);
     let mode = super::parse_conditioning(cfg.conditioning);
-    let all_sources = openentropy_core::platform::detect_available_sources();
 
-    let sources: Vec<_> = super::filter_sources(all_sources, cfg.source_filter);
+    let input = cfg.read_input();
+    let sources = cfg.sources_unless_input(input.is_some());
 
-    if sources.is_empty() {
-        eprintln!("No sources matched filter.");
-        std::process::exit(1);
+    if cfg.repeat > 1 {
+        if input.is_some() {
+            eprintln!("Note: --repeat needs fresh collections and is ignored for --input/--stdin.");
+        } else {
+            if cfg.timings {
+                eprintln!("Note: --timings applies to single runs and is ignored with --repeat.");
+            }
+            run_report_repeated(cfg, &sources, mode, telemetry, cancel);
+            return;
+        }
     }
 
-    println!(
-        "Running NIST test battery on {} source(s), {} samples each...\n",
-        sources.len(),
-        cfg.samples
-    );
+    if let Some((name, data)) = &input {
+        println!(
+            "Running NIST test battery on {name}, {} bytes...\n",
+            data.len()
+        );
+    } else {
+        println!(
+            "Running NIST test battery on {} source(s), {} samples each...\n",
+            sources.len(),
+            cfg.samples
+        );
+    }
 
     let mut all_results = Vec::new();
+    let mut all_timings = Vec::new();
+    let progress = cfg.progress(sources.len(), input.is_some());
 
-    for src in &sources {
-        let info = src.info();
-        print!("  Collecting from {}...", info.name);
-
+    for (name, raw_data, collect_time) in cfg.inputs(&sources, input, &progress, cancel) {
         let t0 = Instant::now();
-        let raw_data = src.collect(cfg.samples);
         let data = condition(&raw_data, raw_data.len(), mode);
-        print!(" {} bytes", data.len());
 
         if data.is_empty() {
-            println!(" (no data)");
+            progress.suspend(|| println!("  {name}... 0 bytes (no data)"));
             continue;
         }
 
-        let results = openentropy_tests::run_all_tests(&data);
-        let elapsed = t0.elapsed().as_secs_f64();
-        let score = openentropy_tests::calculate_quality_score(&results);
-        let passed = results.iter().filter(|r| r.passed).count();
-
-        println!(
-            " -> {:.0}/100 ({}/{} passed) [{:.1}s]",
-            score,
-            passed,
-            results.len(),
-            elapsed
-        );
+        let report = if cfg.timings {
+            let (results, timings): (Vec<_>, Vec<_>) =
+                openentropy_tests::run_all_tests_timed(&data)
+                    .into_iter()
+                    .unzip();
+            let named: Vec<_> = results
+                .iter()
+                .map(|r| r.name.clone())
+                .zip(timings)
+                .collect();
+            all_timings.push((name.clone(), named));
+            BatteryReport::new(results)
+        } else {
+            BatteryReport::from_data(&data)
+        };
+        let elapsed = (collect_time.unwrap_or_default() + t0.elapsed()).as_secs_f64();
+        let passed = report.results.iter().filter(|r| r.passed).count();
 
-        all_results.push((info.name.to_string(), data, results));
+        progress.suspend(|| {
+            println!(
+                "  {name}... {} bytes -> {:.0}/100 ({}/{} passed) [{:.1}s]",
+                data.len(),
+                report.quality_score,
+                passed,
+                report.results.len(),
+                elapsed
+            )
+        });
+
+        all_results.push((name, data, report));
     }
+    progress.finish_and_clear();
+    super::exit_if_interrupted(cancel);
 
     if all_results.is_empty() {
         eprintln!("No sources produced data.");
@@ -241,47 +498,74 @@ fn run_report(cfg: &AnalyzeCommandConfig<'_>) {
     // Summary table
     println!("\n{}", "=".repeat(60));
     println!(
-        "{:<25} {:>6} {:>6} {:>8}",
-        "Source", "Score", "Grade", "Pass"
+        "{:<25} {:>6} {:>6} {:>8} {:>10}",
+        "Source", "Score", "Grade", "Pass", "Stouffer p"
     );
     println!("{}", "-".repeat(60));
 
     let mut sorted_indices: Vec<usize> = (0..all_results.len()).collect();
     sorted_indices.sort_by(|&a, &b| {
-        let sa = openentropy_tests::calculate_quality_score(&all_results[a].2);
-        let sb = openentropy_tests::calculate_quality_score(&all_results[b].2);
+        let sa = all_results[a].2.quality_score;
+        let sb = all_results[b].2.quality_score;
         sb.partial_cmp(&sa).unwrap_or(std::cmp::Ordering::Equal)
     });
 
+    let mut csv_rows = Vec::new();
     for &idx in &sorted_indices {
-        let (ref name, _, ref results) = all_results[idx];
-        let score = openentropy_tests::calculate_quality_score(results);
-        let grade = if score >= 80.0 {
-            'A'
-        } else if score >= 60.0 {
-            'B'
-        } else if score >= 40.0 {
-            'C'
-        } else if score >= 20.0 {
-            'D'
-        } else {
-            'F'
-        };
+        let (ref name, ref data, ref report) = all_results[idx];
+        let results = &report.results;
+        let score = report.quality_score;
+        let grade = report_grade(score);
         let passed = results.iter().filter(|r| r.passed).count();
+        csv_rows.push(vec![
+            name.clone(),
+            data.len().to_string(),
+            format!("{score:.1}"),
+            grade.to_string(),
+            passed.to_string(),
+            results.len().to_string(),
+            report
+                .combined_p_value
+                .map_or_else(String::new, |p| format!("{p:.6}")),
+        ]);
         println!(
-            "  {:<23} {:>5.1} {:>6} {:>4}/{}",
+            "  {:<23} {:>5.1} {:>6} {:>4}/{:<3} {:>10}",
             name,
             score,
             grade,
             passed,
-            results.len()
+            results.len(),
+            format_combined_p(report.combined_p_value)
         );
     }
+    println!("  (Stouffer p combines correlated tests; a heuristic, not a calibrated level)");
+
+    for (name, timings) in &mut all_timings {
+        print_timings(name, timings);
+    }
 
     let telemetry_report = telemetry.finish_and_print("analyze --report");
 
-    // Markdown output.
-    if let Some(path) = cfg.output_path {
+    // JSON output for `.json` paths, Markdown otherwise.
+    if let Some(path) = cfg.output_path
+        && path.ends_with(".json")
+    {
+        let sources: Vec<_> = all_results
+            .iter()
+            .map(|(name, data, report)| {
+                serde_json::json!({
+                    "source": name,
+                    "samples": data.len(),
+                    "report": report,
+                })
+            })
+            .collect();
+        let mut json = serde_json::json!({ "sources": sources });
+        if let Some(window) = telemetry_report {
+            json["telemetry_v1"] = serde_json::json!(window);
+        }
+        super::write_json(&json, path, "Report", cfg.compact);
+    } else if let Some(path) = cfg.output_path {
         let report = generate_markdown_report(&all_results, telemetry_report.as_ref());
         if let Err(e) = std::fs::write(path, &report) {
             eprintln!("Failed to write report to {path}: {e}");
@@ -289,10 +573,306 @@ fn run_report(cfg: &AnalyzeCommandConfig<'_>) {
             println!("\nReport saved to: {path}");
         }
     }
+    if let Some(path) = cfg.csv_path {
+        super::write_csv(&REPORT_CSV_HEADER, &csv_rows, path, "Report");
+    }
+    if let Some(threshold) = cfg.fail_under {
+        let scores: Vec<_> = all_results
+            .iter()
+            .map(|(name, _, report)| (name.clone(), report.quality_score))
+            .collect();
+        super::enforce_fail_under("quality score", &scores, threshold);
+    }
+}
+
+/// `--timings`: per-test wall time for one source, slowest first.
+fn print_timings(name: &str, timings: &mut [(String, Duration)]) {
+    timings.sort_by_key(|(_, elapsed)| std::cmp::Reverse(*elapsed));
+    let total: Duration = timings.iter().map(|(_, t)| *t).sum();
+    println!(
+        "\nTest timings for {name} (total {:.3}s):",
+        total.as_secs_f64()
+    );
+    for (test, elapsed) in timings.iter() {
+        let share = if total.is_zero() {
+            0.0
+        } else {
+            elapsed.as_secs_f64() / total.as_secs_f64() * 100.0
+        };
+        println!(
+            "  {:<30} {:>9.2}ms {:>5.1}%",
+            test,
+            elapsed.as_secs_f64() * 1000.0,
+            share
+        );
+    }
+}
+
+const REPORT_CSV_HEADER: [&str; 7] = [
+    "source",
+    "samples",
+    "score",
+    "grade",
+    "passed",
+    "total",
+    "stouffer_p",
+];
+
+const REPEATED_REPORT_CSV_HEADER: [&str; 5] = [
+    "source",
+    "runs",
+    "samples_per_run",
+    "median_score",
+    "intermittent_tests",
+];
+
+fn report_grade(score: f64) -> char {
+    if score >= 80.0 {
+        'A'
+    } else if score >= 60.0 {
+        'B'
+    } else if score >= 40.0 {
+        'C'
+    } else if score >= 20.0 {
+        'D'
+    } else {
+        'F'
+    }
+}
+
+/// Outcome of one test across `--repeat` battery runs.
+#[derive(Debug, serde::Serialize)]
+struct TestAggregate {
+    name: String,
+    runs: usize,
+    passed: usize,
+    pass_rate: f64,
+    median_p: Option<f64>,
+    /// Passed in some runs and failed in others.
+    intermittent: bool,
+}
+
+/// Collect `repeat` fresh samples from `src` and condition each; runs that
+/// yield no data are dropped.
+fn collect_runs(
+    src: &dyn EntropySource,
+    samples: usize,
+    mode: ConditioningMode,
+    repeat: usize,
+) -> Vec<Vec<u8>> {
+    (0..repeat)
+        .map(|_| {
+            let raw = src.collect(samples);
+            condition(&raw, raw.len(), mode)
+        })
+        .filter(|data| !data.is_empty())
+        .collect()
+}
+
+/// Per-test pass counts and median p-values, in battery order.
+fn aggregate_runs(reports: &[BatteryReport]) -> Vec<TestAggregate> {
+    let Some(first) = reports.first() else {
+        return Vec::new();
+    };
+    first
+        .results
+        .iter()
+        .map(|test| {
+            let outcomes: Vec<&TestResult> = reports
+                .iter()
+                .filter_map(|r| r.results.iter().find(|t| t.name == test.name))
+                .collect();
+            let passed = outcomes.iter().filter(|t| t.passed).count();
+            let mut p_values: Vec<f64> = outcomes.iter().filter_map(|t| t.p_value).collect();
+            p_values.sort_by(f64::total_cmp);
+            let median_p = match p_values.len() {
+                0 => None,
+                n if n % 2 == 1 => Some(p_values[n / 2]),
+                n => Some((p_values[n / 2 - 1] + p_values[n / 2]) / 2.0),
+            };
+            TestAggregate {
+                name: test.name.clone(),
+                runs: outcomes.len(),
+                passed,
+                pass_rate: passed as f64 / outcomes.len() as f64,
+                median_p,
+                intermittent: passed > 0 && passed < outcomes.len(),
+            }
+        })
+        .collect()
+}
+
+/// Median quality score over repeated runs of one source (upper median).
+fn median_score(reports: &[BatteryReport]) -> f64 {
+    let mut scores: Vec<f64> = reports.iter().map(|r| r.quality_score).collect();
+    scores.sort_by(f64::total_cmp);
+    scores[scores.len() / 2]
+}
+
+/// `--report --repeat N`: one battery per fresh collection, aggregated per test.
+fn run_report_repeated(
+    cfg: &AnalyzeCommandConfig<'_>,
+    sources: &[Box<dyn EntropySource>],
+    mode: ConditioningMode,
+    telemetry: super::telemetry::TelemetryCapture,
+    cancel: &CancellationToken,
+) {
+    println!(
+        "Running NIST test battery {} times on {} source(s), {} samples each...\n",
+        cfg.repeat,
+        sources.len(),
+        cfg.samples
+    );
+
+    let mut all_runs = Vec::new();
+    for src in sources {
+        if cancel.is_cancelled() {
+            break;
+        }
+        let name = src.info().name;
+        print!("  Collecting from {name}...");
+        let t0 = Instant::now();
+        let runs = collect_runs(src.as_ref(), cfg.samples, mode, cfg.repeat);
+        if runs.is_empty() {
+            println!(" (no data)");
+            continue;
+        }
+        let reports: Vec<BatteryReport> = runs
+            .iter()
+            .map(|data| BatteryReport::from_data(data))
+            .collect();
+        let aggregate = aggregate_runs(&reports);
+        let intermittent = aggregate.iter().filter(|t| t.intermittent).count();
+        println!(
+            " {} run(s), {intermittent} intermittent test(s) [{:.1}s]",
+            reports.len(),
+            t0.elapsed().as_secs_f64()
+        );
+        let samples: Vec<usize> = runs.iter().map(Vec::len).collect();
+        all_runs.push((name.to_string(), samples, reports, aggregate));
+    }
+    super::exit_if_interrupted(cancel);
+
+    if all_runs.is_empty() {
+        eprintln!("No sources produced data.");
+        std::process::exit(1);
+    }
+
+    for (name, _, reports, aggregate) in &all_runs {
+        println!("\n{}", "=".repeat(60));
+        println!("  {name} ({} runs)", reports.len());
+        println!("  {:<34} {:>7} {:>10}", "Test", "Pass", "Median p");
+        println!("{}", "-".repeat(60));
+        for t in aggregate {
+            println!(
+                "  {:<34} {:>3}/{:<3} {:>10}{}",
+                t.name,
+                t.passed,
+                t.runs,
+                format_combined_p(t.median_p),
+                if t.intermittent { "  intermittent" } else { "" }
+            );
+        }
+    }
+    println!(
+        "  (intermittent = passed in some runs and failed in others; a marginal-source red flag)"
+    );
+
+    let telemetry_report = telemetry.finish_and_print("analyze --report");
+
+    if let Some(path) = cfg.output_path
+        && path.ends_with(".json")
+    {
+        let sources: Vec<_> = all_runs
+            .iter()
+            .map(|(name, samples, reports, aggregate)| {
+                serde_json::json!({
+                    "source": name,
+                    "samples": samples,
+                    "aggregate": aggregate,
+                    "reports": reports,
+                })
+            })
+            .collect();
+        let mut json = serde_json::json!({ "repeat": cfg.repeat, "sources": sources });
+        if let Some(window) = telemetry_report {
+            json["telemetry_v1"] = serde_json::json!(window);
+        }
+        super::write_json(&json, path, "Report", cfg.compact);
+    } else if let Some(path) = cfg.output_path {
+        let mut report = String::new();
+        report.push_str("# OpenEntropy — NIST Randomness Test Report (repeated)\n\n");
+        report.push_str(&format!(
+            "Generated: Unix timestamp: {}\n\nRuns per source: {}\n\n",
+            super::unix_timestamp_now(),
+            cfg.repeat
+        ));
+        for (name, samples, _, aggregate) in &all_runs {
+            report.push_str(&format!("## {name}\n\n"));
+            report.push_str(&format!(
+                "- Runs: {}\n- Samples per run: {} bytes\n\n",
+                samples.len(),
+                samples.first().copied().unwrap_or(0)
+            ));
+            report.push_str("| Test | Pass rate | Median p | Intermittent |\n");
+            report.push_str("|------|-----------|----------|--------------|\n");
+            for t in aggregate {
+                report.push_str(&format!(
+                    "| {} | {}/{} | {} | {} |\n",
+                    t.name,
+                    t.passed,
+                    t.runs,
+                    format_combined_p(t.median_p),
+                    if t.intermittent { "Y" } else { "N" }
+                ));
+            }
+            report.push_str("\n---\n\n");
+        }
+        if let Err(e) = std::fs::write(path, &report) {
+            eprintln!("Failed to write report to {path}: {e}");
+        } else {
+            println!("\nReport saved to: {path}");
+        }
+    }
+    if let Some(path) = cfg.csv_path {
+        let rows: Vec<Vec<String>> = all_runs
+            .iter()
+            .map(|(name, samples, reports, aggregate)| {
+                vec![
+                    name.clone(),
+                    reports.len().to_string(),
+                    samples.first().copied().unwrap_or(0).to_string(),
+                    format!("{:.1}", median_score(reports)),
+                    aggregate
+                        .iter()
+                        .filter(|t| t.intermittent)
+                        .count()
+                        .to_string(),
+                ]
+            })
+            .collect();
+        super::write_csv(&REPEATED_REPORT_CSV_HEADER, &rows, path, "Report");
+    }
+    if let Some(threshold) = cfg.fail_under {
+        let scores: Vec<_> = all_runs
+            .iter()
+            .map(|(name, _, reports, _)| (name.clone(), median_score(reports)))
+            .collect();
+        super::enforce_fail_under("median quality score", &scores, threshold);
+    }
+}
+
+/// Format a [`BatteryReport::combined_p_value`] for display.
+fn format_combined_p(p: Option<f64>) -> String {
+    match p {
+        Some(p) if p < 1e-4 => format!("{p:.2e}"),
+        Some(p) => format!("{p:.4}"),
+        None => "—".to_string(),
+    }
 }
 
 fn generate_markdown_report(
-    results: &[(String, Vec<u8>, Vec<openentropy_tests::TestResult>)],
+    results: &[(String, Vec<u8>, BatteryReport)],
     telemetry: Option<&openentropy_core::TelemetryWindowReport>,
 ) -> String {
     let mut report = String::new();
@@ -313,16 +893,18 @@ fn generate_markdown_report(
         ));
     }
 
-    for (name, data, tests) in results {
-        let score = openentropy_tests::calculate_quality_score(tests);
+    for (name, data, battery) in results {
+        let tests = &battery.results;
+        let score = battery.quality_score;
         let passed = tests.iter().filter(|r| r.passed).count();
         report.push_str(&format!("## {name}\n\n"));
         report.push_str(&format!(
-            "- Samples: {} bytes\n- Score: {:.1}/100\n- Passed: {}/{}\n\n",
+            "- Samples: {} bytes\n- Score: {:.1}/100\n- Passed: {}/{}\n- Combined p (Stouffer, heuristic): {}\n\n",
             data.len(),
             score,
             passed,
-            tests.len()
+            tests.len(),
+            format_combined_p(battery.combined_p_value)
         ));
 
         report.push_str("| Test | P | Grade | p-value | Statistic | Details |\n");
@@ -665,3 +1247,73 @@ impl AnalyzeStatus {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use openentropy_core::ReplaySource;
+
+    use super::*;
+
+    fn result(name: &str, passed: bool, p: f64) -> TestResult {
+        TestResult {
+            name: name.to_string(),
+            passed,
+            p_value: Some(p),
+            statistic: 0.0,
+            details: String::new(),
+            grade: TestResult::grade_from_p(Some(p)),
+            skipped: false,
+        }
+    }
+
+    #[test]
+    fn repeat_collects_fresh_data_each_run() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let src = ReplaySource::from_seed("counting", 0).on_collect(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+        let runs = collect_runs(&src, 64, ConditioningMode::Raw, 3);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert_eq!(runs.len(), 3);
+        assert_ne!(runs[0], runs[1]);
+    }
+
+    #[test]
+    fn aggregate_flags_intermittent_tests() {
+        let reports: Vec<BatteryReport> = [(true, 0.2), (false, 0.001), (true, 0.6)]
+            .into_iter()
+            .map(|(flaky_passed, flaky_p)| {
+                BatteryReport::new(vec![
+                    result("steady", true, 0.5),
+                    result("flaky", flaky_passed, flaky_p),
+                ])
+            })
+            .collect();
+        let aggregate = aggregate_runs(&reports);
+        assert_eq!(aggregate.len(), 2);
+        assert_eq!((aggregate[0].passed, aggregate[0].runs), (3, 3));
+        assert!(!aggregate[0].intermittent);
+        assert_eq!(aggregate[1].passed, 2);
+        assert!(aggregate[1].intermittent);
+        assert_eq!(aggregate[1].median_p, Some(0.2));
+    }
+
+    #[test]
+    fn analysis_csv_leaves_throughput_empty_for_file_input() {
+        let data: Vec<u8> = (0..4096u32).map(|i| (i * 7) as u8).collect();
+        let result = analysis::analyze_bytes("file.bin", &data);
+        let interpretation = interpret_source(&result);
+        let row = analysis_csv_row(&result, &interpretation, None);
+        assert_eq!(row.len(), ANALYSIS_CSV_HEADER.len());
+        assert_eq!(row[0], "file.bin");
+        assert_eq!(row[1], "4096");
+        assert_eq!(row[5], "");
+        assert_eq!(row[9], interpretation.status.as_str());
+        let timed = analysis_csv_row(&result, &interpretation, Some(2048.0));
+        assert_eq!(timed[5], "2048.0");
+    }
+}
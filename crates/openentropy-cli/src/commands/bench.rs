@@ -1,10 +1,10 @@
 use std::collections::HashMap;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use openentropy_core::TelemetryWindowReport;
 use openentropy_core::conditioning::{quick_min_entropy, quick_quality, quick_shannon};
 use openentropy_core::platform::detect_available_sources;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Copy, Debug)]
 enum BenchProfile {
@@ -51,7 +51,7 @@ struct BenchRow {
     score: f64,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct BenchReport {
     generated_unix: u64,
     profile: String,
@@ -60,11 +60,11 @@ struct BenchReport {
     settings: BenchSettingsJson,
     sources: Vec<BenchSourceReport>,
     pool: Option<PoolQualityReport>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     telemetry_v1: Option<TelemetryWindowReport>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct BenchSettingsJson {
     samples_per_round: usize,
     rounds: usize,
@@ -72,7 +72,7 @@ struct BenchSettingsJson {
     timeout_sec: f64,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct BenchSourceReport {
     name: String,
     composite: bool,
@@ -87,7 +87,7 @@ struct BenchSourceReport {
     score: f64,
 }
 
-#[derive(Serialize, Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 struct PoolQualityReport {
     bytes: usize,
     shannon_entropy: f64,
@@ -98,6 +98,8 @@ struct PoolQualityReport {
 
 pub struct BenchCommandConfig<'a> {
     pub source_filter: Option<&'a str>,
+    pub exclude: Option<&'a str>,
+    pub sources_file: Option<&'a str>,
     pub conditioning: &'a str,
     pub source: Option<&'a str>,
     pub profile: &'a str,
@@ -107,8 +109,15 @@ pub struct BenchCommandConfig<'a> {
     pub timeout_sec: Option<f64>,
     pub rank_by: &'a str,
     pub output_path: Option<&'a str>,
+    pub csv_path: Option<&'a str>,
     pub include_pool_quality: bool,
     pub include_telemetry: bool,
+    pub compact: bool,
+    pub baseline_path: Option<&'a str>,
+    /// Percent drop in min-entropy or throughput counted as a regression.
+    pub regression_threshold_pct: f64,
+    /// `--fail-under`: minimum pooled min-entropy (bits/byte).
+    pub fail_under: Option<f64>,
 }
 
 pub fn run(cfg: BenchCommandConfig<'_>) {
@@ -120,6 +129,15 @@ pub fn run(cfg: BenchCommandConfig<'_>) {
         return;
     }
 
+    super::check_fail_under(cfg.fail_under, 8.0);
+    let baseline = cfg.baseline_path.map(|path| match load_baseline(path) {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("Failed to load baseline {path}: {e}");
+            std::process::exit(2);
+        }
+    });
+
     let profile = BenchProfile::parse(cfg.profile);
     let rank_by = RankBy::parse(cfg.rank_by);
     let telemetry = super::telemetry::TelemetryCapture::start(cfg.include_telemetry);
@@ -138,7 +156,9 @@ pub fn run(cfg: BenchCommandConfig<'_>) {
         settings.timeout_sec = v.max(0.1);
     }
 
-    let pool_instance = super::make_pool(cfg.source_filter);
+    let pool_instance = super::make_pool(cfg.source_filter, cfg.exclude, cfg.sources_file);
+    let cancel = pool_instance.cancellation_token();
+    super::cancel_on_ctrlc(&cancel);
     let infos = pool_instance.source_infos();
     let count = infos.len();
 
@@ -158,22 +178,49 @@ pub fn run(cfg: BenchCommandConfig<'_>) {
     );
     println!();
 
+    // The bar counts sources across every round and names each as it
+    // finishes; it ticks on its own while a slow source runs.
+    let total_rounds = settings.warmup_rounds + settings.rounds;
+    let progress = super::progress_bar((total_rounds * count) as u64);
+    progress.enable_steady_tick(Duration::from_millis(200));
+    let collect_round = |label: String, rounds_done: usize| {
+        progress.set_message(label.clone());
+        let collected = pool_instance.collect_all_parallel_n_with_progress(
+            settings.timeout_sec,
+            settings.samples_per_round,
+            |name, _| {
+                progress.inc(1);
+                progress.set_message(format!("{label}: {name}"));
+            },
+        );
+        // Timed-out and backed-off sources never report; skip past them.
+        progress.set_position(((rounds_done + 1) * count) as u64);
+        collected
+    };
+
     for i in 0..settings.warmup_rounds {
-        let _ =
-            pool_instance.collect_all_parallel_n(settings.timeout_sec, settings.samples_per_round);
-        println!("Warmup round {}/{}", i + 1, settings.warmup_rounds);
+        if cancel.is_cancelled() {
+            break;
+        }
+        collect_round(format!("warmup {}/{}", i + 1, settings.warmup_rounds), i);
+        progress.suspend(|| println!("Warmup round {}/{}", i + 1, settings.warmup_rounds));
     }
     if settings.warmup_rounds > 0 {
-        println!();
+        progress.suspend(|| println!());
     }
 
     let mut prev = snapshot_counters(&pool_instance.health_report().sources);
     let mut accum: HashMap<String, SourceAccumulator> = HashMap::new();
 
     for round_idx in 0..settings.rounds {
+        if cancel.is_cancelled() {
+            break;
+        }
         let t0 = Instant::now();
-        let collected =
-            pool_instance.collect_all_parallel_n(settings.timeout_sec, settings.samples_per_round);
+        let collected = collect_round(
+            format!("round {}/{}", round_idx + 1, settings.rounds),
+            settings.warmup_rounds + round_idx,
+        );
         let wall = t0.elapsed().as_secs_f64();
         let health = pool_instance.health_report();
 
@@ -201,14 +248,18 @@ pub fn run(cfg: BenchCommandConfig<'_>) {
             prev.insert(src.name.clone(), (src.bytes, src.failures));
         }
 
-        println!(
-            "Round {}/{} complete: collected {} bytes in {:.2}s",
-            round_idx + 1,
-            settings.rounds,
-            collected,
-            wall
-        );
+        progress.suspend(|| {
+            println!(
+                "Round {}/{} complete: collected {} bytes in {:.2}s",
+                round_idx + 1,
+                settings.rounds,
+                collected,
+                wall
+            )
+        });
     }
+    progress.finish_and_clear();
+    super::exit_if_interrupted(&cancel);
 
     let mut rows: Vec<BenchRow> = infos
         .iter()
@@ -357,8 +408,9 @@ pub fn run(cfg: BenchCommandConfig<'_>) {
     if let Some(ref window) = telemetry_report {
         super::telemetry::print_window_summary("bench", window);
     }
+    let pooled_min_entropy = pool_report.as_ref().map(|r| r.min_entropy);
 
-    if let Some(path) = cfg.output_path {
+    if cfg.output_path.is_some() || cfg.csv_path.is_some() || baseline.is_some() {
         let report = BenchReport {
             generated_unix: super::unix_timestamp_now(),
             profile: profile.as_str().to_string(),
@@ -390,7 +442,185 @@ pub fn run(cfg: BenchCommandConfig<'_>) {
             telemetry_v1: telemetry_report,
         };
 
-        super::write_json(&report, path, "Benchmark report");
+        if let Some(baseline) = &baseline {
+            let deltas = compare_reports(baseline, &report, cfg.regression_threshold_pct);
+            print_baseline_comparison(baseline, &report, &deltas, cfg.regression_threshold_pct);
+        }
+        if let Some(path) = cfg.output_path {
+            super::write_json(&report, path, "Benchmark report", cfg.compact);
+        }
+        if let Some(path) = cfg.csv_path {
+            super::write_csv(
+                &BENCH_CSV_HEADER,
+                &bench_csv_rows(&report.sources),
+                path,
+                "Benchmark",
+            );
+        }
+    }
+    if let (Some(threshold), Some(min_entropy)) = (cfg.fail_under, pooled_min_entropy) {
+        let pooled = [("pool".to_string(), min_entropy)];
+        super::enforce_fail_under("pooled min-entropy (bits/byte)", &pooled, threshold);
+    }
+}
+
+const BENCH_CSV_HEADER: [&str; 11] = [
+    "source",
+    "grade",
+    "min_entropy",
+    "shannon_entropy",
+    "throughput_bps",
+    "stability",
+    "score",
+    "success_rounds",
+    "failures",
+    "healthy",
+    "composite",
+];
+
+/// One CSV row per source, in report (ranked) order.
+fn bench_csv_rows(sources: &[BenchSourceReport]) -> Vec<Vec<String>> {
+    sources
+        .iter()
+        .map(|s| {
+            vec![
+                s.name.clone(),
+                s.grade.to_string(),
+                format!("{:.4}", s.avg_min_entropy),
+                format!("{:.4}", s.avg_shannon),
+                format!("{:.1}", s.avg_throughput_bps),
+                format!("{:.4}", s.stability),
+                format!("{:.4}", s.score),
+                s.success_rounds.to_string(),
+                s.failures.to_string(),
+                s.healthy.to_string(),
+                s.composite.to_string(),
+            ]
+        })
+        .collect()
+}
+
+// ---------------------------------------------------------------------------
+// Baseline comparison (--baseline)
+// ---------------------------------------------------------------------------
+
+/// Change in one source between a baseline report and the current run.
+#[derive(Debug, Clone, PartialEq)]
+struct SourceDelta {
+    name: String,
+    min_entropy_delta: f64,
+    /// Throughput change as a percentage of the baseline (0 when baseline is 0).
+    throughput_delta_pct: f64,
+    grade_before: char,
+    grade_after: char,
+    regressed: bool,
+}
+
+fn load_baseline(path: &str) -> Result<BenchReport, String> {
+    let raw = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&raw).map_err(|e| e.to_string())
+}
+
+/// Percent change from `before` to `after`; 0 when `before` is 0.
+fn pct_change(before: f64, after: f64) -> f64 {
+    if before.abs() < f64::EPSILON {
+        0.0
+    } else {
+        (after - before) / before.abs() * 100.0
+    }
+}
+
+/// Per-source deltas for sources present in both reports, in current order.
+///
+/// A source regresses when its min-entropy or throughput drops by more than
+/// `threshold_pct` percent, or its grade gets worse.
+fn compare_reports(
+    baseline: &BenchReport,
+    current: &BenchReport,
+    threshold_pct: f64,
+) -> Vec<SourceDelta> {
+    let before: HashMap<&str, &BenchSourceReport> = baseline
+        .sources
+        .iter()
+        .map(|s| (s.name.as_str(), s))
+        .collect();
+    current
+        .sources
+        .iter()
+        .filter_map(|now| {
+            let was = before.get(now.name.as_str())?;
+            let min_entropy_pct = pct_change(was.avg_min_entropy, now.avg_min_entropy);
+            let throughput_delta_pct = pct_change(was.avg_throughput_bps, now.avg_throughput_bps);
+            // Grades run 'A' (best) to 'F'; a larger char is worse.
+            let regressed = min_entropy_pct < -threshold_pct
+                || throughput_delta_pct < -threshold_pct
+                || now.grade > was.grade;
+            Some(SourceDelta {
+                name: now.name.clone(),
+                min_entropy_delta: now.avg_min_entropy - was.avg_min_entropy,
+                throughput_delta_pct,
+                grade_before: was.grade,
+                grade_after: now.grade,
+                regressed,
+            })
+        })
+        .collect()
+}
+
+fn indicator(delta: f64) -> &'static str {
+    if delta > 1e-9 {
+        "▲"
+    } else if delta < -1e-9 {
+        "▼"
+    } else {
+        "="
+    }
+}
+
+fn print_baseline_comparison(
+    baseline: &BenchReport,
+    current: &BenchReport,
+    deltas: &[SourceDelta],
+    threshold_pct: f64,
+) {
+    println!("\n{}", "=".repeat(72));
+    println!(
+        "Baseline comparison (baseline generated_unix={}, regression threshold {threshold_pct:.1}%)\n",
+        baseline.generated_unix
+    );
+    println!(
+        "{:<25} {:>11} {:>12} {:>6}  Status",
+        "Source", "ΔH∞", "ΔKB/s %", "Grade"
+    );
+    println!("{}", "-".repeat(72));
+    for d in deltas {
+        println!(
+            "{:<25} {} {:>+9.3} {} {:>+9.1}% {:>4}→{}  {}",
+            d.name,
+            indicator(d.min_entropy_delta),
+            d.min_entropy_delta,
+            indicator(d.throughput_delta_pct),
+            d.throughput_delta_pct,
+            d.grade_before,
+            d.grade_after,
+            if d.regressed { "REGRESSION" } else { "ok" }
+        );
+    }
+
+    let in_baseline = |name: &str| baseline.sources.iter().any(|s| s.name == name);
+    let in_current = |name: &str| current.sources.iter().any(|s| s.name == name);
+    for s in current.sources.iter().filter(|s| !in_baseline(&s.name)) {
+        println!("{:<25} (new, not in baseline)", s.name);
+    }
+    for s in baseline.sources.iter().filter(|s| !in_current(&s.name)) {
+        println!("{:<25} (missing from this run)", s.name);
+    }
+
+    let regressions = deltas.iter().filter(|d| d.regressed).count();
+    if regressions > 0 {
+        println!("\n{regressions} source(s) regressed against the baseline.");
+    } else {
+        println!("\nNo regressions against the baseline.");
     }
 }
 
@@ -536,3 +766,102 @@ fn run_single_source(
     println!("  Unique values:   {}", quality.unique_values);
     println!("  Time:            {:.3}s", elapsed.as_secs_f64());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(name: &str, min_entropy: f64, throughput: f64, grade: char) -> BenchSourceReport {
+        BenchSourceReport {
+            name: name.to_string(),
+            composite: false,
+            healthy: true,
+            success_rounds: 3,
+            failures: 0,
+            avg_shannon: 7.0,
+            avg_min_entropy: min_entropy,
+            avg_throughput_bps: throughput,
+            stability: 0.9,
+            grade,
+            score: 0.5,
+        }
+    }
+
+    fn report(sources: Vec<BenchSourceReport>) -> BenchReport {
+        BenchReport {
+            generated_unix: 1_700_000_000,
+            profile: "standard".to_string(),
+            conditioning: "sha256".to_string(),
+            rank_by: "balanced".to_string(),
+            settings: BenchSettingsJson {
+                samples_per_round: 2048,
+                rounds: 5,
+                warmup_rounds: 1,
+                timeout_sec: 3.0,
+            },
+            sources,
+            pool: None,
+            telemetry_v1: None,
+        }
+    }
+
+    #[test]
+    fn test_csv_has_header_and_one_row_per_source() {
+        let sources = vec![
+            source("clock_jitter", 6.5, 40_000.0, 'A'),
+            source("disk_io", 3.2, 900.0, 'C'),
+        ];
+        let csv = crate::commands::to_csv_string(&BENCH_CSV_HEADER, &bench_csv_rows(&sources));
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "source,grade,min_entropy,shannon_entropy,throughput_bps,stability,score,success_rounds,failures,healthy,composite"
+        );
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("clock_jitter,A,6.5000,7.0000,40000.0,"));
+        assert!(lines[2].starts_with("disk_io,C,"));
+    }
+
+    #[test]
+    fn test_identical_baseline_has_zero_deltas() {
+        let current = report(vec![
+            source("clock_jitter", 6.5, 40_000.0, 'A'),
+            source("disk_io", 3.2, 900.0, 'C'),
+        ]);
+        // Round-trip through JSON, as a saved --output report would be.
+        let json = serde_json::to_string(&current).unwrap();
+        let baseline: BenchReport = serde_json::from_str(&json).unwrap();
+
+        let deltas = compare_reports(&baseline, &current, 10.0);
+        assert_eq!(deltas.len(), 2);
+        for d in &deltas {
+            assert_eq!(d.min_entropy_delta, 0.0);
+            assert_eq!(d.throughput_delta_pct, 0.0);
+            assert_eq!(d.grade_before, d.grade_after);
+            assert!(!d.regressed);
+        }
+    }
+
+    #[test]
+    fn test_baseline_flags_regressions_beyond_threshold() {
+        let baseline = report(vec![
+            source("a", 6.0, 1000.0, 'A'),
+            source("b", 6.0, 1000.0, 'A'),
+            source("c", 6.0, 1000.0, 'A'),
+        ]);
+        let current = report(vec![
+            source("a", 5.7, 950.0, 'A'), // -5% / -5%: within threshold
+            source("b", 6.0, 800.0, 'A'), // -20% throughput
+            source("c", 6.0, 1000.0, 'B'),
+            source("new", 1.0, 1.0, 'F'),
+        ]);
+        let deltas = compare_reports(&baseline, &current, 10.0);
+        let regressed: Vec<&str> = deltas
+            .iter()
+            .filter(|d| d.regressed)
+            .map(|d| d.name.as_str())
+            .collect();
+        assert_eq!(regressed, vec!["b", "c"]);
+        assert!((deltas[1].throughput_delta_pct + 20.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,168 @@
+//! `openentropy explain` — describe a source's physics and failure signature.
+//!
+//! Reads only static [`SourceInfo`] metadata; no source is collected from.
+
+use openentropy_core::{EntropySource, SourceInfo};
+
+/// Sources to explain: the one named `source` (exact match), or all built-in
+/// and registered sources, available or not.
+fn select(
+    all: Vec<Box<dyn EntropySource>>,
+    source: Option<&str>,
+) -> Result<Vec<Box<dyn EntropySource>>, String> {
+    let Some(name) = source else {
+        return Ok(all);
+    };
+    let name = name.to_lowercase();
+    let matched: Vec<_> = all.into_iter().filter(|s| s.name() == name).collect();
+    if matched.is_empty() {
+        Err(format!(
+            "unknown source '{name}' (see 'openentropy explain' for the full list)"
+        ))
+    } else {
+        Ok(matched)
+    }
+}
+
+fn describe(info: &SourceInfo) -> serde_json::Value {
+    serde_json::json!({
+        "name": info.name,
+        "description": info.description,
+        "physics": info.physics,
+        "failure_hint": info.failure_hint,
+        "category": info.category.to_string(),
+        "platform": info.platform.to_string(),
+        "requirements": info.requirements.iter().map(|r| r.to_string()).collect::<Vec<_>>(),
+        "entropy_rate_estimate": info.entropy_rate_estimate,
+        "typical_latency_ms": info.typical_latency_ms,
+        "cost_class": info.cost_class.to_string(),
+        "composite": info.composite,
+    })
+}
+
+fn print_info(info: &SourceInfo) {
+    let requirements: Vec<String> = info.requirements.iter().map(|r| r.to_string()).collect();
+    println!("{} — {}", info.name, info.description);
+    println!("  Category:      {}", info.category);
+    println!(
+        "  Platform:      {}{}",
+        info.platform,
+        if requirements.is_empty() {
+            String::new()
+        } else {
+            format!(" (requires {})", requirements.join(", "))
+        }
+    );
+    println!(
+        "  Entropy rate:  ~{} bits/sample (estimate)",
+        info.entropy_rate_estimate
+    );
+    println!(
+        "  Latency:       ~{} ms per collect ({})",
+        info.typical_latency_ms, info.cost_class
+    );
+    if info.composite {
+        println!("  Composite:     combines other sources");
+    }
+    println!("  Physics:       {}", info.physics);
+    println!("  When it fails: {}", info.failure_hint);
+}
+
+pub fn run(source: Option<&str>, json: bool) {
+    let sources = match select(openentropy_core::sources::all_sources(), source) {
+        Ok(sources) => sources,
+        Err(e) => {
+            eprintln!("Error: {e}");
+            std::process::exit(2);
+        }
+    };
+
+    if json {
+        let value = if source.is_some() {
+            describe(sources[0].info())
+        } else {
+            serde_json::Value::Array(sources.iter().map(|s| describe(s.info())).collect())
+        };
+        match super::to_json_string(&value, false) {
+            Ok(s) => println!("{s}"),
+            Err(e) => {
+                eprintln!("Error: {e}");
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    for (i, src) in sources.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        print_info(src.info());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use openentropy_core::{CostClass, Platform, SourceCategory};
+
+    use super::*;
+
+    /// Panics if collected from, so explaining it proves no data is gathered.
+    struct NoCollect;
+
+    static NO_COLLECT_INFO: SourceInfo = SourceInfo {
+        name: "clock_jitter",
+        description: "stand-in",
+        physics: "static metadata only",
+        failure_hint: "never collected",
+        category: SourceCategory::Timing,
+        platform: Platform::Any,
+        requirements: &[],
+        entropy_rate_estimate: 0.5,
+        typical_latency_ms: 1.0,
+        cost_class: CostClass::Fast,
+        composite: false,
+    };
+
+    impl EntropySource for NoCollect {
+        fn info(&self) -> &SourceInfo {
+            &NO_COLLECT_INFO
+        }
+        fn is_available(&self) -> bool {
+            panic!("explain must not probe availability");
+        }
+        fn collect(&self, _n_samples: usize) -> Vec<u8> {
+            panic!("explain must not collect");
+        }
+    }
+
+    #[test]
+    fn explain_clock_jitter_reads_metadata_only() {
+        let selected = select(vec![Box::new(NoCollect)], Some("clock_jitter")).unwrap();
+        let value = describe(selected[0].info());
+        assert_eq!(value["name"], "clock_jitter");
+        assert_eq!(value["failure_hint"], "never collected");
+
+        let real = select(
+            openentropy_core::sources::all_sources(),
+            Some("clock_jitter"),
+        )
+        .unwrap();
+        let value = describe(real[0].info());
+        assert_eq!(value["category"], "timing");
+        assert!(!value["physics"].as_str().unwrap().is_empty());
+        assert!(!value["failure_hint"].as_str().unwrap().is_empty());
+    }
+
+    #[test]
+    fn explain_rejects_unknown_source() {
+        assert!(select(openentropy_core::sources::all_sources(), Some("nope")).is_err());
+    }
+
+    #[test]
+    fn every_builtin_source_has_a_failure_hint() {
+        for src in openentropy_core::sources::all_sources() {
+            assert!(!src.info().failure_hint.is_empty(), "{}", src.name());
+        }
+    }
+}
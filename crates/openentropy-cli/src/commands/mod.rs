@@ -1,5 +1,6 @@
 pub mod analyze;
 pub mod bench;
+pub mod explain;
 pub mod monitor;
 pub mod record;
 pub mod scan;
@@ -8,100 +9,252 @@ pub mod sessions;
 pub mod stream;
 pub mod telemetry;
 
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::io::IsTerminal;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use openentropy_core::EntropyPool;
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
 use openentropy_core::analysis::CrossCorrMatrix;
-use openentropy_core::conditioning::ConditioningMode;
-
-/// Sources that collect in <2 seconds — safe for real-time use.
-const FAST_SOURCES: &[&str] = &[
-    "clock_jitter",
-    "mach_timing",
-    "sleep_jitter",
-    "sysctl_deltas",
-    "vmstat_deltas",
-    "disk_io",
-    "memory_timing",
-    "dram_row_buffer",
-    "cache_contention",
-    "page_fault_timing",
-    "speculative_execution",
-    "cpu_io_beat",
-    "cpu_memory_beat",
-    "hash_timing",
-    "compression_timing",
-    "dispatch_queue",
-    "vm_page_timing",
-    // Frontier sources (all < 0.1s)
-    "amx_timing",
-    "thread_lifecycle",
-    "mach_ipc",
-    "tlb_shootdown",
-    "pipe_buffer",
-    "kqueue_events",
-    "dvfs_race",
-    "cas_contention",
-    "denormal_timing",
-    "audio_pll_timing",
-    "usb_timing",
-    // Unprecedented entropy sources (fast ones only)
-    "nvme_latency",
-    "pdn_resonance",
-    // Independent oscillator sources
-    "counter_beat",
-    "display_pll",
-    "pcie_pll",
-    // GPU sources (moderate speed)
-    "gpu_divergence",
-    "iosurface_crossing",
-];
+use openentropy_core::conditioning::{ConditioningMode, TOEPLITZ_DEFAULT_OUTPUT_BITS};
+use openentropy_core::{CancellationToken, CostClass, EntropyPool, EntropySource};
 
-/// Build an EntropyPool, optionally filtering sources by name.
-/// If no filter is given, only fast sources (<2s) are included to avoid hangs.
-/// Use `--sources all` to include every available source.
-pub fn make_pool(source_filter: Option<&str>) -> EntropyPool {
-    let mut pool = EntropyPool::new(None);
+/// Whether a source is cheap enough for default, real-time pools.
+fn is_fast(source: &dyn EntropySource) -> bool {
+    source.info().cost_class == CostClass::Fast
+}
 
-    let sources = openentropy_core::detect_available_sources();
+/// Cleared by `--no-color` or `NO_COLOR`; see [`set_color`].
+static COLOR: AtomicBool = AtomicBool::new(true);
 
-    if let Some(filter) = source_filter {
-        if filter == "all" {
-            // Include everything
-            for source in sources {
-                pool.add_source(source, 1.0);
-            }
-        } else {
-            let names: Vec<&str> = filter.split(',').map(|s| s.trim()).collect();
-            for source in sources {
-                let src_name = source.name().to_lowercase();
-                if names.iter().any(|n| src_name.contains(&n.to_lowercase())) {
-                    pool.add_source(source, 1.0);
-                }
-            }
+/// Whether styling was turned off: the `--no-color` flag, or `NO_COLOR` set
+/// to any non-empty value (<https://no-color.org>).
+pub fn no_color_requested(flag: bool, no_color_env: Option<&std::ffi::OsStr>) -> bool {
+    flag || no_color_env.is_some_and(|v| !v.is_empty())
+}
+
+/// Apply the global `--no-color` flag and `NO_COLOR`. Call before
+/// [`init_logging`] so log lines are styled accordingly.
+pub fn set_color(no_color_flag: bool) {
+    let off = no_color_requested(no_color_flag, std::env::var_os("NO_COLOR").as_deref());
+    COLOR.store(!off, Ordering::Relaxed);
+}
+
+/// Whether output may carry ANSI styling. Every colored writer (log lines,
+/// the monitor dashboard) checks this.
+pub fn color_enabled() -> bool {
+    COLOR.load(Ordering::Relaxed)
+}
+
+/// Whether to draw progress bars: stderr is a terminal and color is on, so
+/// piped or redirected output never carries bar redraws.
+pub fn progress_enabled() -> bool {
+    color_enabled() && std::io::stderr().is_terminal()
+}
+
+/// A stderr progress bar over `len` units, or a hidden one when
+/// [`progress_enabled`] is false. Print through [`ProgressBar::suspend`]
+/// while it is live.
+pub fn progress_bar(len: u64) -> ProgressBar {
+    if !progress_enabled() {
+        return ProgressBar::hidden();
+    }
+    let style = ProgressStyle::with_template(
+        "  {elapsed_precise} [{bar:30}] {percent:>3}% ETA {eta:<4} {msg}",
+    )
+    .expect("valid progress template")
+    .progress_chars("=> ");
+    ProgressBar::with_draw_target(Some(len), ProgressDrawTarget::stderr()).with_style(style)
+}
+
+/// Like [`progress_bar`] for open-ended work: a spinner with elapsed time.
+pub fn progress_spinner() -> ProgressBar {
+    if !progress_enabled() {
+        return ProgressBar::hidden();
+    }
+    let style = ProgressStyle::with_template("  {spinner} {elapsed_precise} {msg}")
+        .expect("valid progress template");
+    ProgressBar::with_draw_target(None, ProgressDrawTarget::stderr()).with_style(style)
+}
+
+/// Seed set by the global `--seed` option; see [`set_pool_seed`].
+static POOL_SEED: OnceLock<Vec<u8>> = OnceLock::new();
+
+/// Decode a `--seed` value: a non-empty, even-length hex string.
+pub fn parse_hex_seed(s: &str) -> Result<Vec<u8>, String> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err("seed is empty".to_string());
+    }
+    if !s.len().is_multiple_of(2) {
+        return Err(format!("odd number of hex digits ({})", s.len()));
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| {
+            s.get(i..i + 2)
+                .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                .ok_or_else(|| format!("invalid hex at position {i}"))
+        })
+        .collect()
+}
+
+/// Seed every pool built afterwards by [`make_pool`] with the decoded
+/// `--seed` hex. Exits with code 2 if it is not valid hex.
+pub fn set_pool_seed(hex: &str) {
+    match parse_hex_seed(hex) {
+        Ok(seed) => {
+            let _ = POOL_SEED.set(seed);
         }
-    } else {
-        // Default: fast sources only
-        for source in sources {
-            if FAST_SOURCES.contains(&source.name()) {
-                pool.add_source(source, 1.0);
-            }
+        Err(e) => {
+            eprintln!("Invalid --seed '{hex}': {e}");
+            std::process::exit(2);
         }
     }
+}
+
+/// Build an EntropyPool, optionally filtering sources by name.
+/// If no filter is given, only [`CostClass::Fast`] sources are included to avoid hangs.
+/// Use `--sources all` to include every available source. `exclude` is
+/// applied after inclusion and always wins (see [`filter_sources`]).
+/// `sources_file` (`--sources-file`) adds the sources and weights of a
+/// [`SourceProfile`] on top of `--sources` (see [`select_sources`]).
+/// The pool's initial state comes from `--seed` when given.
+pub fn make_pool(
+    source_filter: Option<&str>,
+    exclude: Option<&str>,
+    sources_file: Option<&str>,
+) -> EntropyPool {
+    let profile = sources_file.map(SourceProfile::load);
+    let mut pool = EntropyPool::new(POOL_SEED.get().map(Vec::as_slice));
+    let sources = openentropy_core::detect_available_sources();
+    for (source, weight) in select_sources(sources, source_filter, exclude, profile.as_ref()) {
+        pool.add_source(source, weight);
+    }
 
-    if pool.source_count() == 0 {
+    if pool.source_count() == 0 && (source_filter.is_some() || profile.is_some()) {
         eprintln!("Warning: no sources matched filter, using all fast sources");
-        return make_pool(None);
+        return make_pool(None, exclude, None);
     }
     pool
 }
 
+/// Named source set loaded from `--sources-file`.
+///
+/// The file is JSON: `{"sources": [...]}`, where each entry is a source name
+/// or `{"name": ..., "weight": ...}` (weight defaults to 1.0). Names match
+/// exactly, ignoring case, and must name a known source (available here or
+/// not).
+#[derive(Debug)]
+pub struct SourceProfile {
+    sources: Vec<(String, f64)>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct SourceProfileFile {
+    sources: Vec<SourceProfileEntry>,
+}
+
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum SourceProfileEntry {
+    Name(String),
+    Weighted { name: String, weight: Option<f64> },
+}
+
+impl SourceProfile {
+    /// Read a profile file. Exits with code 2 if it is missing, invalid or
+    /// lists a source name that no source has.
+    pub fn load(path: &str) -> Self {
+        let known: Vec<&str> = openentropy_core::sources::all_sources()
+            .iter()
+            .map(|s| s.name())
+            .collect();
+        let parsed = std::fs::read_to_string(path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| Self::parse(&text))
+            .and_then(|profile| profile.check_known(&known).map(|()| profile));
+        parsed.unwrap_or_else(|e| {
+            eprintln!("Invalid --sources-file '{path}': {e}");
+            std::process::exit(2);
+        })
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let file: SourceProfileFile = serde_json::from_str(text).map_err(|e| e.to_string())?;
+        let mut sources = Vec::with_capacity(file.sources.len());
+        for entry in file.sources {
+            let (name, weight) = match entry {
+                SourceProfileEntry::Name(name) => (name, 1.0),
+                SourceProfileEntry::Weighted { name, weight } => (name, weight.unwrap_or(1.0)),
+            };
+            if !(weight.is_finite() && weight > 0.0) {
+                return Err(format!(
+                    "weight for '{name}' must be positive, got {weight}"
+                ));
+            }
+            sources.push((name.trim().to_lowercase(), weight));
+        }
+        if sources.is_empty() {
+            return Err("no sources listed".to_string());
+        }
+        Ok(Self { sources })
+    }
+
+    /// Reject names not in `known`, so a typo doesn't silently drop a source.
+    fn check_known(&self, known: &[&str]) -> Result<(), String> {
+        let unknown: Vec<&str> = self
+            .sources
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .filter(|name| !known.iter().any(|k| k.eq_ignore_ascii_case(name)))
+            .collect();
+        if unknown.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "unknown source(s): {} (see `openentropy scan`)",
+                unknown.join(", ")
+            ))
+        }
+    }
+
+    /// Weight for `name`, or `None` if the profile doesn't list it.
+    fn weight_of(&self, name: &str) -> Option<f64> {
+        let name = name.to_lowercase();
+        self.sources
+            .iter()
+            .find_map(|(n, w)| (*n == name).then_some(*w))
+    }
+}
+
+/// Install the stderr log subscriber; `filter` is an `EnvFilter` directive
+/// such as `info` or `openentropy_core=debug`. Exits with code 2 if invalid.
+pub fn init_logging(filter: &str) {
+    let filter = match tracing_subscriber::EnvFilter::try_new(filter) {
+        Ok(filter) => filter,
+        Err(e) => {
+            eprintln!("Invalid --log-level '{filter}': {e}");
+            std::process::exit(2);
+        }
+    };
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .with_ansi(color_enabled())
+        .init();
+}
+
 /// Parse a conditioning mode string into the enum (case-insensitive).
 pub fn parse_conditioning(s: &str) -> ConditioningMode {
     match s.to_lowercase().as_str() {
         "raw" => ConditioningMode::Raw,
         "vonneumann" | "von_neumann" | "vn" => ConditioningMode::VonNeumann,
         "sha256" | "sha" => ConditioningMode::Sha256,
+        "toeplitz" => ConditioningMode::Toeplitz {
+            output_bits: TOEPLITZ_DEFAULT_OUTPUT_BITS,
+        },
         _ => {
             eprintln!("Unknown conditioning mode '{s}', using sha256");
             ConditioningMode::Sha256
@@ -109,6 +262,52 @@ pub fn parse_conditioning(s: &str) -> ConditioningMode {
     }
 }
 
+/// Parse a duration string like "5m", "30s", "1h", "100ms".
+pub fn parse_duration(s: &str) -> Duration {
+    let s = s.trim();
+
+    let (numeric, multiplier) = if let Some(rest) = s.strip_suffix("ms") {
+        (rest, 1u64)
+    } else if let Some(rest) = s.strip_suffix('s') {
+        (rest, 1000)
+    } else if let Some(rest) = s.strip_suffix('m') {
+        (rest, 60_000)
+    } else if let Some(rest) = s.strip_suffix('h') {
+        (rest, 3_600_000)
+    } else {
+        // Assume seconds
+        (s, 1000)
+    };
+
+    let value: u64 = numeric.parse().unwrap_or_else(|_| {
+        eprintln!("Invalid duration: {s}");
+        std::process::exit(1);
+    });
+
+    Duration::from_millis(value * multiplier)
+}
+
+/// Exit code after Ctrl+C stops a command early (128 + SIGINT).
+pub const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// Cancel `cancel` on Ctrl+C instead of killing the process.
+///
+/// Pass a pool's [`EntropyPool::cancellation_token`] so Ctrl+C also abandons
+/// its in-progress collection. The command stops at its next check and calls
+/// [`exit_if_interrupted`].
+pub fn cancel_on_ctrlc(cancel: &CancellationToken) {
+    let handler = cancel.clone();
+    ctrlc::set_handler(move || handler.cancel()).expect("Error setting Ctrl+C handler");
+}
+
+/// Exit with [`INTERRUPTED_EXIT_CODE`] if Ctrl+C cancelled `cancel`.
+pub fn exit_if_interrupted(cancel: &CancellationToken) {
+    if cancel.is_cancelled() {
+        eprintln!("\nInterrupted.");
+        std::process::exit(INTERRUPTED_EXIT_CODE);
+    }
+}
+
 /// Current Unix timestamp in seconds.
 pub fn unix_timestamp_now() -> u64 {
     SystemTime::now()
@@ -117,33 +316,61 @@ pub fn unix_timestamp_now() -> u64 {
         .as_secs()
 }
 
+/// Whether `name` contains any entry of a comma-separated list
+/// (case-insensitive partial match, the `--sources` syntax).
+fn matches_list(name: &str, list: &str) -> bool {
+    let name = name.to_lowercase();
+    list.split(',')
+        .map(|n| n.trim().to_lowercase())
+        .filter(|n| !n.is_empty())
+        .any(|n| name.contains(&n))
+}
+
 /// Filter a list of entropy sources using the standard filter syntax.
 /// - `None` → fast sources only
 /// - `Some("all")` → everything
 /// - `Some("a,b")` → comma-separated partial name match
+///
+/// `exclude` (`--exclude`, same syntax) then drops matching sources, so a
+/// source named by both `--sources` and `--exclude` is excluded. `profile`
+/// (`--sources-file`) adds its sources; see [`select_sources`].
 pub fn filter_sources(
-    all_sources: Vec<Box<dyn openentropy_core::EntropySource>>,
+    all_sources: Vec<Box<dyn EntropySource>>,
     source_filter: Option<&str>,
-) -> Vec<Box<dyn openentropy_core::EntropySource>> {
-    if let Some(filter) = source_filter {
-        if filter == "all" {
-            all_sources
-        } else {
-            let names: Vec<&str> = filter.split(',').map(|s| s.trim()).collect();
-            all_sources
-                .into_iter()
-                .filter(|s| {
-                    let src_name = s.name().to_lowercase();
-                    names.iter().any(|n| src_name.contains(&n.to_lowercase()))
-                })
-                .collect()
-        }
-    } else {
-        all_sources
-            .into_iter()
-            .filter(|s| FAST_SOURCES.contains(&s.name()))
-            .collect()
-    }
+    exclude: Option<&str>,
+    profile: Option<&SourceProfile>,
+) -> Vec<Box<dyn EntropySource>> {
+    select_sources(all_sources, source_filter, exclude, profile)
+        .into_iter()
+        .map(|(source, _)| source)
+        .collect()
+}
+
+/// [`filter_sources`] with an optional `--sources-file` profile, returning
+/// each kept source with its pool weight.
+///
+/// With a profile, a source is kept if the profile lists it or `--sources`
+/// matches it; there is no fast-only default. Profile sources take the
+/// profile's weight, others 1.0. `exclude` still wins.
+pub fn select_sources(
+    all_sources: Vec<Box<dyn EntropySource>>,
+    source_filter: Option<&str>,
+    exclude: Option<&str>,
+    profile: Option<&SourceProfile>,
+) -> Vec<(Box<dyn EntropySource>, f64)> {
+    all_sources
+        .into_iter()
+        .filter_map(|s| {
+            let listed = profile.and_then(|p| p.weight_of(s.name()));
+            let matched = match source_filter {
+                Some("all") => true,
+                Some(filter) => matches_list(s.name(), filter),
+                None => profile.is_none() && is_fast(s.as_ref()),
+            };
+            (listed.is_some() || matched).then(|| (s, listed.unwrap_or(1.0)))
+        })
+        .filter(|(s, _)| !exclude.is_some_and(|list| matches_list(s.name(), list)))
+        .collect()
 }
 
 /// Print a cross-correlation matrix summary to stdout.
@@ -171,9 +398,49 @@ pub fn print_cross_correlation(matrix: &CrossCorrMatrix, source_count: usize) {
     }
 }
 
-/// Write a serializable value as pretty JSON to a file.
-pub fn write_json<T: serde::Serialize>(value: &T, path: &str, label: &str) {
-    match serde_json::to_string_pretty(value) {
+/// Serialize to pretty JSON, or single-line JSON when `compact` is set.
+pub fn to_json_string<T: serde::Serialize>(value: &T, compact: bool) -> serde_json::Result<String> {
+    if compact {
+        serde_json::to_string(value)
+    } else {
+        serde_json::to_string_pretty(value)
+    }
+}
+
+/// Quote a CSV field if it contains a comma, quote or line break (RFC 4180).
+pub fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Render a header and rows as CSV text, one line per row.
+pub fn to_csv_string(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut out = String::new();
+    let header: Vec<String> = header.iter().map(|h| csv_field(h)).collect();
+    out.push_str(&header.join(","));
+    out.push('\n');
+    for row in rows {
+        let fields: Vec<String> = row.iter().map(|f| csv_field(f)).collect();
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Write a header and rows as a CSV file.
+pub fn write_csv(header: &[&str], rows: &[Vec<String>], path: &str, label: &str) {
+    match std::fs::write(path, to_csv_string(header, rows)) {
+        Ok(()) => println!("\n{label} CSV written to {path}"),
+        Err(e) => eprintln!("\nFailed to write {path}: {e}"),
+    }
+}
+
+/// Write a serializable value as JSON to a file (pretty unless `compact`).
+pub fn write_json<T: serde::Serialize>(value: &T, path: &str, label: &str, compact: bool) {
+    match to_json_string(value, compact) {
         Ok(json) => match std::fs::write(path, json) {
             Ok(()) => println!("\n{label} written to {path}"),
             Err(e) => eprintln!("\nFailed to write {path}: {e}"),
@@ -182,10 +449,81 @@ pub fn write_json<T: serde::Serialize>(value: &T, path: &str, label: &str) {
     }
 }
 
+/// Reject a `--fail-under` threshold outside `0..=max` (exit 2).
+pub fn check_fail_under(threshold: Option<f64>, max: f64) {
+    if let Some(t) = threshold
+        && !(0.0..=max).contains(&t)
+    {
+        eprintln!("Error: --fail-under must be between 0 and {max}, got {t}");
+        std::process::exit(2);
+    }
+}
+
+/// `--fail-under`: exit 1 naming every `(name, value)` below `threshold`;
+/// `metric` describes the value, e.g. "quality score".
+pub fn enforce_fail_under(metric: &str, values: &[(String, f64)], threshold: f64) {
+    let failing: Vec<_> = values.iter().filter(|(_, v)| *v < threshold).collect();
+    if failing.is_empty() {
+        println!("\nPASS: every {metric} is at least {threshold} (--fail-under)");
+        return;
+    }
+    for (name, value) in &failing {
+        eprintln!("FAIL: {name} {metric} {value:.2} is below --fail-under {threshold}");
+    }
+    std::process::exit(1);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn no_color_env_must_be_non_empty() {
+        use std::ffi::OsStr;
+        assert!(!no_color_requested(false, None));
+        assert!(!no_color_requested(false, Some(OsStr::new(""))));
+        assert!(no_color_requested(false, Some(OsStr::new("1"))));
+        assert!(no_color_requested(true, None));
+    }
+
+    #[test]
+    fn parse_hex_seed_validates_input() {
+        assert_eq!(parse_hex_seed("00fFa1").unwrap(), vec![0x00, 0xff, 0xa1]);
+        assert_eq!(parse_hex_seed(" 0102 ").unwrap(), vec![1, 2]);
+        assert!(parse_hex_seed("").is_err());
+        assert!(parse_hex_seed("abc").is_err());
+        assert!(parse_hex_seed("zz").is_err());
+        assert!(parse_hex_seed("é0").is_err());
+    }
+
+    // -----------------------------------------------------------------------
+    // JSON output tests
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_compact_json_has_no_newlines() {
+        let value = serde_json::json!({
+            "sources": [{"name": "a", "bytes": 1}, {"name": "b", "bytes": 2}],
+            "nested": {"ok": true},
+        });
+        let compact = to_json_string(&value, true).unwrap();
+        assert!(!compact.contains('\n'));
+        let pretty = to_json_string(&value, false).unwrap();
+        assert!(pretty.contains('\n'));
+        let a: serde_json::Value = serde_json::from_str(&compact).unwrap();
+        let b: serde_json::Value = serde_json::from_str(&pretty).unwrap();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_csv_quotes_only_when_needed() {
+        assert_eq!(csv_field("clock_jitter"), "clock_jitter");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        let csv = to_csv_string(&["name", "note"], &[vec!["x".into(), "1,2".into()]]);
+        assert_eq!(csv, "name,note\nx,\"1,2\"\n");
+    }
+
     // -----------------------------------------------------------------------
     // parse_conditioning tests
     // -----------------------------------------------------------------------
@@ -212,6 +550,10 @@ mod tests {
     fn test_parse_sha256_variants() {
         assert_eq!(parse_conditioning("sha256"), ConditioningMode::Sha256);
         assert_eq!(parse_conditioning("sha"), ConditioningMode::Sha256);
+        assert_eq!(
+            parse_conditioning("toeplitz"),
+            ConditioningMode::Toeplitz { output_bits: 256 }
+        );
     }
 
     #[test]
@@ -231,29 +573,170 @@ mod tests {
     }
 
     // -----------------------------------------------------------------------
-    // FAST_SOURCES constant tests
+    // Cost-class filter tests
     // -----------------------------------------------------------------------
 
+    /// The hand-maintained allowlist the cost-class filter replaced.
+    const LEGACY_FAST_SOURCES: &[&str] = &[
+        "clock_jitter",
+        "mach_timing",
+        "sleep_jitter",
+        "sysctl_deltas",
+        "vmstat_deltas",
+        "disk_io",
+        "memory_timing",
+        "dram_row_buffer",
+        "cache_contention",
+        "page_fault_timing",
+        "speculative_execution",
+        "cpu_io_beat",
+        "cpu_memory_beat",
+        "hash_timing",
+        "compression_timing",
+        "dispatch_queue",
+        "vm_page_timing",
+        "amx_timing",
+        "thread_lifecycle",
+        "mach_ipc",
+        "tlb_shootdown",
+        "pipe_buffer",
+        "kqueue_events",
+        "dvfs_race",
+        "cas_contention",
+        "denormal_timing",
+        "audio_pll_timing",
+        "usb_timing",
+        "nvme_latency",
+        "pdn_resonance",
+        "counter_beat",
+        "display_pll",
+        "pcie_pll",
+        "gpu_divergence",
+        "iosurface_crossing",
+    ];
+
+    fn registry_cost(name: &str) -> Option<CostClass> {
+        openentropy_core::sources::all_sources()
+            .iter()
+            .find(|s| s.name() == name)
+            .map(|s| s.info().cost_class)
+    }
+
     #[test]
-    fn test_fast_sources_not_empty() {
-        assert!(!FAST_SOURCES.is_empty());
+    fn test_legacy_fast_sources_are_fast() {
+        for name in LEGACY_FAST_SOURCES {
+            assert_eq!(registry_cost(name), Some(CostClass::Fast), "{name}");
+        }
     }
 
     #[test]
-    fn test_fast_sources_contains_expected() {
-        assert!(FAST_SOURCES.contains(&"clock_jitter"));
-        assert!(FAST_SOURCES.contains(&"mach_timing"));
-        assert!(FAST_SOURCES.contains(&"sleep_jitter"));
-        assert!(FAST_SOURCES.contains(&"disk_io"));
+    fn test_slow_sources_not_fast() {
+        for name in [
+            "audio_noise",
+            "camera_noise",
+            "bluetooth_noise",
+            "wifi_rssi",
+        ] {
+            let cost = registry_cost(name).expect(name);
+            assert_ne!(cost, CostClass::Fast, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_filter_sources_default_keeps_only_fast() {
+        let kept = filter_sources(openentropy_core::sources::all_sources(), None, None, None);
+        assert!(!kept.is_empty());
+        assert!(kept.iter().all(|s| is_fast(s.as_ref())));
+    }
+
+    fn names(sources: &[Box<dyn EntropySource>]) -> Vec<&'static str> {
+        sources.iter().map(|s| s.name()).collect()
+    }
+
+    #[test]
+    fn test_exclude_omits_exactly_named_source() {
+        let all = names(&openentropy_core::sources::all_sources());
+        let kept = filter_sources(
+            openentropy_core::sources::all_sources(),
+            Some("all"),
+            Some("clock_jitter"),
+            None,
+        );
+        let kept = names(&kept);
+        assert_eq!(kept.len(), all.len() - 1);
+        assert!(!kept.contains(&"clock_jitter"));
+        let mut expected = all.clone();
+        expected.retain(|&n| n != "clock_jitter");
+        assert_eq!(kept, expected);
     }
 
     #[test]
-    fn test_fast_sources_excludes_slow() {
-        // These slow sources should not be in the fast list
-        assert!(!FAST_SOURCES.contains(&"audio_noise"));
-        assert!(!FAST_SOURCES.contains(&"camera_noise"));
-        assert!(!FAST_SOURCES.contains(&"bluetooth_rssi"));
-        assert!(!FAST_SOURCES.contains(&"wifi_rssi"));
+    fn test_exclude_wins_over_sources() {
+        let kept = filter_sources(
+            openentropy_core::sources::all_sources(),
+            Some("clock_jitter,dns_timing"),
+            Some("CLOCK_JITTER, "),
+            None,
+        );
+        assert_eq!(names(&kept), vec!["dns_timing"]);
+    }
+
+    #[test]
+    fn test_sources_file_sets_sources_and_weights() {
+        let path =
+            std::env::temp_dir().join(format!("openentropy-sources-{}.json", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"{"sources": [{"name": "clock_jitter", "weight": 2.5}, "DNS_TIMING"]}"#,
+        )
+        .unwrap();
+        let profile = SourceProfile::load(path.to_str().unwrap());
+        std::fs::remove_file(&path).unwrap();
+
+        let kept = select_sources(
+            openentropy_core::sources::all_sources(),
+            None,
+            None,
+            Some(&profile),
+        );
+        let kept: Vec<(&str, f64)> = kept.iter().map(|(s, w)| (s.name(), *w)).collect();
+        assert_eq!(kept, vec![("clock_jitter", 2.5), ("dns_timing", 1.0)]);
+    }
+
+    #[test]
+    fn test_sources_file_merges_with_flags() {
+        let profile =
+            SourceProfile::parse(r#"{"sources": ["clock_jitter", "dns_timing"]}"#).unwrap();
+        let kept = select_sources(
+            openentropy_core::sources::all_sources(),
+            Some("sleep_jitter"),
+            Some("dns_timing"),
+            Some(&profile),
+        );
+        let mut kept: Vec<&str> = kept.iter().map(|(s, _)| s.name()).collect();
+        kept.sort_unstable();
+        assert_eq!(kept, vec!["clock_jitter", "sleep_jitter"]);
+    }
+
+    #[test]
+    fn test_sources_file_rejects_bad_weights() {
+        assert!(SourceProfile::parse(r#"{"sources": [{"name": "a", "weight": 0}]}"#).is_err());
+        assert!(SourceProfile::parse(r#"{"sources": []}"#).is_err());
+        assert!(SourceProfile::parse(r#"{"source": ["a"]}"#).is_err());
+    }
+
+    #[test]
+    fn test_sources_file_rejects_unknown_names() {
+        let known = ["clock_jitter", "dns_timing"];
+        let profile =
+            SourceProfile::parse(r#"{"sources": ["Clock_Jitter", "dns_timing"]}"#).unwrap();
+        assert!(profile.check_known(&known).is_ok());
+        let profile =
+            SourceProfile::parse(r#"{"sources": ["clock_jitter", "clock_jiter"]}"#).unwrap();
+        assert_eq!(
+            profile.check_known(&known).unwrap_err(),
+            "unknown source(s): clock_jiter (see `openentropy scan`)"
+        );
     }
 
     // -----------------------------------------------------------------------
@@ -263,7 +746,7 @@ mod tests {
     #[test]
     fn test_make_pool_default_has_sources() {
         // Default pool should include fast sources (on macOS at least some will be available)
-        let pool = make_pool(None);
+        let pool = make_pool(None, None, None);
         // On any supported platform, at least the timing sources should work
         assert!(
             pool.source_count() > 0,
@@ -273,14 +756,14 @@ mod tests {
 
     #[test]
     fn test_make_pool_all_sources() {
-        let pool = make_pool(Some("all"));
+        let pool = make_pool(Some("all"), None, None);
         // "all" should include everything available
         assert!(pool.source_count() > 0);
     }
 
     #[test]
     fn test_make_pool_filter_by_name() {
-        let pool = make_pool(Some("clock_jitter"));
+        let pool = make_pool(Some("clock_jitter"), None, None);
         // Should find the clock_jitter source if available on this platform
         // (may be 0 on non-macOS, but the function handles that gracefully)
         // Just verify it doesn't panic
@@ -289,7 +772,7 @@ mod tests {
 
     #[test]
     fn test_make_pool_filter_comma_separated() {
-        let pool = make_pool(Some("clock_jitter,sleep_jitter"));
+        let pool = make_pool(Some("clock_jitter,sleep_jitter"), None, None);
         // Should accept comma-separated names without panicking
         let _ = pool.source_count();
     }
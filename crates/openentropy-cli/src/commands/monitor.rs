@@ -1,14 +1,55 @@
-pub fn run(refresh: f64, source_filter: Option<&str>, include_telemetry: bool) {
+/// Per-source collection bound for `monitor --once`.
+const ONCE_TIMEOUT_SECS: f64 = 10.0;
+
+#[allow(clippy::too_many_arguments)]
+pub fn run(
+    refresh: f64,
+    source_filter: Option<&str>,
+    exclude: Option<&str>,
+    sources_file: Option<&str>,
+    include_telemetry: bool,
+    once: bool,
+    output_path: Option<&str>,
+    theme: &str,
+) {
+    if output_path.is_some() && !once {
+        eprintln!("--output requires --once (the interactive dashboard has its own 's' export).");
+        std::process::exit(2);
+    }
     if super::telemetry::print_snapshot_if_enabled(include_telemetry, "monitor-startup").is_some() {
         println!();
     }
     // Monitor exposes the full source catalog so users can interactively
-    // select any source (including slow sensor sources) at runtime.
-    let pool = match source_filter {
-        Some(filter) => super::make_pool(Some(filter)),
-        None => super::make_pool(Some("all")),
+    // select any source (including slow sensor sources) at runtime, unless a
+    // --sources-file narrows it.
+    let source_filter = match (source_filter, sources_file) {
+        (None, None) => Some("all"),
+        (filter, _) => filter,
+    };
+    let pool = super::make_pool(source_filter, exclude, sources_file);
+    let theme = if super::color_enabled() {
+        crate::tui::theme::Theme::from_name(theme).unwrap_or_default()
+    } else {
+        crate::tui::theme::Theme::MONOCHROME
     };
-    let mut app = crate::tui::app::App::new(pool, refresh);
+    let cancel = pool.cancellation_token();
+    let mut app = crate::tui::app::App::new(pool, refresh).with_theme(theme);
+    if once {
+        super::cancel_on_ctrlc(&cancel);
+        let json = app.run_once(ONCE_TIMEOUT_SECS);
+        super::exit_if_interrupted(&cancel);
+        match output_path {
+            Some(path) => super::write_json(&json, path, "Monitor snapshot", false),
+            None => match super::to_json_string(&json, false) {
+                Ok(s) => println!("{s}"),
+                Err(e) => {
+                    eprintln!("Failed to serialize monitor snapshot: {e}");
+                    std::process::exit(1);
+                }
+            },
+        }
+        return;
+    }
     if let Err(e) = app.run() {
         eprintln!("TUI error: {e}");
         std::process::exit(1);
@@ -7,14 +7,16 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
 
 use openentropy_core::conditioning::condition;
-use openentropy_core::session::{SessionConfig, SessionMeta, SessionWriter};
+use openentropy_core::session::{JsonLinesSink, RecordSink, SessionConfig, SessionWriter};
 
-use super::make_pool;
+use super::{make_pool, parse_duration};
 
 /// Run the record command.
 #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
 pub fn run(
-    sources_filter: &str,
+    sources_filter: Option<&str>,
+    exclude: Option<&str>,
+    sources_file: Option<&str>,
     duration: Option<&str>,
     tags: &[String],
     note: Option<&str>,
@@ -23,17 +25,30 @@ pub fn run(
     analyze: bool,
     conditioning: &str,
     include_telemetry: bool,
+    sink: &str,
 ) {
+    // With the stdout sink, stdout carries the recording itself, so all
+    // status output moves to stderr.
+    let to_stdout = sink == "stdout";
+    let status = |line: &str| {
+        if to_stdout {
+            eprintln!("{line}");
+        } else {
+            println!("{line}");
+        }
+    };
+
     // Parse conditioning mode
     let mode = super::parse_conditioning(conditioning);
 
     // Build pool from source filter
-    let pool = make_pool(Some(sources_filter));
+    let pool = make_pool(sources_filter, exclude, sources_file);
 
     // Verify we got the requested sources
     let available: Vec<String> = pool.source_infos().iter().map(|i| i.name.clone()).collect();
     if available.is_empty() {
-        eprintln!("Error: no matching sources found for '{sources_filter}'");
+        let requested = sources_filter.or(sources_file).unwrap_or_default();
+        eprintln!("Error: no matching sources found for '{requested}'");
         std::process::exit(1);
     }
 
@@ -69,12 +84,19 @@ pub fn run(
         include_telemetry,
     };
 
-    // Create session writer
-    let mut writer = match SessionWriter::new(config) {
-        Ok(w) => w,
-        Err(e) => {
-            eprintln!("Error creating session: {e}");
-            std::process::exit(1);
+    // Create the sink
+    let (mut writer, session_dir): (Box<dyn RecordSink>, Option<PathBuf>) = if to_stdout {
+        (Box::new(JsonLinesSink::stdout(config)), None)
+    } else {
+        match SessionWriter::new(config) {
+            Ok(w) => {
+                let dir = w.session_dir().to_path_buf();
+                (Box::new(w), Some(dir))
+            }
+            Err(e) => {
+                eprintln!("Error creating session: {e}");
+                std::process::exit(1);
+            }
         }
     };
 
@@ -87,38 +109,47 @@ pub fn run(
     .expect("Error setting Ctrl+C handler");
 
     // Print session start info
-    let session_dir = writer.session_dir().to_path_buf();
-    println!("Recording session");
-    println!("  Sources:   {}", available.join(", "));
-    println!("  Conditioning: {mode}");
+    status("Recording session");
+    status(&format!("  Sources:   {}", available.join(", ")));
+    status(&format!("  Conditioning: {mode}"));
     if let Some(d) = max_duration {
-        println!("  Duration:  {}s", d.as_secs());
+        status(&format!("  Duration:  {}s", d.as_secs()));
     } else {
-        println!("  Duration:  until Ctrl+C");
+        status("  Duration:  until Ctrl+C");
     }
     if let Some(iv) = interval_dur {
-        println!("  Interval:  {}ms", iv.as_millis());
+        status(&format!("  Interval:  {}ms", iv.as_millis()));
     } else {
-        println!("  Interval:  continuous");
+        status("  Interval:  continuous");
     }
-    println!(
+    status(&format!(
         "  Analysis:  {}",
         if analyze { "enabled" } else { "disabled" }
-    );
-    println!(
+    ));
+    status(&format!(
         "  Telemetry: {}",
         if include_telemetry {
             "enabled (session start/end snapshot)"
         } else {
             "disabled"
         }
-    );
-    println!("  Output:    {}", session_dir.display());
-    println!();
+    ));
+    match &session_dir {
+        Some(dir) => status(&format!("  Output:    {}", dir.display())),
+        None => status("  Output:    stdout (JSON Lines)"),
+    }
+    status("");
 
     // Recording loop
     let start = Instant::now();
     let mut had_write_error = false;
+    let mut announced = false;
+    // Elapsed/remaining against --duration (in ms), or a spinner when open-ended.
+    let progress = match max_duration {
+        Some(max) => super::progress_bar(max.as_millis() as u64),
+        None => super::progress_spinner(),
+    };
+    progress.enable_steady_tick(Duration::from_millis(200));
 
     'outer: while running.load(Ordering::SeqCst) {
         // Check duration limit
@@ -134,6 +165,10 @@ pub fn run(
             if !running.load(Ordering::SeqCst) {
                 break 'outer;
             }
+            progress.set_message(format!(
+                "{source_name} ({} samples)",
+                writer.total_samples()
+            ));
 
             let raw = pool
                 .get_source_raw_bytes(source_name, 1000)
@@ -144,21 +179,23 @@ pub fn run(
 
             let conditioned = condition(&raw, raw.len(), mode);
 
-            if let Err(e) = writer.write_sample(source_name, &raw, &conditioned) {
-                eprintln!("\nError writing sample: {e}");
+            if let Err(e) = writer.write_chunk(source_name, &raw, &conditioned) {
+                progress.suspend(|| eprintln!("Error writing sample: {e}"));
                 had_write_error = true;
                 break 'outer;
             }
+            if !announced {
+                progress.suspend(|| status("First sample written; press Ctrl+C to stop."));
+                announced = true;
+            }
         }
 
-        // Print status
-        let elapsed = start.elapsed();
-        let total = writer.total_samples();
-        print!(
-            "\r  Samples: {total:<8} Elapsed: {:.1}s",
-            elapsed.as_secs_f64()
+        let elapsed_ms = start.elapsed().as_millis() as u64;
+        progress.set_position(
+            progress
+                .length()
+                .map_or(elapsed_ms, |len| elapsed_ms.min(len)),
         );
-        let _ = std::io::Write::flush(&mut std::io::stdout());
 
         // Wait for interval if configured
         if let Some(iv) = interval_dur {
@@ -169,36 +206,43 @@ pub fn run(
         }
     }
 
-    println!();
-    println!();
+    progress.finish_and_clear();
+    status("");
 
     if had_write_error {
         eprintln!("Recording stopped due to write error.");
+    } else if !running.load(Ordering::SeqCst) {
+        status(&format!(
+            "Interrupted after {:.1}s; finalizing session{}...",
+            start.elapsed().as_secs_f64(),
+            if analyze { " and running analysis" } else { "" }
+        ));
     }
 
     // Finalize session
-    match writer.finish() {
-        Ok(dir) => {
-            println!("Session saved to {}", dir.display());
-            println!("  session.json          — metadata");
-            println!("  samples.csv           — per-sample raw/conditioned metrics");
-            println!("  raw.bin               — raw entropy bytes");
-            println!("  raw_index.csv         — byte offset index for raw.bin");
-            println!("  conditioned.bin       — conditioned entropy bytes");
-            println!("  conditioned_index.csv — byte offset index for conditioned.bin");
-            if include_telemetry {
-                let meta_path = dir.join("session.json");
-                if let Ok(raw) = std::fs::read_to_string(&meta_path)
-                    && let Ok(meta) = serde_json::from_str::<SessionMeta>(&raw)
-                    && let Some(t) = meta.telemetry_v1
-                {
-                    println!(
-                        "  telemetry_v1:         {} ({:.1}s, {} metrics)",
-                        t.model_id,
-                        t.elapsed_ms as f64 / 1000.0,
-                        t.end.metrics.len()
-                    );
-                }
+    match writer.finalize() {
+        Ok(meta) => {
+            if let Some(dir) = &session_dir {
+                status(&format!("Session saved to {}", dir.display()));
+                status("  session.json          — metadata");
+                status("  samples.csv           — per-sample raw/conditioned metrics");
+                status("  raw.bin               — raw entropy bytes");
+                status("  raw_index.csv         — byte offset index for raw.bin");
+                status("  conditioned.bin       — conditioned entropy bytes");
+                status("  conditioned_index.csv — byte offset index for conditioned.bin");
+            } else {
+                status(&format!(
+                    "Session {} streamed to stdout ({} samples)",
+                    meta.id, meta.total_samples
+                ));
+            }
+            if let Some(t) = meta.telemetry_v1 {
+                status(&format!(
+                    "  telemetry_v1:         {} ({:.1}s, {} metrics)",
+                    t.model_id,
+                    t.elapsed_ms as f64 / 1000.0,
+                    t.end.metrics.len()
+                ));
             }
         }
         Err(e) => {
@@ -207,28 +251,3 @@ pub fn run(
         }
     }
 }
-
-/// Parse a duration string like "5m", "30s", "1h", "100ms".
-fn parse_duration(s: &str) -> Duration {
-    let s = s.trim();
-
-    let (numeric, multiplier) = if let Some(rest) = s.strip_suffix("ms") {
-        (rest, 1u64)
-    } else if let Some(rest) = s.strip_suffix('s') {
-        (rest, 1000)
-    } else if let Some(rest) = s.strip_suffix('m') {
-        (rest, 60_000)
-    } else if let Some(rest) = s.strip_suffix('h') {
-        (rest, 3_600_000)
-    } else {
-        // Assume seconds
-        (s, 1000)
-    };
-
-    let value: u64 = numeric.parse().unwrap_or_else(|_| {
-        eprintln!("Invalid duration: {s}");
-        std::process::exit(1);
-    });
-
-    Duration::from_millis(value * multiplier)
-}
@@ -1,24 +1,29 @@
-use openentropy_core::platform::{detect_available_sources, platform_info};
+use openentropy_core::platform::{platform_info, source_availability};
 
 pub fn run(include_telemetry: bool) {
     let info = platform_info();
     println!("Platform: {} {} (Rust)", info.system, info.machine);
     println!();
 
-    let sources = detect_available_sources();
+    let (sources, unavailable): (Vec<_>, Vec<_>) = source_availability()
+        .into_iter()
+        .partition(|(_, availability)| availability.is_available());
 
-    let standalone: Vec<_> = sources.iter().filter(|s| !s.info().composite).collect();
-    let composite: Vec<_> = sources.iter().filter(|s| s.info().composite).collect();
+    let standalone: Vec<_> = sources
+        .iter()
+        .filter(|(s, _)| !s.info().composite)
+        .collect();
+    let composite: Vec<_> = sources.iter().filter(|(s, _)| s.info().composite).collect();
 
     println!("Found {} available entropy source(s):\n", sources.len());
-    for src in &standalone {
+    for (src, _) in &standalone {
         let info = src.info();
         println!("  \u{2705} {:<25} {}", info.name, info.description);
     }
 
     if !composite.is_empty() {
         println!("\nComposite sources (combine multiple sources above):\n");
-        for src in &composite {
+        for (src, _) in &composite {
             let info = src.info();
             println!("  \u{1F504} {:<25} {}", info.name, info.description);
         }
@@ -28,5 +33,16 @@ pub fn run(include_telemetry: bool) {
         println!("  (none found)");
     }
 
+    if !unavailable.is_empty() {
+        println!("\nUnavailable on this machine ({}):\n", unavailable.len());
+        for (src, availability) in &unavailable {
+            println!(
+                "  \u{274C} {:<25} {}",
+                src.name(),
+                availability.reason().unwrap_or_default()
+            );
+        }
+    }
+
     let _ = super::telemetry::print_snapshot_if_enabled(include_telemetry, "scan");
 }
@@ -1,11 +1,19 @@
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     host: &str,
     port: u16,
     source_filter: Option<&str>,
+    exclude: Option<&str>,
+    sources_file: Option<&str>,
     allow_raw: bool,
     include_telemetry: bool,
+    compress: bool,
+    allow_refresh: bool,
+    prefill: usize,
+    require_sources: usize,
+    max_batch_bytes: usize,
 ) {
-    let pool = super::make_pool(source_filter);
+    let pool = super::make_pool(source_filter, exclude, sources_file);
 
     let base = format!("http://{host}:{port}");
     let n_sources = pool.source_count();
@@ -17,13 +25,23 @@ pub fn run(
     println!("   Endpoints:");
     println!("     GET /                 API index (try: curl {base})");
     println!("     GET /api/v1/random    Random bytes from the mixed pool");
+    println!(
+        "     POST /api/v1/random/batch  Several draws in one request (up to {max_batch_bytes} bytes)"
+    );
     println!("     GET /sources          List all sources with health metrics");
     println!("     GET /health           Pool health check");
     println!("     GET /pool/status      Detailed pool status");
+    if allow_refresh {
+        println!("     POST /sources/refresh Re-detect hardware and rebuild the source set");
+    }
+    if prefill > 0 {
+        println!("   Keeping {prefill} bytes of SHA-256 output pre-filled");
+    }
     println!();
     println!("   Query params for /api/v1/random:");
     println!("     length=N              Bytes to return (1-65536, default: 1024)");
-    println!("     type=hex16|uint8|uint16  Output format (default: hex16)");
+    println!("     type=hex16|uint8|uint16|uint32  Output format (default: hex16)");
+    println!("     endian=le|be          Byte order for uint16/uint32 (default: le)");
     println!("     source=<name>         Request from a specific source");
     println!("     conditioning=sha256|vonneumann|raw");
     println!("   Query params for /sources and /pool/status:");
@@ -31,6 +49,11 @@ pub fn run(
     if !allow_raw {
         println!("     (raw conditioning requires --allow-raw flag)");
     }
+    if compress {
+        println!(
+            "   JSON responses are gzip/deflate encoded when the client sends Accept-Encoding."
+        );
+    }
     println!();
     println!("   Examples:");
     println!("     curl {base}/api/v1/random?length=32&type=uint8");
@@ -43,6 +66,35 @@ pub fn run(
         println!();
     }
 
+    let refresh: Option<openentropy_server::SourceDetector> = allow_refresh.then(|| {
+        let filter = source_filter.map(str::to_string);
+        let exclude = exclude.map(str::to_string);
+        let profile = sources_file.map(super::SourceProfile::load);
+        let detect: openentropy_server::SourceDetector = std::sync::Arc::new(move || {
+            openentropy_core::refresh_source_availability();
+            super::select_sources(
+                openentropy_core::detect_available_sources(),
+                filter.as_deref(),
+                exclude.as_deref(),
+                profile.as_ref(),
+            )
+        });
+        detect
+    });
+
     let rt = tokio::runtime::Runtime::new().unwrap();
-    rt.block_on(openentropy_server::run_server(pool, host, port, allow_raw));
+    let config = openentropy_server::ServerConfig {
+        host: host.to_string(),
+        port,
+        allow_raw,
+        compress,
+        refresh,
+        prefill,
+        require_sources,
+        max_batch_bytes,
+    };
+    if let Err(e) = rt.block_on(openentropy_server::run_server(pool, config)) {
+        eprintln!("Server error on {host}:{port}: {e}");
+        std::process::exit(1);
+    }
 }
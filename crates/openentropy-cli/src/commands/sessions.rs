@@ -1,13 +1,22 @@
 //! `openentropy sessions` — list and analyze recorded sessions.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::io::{Read, Seek, SeekFrom};
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
 
 use openentropy_core::analysis;
-use openentropy_core::conditioning::min_entropy_estimate;
-use openentropy_core::session::SessionMeta;
+use openentropy_core::conditioning::{min_entropy_estimate, quick_min_entropy, quick_shannon};
+use openentropy_core::replay;
+use openentropy_core::session::{SessionMeta, verify_session};
+
+/// Exit code when `--verify` finds a mismatch.
+pub const INTEGRITY_FAILURE_EXIT_CODE: i32 = 1;
 
 /// Run the sessions command.
+#[allow(clippy::too_many_arguments)]
 pub fn run(
     session_path: Option<&str>,
     dir: &str,
@@ -15,7 +24,21 @@ pub fn run(
     do_entropy: bool,
     output: Option<&str>,
     include_telemetry: bool,
+    verify: bool,
+    compact: bool,
+    export_oerec: Option<&str>,
+    follow: bool,
+    poll_interval: &str,
 ) {
+    if follow {
+        let Some(path) = session_path else {
+            eprintln!("--follow requires a session directory argument.");
+            std::process::exit(2);
+        };
+        follow_session(Path::new(path), super::parse_duration(poll_interval));
+        return;
+    }
+
     if let Some(path) = session_path {
         // Single session mode
         let session_dir = PathBuf::from(path);
@@ -28,14 +51,68 @@ pub fn run(
         show_session(&session_dir);
 
         if do_analyze || do_entropy {
-            analyze_session(&session_dir, do_entropy, output, include_telemetry);
+            analyze_session(&session_dir, do_entropy, output, include_telemetry, compact);
+        }
+
+        if let Some(out) = export_oerec {
+            export_to_oerec(&session_dir, out);
+        }
+
+        if verify {
+            let code = verify_and_report(&session_dir);
+            if code != 0 {
+                std::process::exit(code);
+            }
         }
+    } else if verify {
+        eprintln!("--verify requires a session directory argument.");
+        std::process::exit(2);
+    } else if export_oerec.is_some() {
+        eprintln!("--export-oerec requires a session directory argument.");
+        std::process::exit(2);
     } else {
         // List mode
         list_sessions(dir);
     }
 }
 
+/// Write the session's raw samples to a single `.oerec` file.
+fn export_to_oerec(session_dir: &Path, out: &str) {
+    match replay::export_session(session_dir, Path::new(out)) {
+        Ok(frames) => {
+            println!();
+            println!("Exported {frames} frame(s) to {out}");
+        }
+        Err(e) => {
+            eprintln!("Failed to export {}: {e}", session_dir.display());
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Check data files against the digests in session.json and print the result.
+/// Returns the process exit code.
+fn verify_and_report(session_dir: &Path) -> i32 {
+    println!();
+    match verify_session(session_dir) {
+        Ok(issues) if issues.is_empty() => {
+            println!("Integrity: OK (all data files match session.json)");
+            0
+        }
+        Ok(issues) => {
+            println!("Integrity: FAILED ({} issue(s))", issues.len());
+            for issue in &issues {
+                println!("  - {issue}");
+            }
+            INTEGRITY_FAILURE_EXIT_CODE
+        }
+        Err(e) => {
+            eprintln!("Failed to verify {}: {e}", session_dir.display());
+            INTEGRITY_FAILURE_EXIT_CODE
+        }
+    }
+}
+
 /// List all sessions in a directory.
 fn list_sessions(dir: &str) {
     let sessions_dir = PathBuf::from(dir);
@@ -195,6 +272,7 @@ fn analyze_session(
     do_entropy: bool,
     output: Option<&str>,
     include_telemetry: bool,
+    compact: bool,
 ) {
     let telemetry = super::telemetry::TelemetryCapture::start(include_telemetry);
     let meta = read_session_meta(session_dir);
@@ -327,8 +405,158 @@ fn analyze_session(
             json["telemetry_v1"] = serde_json::json!(window);
         }
 
-        super::write_json(&json, path, "Results");
+        super::write_json(&json, path, "Results", compact);
+    }
+}
+
+/// Incremental reader for a session that may still be recording.
+///
+/// Tracks how much of `raw_index.csv` has been consumed and appends each
+/// indexed frame once its bytes are present in `raw.bin`. Only complete
+/// index lines are parsed, and frames whose data has not been flushed yet
+/// are kept pending until a later poll.
+struct SessionFollower {
+    session_dir: PathBuf,
+    index_pos: u64,
+    header_skipped: bool,
+    pending: VecDeque<(u64, usize, String)>,
+    source_bytes: BTreeMap<String, Vec<u8>>,
+    total_bytes: usize,
+}
+
+impl SessionFollower {
+    fn new(session_dir: &Path) -> Self {
+        Self {
+            session_dir: session_dir.to_path_buf(),
+            index_pos: 0,
+            header_skipped: false,
+            pending: VecDeque::new(),
+            source_bytes: BTreeMap::new(),
+            total_bytes: 0,
+        }
+    }
+
+    /// Read any newly written frames. Returns the number of bytes added.
+    ///
+    /// Missing files are not an error: the recorder may not have created
+    /// them yet.
+    fn poll(&mut self) -> std::io::Result<usize> {
+        let index_path = self.session_dir.join("raw_index.csv");
+        let raw_path = self.session_dir.join("raw.bin");
+        if !index_path.exists() || !raw_path.exists() {
+            return Ok(0);
+        }
+
+        let mut index = std::fs::File::open(&index_path)?;
+        index.seek(SeekFrom::Start(self.index_pos))?;
+        let mut tail = Vec::new();
+        index.read_to_end(&mut tail)?;
+        let complete = tail.iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+        self.index_pos += complete as u64;
+
+        for line in String::from_utf8_lossy(&tail[..complete]).lines() {
+            if !self.header_skipped {
+                self.header_skipped = true;
+                if line.starts_with("offset,") {
+                    continue;
+                }
+            }
+            // Format: offset,length,timestamp_ns,source
+            let parts: Vec<&str> = line.splitn(4, ',').collect();
+            if parts.len() < 4 {
+                continue;
+            }
+            let (Ok(offset), Ok(length)) = (parts[0].parse::<u64>(), parts[1].parse::<usize>())
+            else {
+                continue;
+            };
+            self.pending
+                .push_back((offset, length, parts[3].to_string()));
+        }
+
+        let mut raw = std::fs::File::open(&raw_path)?;
+        let raw_len = raw.metadata()?.len();
+        let mut added = 0;
+        while let Some((offset, length, _)) = self.pending.front() {
+            if offset + *length as u64 > raw_len {
+                break;
+            }
+            let mut buf = vec![0u8; *length];
+            raw.seek(SeekFrom::Start(*offset))?;
+            raw.read_exact(&mut buf)?;
+            let (_, _, source) = self.pending.pop_front().expect("front checked above");
+            added += buf.len();
+            self.source_bytes.entry(source).or_default().extend(buf);
+        }
+        self.total_bytes += added;
+        Ok(added)
+    }
+}
+
+/// Poll an in-progress session and print a rolling per-source summary as
+/// new frames appear. Stops on Ctrl+C, or once `session.json` has been
+/// finalized and no further frames arrive.
+fn follow_session(session_dir: &Path, interval: Duration) {
+    if !session_dir.is_dir() {
+        eprintln!("Not a session directory: {}", session_dir.display());
+        std::process::exit(1);
+    }
+
+    let running = Arc::new(AtomicBool::new(true));
+    let r = running.clone();
+    ctrlc::set_handler(move || {
+        r.store(false, Ordering::SeqCst);
+    })
+    .expect("Error setting Ctrl+C handler");
+
+    println!("Following session: {}", session_dir.display());
+    match try_read_session_meta(session_dir) {
+        Some(meta) => println!("  ID:           {} (finalized)", meta.id),
+        None => println!("  session.json: not yet written (recording in progress)"),
+    }
+    println!(
+        "  Poll interval: {}ms (Ctrl+C to stop)\n",
+        interval.as_millis()
+    );
+
+    let mut follower = SessionFollower::new(session_dir);
+    while running.load(Ordering::SeqCst) {
+        let added = match follower.poll() {
+            Ok(n) => n,
+            Err(e) => {
+                eprintln!("Failed to read session data: {e}");
+                std::process::exit(1);
+            }
+        };
+        if added > 0 {
+            print_follow_summary(&follower, added);
+        } else if try_read_session_meta(session_dir).is_some() {
+            println!(
+                "Session finalized; {} bytes analyzed.",
+                follower.total_bytes
+            );
+            break;
+        }
+        std::thread::sleep(interval);
+    }
+}
+
+fn print_follow_summary(follower: &SessionFollower, added: usize) {
+    println!(
+        "+{added} bytes ({} total, {} pending frame(s))",
+        follower.total_bytes,
+        follower.pending.len()
+    );
+    for (name, data) in &follower.source_bytes {
+        println!(
+            "  {:<25} {:>10} bytes  H={:.3}  H∞={:.3}",
+            name,
+            data.len(),
+            quick_shannon(data),
+            quick_min_entropy(data)
+        );
     }
+    println!();
 }
 
 fn print_source_report(r: &analysis::SourceAnalysis) {
@@ -407,6 +635,13 @@ fn read_session_meta(session_dir: &Path) -> SessionMeta {
     }
 }
 
+/// Read session.json if it exists and parses; `None` for sessions that are
+/// still recording or whose metadata was only partially written.
+fn try_read_session_meta(session_dir: &Path) -> Option<SessionMeta> {
+    let contents = std::fs::read_to_string(session_dir.join("session.json")).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
 fn format_duration_ms(ms: u64) -> String {
     if ms < 1000 {
         format!("{ms}ms")
@@ -426,3 +661,59 @@ fn truncate(s: &str, max: usize) -> String {
         format!("{}...", &s[..max - 3])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn append(path: &Path, bytes: &[u8]) {
+        let mut f = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .unwrap();
+        f.write_all(bytes).unwrap();
+    }
+
+    #[test]
+    fn follower_picks_up_appended_frames() {
+        let dir = std::env::temp_dir().join(format!("openentropy-follow-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let raw = dir.join("raw.bin");
+        let index = dir.join("raw_index.csv");
+
+        append(&raw, &[1u8; 16]);
+        append(&raw, &[2u8; 16]);
+        append(
+            &index,
+            b"offset,length,timestamp_ns,source\n0,16,1,alpha\n16,16,2,beta\n",
+        );
+
+        // No session.json: the recording is still in progress.
+        let mut follower = SessionFollower::new(&dir);
+        assert_eq!(follower.poll().unwrap(), 32);
+        assert_eq!(follower.total_bytes, 32);
+        assert_eq!(follower.poll().unwrap(), 0);
+
+        // A partially written index line is not consumed yet.
+        append(&index, b"32,8,3,al");
+        assert_eq!(follower.poll().unwrap(), 0);
+
+        append(&index, b"pha\n");
+        // Index line present but frame data not yet flushed.
+        assert_eq!(follower.poll().unwrap(), 0);
+        assert_eq!(follower.pending.len(), 1);
+
+        append(&raw, &[3u8; 8]);
+        assert_eq!(follower.poll().unwrap(), 8);
+        assert_eq!(follower.total_bytes, 40);
+        assert_eq!(follower.source_bytes["alpha"].len(), 24);
+        assert_eq!(follower.source_bytes["beta"].len(), 16);
+
+        std::fs::write(dir.join("session.json"), "{\"id\": \"trunc").unwrap();
+        assert!(try_read_session_meta(&dir).is_none());
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
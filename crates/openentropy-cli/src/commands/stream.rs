@@ -1,34 +1,273 @@
+use std::collections::VecDeque;
 use std::io::Write;
+use std::time::{Duration, Instant};
 
-pub fn run(
-    format: &str,
-    rate: usize,
-    source_filter: Option<&str>,
-    n_bytes: usize,
-    conditioning: &str,
-    fifo_path: Option<&str>,
-) {
-    if let Some(path) = fifo_path {
-        run_fifo(path, rate, source_filter, conditioning);
+use openentropy_core::EntropyPool;
+use openentropy_core::conditioning::{ConditioningMode, quick_min_entropy};
+
+pub struct StreamCommandConfig<'a> {
+    pub format: &'a str,
+    pub rate: usize,
+    pub source_filter: Option<&'a str>,
+    pub exclude: Option<&'a str>,
+    pub sources_file: Option<&'a str>,
+    pub n_bytes: usize,
+    pub warmup: usize,
+    pub conditioning: &'a str,
+    pub fifo_path: Option<&'a str>,
+    pub max_bytes: Option<u64>,
+    pub max_duration: Option<&'a str>,
+    pub entropy_floor: Option<f64>,
+    pub on_low: &'a str,
+}
+
+pub fn run(cfg: StreamCommandConfig<'_>) {
+    if let Some(floor) = cfg.entropy_floor
+        && !(floor > 0.0 && floor <= 8.0)
+    {
+        eprintln!("--entropy-floor must be in (0, 8] bits/byte, got {floor}");
+        std::process::exit(2);
+    }
+    let on_low = if cfg.on_low == "reseed" {
+        OnLow::Reseed
+    } else {
+        OnLow::Exit
+    };
+    let gate = EntropyGate::new(cfg.entropy_floor, on_low);
+    let pool = super::make_pool(cfg.source_filter, cfg.exclude, cfg.sources_file);
+
+    if let Some(path) = cfg.fifo_path {
+        let quota = FifoQuota {
+            max_bytes: cfg.max_bytes.filter(|&n| n > 0),
+            max_duration: cfg.max_duration.map(super::parse_duration),
+        };
+        run_fifo(
+            path,
+            cfg.rate,
+            pool,
+            cfg.warmup,
+            cfg.conditioning,
+            quota,
+            gate,
+        );
     } else {
-        run_stdout(format, rate, source_filter, n_bytes, conditioning);
+        run_stdout(
+            cfg.format,
+            cfg.rate,
+            pool,
+            cfg.n_bytes,
+            cfg.warmup,
+            cfg.conditioning,
+            gate,
+        );
     }
 }
 
 fn run_stdout(
     format: &str,
     rate: usize,
-    source_filter: Option<&str>,
+    pool: EntropyPool,
     n_bytes: usize,
+    warmup: usize,
     conditioning: &str,
+    mut gate: EntropyGate,
 ) {
-    let pool = super::make_pool(source_filter);
     let mode = super::parse_conditioning(conditioning);
-    let chunk_size = if rate > 0 { rate.min(4096) } else { 4096 };
-    let mut total = 0usize;
+
+    // Ctrl+C abandons any in-progress collection and ends the stream
+    // after the current chunk instead of killing the process mid-write.
+    let cancel = pool.cancellation_token();
+    super::cancel_on_ctrlc(&cancel);
+
+    discard_warmup(warmup, |n| pool.get_bytes(n, mode));
 
     let stdout = std::io::stdout();
     let mut out = stdout.lock();
+    let produced = write_stream(&mut out, format, rate, n_bytes, 0, |n| {
+        if cancel.is_cancelled() {
+            return None;
+        }
+        gate.next(
+            n,
+            |n| pool.get_bytes(n, mode),
+            || {
+                pool.collect_all();
+            },
+        )
+    });
+    drop(out);
+    super::exit_if_interrupted(&cancel);
+    gate.exit_if_tripped();
+    if !produced {
+        eprintln!("\nSources produced no {mode} output; stopping.");
+        std::process::exit(1);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Entropy floor
+// ---------------------------------------------------------------------------
+
+/// Exit code used when an emitted chunk falls below `--entropy-floor`.
+pub const ENTROPY_FLOOR_EXIT_CODE: i32 = 1;
+
+/// Reseed attempts per chunk under `--on-low reseed` before giving up.
+const MAX_RESEED_ATTEMPTS: usize = 3;
+
+/// What to do when a chunk falls below the entropy floor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OnLow {
+    /// Stop emitting and exit nonzero.
+    Exit,
+    /// Discard the pending bytes, re-collect from every source and retry.
+    Reseed,
+}
+
+/// Bytes scored by the `--entropy-floor` gate on every emitted chunk.
+const GATE_WINDOW: usize = 4096;
+
+/// Rolling min-entropy gate for `--entropy-floor`.
+///
+/// Each emitted chunk is scored together with the bytes emitted before it:
+/// [`quick_min_entropy`] over the last [`GATE_WINDOW`] bytes, one O(n) MCV
+/// pass that is cheap next to collection. The estimate uses a 99% upper
+/// confidence bound and is capped by its sample size, so scoring short
+/// chunks (small `--rate`, a `--bytes` tail) alone would always fail.
+/// Until a full window has been emitted, the gate collects ahead and holds
+/// the surplus for the next chunks. Healthy output scores about 7
+/// bits/byte. The gate sees conditioned output; use `--conditioning raw`
+/// to gate on source quality itself.
+struct EntropyGate {
+    floor: Option<f64>,
+    on_low: OnLow,
+    /// Up to [`GATE_WINDOW`] most recently emitted bytes.
+    history: VecDeque<u8>,
+    /// Collected and scored, not yet emitted.
+    pending: VecDeque<u8>,
+    /// Min-entropy of the window that tripped the floor.
+    tripped: Option<f64>,
+}
+
+impl EntropyGate {
+    fn new(floor: Option<f64>, on_low: OnLow) -> Self {
+        Self {
+            floor,
+            on_low,
+            history: VecDeque::new(),
+            pending: VecDeque::new(),
+            tripped: None,
+        }
+    }
+
+    /// Fetch the next `n`-byte chunk, or `None` once the window (after any
+    /// reseeds) has fallen below the floor. The chunk is shorter, possibly
+    /// empty, only when `fetch` runs dry.
+    fn next(
+        &mut self,
+        n: usize,
+        mut fetch: impl FnMut(usize) -> Vec<u8>,
+        mut reseed: impl FnMut(),
+    ) -> Option<Vec<u8>> {
+        let Some(floor) = self.floor else {
+            return Some(fetch(n));
+        };
+        let mut reseeds = 0;
+        loop {
+            self.fill(n, &mut fetch);
+            let h = self.score();
+            if h >= floor {
+                let take = n.min(self.pending.len());
+                let chunk: Vec<u8> = self.pending.drain(..take).collect();
+                self.history.extend(&chunk);
+                let excess = self.history.len().saturating_sub(GATE_WINDOW);
+                self.history.drain(..excess);
+                return Some(chunk);
+            }
+            if self.on_low == OnLow::Exit || reseeds == MAX_RESEED_ATTEMPTS {
+                self.tripped = Some(h);
+                return None;
+            }
+            reseeds += 1;
+            eprintln!(
+                "\nWindow min-entropy {h:.3} below floor {floor:.3}; reseeding ({reseeds}/{MAX_RESEED_ATTEMPTS})"
+            );
+            reseed();
+            // Judge the reseeded output on its own bytes.
+            self.history.clear();
+            self.pending.clear();
+        }
+    }
+
+    /// Collect until `n` bytes are pending and the window is full, or
+    /// `fetch` returns nothing.
+    fn fill(&mut self, n: usize, fetch: &mut impl FnMut(usize) -> Vec<u8>) {
+        loop {
+            let short = n
+                .saturating_sub(self.pending.len())
+                .max(GATE_WINDOW.saturating_sub(self.history.len() + self.pending.len()));
+            if short == 0 {
+                return;
+            }
+            let data = fetch(short);
+            if data.is_empty() {
+                return;
+            }
+            self.pending.extend(data);
+        }
+    }
+
+    /// Min-entropy of the last `max(GATE_WINDOW, pending)` bytes of
+    /// history followed by the pending bytes.
+    fn score(&self) -> f64 {
+        let keep = GATE_WINDOW
+            .saturating_sub(self.pending.len())
+            .min(self.history.len());
+        let window: Vec<u8> = self
+            .history
+            .range(self.history.len() - keep..)
+            .chain(&self.pending)
+            .copied()
+            .collect();
+        quick_min_entropy(&window)
+    }
+
+    /// Report a tripped floor and exit with [`ENTROPY_FLOOR_EXIT_CODE`].
+    fn exit_if_tripped(&self) {
+        if let (Some(h), Some(floor)) = (self.tripped, self.floor) {
+            eprintln!("\nEntropy floor tripped: window min-entropy {h:.3} < {floor:.3} bits/byte");
+            std::process::exit(ENTROPY_FLOOR_EXIT_CODE);
+        }
+    }
+}
+
+/// Collect and throw away `warmup` bytes so cold timing sources can settle.
+fn discard_warmup(warmup: usize, mut next_chunk: impl FnMut(usize) -> Vec<u8>) {
+    let mut discarded = 0usize;
+    while discarded < warmup {
+        let got = next_chunk((warmup - discarded).min(4096)).len();
+        if got == 0 {
+            break;
+        }
+        discarded += got;
+    }
+}
+
+/// Write formatted chunks from `next_chunk` to `out` until `n_bytes` have
+/// been emitted (0 = forever), `next_chunk` returns `None` or an empty chunk,
+/// or the writer fails. Warmup bytes are discarded first and do not count
+/// toward `n_bytes`. Returns `false` if the stream stopped on an empty chunk.
+fn write_stream<W: Write>(
+    out: &mut W,
+    format: &str,
+    rate: usize,
+    n_bytes: usize,
+    warmup: usize,
+    mut next_chunk: impl FnMut(usize) -> Option<Vec<u8>>,
+) -> bool {
+    let chunk_size = if rate > 0 { rate.min(4096) } else { 4096 };
+    let mut total = 0usize;
+
+    discard_warmup(warmup, |n| next_chunk(n).unwrap_or_default());
 
     loop {
         if n_bytes > 0 && total >= n_bytes {
@@ -40,7 +279,12 @@ fn run_stdout(
             chunk_size.min(n_bytes - total)
         };
 
-        let data = pool.get_bytes(want, mode);
+        let Some(data) = next_chunk(want) else {
+            break;
+        };
+        if data.is_empty() {
+            return false;
+        }
 
         let write_result = match format {
             "raw" => out.write_all(&data),
@@ -67,10 +311,191 @@ fn run_stdout(
             std::thread::sleep(sleep_dur);
         }
     }
+    true
+}
+
+/// Optional limits on how much a FIFO writer may emit before stopping.
+#[derive(Debug, Clone, Copy, Default)]
+struct FifoQuota {
+    /// Stop after this many bytes have been written across all readers.
+    max_bytes: Option<u64>,
+    /// Stop after this much wall-clock time since the writer started.
+    max_duration: Option<Duration>,
 }
 
-fn run_fifo(path: &str, buffer_size: usize, source_filter: Option<&str>, conditioning: &str) {
-    let pool = super::make_pool(source_filter);
+impl FifoQuota {
+    /// Bytes still allowed under the byte quota (`None` = unlimited).
+    fn remaining_bytes(&self, written: u64) -> Option<u64> {
+        self.max_bytes.map(|max| max.saturating_sub(written))
+    }
+
+    fn is_exhausted(&self, written: u64, started: Instant) -> bool {
+        self.remaining_bytes(written) == Some(0)
+            || self.max_duration.is_some_and(|d| started.elapsed() >= d)
+    }
+
+    /// When the duration quota runs out (`None` = never).
+    fn deadline(&self, started: Instant) -> Option<Instant> {
+        self.max_duration.map(|d| started + d)
+    }
+}
+
+/// Why a FIFO write session ended.
+#[derive(Debug)]
+enum FifoOutcome {
+    /// The byte or duration quota was reached.
+    QuotaReached,
+    /// The reader closed its end of the pipe (`EPIPE`).
+    ReaderClosed,
+    /// `next_chunk` stopped supplying data (the entropy floor tripped, or
+    /// the sources produced nothing).
+    SourceStopped,
+    /// Any other write error.
+    Failed(std::io::Error),
+}
+
+/// How often the stderr status line is refreshed.
+const STATUS_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Pause before reopening the FIFO after a reader disconnects.
+const REOPEN_BACKOFF: Duration = Duration::from_millis(100);
+
+/// How often a writer waiting for a reader re-checks the FIFO.
+const READER_POLL: Duration = Duration::from_millis(50);
+
+/// Nonblocking FIFO write end that gives up at a deadline.
+///
+/// A blocking `open()` waits for a reader and a blocking `write` waits for a
+/// full pipe to drain, with no way to honor `--max-duration`. This opens with
+/// `O_NONBLOCK` and waits in `poll(2)` instead, failing with
+/// [`ErrorKind::TimedOut`](std::io::ErrorKind::TimedOut) once the deadline
+/// passes.
+struct FifoWriter {
+    file: std::fs::File,
+    deadline: Option<Instant>,
+}
+
+impl FifoWriter {
+    /// Open `path` for writing once a reader attaches. Returns `Ok(None)` if
+    /// the deadline passes first.
+    fn open(path: &str, deadline: Option<Instant>) -> std::io::Result<Option<Self>> {
+        #[cfg(unix)]
+        loop {
+            use std::os::unix::fs::OpenOptionsExt;
+            match std::fs::OpenOptions::new()
+                .write(true)
+                .custom_flags(libc::O_NONBLOCK)
+                .open(path)
+            {
+                Ok(file) => return Ok(Some(Self { file, deadline })),
+                // ENXIO: no reader has the FIFO open yet.
+                Err(e) if e.raw_os_error() == Some(libc::ENXIO) => {
+                    if deadline.is_some_and(|d| Instant::now() >= d) {
+                        return Ok(None);
+                    }
+                    std::thread::sleep(READER_POLL);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        #[cfg(not(unix))]
+        {
+            let file = std::fs::OpenOptions::new().write(true).open(path)?;
+            Ok(Some(Self { file, deadline }))
+        }
+    }
+
+    /// Wait until the pipe has room, or fail at the deadline.
+    #[cfg(unix)]
+    fn wait_writable(&self) -> std::io::Result<()> {
+        use std::os::unix::io::AsRawFd;
+        let timeout_ms = match self.deadline {
+            Some(d) => {
+                let left = d.saturating_duration_since(Instant::now());
+                if left.is_zero() {
+                    return Err(std::io::ErrorKind::TimedOut.into());
+                }
+                i32::try_from(left.as_millis().max(1)).unwrap_or(i32::MAX)
+            }
+            None => -1,
+        };
+        let mut pfd = libc::pollfd {
+            fd: self.file.as_raw_fd(),
+            events: libc::POLLOUT,
+            revents: 0,
+        };
+        // SAFETY: pfd is a valid pollfd for an open descriptor, and nfds is 1.
+        if unsafe { libc::poll(&mut pfd, 1, timeout_ms) } < 0 {
+            let e = std::io::Error::last_os_error();
+            if e.kind() != std::io::ErrorKind::Interrupted {
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Write for FifoWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        loop {
+            match self.file.write(buf) {
+                #[cfg(unix)]
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => self.wait_writable()?,
+                result => return result,
+            }
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.file.flush()
+    }
+}
+
+/// Write chunks from `next_chunk` into an open FIFO until the quota is
+/// exhausted, `next_chunk` returns `None`, or the reader goes away. `written` is carried across reader
+/// sessions so quotas apply to the writer as a whole.
+fn feed_fifo<W: Write>(
+    fifo: &mut W,
+    buffer_size: usize,
+    quota: FifoQuota,
+    started: Instant,
+    written: &mut u64,
+    mut next_chunk: impl FnMut(usize) -> Option<Vec<u8>>,
+    mut on_progress: impl FnMut(u64),
+) -> FifoOutcome {
+    loop {
+        if quota.is_exhausted(*written, started) {
+            return FifoOutcome::QuotaReached;
+        }
+        let want = match quota.remaining_bytes(*written) {
+            Some(left) => buffer_size.min(usize::try_from(left).unwrap_or(usize::MAX)),
+            None => buffer_size,
+        };
+        let Some(data) = next_chunk(want).filter(|d| !d.is_empty()) else {
+            return FifoOutcome::SourceStopped;
+        };
+        if let Err(e) = fifo.write_all(&data).and_then(|()| fifo.flush()) {
+            return match e.kind() {
+                std::io::ErrorKind::BrokenPipe => FifoOutcome::ReaderClosed,
+                // A write blocked past --max-duration.
+                std::io::ErrorKind::TimedOut => FifoOutcome::QuotaReached,
+                _ => FifoOutcome::Failed(e),
+            };
+        }
+        *written += data.len() as u64;
+        on_progress(*written);
+    }
+}
+
+fn run_fifo(
+    path: &str,
+    buffer_size: usize,
+    pool: EntropyPool,
+    warmup: usize,
+    conditioning: &str,
+    quota: FifoQuota,
+    mut gate: EntropyGate,
+) {
     let mode = super::parse_conditioning(conditioning);
     let buffer_size = if buffer_size > 0 { buffer_size } else { 4096 };
 
@@ -106,28 +531,83 @@ fn run_fifo(path: &str, buffer_size: usize, source_filter: Option<&str>, conditi
     }
 
     println!("Feeding entropy to {path} (conditioning={conditioning}, buffer={buffer_size}B)");
+    if let Some(max) = quota.max_bytes {
+        println!("Byte quota: {max}");
+    }
+    if let Some(d) = quota.max_duration {
+        println!("Duration quota: {:.1}s", d.as_secs_f64());
+    }
     println!("Press Ctrl+C to stop.");
 
+    discard_warmup(warmup, |n| pool.get_bytes(n, mode));
+
     let path_owned = path.to_string();
     install_cleanup_handler(&path_owned);
 
+    let started = Instant::now();
+    let mut written = 0u64;
+    let mut last_status = Instant::now();
+
     loop {
-        match std::fs::OpenOptions::new().write(true).open(path) {
-            Ok(mut fifo) => loop {
-                let data = pool.get_bytes(buffer_size, mode);
-                if fifo.write_all(&data).is_err() {
-                    break;
-                }
-                let _ = fifo.flush();
-            },
+        if quota.is_exhausted(written, started) {
+            break;
+        }
+        // Waits for a reader, but no longer than --max-duration allows.
+        let mut fifo = match FifoWriter::open(path, quota.deadline(started)) {
+            Ok(Some(f)) => f,
+            Ok(None) => break,
             Err(e) => {
                 eprintln!("Error opening FIFO: {e}");
                 break;
             }
+        };
+        let outcome = feed_fifo(
+            &mut fifo,
+            buffer_size,
+            quota,
+            started,
+            &mut written,
+            |n| {
+                gate.next(
+                    n,
+                    |n| pool.get_bytes(n, mode),
+                    || {
+                        pool.collect_all();
+                    },
+                )
+            },
+            |total| {
+                if last_status.elapsed() >= STATUS_INTERVAL {
+                    print_status(total, mode);
+                    last_status = Instant::now();
+                }
+            },
+        );
+        match outcome {
+            FifoOutcome::QuotaReached | FifoOutcome::SourceStopped => break,
+            FifoOutcome::ReaderClosed => {
+                print_status(written, mode);
+                eprintln!("\nReader disconnected; waiting for a new reader...");
+                std::thread::sleep(REOPEN_BACKOFF);
+            }
+            FifoOutcome::Failed(e) => {
+                eprintln!("\nError writing to FIFO: {e}");
+                break;
+            }
         }
     }
 
+    print_status(written, mode);
+    eprintln!();
+    println!("Stopped after writing {written} bytes.");
     let _ = std::fs::remove_file(path);
+    gate.exit_if_tripped();
+}
+
+/// Overwrite the stderr status line with the running byte count.
+fn print_status(written: u64, mode: ConditioningMode) {
+    eprint!("\r[{mode}] {written} bytes written");
+    let _ = std::io::stderr().flush();
 }
 
 /// Store the FIFO path globally so the signal handler can clean it up.
@@ -180,3 +660,284 @@ fn base64_encode(data: &[u8]) -> String {
     }
     result
 }
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    #[test]
+    fn test_quota_remaining_bytes() {
+        let quota = FifoQuota {
+            max_bytes: Some(100),
+            max_duration: None,
+        };
+        assert_eq!(quota.remaining_bytes(40), Some(60));
+        assert_eq!(quota.remaining_bytes(200), Some(0));
+        assert!(quota.is_exhausted(100, Instant::now()));
+        assert!(!FifoQuota::default().is_exhausted(u64::MAX, Instant::now()));
+    }
+
+    #[test]
+    fn test_warmup_not_counted_toward_bytes() {
+        let mut out = Vec::new();
+        let mut requested = 0usize;
+        write_stream(&mut out, "raw", 0, 10, 100, |n| {
+            requested += n;
+            Some(vec![0x42; n])
+        });
+        assert_eq!(out.len(), 10);
+        assert_eq!(requested, 110);
+    }
+
+    #[test]
+    fn test_stream_stops_on_empty_chunk() {
+        let mut out = Vec::new();
+        let mut calls = 0;
+        let produced = write_stream(&mut out, "raw", 0, 4100, 0, |n| {
+            calls += 1;
+            Some(if calls == 1 {
+                vec![0x42; n]
+            } else {
+                Vec::new()
+            })
+        });
+        assert!(!produced);
+        assert_eq!(out.len(), 4096);
+        assert_eq!(calls, 2);
+
+        let mut written = 0u64;
+        let outcome = feed_fifo(
+            &mut Vec::new(),
+            4096,
+            FifoQuota::default(),
+            Instant::now(),
+            &mut written,
+            |_| Some(Vec::new()),
+            |_| {},
+        );
+        assert!(matches!(outcome, FifoOutcome::SourceStopped));
+    }
+
+    #[test]
+    fn test_entropy_floor_scores_short_chunks_in_a_window() {
+        use openentropy_core::{EntropySource, ReplaySource};
+        let source = ReplaySource::from_seed("seeded", 1);
+
+        // 4096 + 1: the 1-byte tail is judged with the 4 KiB before it.
+        let mut gate = EntropyGate::new(Some(6.0), OnLow::Exit);
+        let mut out = Vec::new();
+        write_stream(&mut out, "raw", 0, 4097, 0, |n| {
+            gate.next(n, |n| source.collect(n), || {})
+        });
+        assert_eq!(out.len(), 4097);
+        assert_eq!(gate.tripped, None);
+
+        // --rate 16: every chunk is 16 bytes.
+        let mut gate = EntropyGate::new(Some(6.0), OnLow::Exit);
+        for _ in 0..600 {
+            let chunk = gate.next(16, |n| source.collect(n), || {});
+            assert_eq!(chunk.map(|c| c.len()), Some(16));
+        }
+        assert_eq!(gate.tripped, None);
+    }
+
+    /// Every byte value equally often: MCV min-entropy ~7.3 bits/byte at 4 KiB.
+    fn uniform_chunk(n: usize) -> Vec<u8> {
+        (0..n).map(|i| i as u8).collect()
+    }
+
+    #[test]
+    fn test_entropy_floor_trips_on_low_entropy_source() {
+        // Deterministic stuck source: every byte identical (H∞ = 0).
+        let mut gate = EntropyGate::new(Some(4.0), OnLow::Exit);
+        let mut out = Vec::new();
+        write_stream(&mut out, "raw", 0, 0, 0, |n| {
+            gate.next(n, |n| vec![0x00; n], || panic!("exit mode never reseeds"))
+        });
+        assert!(out.is_empty());
+        assert_eq!(gate.tripped, Some(0.0));
+    }
+
+    #[test]
+    fn test_entropy_floor_passes_healthy_chunks() {
+        let mut gate = EntropyGate::new(Some(4.0), OnLow::Exit);
+        let mut out = Vec::new();
+        write_stream(&mut out, "raw", 0, 8192, 0, |n| {
+            gate.next(n, uniform_chunk, || {})
+        });
+        assert_eq!(out.len(), 8192);
+        assert_eq!(gate.tripped, None);
+    }
+
+    #[test]
+    fn test_entropy_floor_reseed_recovers() {
+        let mut gate = EntropyGate::new(Some(4.0), OnLow::Reseed);
+        let reseeded = std::cell::Cell::new(0);
+        let data = gate.next(
+            4096,
+            |n| {
+                if reseeded.get() == 0 {
+                    vec![0x00; n]
+                } else {
+                    uniform_chunk(n)
+                }
+            },
+            || reseeded.set(reseeded.get() + 1),
+        );
+        assert_eq!(data.map(|d| d.len()), Some(4096));
+        assert_eq!(reseeded.get(), 1);
+        assert_eq!(gate.tripped, None);
+    }
+
+    #[test]
+    fn test_entropy_floor_reseed_gives_up() {
+        let mut gate = EntropyGate::new(Some(4.0), OnLow::Reseed);
+        let mut reseeds = 0;
+        let data = gate.next(4096, |n| vec![0x00; n], || reseeds += 1);
+        assert!(data.is_none());
+        assert_eq!(reseeds, MAX_RESEED_ATTEMPTS);
+        assert_eq!(gate.tripped, Some(0.0));
+    }
+
+    #[test]
+    fn test_feed_fifo_stops_when_source_stops() {
+        let mut gate = EntropyGate::new(Some(4.0), OnLow::Exit);
+        let mut sink = Vec::new();
+        let mut written = 0u64;
+        let outcome = feed_fifo(
+            &mut sink,
+            4096,
+            FifoQuota::default(),
+            Instant::now(),
+            &mut written,
+            |n| gate.next(n, |n| vec![0x11; n], || {}),
+            |_| {},
+        );
+        assert!(matches!(outcome, FifoOutcome::SourceStopped));
+        assert_eq!(written, 0);
+    }
+
+    #[test]
+    fn test_feed_fifo_stops_at_byte_quota() {
+        let quota = FifoQuota {
+            max_bytes: Some(10_000),
+            max_duration: None,
+        };
+        let mut sink = Vec::new();
+        let mut written = 0u64;
+        let outcome = feed_fifo(
+            &mut sink,
+            4096,
+            quota,
+            Instant::now(),
+            &mut written,
+            |n| Some(vec![0xAB; n]),
+            |_| {},
+        );
+        assert!(matches!(outcome, FifoOutcome::QuotaReached));
+        assert_eq!(written, 10_000);
+        assert_eq!(sink.len(), 10_000);
+    }
+
+    #[test]
+    fn test_reader_disconnect_does_not_panic_writer() {
+        use std::ffi::CString;
+        use std::io::Read;
+
+        let dir = std::env::temp_dir().join(format!("openentropy-fifo-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("entropy.fifo");
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+        // SAFETY: c_path is a valid NUL-terminated CString.
+        assert_eq!(unsafe { libc::mkfifo(c_path.as_ptr(), 0o600) }, 0);
+
+        let reader_path = path.clone();
+        let reader = std::thread::spawn(move || {
+            let mut fifo = std::fs::File::open(reader_path).unwrap();
+            let mut buf = [0u8; 256];
+            fifo.read_exact(&mut buf).unwrap();
+            buf.len()
+        });
+
+        let mut fifo = std::fs::OpenOptions::new().write(true).open(&path).unwrap();
+        let mut written = 0u64;
+        let outcome = feed_fifo(
+            &mut fifo,
+            64,
+            FifoQuota::default(),
+            Instant::now(),
+            &mut written,
+            |n| Some(vec![0x5A; n]),
+            |_| {},
+        );
+
+        assert_eq!(reader.join().unwrap(), 256);
+        assert!(matches!(outcome, FifoOutcome::ReaderClosed));
+        assert!(written >= 256);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    /// A FIFO at a fresh temp path, plus the directory to clean up.
+    fn temp_fifo() -> (std::path::PathBuf, String) {
+        use std::ffi::CString;
+
+        let dir = std::env::temp_dir().join(format!("openentropy-fifo-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("entropy.fifo");
+        let c_path = CString::new(path.to_str().unwrap()).unwrap();
+        // SAFETY: c_path is a valid NUL-terminated CString.
+        assert_eq!(unsafe { libc::mkfifo(c_path.as_ptr(), 0o600) }, 0);
+        let path = path.to_str().unwrap().to_string();
+        (dir, path)
+    }
+
+    #[test]
+    fn test_fifo_open_gives_up_at_deadline_without_reader() {
+        let (dir, path) = temp_fifo();
+        let t0 = Instant::now();
+        let deadline = t0 + Duration::from_millis(200);
+        assert!(FifoWriter::open(&path, Some(deadline)).unwrap().is_none());
+        assert!(t0.elapsed() < Duration::from_secs(2), "{:?}", t0.elapsed());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_fifo_write_gives_up_at_deadline_when_reader_stalls() {
+        let (dir, path) = temp_fifo();
+        // A reader that never reads: the pipe fills and writes would block.
+        let _reader = std::fs::OpenOptions::new()
+            .read(true)
+            .custom_flags(libc::O_NONBLOCK)
+            .open(&path)
+            .unwrap();
+
+        let started = Instant::now();
+        let quota = FifoQuota {
+            max_bytes: None,
+            max_duration: Some(Duration::from_millis(300)),
+        };
+        let mut fifo = FifoWriter::open(&path, quota.deadline(started))
+            .unwrap()
+            .unwrap();
+        let mut written = 0u64;
+        let outcome = feed_fifo(
+            &mut fifo,
+            4096,
+            quota,
+            started,
+            &mut written,
+            |n| Some(vec![0x5A; n]),
+            |_| {},
+        );
+
+        assert!(matches!(outcome, FifoOutcome::QuotaReached), "{outcome:?}");
+        assert!(written > 0);
+        assert!(
+            started.elapsed() < Duration::from_secs(2),
+            "{:?}",
+            started.elapsed()
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
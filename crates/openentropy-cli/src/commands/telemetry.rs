@@ -6,10 +6,36 @@ use std::collections::HashMap;
 use std::time::Duration;
 
 use openentropy_core::{
-    TelemetryMetricDelta, TelemetrySnapshot, TelemetryWindowReport, collect_telemetry_snapshot,
-    collect_telemetry_window,
+    TelemetryConfound, TelemetryMetricDelta, TelemetrySeriesReport, TelemetrySnapshot,
+    TelemetryWindowReport, build_telemetry_series_window, collect_telemetry_series,
+    collect_telemetry_snapshot, collect_telemetry_window, telemetry_confound_from_window,
 };
 
+/// Exit code used when a telemetry window exceeds a configured threshold, or
+/// a threshold could not be evaluated.
+pub const THRESHOLD_EXCEEDED_EXIT_CODE: i32 = 1;
+
+/// Optional alerting thresholds for a windowed telemetry capture.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TelemetryThresholds {
+    /// Maximum allowed temperature rise (degrees C).
+    pub max_thermal_rise: Option<f64>,
+    /// Maximum allowed absolute change in 1-minute load average.
+    pub max_load_delta: Option<f64>,
+    /// Maximum allowed CPU frequency drift (percent of start value).
+    pub max_freq_drift: Option<f64>,
+    /// Pass thresholds on terms the host cannot observe instead of failing.
+    pub allow_missing: bool,
+}
+
+impl TelemetryThresholds {
+    fn any(&self) -> bool {
+        self.max_thermal_rise.is_some()
+            || self.max_load_delta.is_some()
+            || self.max_freq_drift.is_some()
+    }
+}
+
 /// Telemetry capture lifecycle helper shared by command handlers.
 pub struct TelemetryCapture {
     start: Option<TelemetrySnapshot>,
@@ -282,6 +308,32 @@ pub fn print_window_summary(label: &str, window: &TelemetryWindowReport) {
     }
 }
 
+/// Print min/max/mean for the metrics that moved most over a series.
+fn print_series_summary(series: &TelemetrySeriesReport) {
+    println!(
+        "\n  series: {} samples, {} steps",
+        series.samples.len(),
+        series.steps.len()
+    );
+    for d in top_deltas(&series.window, 5) {
+        let Some(st) = series
+            .stats
+            .iter()
+            .find(|st| st.domain == d.domain && st.name == d.name && st.source == d.source)
+        else {
+            continue;
+        };
+        println!(
+            "    {}.{}: min {}  max {}  mean {}",
+            st.domain,
+            st.name,
+            format_value(st.min, &st.unit),
+            format_value(st.max, &st.unit),
+            format_value(st.mean, &st.unit),
+        );
+    }
+}
+
 /// Capture and print a snapshot if telemetry is enabled.
 pub fn print_snapshot_if_enabled(enabled: bool, label: &str) -> Option<TelemetrySnapshot> {
     if !enabled {
@@ -292,27 +344,225 @@ pub fn print_snapshot_if_enabled(enabled: bool, label: &str) -> Option<Telemetry
     Some(snapshot)
 }
 
+/// Compare drift terms against thresholds, returning one message per violation.
+/// A threshold on an unavailable term is a violation unless `allow_missing`.
+fn threshold_violations(
+    confound: &TelemetryConfound,
+    thresholds: &TelemetryThresholds,
+) -> Vec<String> {
+    let checks = [
+        (
+            "thermal rise",
+            confound.thermal_rise_c,
+            thresholds.max_thermal_rise,
+            "C",
+        ),
+        (
+            "load delta (1m)",
+            confound.load_delta_1m.map(f64::abs),
+            thresholds.max_load_delta,
+            "",
+        ),
+        (
+            "frequency drift",
+            confound.freq_drift_pct,
+            thresholds.max_freq_drift,
+            "%",
+        ),
+    ];
+    checks
+        .iter()
+        .filter_map(|&(label, observed, limit, unit)| match (observed, limit) {
+            (Some(v), Some(max)) if v > max => {
+                Some(format!("{label} {v:.2}{unit} exceeds limit {max:.2}{unit}"))
+            }
+            (None, Some(max)) if !thresholds.allow_missing => Some(format!(
+                "{label} not evaluated: unavailable on this host (limit {max:.2}{unit}; \
+                 pass --allow-missing to ignore)"
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Print the drift summary and return the process exit code for the window.
+fn evaluate_thresholds(window: &TelemetryWindowReport, thresholds: &TelemetryThresholds) -> i32 {
+    let confound = telemetry_confound_from_window(window);
+    let fmt = |v: Option<f64>, unit: &str| {
+        v.map(|v| format!("{v:+.2}{unit}"))
+            .unwrap_or_else(|| "unavailable".to_string())
+    };
+    println!("\n  drift:");
+    println!("    thermal rise:    {}", fmt(confound.thermal_rise_c, "C"));
+    println!("    load delta (1m): {}", fmt(confound.load_delta_1m, ""));
+    println!("    frequency drift: {}", fmt(confound.freq_drift_pct, "%"));
+
+    let violations = threshold_violations(&confound, thresholds);
+    if violations.is_empty() {
+        println!("  thresholds: OK");
+        0
+    } else {
+        eprintln!("\nTelemetry thresholds exceeded or not evaluated:");
+        for v in &violations {
+            eprintln!("  - {v}");
+        }
+        THRESHOLD_EXCEEDED_EXIT_CODE
+    }
+}
+
 /// Standalone telemetry command.
-pub fn run(window_sec: f64, output_path: Option<&str>) {
+pub fn run(
+    window_sec: f64,
+    output_path: Option<&str>,
+    series_interval_sec: Option<f64>,
+    compact: bool,
+    thresholds: TelemetryThresholds,
+) {
     if !window_sec.is_finite() || window_sec < 0.0 {
         eprintln!("Invalid --window-sec value: {window_sec}. Expected a finite value >= 0.");
         std::process::exit(2);
     }
+    if let Some(interval) = series_interval_sec {
+        if !interval.is_finite() || interval <= 0.0 {
+            eprintln!("Invalid --series value: {interval}. Expected a finite value > 0.");
+            std::process::exit(2);
+        }
+        if window_sec == 0.0 {
+            eprintln!("--series requires a windowed capture (--window-sec > 0).");
+            std::process::exit(2);
+        }
+    }
+    if thresholds.any() && window_sec == 0.0 {
+        eprintln!("Threshold flags require a windowed capture (--window-sec > 0).");
+        std::process::exit(2);
+    }
     let window_sec = window_sec.min(86_400.0);
-    if window_sec > 0.0 {
+    if let Some(interval) = series_interval_sec {
+        println!(
+            "Collecting telemetry series for {:.2}s every {:.2}s...",
+            window_sec, interval
+        );
+        let samples = collect_telemetry_series(
+            collect_telemetry_snapshot(),
+            Duration::from_secs_f64(window_sec),
+            Duration::from_secs_f64(interval.min(window_sec)),
+        );
+        let series = build_telemetry_series_window(samples)
+            .expect("collected series always holds the start snapshot");
+        print_window_summary("telemetry", &series.window);
+        print_series_summary(&series);
+        if let Some(path) = output_path {
+            super::write_json(&series, path, "Telemetry series", compact);
+        }
+        if thresholds.any() {
+            let code = evaluate_thresholds(&series.window, &thresholds);
+            if code != 0 {
+                std::process::exit(code);
+            }
+        }
+    } else if window_sec > 0.0 {
         println!("Collecting telemetry window for {:.2}s...", window_sec);
         let start = collect_telemetry_snapshot();
         std::thread::sleep(Duration::from_secs_f64(window_sec));
         let report = collect_telemetry_window(start);
         print_window_summary("telemetry", &report);
         if let Some(path) = output_path {
-            super::write_json(&report, path, "Telemetry window");
+            super::write_json(&report, path, "Telemetry window", compact);
+        }
+        if thresholds.any() {
+            let code = evaluate_thresholds(&report, &thresholds);
+            if code != 0 {
+                std::process::exit(code);
+            }
         }
     } else {
         let snapshot = collect_telemetry_snapshot();
         print_snapshot_summary("telemetry", &snapshot);
         if let Some(path) = output_path {
-            super::write_json(&snapshot, path, "Telemetry snapshot");
+            super::write_json(&snapshot, path, "Telemetry snapshot", compact);
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openentropy_core::{TelemetryMetric, build_telemetry_window};
+
+    fn synthetic_window(temp_start: f64, temp_end: f64) -> TelemetryWindowReport {
+        let start = TelemetrySnapshot {
+            model_id: "telemetry_v1".to_string(),
+            model_version: 1,
+            collected_unix_ms: 1000,
+            os: "test".to_string(),
+            arch: "test".to_string(),
+            cpu_count: 1,
+            loadavg_1m: Some(0.5),
+            loadavg_5m: None,
+            loadavg_15m: None,
+            metrics: vec![TelemetryMetric {
+                domain: "thermal".to_string(),
+                name: "cpu".to_string(),
+                value: temp_start,
+                unit: "C".to_string(),
+                source: "test".to_string(),
+            }],
+        };
+        let mut end = start.clone();
+        end.collected_unix_ms = 6000;
+        end.metrics[0].value = temp_end;
+        build_telemetry_window(start, end)
+    }
+
+    #[test]
+    fn test_thermal_rise_over_threshold_exits_nonzero() {
+        let window = synthetic_window(40.0, 48.0);
+        let thresholds = TelemetryThresholds {
+            max_thermal_rise: Some(5.0),
+            ..Default::default()
+        };
+        assert_eq!(
+            evaluate_thresholds(&window, &thresholds),
+            THRESHOLD_EXCEEDED_EXIT_CODE
+        );
+    }
+
+    #[test]
+    fn test_within_thresholds_exits_zero() {
+        let window = synthetic_window(40.0, 41.0);
+        let thresholds = TelemetryThresholds {
+            max_thermal_rise: Some(5.0),
+            max_load_delta: Some(1.0),
+            ..Default::default()
+        };
+        assert_eq!(evaluate_thresholds(&window, &thresholds), 0);
+    }
+
+    #[test]
+    fn test_unavailable_terms_fail_unless_allowed() {
+        let confound = TelemetryConfound::default();
+        let mut thresholds = TelemetryThresholds {
+            max_thermal_rise: Some(0.0),
+            max_load_delta: Some(0.0),
+            max_freq_drift: Some(0.0),
+            allow_missing: false,
+        };
+        let violations = threshold_violations(&confound, &thresholds);
+        assert_eq!(violations.len(), 3);
+        assert!(violations.iter().all(|v| v.contains("not evaluated")));
+
+        thresholds.allow_missing = true;
+        assert!(threshold_violations(&confound, &thresholds).is_empty());
+
+        // The synthetic window has no CPU frequency metric.
+        let window = synthetic_window(40.0, 41.0);
+        let thresholds = TelemetryThresholds {
+            max_freq_drift: Some(10.0),
+            ..Default::default()
+        };
+        assert_eq!(
+            evaluate_thresholds(&window, &thresholds),
+            THRESHOLD_EXCEEDED_EXIT_CODE
+        );
+    }
+}
@@ -12,6 +12,23 @@ use clap::{Parser, Subcommand};
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+
+    /// Log verbosity on stderr (default: info for `server`, off otherwise).
+    /// RUST_LOG-style directives such as `openentropy_core=debug` also work.
+    #[arg(long, global = true)]
+    log_level: Option<String>,
+
+    /// Hex seed for the initial state of pools built by `stream`, `bench`,
+    /// `record`, `monitor` and `server` (default: OS randomness). Output still
+    /// mixes in live source samples, timestamps and OS randomness, so seeding
+    /// alone does not make runs reproducible.
+    #[arg(long, global = true, value_name = "HEX")]
+    seed: Option<String>,
+
+    /// Disable ANSI colors in log lines and the monitor dashboard (also set
+    /// by a non-empty NO_COLOR environment variable)
+    #[arg(long, global = true)]
+    no_color: bool,
 }
 
 #[derive(Subcommand)]
@@ -23,6 +40,17 @@ enum Commands {
         telemetry: bool,
     },
 
+    /// Explain a source's physics, expected entropy rate, and what failure
+    /// looks like (all known sources if none is named). Collects no data.
+    Explain {
+        /// Source name (exact); omit to explain every source
+        source: Option<String>,
+
+        /// Print JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+
     /// Benchmark sources: Shannon entropy, min-entropy, grade, speed.
     /// Use --source to probe a single source in detail.
     /// Includes a conditioned pool quality section by default.
@@ -35,8 +63,17 @@ enum Commands {
         #[arg(long)]
         sources: Option<String>,
 
-        /// Conditioning mode: raw (none), vonneumann (debias only), sha256 (full, default)
-        #[arg(long, default_value = "sha256", value_parser = ["raw", "vonneumann", "sha256"])]
+        /// Comma-separated source names to leave out (applied after --sources; exclude wins)
+        #[arg(long)]
+        exclude: Option<String>,
+
+        /// JSON source profile ({"sources": [name | {"name", "weight"}]}), merged with --sources/--exclude
+        #[arg(long, value_name = "PATH")]
+        sources_file: Option<String>,
+
+        /// Conditioning mode: raw (none), vonneumann (debias only), sha256 (full, default),
+        /// toeplitz (2-universal extractor sized by min-entropy)
+        #[arg(long, default_value = "sha256", value_parser = ["raw", "vonneumann", "sha256", "toeplitz"])]
         conditioning: String,
 
         /// Benchmark profile: quick (<10s), standard (default), deep (higher confidence)
@@ -71,9 +108,29 @@ enum Commands {
         #[arg(long)]
         output: Option<String>,
 
+        /// Write one CSV row per source (grade, entropy, throughput, stability)
+        #[arg(long, value_name = "PATH")]
+        csv: Option<String>,
+
         /// Skip conditioned pool output quality section
         #[arg(long)]
         no_pool: bool,
+
+        /// Compare against a previous `bench --output` report and print per-source deltas
+        #[arg(long)]
+        baseline: Option<String>,
+
+        /// Percent drop in min-entropy or throughput flagged as a regression (with --baseline)
+        #[arg(long, default_value = "10.0")]
+        regression_threshold: f64,
+
+        /// Exit 1 if pooled output min-entropy is below this many bits/byte (0-8), for CI
+        #[arg(long, value_name = "BITS", conflicts_with_all = ["no_pool", "source"])]
+        fail_under: Option<f64>,
+
+        /// Write single-line JSON instead of pretty-printed (for machine pipelines)
+        #[arg(long)]
+        compact: bool,
     },
 
     /// Statistical analysis: autocorrelation, spectral, bias, stationarity, runs.
@@ -84,6 +141,14 @@ enum Commands {
         #[arg(long)]
         sources: Option<String>,
 
+        /// Comma-separated source names to leave out (applied after --sources; exclude wins)
+        #[arg(long)]
+        exclude: Option<String>,
+
+        /// JSON source profile ({"sources": [name | {"name", "weight"}]}), merged with --sources/--exclude
+        #[arg(long, value_name = "PATH")]
+        sources_file: Option<String>,
+
         /// Number of samples to collect per source
         #[arg(long, default_value = "50000")]
         samples: usize,
@@ -92,6 +157,10 @@ enum Commands {
         #[arg(long)]
         output: Option<String>,
 
+        /// Write one CSV row per source with the key scalar metrics
+        #[arg(long, value_name = "PATH")]
+        csv: Option<String>,
+
         /// Compute cross-correlation matrix between all analyzed sources
         #[arg(long)]
         cross_correlation: bool,
@@ -100,8 +169,8 @@ enum Commands {
         #[arg(long)]
         no_entropy: bool,
 
-        /// Conditioning mode: raw (default), vonneumann, sha256
-        #[arg(long, default_value = "raw", value_parser = ["raw", "vonneumann", "sha256"])]
+        /// Conditioning mode: raw (default), vonneumann, sha256, toeplitz
+        #[arg(long, default_value = "raw", value_parser = ["raw", "vonneumann", "sha256", "toeplitz"])]
         conditioning: String,
 
         /// Output view: summary (default, verdict-driven) or detailed (full metrics)
@@ -113,16 +182,50 @@ enum Commands {
         telemetry: bool,
 
         /// Run NIST-inspired randomness test battery with pass/fail, p-values, and scores.
-        /// When combined with --output, writes a Markdown report.
+        /// When combined with --output, writes a Markdown report (JSON if the path ends in .json).
         #[arg(long)]
         report: bool,
+
+        /// With --report: run the battery on N fresh collections per source and
+        /// report per-test pass rates and median p-values
+        #[arg(long, default_value = "1")]
+        repeat: usize,
+
+        /// With --report: print each test's wall time, slowest first
+        #[arg(long, requires = "report")]
+        timings: bool,
+
+        /// With --report: exit 1 if any source's quality score is below this (0-100), for CI.
+        /// With --repeat, each source's median score is checked.
+        #[arg(long, value_name = "SCORE", requires = "report")]
+        fail_under: Option<f64>,
+
+        /// Analyze the bytes of this file instead of collecting from sources
+        #[arg(long, value_name = "PATH", conflicts_with_all = ["sources", "exclude", "sources_file", "stdin"])]
+        input: Option<String>,
+
+        /// Analyze bytes read from stdin instead of collecting from sources
+        #[arg(long, conflicts_with_all = ["sources", "exclude", "sources_file"])]
+        stdin: bool,
+
+        /// Write single-line JSON instead of pretty-printed (for machine pipelines)
+        #[arg(long)]
+        compact: bool,
     },
 
     /// Record entropy samples to disk for offline analysis
     Record {
         /// Comma-separated source names to record from
+        #[arg(long, required_unless_present = "sources_file")]
+        sources: Option<String>,
+
+        /// Comma-separated source names to leave out (applied after --sources; exclude wins)
         #[arg(long)]
-        sources: String,
+        exclude: Option<String>,
+
+        /// JSON source profile ({"sources": [name | {"name", "weight"}]}), merged with --sources/--exclude
+        #[arg(long, value_name = "PATH")]
+        sources_file: Option<String>,
 
         /// Maximum recording duration (e.g. "5m", "30s", "1h")
         #[arg(long)]
@@ -148,13 +251,18 @@ enum Commands {
         #[arg(long)]
         analyze: bool,
 
-        /// Conditioning mode: raw (default for recording), vonneumann, sha256
-        #[arg(long, default_value = "raw", value_parser = ["raw", "vonneumann", "sha256"])]
+        /// Conditioning mode: raw (default for recording), vonneumann, sha256, toeplitz
+        #[arg(long, default_value = "raw", value_parser = ["raw", "vonneumann", "sha256", "toeplitz"])]
         conditioning: String,
 
         /// Store telemetry_v1 start/end snapshots in session.json.
         #[arg(long)]
         telemetry: bool,
+
+        /// Where to write the recording: file (session directory, default) or
+        /// stdout (JSON Lines, one sample per line, then the session metadata)
+        #[arg(long, default_value = "file", value_parser = ["file", "stdout"])]
+        sink: String,
     },
 
     /// Live interactive entropy dashboard (TUI)
@@ -167,9 +275,29 @@ enum Commands {
         #[arg(long)]
         sources: Option<String>,
 
+        /// Comma-separated source names to leave out (applied after --sources; exclude wins)
+        #[arg(long)]
+        exclude: Option<String>,
+
+        /// JSON source profile ({"sources": [name | {"name", "weight"}]}), merged with --sources/--exclude
+        #[arg(long, value_name = "PATH")]
+        sources_file: Option<String>,
+
         /// Print a telemetry_v1 snapshot before launching the dashboard.
         #[arg(long)]
         telemetry: bool,
+
+        /// Collect every source once, print the dashboard metrics as JSON, and exit (no TUI)
+        #[arg(long)]
+        once: bool,
+
+        /// With --once, write the JSON snapshot to this path instead of stdout
+        #[arg(long)]
+        output: Option<String>,
+
+        /// Dashboard palette; colorblind avoids red/green and adds ✔/▲/✖ symbols
+        #[arg(long, default_value = "default", value_parser = tui::theme::Theme::NAMES)]
+        theme: String,
     },
 
     /// Stream raw entropy bytes to stdout (pipe-friendly).
@@ -187,17 +315,46 @@ enum Commands {
         #[arg(long)]
         sources: Option<String>,
 
+        /// Comma-separated source names to leave out (applied after --sources; exclude wins)
+        #[arg(long)]
+        exclude: Option<String>,
+
+        /// JSON source profile ({"sources": [name | {"name", "weight"}]}), merged with --sources/--exclude
+        #[arg(long, value_name = "PATH")]
+        sources_file: Option<String>,
+
         /// Total bytes (0 = infinite, stdout mode only)
         #[arg(long, default_value = "0")]
         bytes: usize,
 
-        /// Conditioning mode: raw (none), vonneumann (debias only), sha256 (full, default)
-        #[arg(long, default_value = "sha256", value_parser = ["raw", "vonneumann", "sha256"])]
+        /// Collect and discard this many bytes before emitting output (not counted toward --bytes)
+        #[arg(long, default_value = "0")]
+        warmup: usize,
+
+        /// Conditioning mode: raw (none), vonneumann (debias only), sha256 (full, default),
+        /// toeplitz (2-universal extractor sized by min-entropy)
+        #[arg(long, default_value = "sha256", value_parser = ["raw", "vonneumann", "sha256", "toeplitz"])]
         conditioning: String,
 
         /// Create a FIFO (named pipe) at this path and feed entropy to readers
         #[arg(long)]
         fifo: Option<String>,
+
+        /// Stop after writing this many bytes in total (FIFO mode only)
+        #[arg(long)]
+        max_bytes: Option<u64>,
+
+        /// Stop after this long (e.g. "30s", "5m", "1h"; FIFO mode only)
+        #[arg(long)]
+        max_duration: Option<String>,
+
+        /// Halt if the min-entropy (MCV, bits/byte) of the last 4 KiB emitted drops below this
+        #[arg(long, value_name = "BITS")]
+        entropy_floor: Option<f64>,
+
+        /// Action when output falls below --entropy-floor: exit nonzero, or re-collect and retry
+        #[arg(long, default_value = "exit", value_parser = ["exit", "reseed"])]
+        on_low: String,
     },
 
     /// List and analyze recorded entropy sessions
@@ -224,6 +381,29 @@ enum Commands {
         /// Write analysis results as JSON
         #[arg(long)]
         output: Option<String>,
+
+        /// Recompute SHA-256 and byte counts of the session's .bin files and
+        /// compare with session.json; exits nonzero on any mismatch
+        #[arg(long)]
+        verify: bool,
+
+        /// Write single-line JSON instead of pretty-printed (for machine pipelines)
+        #[arg(long)]
+        compact: bool,
+
+        /// Export the session's raw samples as a single binary .oerec file
+        /// (header + timestamped per-source frames; see openentropy_core::replay)
+        #[arg(long, value_name = "PATH")]
+        export_oerec: Option<String>,
+
+        /// Follow an in-progress recording: poll raw.bin/raw_index.csv and
+        /// print a rolling per-source summary as new frames are written
+        #[arg(long)]
+        follow: bool,
+
+        /// How often --follow polls for new frames (e.g. "500ms", "2s")
+        #[arg(long, default_value = "1s")]
+        poll_interval: String,
     },
 
     /// Start an HTTP entropy server (ANU QRNG API compatible)
@@ -240,6 +420,14 @@ enum Commands {
         #[arg(long)]
         sources: Option<String>,
 
+        /// Comma-separated source names to leave out (applied after --sources; exclude wins)
+        #[arg(long)]
+        exclude: Option<String>,
+
+        /// JSON source profile ({"sources": [name | {"name", "weight"}]}), merged with --sources/--exclude
+        #[arg(long, value_name = "PATH")]
+        sources_file: Option<String>,
+
         /// Allow conditioning mode selection via ?conditioning=raw|vonneumann|sha256
         #[arg(long)]
         allow_raw: bool,
@@ -247,6 +435,27 @@ enum Commands {
         /// Print a telemetry_v1 snapshot at server startup.
         #[arg(long)]
         telemetry: bool,
+
+        /// Compress JSON responses (gzip/deflate) when the client sends Accept-Encoding
+        #[arg(long)]
+        compress: bool,
+
+        /// Enable POST /sources/refresh to re-detect hardware without restarting
+        #[arg(long)]
+        allow_refresh: bool,
+
+        /// Keep this many bytes of SHA-256 output pre-filled in the background
+        /// so requests are served without collecting (0 = off)
+        #[arg(long, default_value = "0")]
+        prefill: usize,
+
+        /// Refuse to start unless at least N sources pass a startup collection
+        #[arg(long, value_name = "N", default_value = "0")]
+        require_sources: usize,
+
+        /// Total bytes one POST /api/v1/random/batch may draw
+        #[arg(long, value_name = "BYTES", default_value_t = openentropy_server::DEFAULT_MAX_BATCH_BYTES)]
+        max_batch_bytes: usize,
     },
 
     /// Capture telemetry_v1 as a standalone snapshot or timed window
@@ -258,17 +467,53 @@ enum Commands {
         /// Write telemetry JSON to path.
         #[arg(long)]
         output: Option<String>,
+
+        /// Also sample every N seconds within the window (adds per-step deltas and min/max/mean)
+        #[arg(long, value_name = "SECS")]
+        series: Option<f64>,
+
+        /// Exit nonzero if any thermal sensor rises by more than this many degrees C
+        #[arg(long)]
+        max_thermal_rise: Option<f64>,
+
+        /// Exit nonzero if the 1-minute load average changes by more than this
+        #[arg(long)]
+        max_load_delta: Option<f64>,
+
+        /// Exit nonzero if CPU frequency drifts by more than this percentage
+        #[arg(long)]
+        max_freq_drift: Option<f64>,
+
+        /// Pass thresholds on terms this host cannot observe instead of exiting nonzero
+        #[arg(long)]
+        allow_missing: bool,
+
+        /// Write single-line JSON instead of pretty-printed (for machine pipelines)
+        #[arg(long)]
+        compact: bool,
     },
 }
 
 fn main() {
     let cli = Cli::parse();
+    let default_level = match cli.command {
+        Commands::Server { .. } => "info",
+        _ => "off",
+    };
+    commands::set_color(cli.no_color);
+    commands::init_logging(cli.log_level.as_deref().unwrap_or(default_level));
+    if let Some(seed) = cli.seed.as_deref() {
+        commands::set_pool_seed(seed);
+    }
 
     match cli.command {
         Commands::Scan { telemetry } => commands::scan::run(telemetry),
+        Commands::Explain { source, json } => commands::explain::run(source.as_deref(), json),
         Commands::Bench {
             source,
             sources,
+            exclude,
+            sources_file,
             conditioning,
             profile,
             samples_per_round,
@@ -279,8 +524,15 @@ fn main() {
             telemetry,
             output,
             no_pool,
+            csv,
+            compact,
+            baseline,
+            regression_threshold,
+            fail_under,
         } => commands::bench::run(commands::bench::BenchCommandConfig {
             source_filter: sources.as_deref(),
+            exclude: exclude.as_deref(),
+            sources_file: sources_file.as_deref(),
             conditioning: &conditioning,
             source: source.as_deref(),
             profile: &profile,
@@ -290,22 +542,39 @@ fn main() {
             timeout_sec,
             rank_by: &rank_by,
             output_path: output.as_deref(),
+            csv_path: csv.as_deref(),
             include_pool_quality: !no_pool,
             include_telemetry: telemetry,
+            compact,
+            baseline_path: baseline.as_deref(),
+            regression_threshold_pct: regression_threshold,
+            fail_under,
         }),
         Commands::Analyze {
             sources,
+            exclude,
+            sources_file,
             samples,
             output,
+            csv,
             cross_correlation,
             no_entropy,
             conditioning,
             view,
             telemetry,
             report,
+            repeat,
+            timings,
+            fail_under,
+            input,
+            stdin,
+            compact,
         } => commands::analyze::run(commands::analyze::AnalyzeCommandConfig {
             source_filter: sources.as_deref(),
+            exclude: exclude.as_deref(),
+            sources_file: sources_file.as_deref(),
             output_path: output.as_deref(),
+            csv_path: csv.as_deref(),
             samples,
             cross_correlation,
             entropy: !no_entropy,
@@ -313,9 +582,17 @@ fn main() {
             view: &view,
             include_telemetry: telemetry,
             report,
+            repeat,
+            timings,
+            fail_under,
+            input: input.as_deref(),
+            stdin,
+            compact,
         }),
         Commands::Record {
             sources,
+            exclude,
+            sources_file,
             duration,
             tags,
             note,
@@ -324,8 +601,11 @@ fn main() {
             analyze,
             conditioning,
             telemetry,
+            sink,
         } => commands::record::run(
-            &sources,
+            sources.as_deref(),
+            exclude.as_deref(),
+            sources_file.as_deref(),
             duration.as_deref(),
             &tags,
             note.as_deref(),
@@ -334,27 +614,56 @@ fn main() {
             analyze,
             &conditioning,
             telemetry,
+            &sink,
         ),
         Commands::Monitor {
             refresh,
             sources,
+            exclude,
+            sources_file,
             telemetry,
-        } => commands::monitor::run(refresh, sources.as_deref(), telemetry),
+            once,
+            output,
+            theme,
+        } => commands::monitor::run(
+            refresh,
+            sources.as_deref(),
+            exclude.as_deref(),
+            sources_file.as_deref(),
+            telemetry,
+            once,
+            output.as_deref(),
+            &theme,
+        ),
         Commands::Stream {
             format,
             rate,
             sources,
+            exclude,
+            sources_file,
             bytes,
+            warmup,
             conditioning,
             fifo,
-        } => commands::stream::run(
-            &format,
+            max_bytes,
+            max_duration,
+            entropy_floor,
+            on_low,
+        } => commands::stream::run(commands::stream::StreamCommandConfig {
+            format: &format,
             rate,
-            sources.as_deref(),
-            bytes,
-            &conditioning,
-            fifo.as_deref(),
-        ),
+            source_filter: sources.as_deref(),
+            exclude: exclude.as_deref(),
+            sources_file: sources_file.as_deref(),
+            n_bytes: bytes,
+            warmup,
+            conditioning: &conditioning,
+            fifo_path: fifo.as_deref(),
+            max_bytes,
+            max_duration: max_duration.as_deref(),
+            entropy_floor,
+            on_low: &on_low,
+        }),
         Commands::Sessions {
             session,
             dir,
@@ -362,6 +671,11 @@ fn main() {
             entropy,
             telemetry,
             output,
+            verify,
+            compact,
+            export_oerec,
+            follow,
+            poll_interval,
         } => commands::sessions::run(
             session.as_deref(),
             &dir,
@@ -369,16 +683,59 @@ fn main() {
             entropy,
             output.as_deref(),
             telemetry,
+            verify,
+            compact,
+            export_oerec.as_deref(),
+            follow,
+            &poll_interval,
         ),
         Commands::Server {
             port,
             host,
             sources,
+            exclude,
+            sources_file,
             allow_raw,
             telemetry,
-        } => commands::server::run(&host, port, sources.as_deref(), allow_raw, telemetry),
-        Commands::Telemetry { window_sec, output } => {
-            commands::telemetry::run(window_sec, output.as_deref())
-        }
+            compress,
+            allow_refresh,
+            prefill,
+            require_sources,
+            max_batch_bytes,
+        } => commands::server::run(
+            &host,
+            port,
+            sources.as_deref(),
+            exclude.as_deref(),
+            sources_file.as_deref(),
+            allow_raw,
+            telemetry,
+            compress,
+            allow_refresh,
+            prefill,
+            require_sources,
+            max_batch_bytes,
+        ),
+        Commands::Telemetry {
+            window_sec,
+            output,
+            series,
+            max_thermal_rise,
+            max_load_delta,
+            max_freq_drift,
+            allow_missing,
+            compact,
+        } => commands::telemetry::run(
+            window_sec,
+            output.as_deref(),
+            series,
+            compact,
+            commands::telemetry::TelemetryThresholds {
+                max_thermal_rise,
+                max_load_delta,
+                max_freq_drift,
+                allow_missing,
+            },
+        ),
     }
 }
@@ -3,6 +3,7 @@
 //! Design: Single-source selection. Navigate the list, press space to activate
 //! a source. Only the active source collects — keeps everything fast and focused.
 //! Collection runs on a background thread so the UI never blocks.
+//! [`App::run_once`] is the headless path: one collection, rendered as JSON.
 
 use std::collections::{HashMap, VecDeque};
 use std::io;
@@ -27,6 +28,8 @@ use openentropy_core::conditioning::condition;
 use openentropy_core::pool::{EntropyPool, SourceHealth};
 use openentropy_core::session::{SessionConfig, SessionWriter};
 
+use super::theme::Theme;
+
 // ---------------------------------------------------------------------------
 // ChartMode
 // ---------------------------------------------------------------------------
@@ -248,7 +251,9 @@ pub fn next_conditioning(mode: ConditioningMode) -> ConditioningMode {
     match mode {
         ConditioningMode::Sha256 => ConditioningMode::Raw,
         ConditioningMode::Raw => ConditioningMode::VonNeumann,
-        ConditioningMode::VonNeumann => ConditioningMode::Sha256,
+        ConditioningMode::VonNeumann
+        | ConditioningMode::Toeplitz { .. }
+        | ConditioningMode::Keyed { .. } => ConditioningMode::Sha256,
     }
 }
 
@@ -337,6 +342,8 @@ pub struct App {
     recording_path: Option<PathBuf>,
     /// Last start/stop recording error to surface in the TUI.
     recording_error: Option<String>,
+    /// Color palette (`--theme`).
+    theme: Theme,
 }
 
 impl App {
@@ -378,9 +385,20 @@ impl App {
             recording_since: None,
             recording_path: None,
             recording_error: None,
+            theme: Theme::default(),
         }
     }
 
+    /// Use `theme` for rendering.
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
     pub fn run(&mut self) -> io::Result<()> {
         enable_raw_mode()?;
         let mut stdout = io::stdout();
@@ -420,6 +438,63 @@ impl App {
         result
     }
 
+    /// Collect every source once and return the dashboard's metrics as JSON.
+    ///
+    /// Headless: never touches the terminal or enters the event loop. Each
+    /// source collects one `sample_size` batch, bounded by `timeout_secs`;
+    /// sources that time out are reported with zero bytes.
+    pub fn run_once(&self, timeout_secs: f64) -> serde_json::Value {
+        let sample_size = self.sample_size();
+        self.pool.collect_all_parallel_n(timeout_secs, sample_size);
+        let health = self.pool.health_report();
+        {
+            let mut s = self.shared.lock().unwrap();
+            s.cycle_count += 1;
+            for src in &health.sources {
+                s.source_stats.insert(src.name.clone(), src.clone());
+            }
+        }
+
+        let snap = self.snapshot();
+        let sources: Vec<serde_json::Value> = self
+            .source_names
+            .iter()
+            .zip(&self.source_categories)
+            .map(|(name, category)| {
+                let stat = snap.source_stats.get(name);
+                let throughput_bps = stat
+                    .filter(|st| st.time > 0.0)
+                    .map(|st| st.bytes as f64 / st.time);
+                serde_json::json!({
+                    "name": name,
+                    "category": category,
+                    "healthy": stat.map(|st| st.healthy),
+                    "bytes": stat.map_or(0, |st| st.bytes),
+                    "entropy": stat.map(|st| st.entropy),
+                    "min_entropy": stat.map(|st| st.min_entropy),
+                    "collect_time_ms": stat.map(|st| st.time * 1000.0),
+                    "throughput_bps": throughput_bps,
+                    "failures": stat.map_or(0, |st| st.failures),
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "generated_unix": SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            "conditioning": self.conditioning_mode.to_string(),
+            "sample_size": sample_size,
+            "cycle_count": snap.cycle_count,
+            "healthy": health.healthy,
+            "total": health.total,
+            "degraded": health.degraded,
+            "raw_bytes": health.raw_bytes,
+            "sources": sources,
+        })
+    }
+
     fn run_loop(
         &mut self,
         terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
@@ -448,6 +523,8 @@ impl App {
         Ok(())
     }
 
+    // Cursor bounds checks read more clearly inside the arms than as guards.
+    #[allow(clippy::collapsible_match)]
     fn handle_key(&mut self, key: KeyCode) {
         match key {
             KeyCode::Char('q') | KeyCode::Esc => self.running = false,
@@ -809,6 +886,25 @@ impl App {
 mod tests {
     use super::*;
 
+    #[test]
+    fn run_once_returns_without_event_loop() {
+        let app = App::new(
+            crate::commands::make_pool(Some("clock_jitter"), None, None),
+            1.0,
+        );
+        let json = app.run_once(5.0);
+        assert!(!crossterm::terminal::is_raw_mode_enabled().unwrap_or(false));
+        assert_eq!(json["cycle_count"], 1);
+        let sources = json["sources"].as_array().unwrap();
+        assert_eq!(sources.len(), app.source_names().len());
+        let clock = sources
+            .iter()
+            .find(|s| s["name"] == "clock_jitter")
+            .expect("clock_jitter in snapshot");
+        assert!(clock["bytes"].as_u64().unwrap() > 0);
+        assert!(clock["entropy"].is_number());
+    }
+
     #[test]
     fn chart_mode_cycles_through_all_variants() {
         let mode = ChartMode::Shannon;
@@ -0,0 +1,243 @@
+//! Color palettes for the monitor dashboard (`monitor --theme`).
+//!
+//! Draw code never names a color directly; it asks the active [`Theme`] for a
+//! role (accent, muted, ...) or a quality [`Level`]. The colorblind palette
+//! follows Okabe–Ito (blue / yellow / vermillion instead of green / yellow /
+//! red) and also tags levels with symbols, so pass/fail never depends on hue
+//! alone. The monochrome palette (also used under `--no-color`/`NO_COLOR`)
+//! uses the terminal's own colors and tells levels apart by weight and
+//! symbols.
+
+use ratatui::style::{Color, Modifier, Style};
+
+/// Quality level of a measured value, e.g. per-source entropy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl Level {
+    /// Classify a bits/byte entropy value (≥ 7.5 pass, ≥ 5.0 warn).
+    pub fn for_entropy(bits: f64) -> Self {
+        if bits >= 7.5 {
+            Self::Pass
+        } else if bits >= 5.0 {
+            Self::Warn
+        } else {
+            Self::Fail
+        }
+    }
+}
+
+/// A dashboard palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub name: &'static str,
+    pass: Style,
+    warn: Style,
+    fail: Style,
+    /// Suffix symbols for pass/warn/fail; empty when the palette relies on color.
+    symbols: [&'static str; 3],
+    /// Borders, app title, primary chart trace.
+    pub accent: Color,
+    /// The watched source and live output bytes.
+    pub highlight: Color,
+    /// Comparison trace, averages, refresh rate.
+    pub secondary: Color,
+    /// Labels, hints and other de-emphasized text.
+    pub muted: Color,
+    /// Plain foreground text.
+    pub text: Color,
+    /// Background of the cursor row and key bar.
+    pub panel: Color,
+}
+
+const OKABE_BLUE: Color = Color::Rgb(0, 114, 178);
+const OKABE_YELLOW: Color = Color::Rgb(240, 228, 66);
+const OKABE_VERMILLION: Color = Color::Rgb(213, 94, 0);
+const OKABE_SKY: Color = Color::Rgb(86, 180, 233);
+const OKABE_PURPLE: Color = Color::Rgb(204, 121, 167);
+
+impl Theme {
+    /// Names accepted by `--theme`.
+    pub const NAMES: [&'static str; 4] = ["default", "high-contrast", "colorblind", "mono"];
+
+    pub const DEFAULT: Self = Self {
+        name: "default",
+        pass: Style::new().fg(Color::Green),
+        warn: Style::new().fg(Color::Yellow),
+        fail: Style::new().fg(Color::Red),
+        symbols: ["", "", ""],
+        accent: Color::Cyan,
+        highlight: Color::Yellow,
+        secondary: Color::Magenta,
+        muted: Color::DarkGray,
+        text: Color::White,
+        panel: Color::DarkGray,
+    };
+
+    pub const HIGH_CONTRAST: Self = Self {
+        name: "high-contrast",
+        pass: Style::new()
+            .fg(Color::LightGreen)
+            .add_modifier(Modifier::BOLD),
+        warn: Style::new()
+            .fg(Color::LightYellow)
+            .add_modifier(Modifier::BOLD),
+        fail: Style::new()
+            .fg(Color::LightRed)
+            .add_modifier(Modifier::BOLD.union(Modifier::REVERSED)),
+        symbols: ["", "", ""],
+        accent: Color::LightCyan,
+        highlight: Color::LightYellow,
+        secondary: Color::LightMagenta,
+        muted: Color::Gray,
+        text: Color::White,
+        panel: Color::Black,
+    };
+
+    pub const COLORBLIND: Self = Self {
+        name: "colorblind",
+        pass: Style::new().fg(OKABE_BLUE),
+        warn: Style::new().fg(OKABE_YELLOW),
+        fail: Style::new()
+            .fg(OKABE_VERMILLION)
+            .add_modifier(Modifier::BOLD.union(Modifier::UNDERLINED)),
+        symbols: [" ✔", " ▲", " ✖"],
+        accent: OKABE_SKY,
+        highlight: OKABE_YELLOW,
+        secondary: OKABE_PURPLE,
+        muted: Color::DarkGray,
+        text: Color::White,
+        panel: Color::DarkGray,
+    };
+
+    pub const MONOCHROME: Self = Self {
+        name: "mono",
+        pass: Style::new(),
+        warn: Style::new().add_modifier(Modifier::BOLD),
+        fail: Style::new().add_modifier(Modifier::BOLD.union(Modifier::REVERSED)),
+        symbols: [" ✔", " ▲", " ✖"],
+        accent: Color::Reset,
+        highlight: Color::Reset,
+        secondary: Color::Reset,
+        muted: Color::Reset,
+        text: Color::Reset,
+        panel: Color::Reset,
+    };
+
+    /// Look up a theme by its `--theme` name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Self::DEFAULT),
+            "high-contrast" => Some(Self::HIGH_CONTRAST),
+            "colorblind" => Some(Self::COLORBLIND),
+            "mono" => Some(Self::MONOCHROME),
+            _ => None,
+        }
+    }
+
+    /// Style for a quality level.
+    pub fn level(&self, level: Level) -> Style {
+        match level {
+            Level::Pass => self.pass,
+            Level::Warn => self.warn,
+            Level::Fail => self.fail,
+        }
+    }
+
+    /// Symbol suffix for a quality level ("" when the palette has none).
+    pub fn symbol(&self, level: Level) -> &'static str {
+        match level {
+            Level::Pass => self.symbols[0],
+            Level::Warn => self.symbols[1],
+            Level::Fail => self.symbols[2],
+        }
+    }
+
+    /// Inverted badge for alerts such as the recording indicator.
+    /// Palettes without a level color (monochrome) reverse the text instead.
+    pub fn badge(&self, level: Level) -> Style {
+        match self.level(level).fg {
+            Some(color) => Style::new().fg(self.text).bg(color),
+            None => Style::new().add_modifier(Modifier::REVERSED),
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEVELS: [Level; 3] = [Level::Pass, Level::Warn, Level::Fail];
+
+    #[test]
+    fn every_name_resolves() {
+        for name in Theme::NAMES {
+            assert_eq!(Theme::from_name(name).map(|t| t.name), Some(name));
+        }
+        assert_eq!(Theme::from_name("neon"), None);
+    }
+
+    #[test]
+    fn levels_map_to_distinct_styles() {
+        for name in Theme::NAMES {
+            let theme = Theme::from_name(name).unwrap();
+            for (i, a) in LEVELS.iter().enumerate() {
+                for b in &LEVELS[i + 1..] {
+                    assert_ne!(theme.level(*a), theme.level(*b), "{name}: {a:?} vs {b:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn colorblind_does_not_rely_on_hue() {
+        let theme = Theme::COLORBLIND;
+        for level in LEVELS {
+            let fg = theme.level(level).fg;
+            assert_ne!(fg, Some(Color::Red));
+            assert_ne!(fg, Some(Color::Green));
+        }
+        let symbols: Vec<&str> = LEVELS.iter().map(|&l| theme.symbol(l)).collect();
+        assert!(symbols.iter().all(|s| !s.is_empty()));
+        assert_ne!(symbols[0], symbols[1]);
+        assert_ne!(symbols[1], symbols[2]);
+        assert_ne!(symbols[0], symbols[2]);
+    }
+
+    #[test]
+    fn monochrome_sets_no_colors() {
+        let theme = Theme::MONOCHROME;
+        for level in LEVELS {
+            assert_eq!(theme.level(level).fg, None);
+            assert_eq!(theme.level(level).bg, None);
+            assert!(!theme.symbol(level).is_empty());
+        }
+        let roles = [
+            theme.accent,
+            theme.highlight,
+            theme.secondary,
+            theme.muted,
+            theme.text,
+            theme.panel,
+        ];
+        assert!(roles.iter().all(|&c| c == Color::Reset));
+    }
+
+    #[test]
+    fn entropy_levels() {
+        assert_eq!(Level::for_entropy(8.0), Level::Pass);
+        assert_eq!(Level::for_entropy(7.5), Level::Pass);
+        assert_eq!(Level::for_entropy(5.0), Level::Warn);
+        assert_eq!(Level::for_entropy(4.9), Level::Fail);
+    }
+}
@@ -4,6 +4,7 @@
 //! (shared state captured in a single mutex lock per frame).
 
 use super::app::{App, ChartMode, Sample, Snapshot, rolling_autocorr};
+use super::theme::{Level, Theme};
 use openentropy_core::ConditioningMode;
 use ratatui::{prelude::*, widgets::*};
 
@@ -46,14 +47,13 @@ fn display_cat(cat: &str) -> &str {
 // Shared helpers
 // ---------------------------------------------------------------------------
 
-fn entropy_color(val: f64) -> Style {
-    if val >= 7.5 {
-        Style::default().fg(Color::Green)
-    } else if val >= 5.0 {
-        Style::default().fg(Color::Yellow)
-    } else {
-        Style::default().fg(Color::Red)
-    }
+fn entropy_color(theme: &Theme, val: f64) -> Style {
+    theme.level(Level::for_entropy(val))
+}
+
+/// An entropy value with the theme's level symbol appended.
+fn entropy_text(theme: &Theme, val: f64, precision: usize) -> String {
+    format!("{val:.precision$}{}", theme.symbol(Level::for_entropy(val)))
 }
 
 fn format_time(secs: f64) -> String {
@@ -65,13 +65,19 @@ fn format_time(secs: f64) -> String {
 }
 
 /// Build spans for entropy values with coloring.
-fn entropy_spans(label: &str, label_style: Style, h: f64, h_min: f64) -> Vec<Span<'static>> {
+fn entropy_spans(
+    theme: &Theme,
+    label: &str,
+    label_style: Style,
+    h: f64,
+    h_min: f64,
+) -> Vec<Span<'static>> {
     vec![
         Span::styled(label.to_string(), label_style),
         Span::styled("Shannon ", Style::default().bold()),
-        Span::styled(format!("{h:.3}"), entropy_color(h)),
+        Span::styled(entropy_text(theme, h, 3), entropy_color(theme, h)),
         Span::styled("  NIST min ", Style::default().bold()),
-        Span::styled(format!("{h_min:.3}"), entropy_color(h_min)),
+        Span::styled(entropy_text(theme, h_min, 3), entropy_color(theme, h_min)),
     ]
 }
 
@@ -86,10 +92,10 @@ fn extract_chart_values(history: &[Sample], mode: ChartMode) -> Vec<f64> {
 }
 
 /// Render a placeholder block with a gray message (used for empty states).
-fn draw_placeholder(f: &mut Frame, area: Rect, title: String, message: &str) {
+fn draw_placeholder(f: &mut Frame, area: Rect, theme: &Theme, title: String, message: &str) {
     let block = Block::default().borders(Borders::ALL).title(title);
     let p = Paragraph::new(message)
-        .style(Style::default().fg(Color::DarkGray))
+        .style(Style::default().fg(theme.muted))
         .block(block);
     f.render_widget(p, area);
 }
@@ -113,7 +119,7 @@ pub fn draw(f: &mut Frame, app: &mut App) {
     draw_title(f, rows[0], app, &snap);
     draw_main(f, rows[1], app, &snap);
     draw_output(f, rows[2], app, &snap);
-    draw_keys(f, rows[3]);
+    draw_keys(f, rows[3], app.theme());
 }
 
 // ---------------------------------------------------------------------------
@@ -121,6 +127,7 @@ pub fn draw(f: &mut Frame, app: &mut App) {
 // ---------------------------------------------------------------------------
 
 fn draw_title(f: &mut Frame, area: Rect, app: &App, snap: &Snapshot) {
+    let theme = app.theme();
     let rate = app.refresh_rate_secs();
     // Keep a fixed-width activity marker so the title doesn't shift.
     // While recording, keep it static to avoid visual jitter near the REC badge.
@@ -142,15 +149,15 @@ fn draw_title(f: &mut Frame, area: Rect, app: &App, snap: &Snapshot) {
     };
 
     let mut title_spans = vec![
-        Span::styled(" 🔬 OpenEntropy ", Style::default().bold().fg(Color::Cyan)),
+        Span::styled(" 🔬 OpenEntropy ", Style::default().bold().fg(theme.accent)),
         Span::raw("  watching: "),
-        Span::styled(active_label, Style::default().bold().fg(Color::Yellow)),
+        Span::styled(active_label, Style::default().bold().fg(theme.highlight)),
     ];
 
     if let Some(cmp_name) = app.compare_name() {
         title_spans.push(Span::styled(
             format!(" vs {cmp_name}"),
-            Style::default().bold().fg(Color::Magenta),
+            Style::default().bold().fg(theme.secondary),
         ));
     }
 
@@ -160,16 +167,13 @@ fn draw_title(f: &mut Frame, area: Rect, app: &App, snap: &Snapshot) {
                 "  #{}  {}ms  {}B ",
                 snap.cycle_count, snap.last_ms, snap.total_bytes
             ),
-            Style::default().fg(Color::DarkGray),
+            Style::default().fg(theme.muted),
         ),
         Span::styled(
             format!(" @{rate_str}"),
-            Style::default().bold().fg(Color::Magenta),
-        ),
-        Span::styled(
-            format!(" {activity} "),
-            Style::default().fg(Color::DarkGray),
+            Style::default().bold().fg(theme.secondary),
         ),
+        Span::styled(format!(" {activity} "), Style::default().fg(theme.muted)),
     ]);
 
     if app.is_recording() {
@@ -179,32 +183,32 @@ fn draw_title(f: &mut Frame, area: Rect, app: &App, snap: &Snapshot) {
             .unwrap_or_default();
         title_spans.push(Span::styled(
             format!(" REC {} {}smp ", rec_elapsed, snap.recording_samples),
-            Style::default().bold().fg(Color::White).bg(Color::Red),
+            theme.badge(Level::Fail).bold(),
         ));
         if let Some(path) = app.recording_path() {
             title_spans.push(Span::styled(
                 format!(" {} ", path.display()),
-                Style::default().fg(Color::Red),
+                theme.level(Level::Fail),
             ));
         }
     }
     if let Some(err) = app.recording_error() {
         title_spans.push(Span::styled(
             format!(" REC ERROR: {} ", truncate_message(err, 72)),
-            Style::default().fg(Color::White).bg(Color::Red),
+            theme.badge(Level::Fail),
         ));
     }
 
     if let Some(path) = &snap.last_export {
         title_spans.push(Span::styled(
             format!(" saved: {} ", path.display()),
-            Style::default().fg(Color::Green),
+            theme.level(Level::Pass),
         ));
     }
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan))
+        .border_style(Style::default().fg(theme.accent))
         .title(Line::from(title_spans));
 
     f.render_widget(block, area);
@@ -248,6 +252,7 @@ fn truncate_message(s: &str, max_chars: usize) -> String {
 // ---------------------------------------------------------------------------
 
 fn draw_source_list(f: &mut Frame, area: Rect, app: &mut App, snap: &Snapshot) {
+    let theme = *app.theme();
     let names = app.source_names();
     let cats = app.source_categories();
 
@@ -264,7 +269,7 @@ fn draw_source_list(f: &mut Frame, area: Rect, app: &mut App, snap: &Snapshot) {
 
             let stat = snap.source_stats.get(name.as_str());
             let entropy_str = match stat {
-                Some(s) => format!("{:.1}", s.entropy),
+                Some(s) => entropy_text(&theme, s.entropy, 1),
                 None => "—".into(),
             };
             let time_str = match stat {
@@ -273,15 +278,13 @@ fn draw_source_list(f: &mut Frame, area: Rect, app: &mut App, snap: &Snapshot) {
             };
 
             let style = if is_cursor {
-                Style::default().bg(Color::DarkGray).fg(Color::White)
+                Style::default().bg(theme.panel).fg(theme.text)
             } else if is_active {
-                Style::default().fg(Color::Yellow).bold()
+                Style::default().fg(theme.highlight).bold()
             } else {
                 match stat {
-                    Some(s) if s.entropy >= 7.5 => Style::default().fg(Color::Green),
-                    Some(s) if s.entropy >= 5.0 => Style::default().fg(Color::Yellow),
-                    Some(_) => Style::default().fg(Color::Red),
-                    None => Style::default().fg(Color::White),
+                    Some(s) => entropy_color(&theme, s.entropy),
+                    None => Style::default().fg(theme.text),
                 }
             };
 
@@ -298,7 +301,7 @@ fn draw_source_list(f: &mut Frame, area: Rect, app: &mut App, snap: &Snapshot) {
         .collect();
 
     let header = Row::new(vec!["", "", "Source", "Cat", "H", "Time"])
-        .style(Style::default().bold().fg(Color::DarkGray))
+        .style(Style::default().bold().fg(theme.muted))
         .bottom_margin(0);
 
     let table = Table::new(
@@ -308,7 +311,7 @@ fn draw_source_list(f: &mut Frame, area: Rect, app: &mut App, snap: &Snapshot) {
             Constraint::Length(2),  // active marker
             Constraint::Length(22), // name
             Constraint::Length(4),  // category
-            Constraint::Length(5),  // entropy
+            Constraint::Length(7),  // entropy (+ level symbol)
             Constraint::Length(7),  // time
         ],
     )
@@ -328,6 +331,7 @@ fn draw_source_list(f: &mut Frame, area: Rect, app: &mut App, snap: &Snapshot) {
 // ---------------------------------------------------------------------------
 
 fn draw_info(f: &mut Frame, area: Rect, app: &App, snap: &Snapshot) {
+    let theme = app.theme();
     let infos = app.source_infos();
     let idx = app.active().unwrap_or(app.cursor());
 
@@ -338,19 +342,20 @@ fn draw_info(f: &mut Frame, area: Rect, app: &App, snap: &Snapshot) {
         let mut lines = vec![
             Line::from(Span::styled(
                 &info.name,
-                Style::default().bold().fg(Color::Cyan),
+                Style::default().bold().fg(theme.accent),
             )),
             Line::from(Span::styled(
                 display_cat(&info.category),
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.muted),
             )),
         ];
 
         if let Some(s) = stat {
             lines.push(Line::from(""));
             lines.push(Line::from(entropy_spans(
+                theme,
                 "last ",
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.muted),
                 s.entropy,
                 s.min_entropy,
             )));
@@ -366,14 +371,15 @@ fn draw_info(f: &mut Frame, area: Rect, app: &App, snap: &Snapshot) {
                 .sum::<f64>()
                 / n;
             let mut spans = entropy_spans(
+                theme,
                 "avg  ",
-                Style::default().bold().fg(Color::Magenta),
+                Style::default().bold().fg(theme.secondary),
                 avg_sh,
                 avg_min,
             );
             spans.push(Span::styled(
                 format!("  n={}", snap.active_history.len()),
-                Style::default().fg(Color::DarkGray),
+                Style::default().fg(theme.muted),
             ));
             lines.push(Line::from(spans));
         }
@@ -402,6 +408,7 @@ fn draw_info(f: &mut Frame, area: Rect, app: &App, snap: &Snapshot) {
 // ---------------------------------------------------------------------------
 
 fn draw_chart(f: &mut Frame, area: Rect, app: &App, snap: &Snapshot) {
+    let theme = app.theme();
     let mode = app.chart_mode();
     let name = app.active_name().unwrap_or("—");
 
@@ -414,14 +421,14 @@ fn draw_chart(f: &mut Frame, area: Rect, app: &App, snap: &Snapshot) {
     let desc_area = parts[1];
 
     if mode == ChartMode::ByteDistribution {
-        draw_byte_dist(f, chart_area, snap, name);
-        draw_description(f, desc_area, mode);
+        draw_byte_dist(f, chart_area, theme, snap, name);
+        draw_description(f, desc_area, theme, mode);
         return;
     }
 
     if mode == ChartMode::RandomWalk {
-        draw_random_walk(f, chart_area, snap, name);
-        draw_description(f, desc_area, mode);
+        draw_random_walk(f, chart_area, theme, snap, name);
+        draw_description(f, desc_area, theme, mode);
         return;
     }
 
@@ -429,10 +436,11 @@ fn draw_chart(f: &mut Frame, area: Rect, app: &App, snap: &Snapshot) {
         draw_placeholder(
             f,
             chart_area,
+            theme,
             format!(" {name} — select a source "),
             "Press space on a source to start watching",
         );
-        draw_description(f, desc_area, mode);
+        draw_description(f, desc_area, theme, mode);
         return;
     }
 
@@ -443,10 +451,11 @@ fn draw_chart(f: &mut Frame, area: Rect, app: &App, snap: &Snapshot) {
         draw_placeholder(
             f,
             chart_area,
+            theme,
             format!(" {name} — collecting... "),
             "Waiting for data...",
         );
-        draw_description(f, desc_area, mode);
+        draw_description(f, desc_area, theme, mode);
         return;
     }
 
@@ -471,7 +480,7 @@ fn draw_chart(f: &mut Frame, area: Rect, app: &App, snap: &Snapshot) {
         Dataset::default()
             .name(format!("{name} {latest:.2}"))
             .marker(symbols::Marker::Braille)
-            .style(Style::default().fg(Color::Cyan))
+            .style(Style::default().fg(theme.accent))
             .data(&data),
     ];
 
@@ -481,7 +490,7 @@ fn draw_chart(f: &mut Frame, area: Rect, app: &App, snap: &Snapshot) {
             Dataset::default()
                 .name(format!("{cmp_name} {cmp_latest:.2}"))
                 .marker(symbols::Marker::Braille)
-                .style(Style::default().fg(Color::Magenta))
+                .style(Style::default().fg(theme.secondary))
                 .data(&compare_data),
         );
     }
@@ -524,18 +533,18 @@ fn draw_chart(f: &mut Frame, area: Rect, app: &App, snap: &Snapshot) {
         );
 
     f.render_widget(chart, chart_area);
-    draw_description(f, desc_area, mode);
+    draw_description(f, desc_area, theme, mode);
 }
 
 // ---------------------------------------------------------------------------
 // Chart description panel
 // ---------------------------------------------------------------------------
 
-fn draw_description(f: &mut Frame, area: Rect, mode: ChartMode) {
+fn draw_description(f: &mut Frame, area: Rect, theme: &Theme, mode: ChartMode) {
     let desc = mode.description();
     let lines: Vec<Line> = desc
         .iter()
-        .map(|&s| Line::from(Span::styled(s, Style::default().fg(Color::DarkGray))))
+        .map(|&s| Line::from(Span::styled(s, Style::default().fg(theme.muted))))
         .collect();
     let p = Paragraph::new(lines).wrap(Wrap { trim: true });
     f.render_widget(p, area);
@@ -545,7 +554,7 @@ fn draw_description(f: &mut Frame, area: Rect, mode: ChartMode) {
 // Byte distribution (sparkline)
 // ---------------------------------------------------------------------------
 
-fn draw_byte_dist(f: &mut Frame, area: Rect, snap: &Snapshot, name: &str) {
+fn draw_byte_dist(f: &mut Frame, area: Rect, theme: &Theme, snap: &Snapshot, name: &str) {
     let freq = snap.byte_freq;
     let total: u64 = freq.iter().sum();
 
@@ -553,6 +562,7 @@ fn draw_byte_dist(f: &mut Frame, area: Rect, snap: &Snapshot, name: &str) {
         draw_placeholder(
             f,
             area,
+            theme,
             format!(" {name} — [g] Byte dist — collecting... "),
             "Accumulating byte frequencies...",
         );
@@ -592,7 +602,7 @@ fn draw_byte_dist(f: &mut Frame, area: Rect, snap: &Snapshot, name: &str) {
         .block(block)
         .data(&bins)
         .max(max_bin)
-        .style(Style::default().fg(Color::Cyan));
+        .style(Style::default().fg(theme.accent));
 
     f.render_widget(sparkline, area);
 }
@@ -607,11 +617,12 @@ fn draw_byte_dist(f: &mut Frame, area: Rect, snap: &Snapshot, name: &str) {
 /// - **Biased data** → steady drift up or down
 /// - **Correlated data** → smooth, sweeping curves
 /// - **Stuck/broken** → flat line or extreme runaway
-fn draw_random_walk(f: &mut Frame, area: Rect, snap: &Snapshot, name: &str) {
+fn draw_random_walk(f: &mut Frame, area: Rect, theme: &Theme, snap: &Snapshot, name: &str) {
     if snap.walk.is_empty() {
         draw_placeholder(
             f,
             area,
+            theme,
             format!(" {name} — [g] Random walk — collecting... "),
             "Waiting for data...",
         );
@@ -645,7 +656,7 @@ fn draw_random_walk(f: &mut Frame, area: Rect, snap: &Snapshot, name: &str) {
     let dataset = Dataset::default()
         .name(format!("{current:+.0}"))
         .marker(symbols::Marker::Braille)
-        .style(Style::default().fg(Color::Cyan))
+        .style(Style::default().fg(theme.accent))
         .data(&data);
 
     // Zero line reference points (draw a flat line at y=0)
@@ -653,7 +664,7 @@ fn draw_random_walk(f: &mut Frame, area: Rect, snap: &Snapshot, name: &str) {
     let zero_dataset = Dataset::default()
         .name("0")
         .marker(symbols::Marker::Dot)
-        .style(Style::default().fg(Color::DarkGray))
+        .style(Style::default().fg(theme.muted))
         .data(&zero_line);
 
     let chart = Chart::new(vec![dataset, zero_dataset])
@@ -685,25 +696,25 @@ fn draw_random_walk(f: &mut Frame, area: Rect, snap: &Snapshot, name: &str) {
 // ---------------------------------------------------------------------------
 
 fn draw_output(f: &mut Frame, area: Rect, app: &App, snap: &Snapshot) {
+    let theme = app.theme();
     let mode = app.conditioning_mode();
 
-    let (mode_label, mode_color) = match mode {
-        ConditioningMode::Sha256 => ("SHA-256", Color::Green),
-        ConditioningMode::VonNeumann => ("VonNeumann", Color::Yellow),
-        ConditioningMode::Raw => ("Raw", Color::Red),
+    let (mode_label, mode_level) = match mode {
+        ConditioningMode::Sha256 => ("SHA-256", Level::Pass),
+        ConditioningMode::VonNeumann => ("VonNeumann", Level::Warn),
+        ConditioningMode::Raw => ("Raw", Level::Fail),
+        ConditioningMode::Toeplitz { .. } => ("Toeplitz", Level::Pass),
+        ConditioningMode::Keyed { .. } => ("SipHash", Level::Warn),
     };
 
     let lines = vec![
         Line::from(vec![
-            Span::styled("raw   ", Style::default().fg(Color::DarkGray)),
-            Span::styled(&snap.raw_hex, Style::default().fg(Color::White)),
+            Span::styled("raw   ", Style::default().fg(theme.muted)),
+            Span::styled(&snap.raw_hex, Style::default().fg(theme.text)),
         ]),
         Line::from(vec![
-            Span::styled(
-                format!("{mode_label:<6}"),
-                Style::default().bold().fg(mode_color),
-            ),
-            Span::styled(&snap.rng_hex, Style::default().fg(Color::Yellow)),
+            Span::styled(format!("{mode_label:<6}"), theme.level(mode_level).bold()),
+            Span::styled(&snap.rng_hex, Style::default().fg(theme.highlight)),
         ]),
     ];
 
@@ -719,9 +730,9 @@ fn draw_output(f: &mut Frame, area: Rect, app: &App, snap: &Snapshot) {
 // Key help bar
 // ---------------------------------------------------------------------------
 
-fn draw_keys(f: &mut Frame, area: Rect) {
+fn draw_keys(f: &mut Frame, area: Rect, theme: &Theme) {
     let bar = Paragraph::new(" ↑↓ nav  space: select  r: record  g: graph  c: cond  n: size  Tab: compare  p: pause  s: export  +/-: speed  q: quit")
-        .style(Style::default().bg(Color::DarkGray).fg(Color::White));
+        .style(Style::default().bg(theme.panel).fg(theme.text));
     f.render_widget(bar, area);
 }
 
@@ -807,12 +818,20 @@ mod tests {
 
     #[test]
     fn entropy_color_thresholds() {
-        assert_eq!(entropy_color(7.5).fg, Some(Color::Green));
-        assert_eq!(entropy_color(8.0).fg, Some(Color::Green));
-        assert_eq!(entropy_color(5.0).fg, Some(Color::Yellow));
-        assert_eq!(entropy_color(6.0).fg, Some(Color::Yellow));
-        assert_eq!(entropy_color(4.9).fg, Some(Color::Red));
-        assert_eq!(entropy_color(0.0).fg, Some(Color::Red));
+        let theme = Theme::default();
+        assert_eq!(entropy_color(&theme, 7.5).fg, Some(Color::Green));
+        assert_eq!(entropy_color(&theme, 8.0).fg, Some(Color::Green));
+        assert_eq!(entropy_color(&theme, 5.0).fg, Some(Color::Yellow));
+        assert_eq!(entropy_color(&theme, 6.0).fg, Some(Color::Yellow));
+        assert_eq!(entropy_color(&theme, 4.9).fg, Some(Color::Red));
+        assert_eq!(entropy_color(&theme, 0.0).fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn entropy_text_appends_colorblind_symbols() {
+        assert_eq!(entropy_text(&Theme::default(), 7.91, 1), "7.9");
+        assert_eq!(entropy_text(&Theme::COLORBLIND, 7.91, 1), "7.9 ✔");
+        assert_eq!(entropy_text(&Theme::COLORBLIND, 2.0, 1), "2.0 ✖");
     }
 
     #[test]
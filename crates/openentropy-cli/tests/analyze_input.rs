@@ -0,0 +1,36 @@
+//! `openentropy analyze --input` analyzes a byte file without any sources.
+
+use std::process::Command;
+
+#[test]
+fn analyze_input_file_reports_structured_data() {
+    let dir = std::env::temp_dir().join(format!("openentropy-input-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let input = dir.join("ramp.bin");
+    let output = dir.join("out.json");
+    let data: Vec<u8> = (0..20_000).map(|i| (i / 16) as u8).collect();
+    std::fs::write(&input, &data).unwrap();
+
+    let status = Command::new(env!("CARGO_BIN_EXE_openentropy"))
+        .args(["analyze", "--no-entropy", "--input"])
+        .arg(&input)
+        .arg("--output")
+        .arg(&output)
+        .output()
+        .expect("run openentropy analyze");
+    assert!(status.status.success(), "{status:?}");
+
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&output).unwrap()).unwrap();
+    let source = &json["sources"][0];
+    assert_eq!(source["source_name"], "ramp.bin");
+    assert_eq!(source["sample_size"], 20_000);
+    assert!(
+        source["autocorrelation"]["max_abs_correlation"]
+            .as_f64()
+            .unwrap()
+            > 0.9
+    );
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,88 @@
+//! `--fail-under` turns `analyze --report` and `bench` into CI gates.
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+fn report_fail_under(input: &Path, threshold: &str) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_openentropy"))
+        .args(["analyze", "--report", "--fail-under", threshold, "--input"])
+        .arg(input)
+        .output()
+        .expect("run openentropy analyze --report")
+}
+
+#[test]
+fn fail_under_trips_on_low_quality_and_passes_high_quality() {
+    let dir = std::env::temp_dir().join(format!("openentropy-fail-under-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let low = dir.join("ramp.bin");
+    std::fs::write(
+        &low,
+        (0..50_000u32).map(|i| (i / 16) as u8).collect::<Vec<_>>(),
+    )
+    .unwrap();
+    // SplitMix64 output stands in for a good source.
+    let high = dir.join("splitmix.bin");
+    let mut state = 0x9e37_79b9_7f4a_7c15u64;
+    let data: Vec<u8> = (0..50_000)
+        .map(|_| {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            (z ^ (z >> 31)) as u8
+        })
+        .collect();
+    std::fs::write(&high, data).unwrap();
+
+    let out = report_fail_under(&low, "70");
+    assert_eq!(out.status.code(), Some(1), "{out:?}");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("ramp.bin quality score") && stderr.contains("below --fail-under 70"),
+        "{stderr}"
+    );
+
+    let out = report_fail_under(&high, "70");
+    assert!(out.status.success(), "{out:?}");
+    assert!(String::from_utf8_lossy(&out.stdout).contains("PASS"));
+
+    let out = report_fail_under(&high, "101");
+    assert_eq!(out.status.code(), Some(2), "{out:?}");
+
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+fn bench_fail_under(threshold: &str) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_openentropy"))
+        .args([
+            "bench",
+            "--sources",
+            "clock_jitter",
+            "--rounds",
+            "1",
+            "--warmup-rounds",
+            "0",
+            "--fail-under",
+            threshold,
+        ])
+        .output()
+        .expect("run openentropy bench")
+}
+
+#[test]
+fn bench_fail_under_gates_pooled_min_entropy() {
+    // A finite sample's MCV estimate is always below 8 bits/byte.
+    let out = bench_fail_under("8");
+    assert_eq!(out.status.code(), Some(1), "{out:?}");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains("pooled min-entropy") && stderr.contains("below --fail-under 8"),
+        "{stderr}"
+    );
+
+    let out = bench_fail_under("1");
+    assert!(out.status.success(), "{out:?}");
+    assert!(String::from_utf8_lossy(&out.stdout).contains("\nPASS: every pooled min-entropy"));
+}
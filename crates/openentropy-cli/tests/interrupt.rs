@@ -0,0 +1,73 @@
+//! Ctrl-C stops long-running commands early with a nonzero exit code.
+
+#![cfg(unix)]
+
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Command, Stdio};
+use std::thread;
+
+/// Exit code for a command stopped by SIGINT (128 + 2).
+const INTERRUPTED: i32 = 130;
+
+/// Run `openentropy args`, send SIGINT once stdout shows `ready`, and return
+/// the exit code. An empty `ready` waits for the first output byte.
+fn interrupt_when_ready(args: &[&str], ready: &str) -> Option<i32> {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_openentropy"))
+        .args(args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("spawn openentropy");
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+    if ready.is_empty() {
+        stdout.read_exact(&mut [0u8; 1]).expect("first output byte");
+    } else {
+        let mut line = String::new();
+        while !line.contains(ready) {
+            line.clear();
+            let n = stdout.read_line(&mut line).expect("read stdout");
+            assert!(n > 0, "exited before printing {ready:?}");
+        }
+    }
+    // Keep draining so a blocked write cannot hide the interrupt.
+    let drain = thread::spawn(move || std::io::copy(&mut stdout, &mut std::io::sink()));
+
+    let killed = Command::new("kill")
+        .args(["-INT", &child.id().to_string()])
+        .status()
+        .expect("run kill");
+    assert!(killed.success());
+    let status = child.wait().expect("wait for openentropy");
+    let _ = drain.join();
+    status.code()
+}
+
+#[test]
+fn sigint_stops_stream_nonzero() {
+    let code = interrupt_when_ready(&["stream", "--sources", "clock_jitter"], "");
+    assert_eq!(code, Some(INTERRUPTED));
+}
+
+#[test]
+fn sigint_stops_bench_nonzero() {
+    let code = interrupt_when_ready(
+        &["bench", "--sources", "clock_jitter", "--rounds", "100000"],
+        "Benchmarking",
+    );
+    assert_eq!(code, Some(INTERRUPTED));
+}
+
+#[test]
+fn sigint_stops_analyze_nonzero() {
+    let code = interrupt_when_ready(
+        &[
+            "analyze",
+            "--sources",
+            "clock_jitter",
+            "--samples",
+            "10000000",
+        ],
+        "Analyzing",
+    );
+    assert_eq!(code, Some(INTERRUPTED));
+}
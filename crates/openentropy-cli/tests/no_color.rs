@@ -0,0 +1,43 @@
+//! `--no-color` and `NO_COLOR` strip ANSI styling from log output, which is
+//! otherwise colored even when stderr is not a terminal.
+
+use std::process::Command;
+
+fn debug_stream() -> Command {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_openentropy"));
+    cmd.args([
+        "--log-level",
+        "debug",
+        "stream",
+        "--sources",
+        "clock_jitter",
+    ])
+    .args(["--bytes", "16", "--format", "hex"])
+    .env_remove("NO_COLOR");
+    cmd
+}
+
+fn assert_plain_logs(out: &std::process::Output) {
+    assert!(out.status.success(), "{out:?}");
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("DEBUG"), "expected log lines: {stderr}");
+    assert!(!stderr.contains('\x1b'), "escape sequence in: {stderr:?}");
+}
+
+#[test]
+fn no_color_env_disables_ansi() {
+    let out = debug_stream()
+        .env("NO_COLOR", "1")
+        .output()
+        .expect("run openentropy stream");
+    assert_plain_logs(&out);
+}
+
+#[test]
+fn no_color_flag_disables_ansi() {
+    let out = debug_stream()
+        .arg("--no-color")
+        .output()
+        .expect("run openentropy stream");
+    assert_plain_logs(&out);
+}
@@ -0,0 +1,53 @@
+//! Progress bars draw only on a terminal; piped runs must stay clean.
+
+use std::process::{Command, Output};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_openentropy"))
+        .args(args)
+        .output()
+        .expect("run openentropy")
+}
+
+/// No carriage returns or escape sequences, which bar redraws would leave.
+fn assert_no_redraws(stream: &[u8]) {
+    let text = String::from_utf8_lossy(stream);
+    assert!(!text.contains(['\r', '\x1b']), "redraw output: {text:?}");
+}
+
+#[test]
+fn piped_record_to_stdout_is_pure_json_lines() {
+    let out = run(&[
+        "record",
+        "--sources",
+        "clock_jitter",
+        "--duration",
+        "1s",
+        "--sink",
+        "stdout",
+    ]);
+    assert!(out.status.success(), "{out:?}");
+
+    let stdout = String::from_utf8(out.stdout).expect("utf-8 stdout");
+    assert!(stdout.lines().count() > 1);
+    for line in stdout.lines() {
+        serde_json::from_str::<serde_json::Value>(line)
+            .unwrap_or_else(|e| panic!("not JSON ({e}): {line:?}"));
+    }
+    assert_no_redraws(&out.stderr);
+}
+
+#[test]
+fn piped_analyze_emits_no_progress() {
+    let out = run(&[
+        "analyze",
+        "--sources",
+        "clock_jitter",
+        "--samples",
+        "5000",
+        "--no-entropy",
+    ]);
+    assert!(out.status.success(), "{out:?}");
+    assert_no_redraws(&out.stdout);
+    assert_no_redraws(&out.stderr);
+}
@@ -0,0 +1,65 @@
+//! `openentropy record` must still write a complete session when stopped
+//! with Ctrl-C.
+
+#![cfg(unix)]
+
+use std::io::{BufRead, BufReader};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::thread;
+
+use openentropy_core::session::{SessionMeta, verify_session};
+
+#[test]
+fn sigint_mid_record_writes_loadable_session() {
+    let out = std::env::temp_dir().join(format!("openentropy-sigint-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&out);
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_openentropy"))
+        .args([
+            "record",
+            "--sources",
+            "clock_jitter",
+            "--analyze",
+            "--output",
+        ])
+        .arg(&out)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("spawn openentropy record");
+    let mut stdout = BufReader::new(child.stdout.take().unwrap());
+    let mut line = String::new();
+    while !line.contains("First sample written") {
+        line.clear();
+        let n = stdout.read_line(&mut line).expect("read stdout");
+        assert!(n > 0, "record exited before writing a sample");
+    }
+    // Keep draining so a blocked write cannot hide the interrupt.
+    let drain = thread::spawn(move || std::io::copy(&mut stdout, &mut std::io::sink()));
+
+    let killed = Command::new("kill")
+        .args(["-INT", &child.id().to_string()])
+        .status()
+        .expect("run kill");
+    assert!(killed.success());
+    let status = child.wait().expect("wait for record");
+    let _ = drain.join();
+    assert!(status.success(), "record exited with {status}");
+
+    let sessions: Vec<PathBuf> = std::fs::read_dir(&out)
+        .expect("output dir")
+        .map(|e| e.unwrap().path())
+        .collect();
+    assert_eq!(sessions.len(), 1);
+    let dir = &sessions[0];
+
+    let json = std::fs::read_to_string(dir.join("session.json")).expect("session.json");
+    let meta: SessionMeta = serde_json::from_str(&json).expect("valid session.json");
+    assert!(meta.total_samples > 0);
+    assert!(meta.duration_ms > 0);
+    assert!(meta.analysis.is_some());
+    assert!(verify_session(dir).unwrap().is_empty());
+
+    let _ = std::fs::remove_dir_all(&out);
+}
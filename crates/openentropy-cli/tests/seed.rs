@@ -0,0 +1,25 @@
+//! The global `--seed` option is validated before any command runs.
+
+use std::process::Command;
+
+#[test]
+fn invalid_seed_exits_with_usage_error() {
+    let out = Command::new(env!("CARGO_BIN_EXE_openentropy"))
+        .args(["--seed", "not-hex", "stream", "--bytes", "16"])
+        .output()
+        .expect("run openentropy stream");
+    assert_eq!(out.status.code(), Some(2), "{out:?}");
+    assert!(String::from_utf8_lossy(&out.stderr).contains("Invalid --seed"));
+    assert!(out.stdout.is_empty());
+}
+
+#[test]
+fn seeded_stream_emits_requested_bytes() {
+    let out = Command::new(env!("CARGO_BIN_EXE_openentropy"))
+        .args(["stream", "--seed", "00112233", "--sources", "clock_jitter"])
+        .args(["--bytes", "32", "--format", "hex"])
+        .output()
+        .expect("run openentropy stream");
+    assert!(out.status.success(), "{out:?}");
+    assert_eq!(String::from_utf8_lossy(&out.stdout).trim().len(), 64);
+}
@@ -5,8 +5,19 @@
 //! statistics, stationarity, runs analysis, and entropy scaling.
 
 use serde::Serialize;
+use std::collections::VecDeque;
 use std::f64::consts::PI;
 
+/// Lags in the [`full_analysis`] autocorrelation profile.
+const FULL_ANALYSIS_LAGS: usize = 100;
+/// Leading bytes [`spectral_analysis`] looks at.
+const SPECTRAL_WINDOW: usize = 4096;
+/// Windows compared by [`stationarity_test`].
+const STATIONARITY_WINDOWS: usize = 10;
+/// Blocks per stationarity window that [`OnlineAnalysis`] keeps, so it can
+/// rebin to the windows of a stream that ends short.
+const STATIONARITY_BLOCKS: usize = 100;
+
 // ---------------------------------------------------------------------------
 // Result types
 // ---------------------------------------------------------------------------
@@ -204,14 +215,8 @@ pub fn autocorrelation_profile(data: &[u8], max_lag: usize) -> AutocorrResult {
     let mean: f64 = arr.iter().sum::<f64>() / n as f64;
     let var: f64 = arr.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
 
-    let threshold = 2.0 / (n as f64).sqrt();
-    let mut lags = Vec::with_capacity(max_lag);
-    let mut max_abs = 0.0f64;
-    let mut max_abs_lag = 1;
-    let mut violations = 0;
-
-    for lag in 1..=max_lag {
-        let corr = if var < 1e-10 {
+    let corrs = (1..=max_lag).map(|lag| {
+        if var < 1e-10 {
             0.0
         } else {
             let mut sum = 0.0;
@@ -220,8 +225,21 @@ pub fn autocorrelation_profile(data: &[u8], max_lag: usize) -> AutocorrResult {
                 sum += (arr[i] - mean) * (arr[i + lag] - mean);
             }
             sum / (count as f64 * var)
-        };
+        }
+    });
+    summarize_lags(corrs, n)
+}
 
+/// Collect lag-1.. correlations into an [`AutocorrResult`] for `n` bytes.
+fn summarize_lags(corrs: impl Iterator<Item = f64>, n: usize) -> AutocorrResult {
+    let threshold = 2.0 / (n as f64).sqrt();
+    let mut lags = Vec::new();
+    let mut max_abs = 0.0f64;
+    let mut max_abs_lag = 1;
+    let mut violations = 0;
+
+    for (i, corr) in corrs.enumerate() {
+        let lag = i + 1;
         if corr.abs() > max_abs {
             max_abs = corr.abs();
             max_abs_lag = lag;
@@ -247,7 +265,7 @@ pub fn autocorrelation_profile(data: &[u8], max_lag: usize) -> AutocorrResult {
 
 /// Compute spectral analysis via DFT (no external FFT crate).
 pub fn spectral_analysis(data: &[u8]) -> SpectralResult {
-    let n = data.len().min(4096); // Cap for performance
+    let n = data.len().min(SPECTRAL_WINDOW); // Cap for performance
     if n < 2 {
         return SpectralResult {
             peaks: Vec::new(),
@@ -323,7 +341,6 @@ pub fn bit_bias(data: &[u8]) -> BitBiasResult {
         };
     }
 
-    let n = data.len() as f64;
     let mut counts = [0u64; 8];
 
     for &byte in data {
@@ -333,7 +350,11 @@ pub fn bit_bias(data: &[u8]) -> BitBiasResult {
             }
         }
     }
+    bit_bias_from_counts(&counts, data.len() as f64)
+}
 
+/// [`bit_bias`] from per-bit one counts over `n > 0` bytes.
+fn bit_bias_from_counts(counts: &[u64; 8], n: f64) -> BitBiasResult {
     let bit_probs: [f64; 8] = {
         let mut arr = [0.0; 8];
         for (i, &c) in counts.iter().enumerate() {
@@ -384,49 +405,56 @@ pub fn distribution_stats(data: &[u8]) -> DistributionResult {
         };
     }
 
-    let n = data.len() as f64;
-    let arr: Vec<f64> = data.iter().map(|&b| b as f64).collect();
+    let mut histogram = [0u64; 256];
+    for &b in data {
+        histogram[b as usize] += 1;
+    }
+    distribution_from_histogram(&histogram, data.len() as f64)
+}
+
+/// [`distribution_stats`] from a byte histogram over `n > 0` bytes.
+fn distribution_from_histogram(histogram: &[u64; 256], n: f64) -> DistributionResult {
+    // Sum of count * f(value) over the histogram.
+    let moment = |f: &dyn Fn(f64) -> f64| -> f64 {
+        histogram
+            .iter()
+            .enumerate()
+            .map(|(v, &c)| c as f64 * f(v as f64))
+            .sum::<f64>()
+            / n
+    };
 
-    let mean = arr.iter().sum::<f64>() / n;
-    let variance = arr.iter().map(|&x| (x - mean).powi(2)).sum::<f64>() / n;
+    let mean = moment(&|x| x);
+    let variance = moment(&|x| (x - mean).powi(2));
     let std_dev = variance.sqrt();
 
     let skewness = if std_dev > 1e-10 {
-        arr.iter()
-            .map(|&x| ((x - mean) / std_dev).powi(3))
-            .sum::<f64>()
-            / n
+        moment(&|x| ((x - mean) / std_dev).powi(3))
     } else {
         0.0
     };
 
     let kurtosis = if std_dev > 1e-10 {
-        arr.iter()
-            .map(|&x| ((x - mean) / std_dev).powi(4))
-            .sum::<f64>()
-            / n
-            - 3.0 // excess kurtosis
+        moment(&|x| ((x - mean) / std_dev).powi(4)) - 3.0 // excess kurtosis
     } else {
         0.0
     };
 
-    // Histogram
-    let mut histogram = vec![0u64; 256];
-    for &b in data {
-        histogram[b as usize] += 1;
-    }
-
-    // KS test vs uniform [0, 255]
-    let mut sorted: Vec<f64> = arr.clone();
-    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    // KS test vs uniform [0, 255]. The empirical CDF steps through each
+    // value's count, so the largest gap sits at the first or last copy.
     let mut ks_stat = 0.0f64;
-    for (i, &x) in sorted.iter().enumerate() {
-        let empirical = (i + 1) as f64 / n;
-        let theoretical = (x + 0.5) / 256.0; // uniform over [0, 255]
-        let diff = (empirical - theoretical).abs();
-        if diff > ks_stat {
-            ks_stat = diff;
+    let mut seen = 0u64;
+    for (v, &c) in histogram.iter().enumerate() {
+        if c == 0 {
+            continue;
         }
+        let theoretical = (v as f64 + 0.5) / 256.0; // uniform over [0, 255]
+        let first = (seen + 1) as f64 / n;
+        seen += c;
+        let last = seen as f64 / n;
+        ks_stat = ks_stat
+            .max((first - theoretical).abs())
+            .max((last - theoretical).abs());
     }
     // Approximate p-value (Kolmogorov-Smirnov)
     let sqrt_n = n.sqrt();
@@ -439,7 +467,7 @@ pub fn distribution_stats(data: &[u8]) -> DistributionResult {
         std_dev,
         skewness,
         kurtosis,
-        histogram,
+        histogram: histogram.to_vec(),
         ks_statistic: ks_stat,
         ks_p_value: ks_p.min(1.0),
     }
@@ -447,16 +475,10 @@ pub fn distribution_stats(data: &[u8]) -> DistributionResult {
 
 /// Test stationarity by comparing window means (ANOVA-like).
 pub fn stationarity_test(data: &[u8]) -> StationarityResult {
-    let n_windows = 10usize;
+    let n_windows = STATIONARITY_WINDOWS;
     let window_size = data.len() / n_windows;
     if window_size < 10 {
-        return StationarityResult {
-            is_stationary: true,
-            f_statistic: 0.0,
-            window_means: vec![],
-            window_std_devs: vec![],
-            n_windows: 0,
-        };
+        return stationary_by_default();
     }
 
     let mut window_means = Vec::with_capacity(n_windows);
@@ -472,6 +494,27 @@ pub fn stationarity_test(data: &[u8]) -> StationarityResult {
         window_means.push(mean);
         window_std_devs.push(var.sqrt());
     }
+    stationarity_from_windows(window_means, window_std_devs, window_size)
+}
+
+/// Result for data too short to split into windows.
+fn stationary_by_default() -> StationarityResult {
+    StationarityResult {
+        is_stationary: true,
+        f_statistic: 0.0,
+        window_means: vec![],
+        window_std_devs: vec![],
+        n_windows: 0,
+    }
+}
+
+/// ANOVA over at least two windows of `window_size` bytes each.
+fn stationarity_from_windows(
+    window_means: Vec<f64>,
+    window_std_devs: Vec<f64>,
+    window_size: usize,
+) -> StationarityResult {
+    let n_windows = window_means.len();
 
     // One-way ANOVA F-statistic
     let grand_mean: f64 = window_means.iter().sum::<f64>() / n_windows as f64;
@@ -528,8 +571,11 @@ pub fn runs_analysis(data: &[u8]) -> RunsResult {
             current = 1;
         }
     }
+    runs_result(longest, total_runs, data.len() as f64)
+}
 
-    let n = data.len() as f64;
+/// [`RunsResult`] with expectations for `n > 0` random bytes.
+fn runs_result(longest: usize, total_runs: usize, n: f64) -> RunsResult {
     // Expected longest run of same byte ≈ log_256(n) for byte-level
     let expected_longest = (n.ln() / 256.0_f64.ln()).max(1.0);
     // Expected total runs ≈ n * (1 - 1/256) + 1
@@ -584,7 +630,7 @@ pub fn full_analysis(source_name: &str, data: &[u8]) -> SourceAnalysis {
         sample_size: data.len(),
         shannon_entropy: quick_shannon(data),
         min_entropy: quick_min_entropy(data),
-        autocorrelation: autocorrelation_profile(data, 100),
+        autocorrelation: autocorrelation_profile(data, FULL_ANALYSIS_LAGS),
         spectral: spectral_analysis(data),
         bit_bias: bit_bias(data),
         distribution: distribution_stats(data),
@@ -593,6 +639,238 @@ pub fn full_analysis(source_name: &str, data: &[u8]) -> SourceAnalysis {
     }
 }
 
+/// Streaming counterpart of [`full_analysis`] for data that arrives in
+/// chunks, e.g. from
+/// [`EntropyPool::collect_source_chunks`](crate::EntropyPool::collect_source_chunks).
+///
+/// Memory stays bounded whatever the stream length: a byte histogram, running
+/// sums for the autocorrelation lags and stationarity windows, and the first
+/// 4096 bytes that [`spectral_analysis`] looks at. [`finish`](Self::finish)
+/// matches [`full_analysis`] over the concatenated chunks up to floating-point
+/// rounding. The one exception is a stream that ends short of `expected_len`:
+/// stationarity sums are kept per block of a hundredth of an `expected_len`
+/// window, so the windows of the shorter stream are rebinned from whole blocks
+/// and their edges can shift by up to half a block. Bytes past `expected_len`
+/// do not enter the stationarity windows.
+pub struct OnlineAnalysis {
+    n: u64,
+    histogram: [u64; 256],
+    /// First [`SPECTRAL_WINDOW`] bytes.
+    head: Vec<u8>,
+    /// Last [`FULL_ANALYSIS_LAGS`] bytes, oldest first.
+    tail: VecDeque<u8>,
+    /// `lag_products[l - 1]` = sum of `x[i] * x[i + l]`.
+    lag_products: [u64; FULL_ANALYSIS_LAGS],
+    longest_run: usize,
+    current_run: usize,
+    total_runs: usize,
+    /// Bytes covered by the stationarity blocks: `expected_len` rounded down
+    /// to whole windows, or 0 when the windows would be too small to test.
+    blocked_len: u64,
+    /// `(end offset, sum, sum of squares)` of each complete block.
+    blocks: Vec<(u64, u64, u64)>,
+    /// `(sum, sum of squares)` of the block being filled.
+    block: (u64, u64),
+}
+
+impl OnlineAnalysis {
+    /// Start an analysis of a stream expected to be `expected_len` bytes.
+    pub fn new(expected_len: usize) -> Self {
+        Self {
+            n: 0,
+            histogram: [0; 256],
+            head: Vec::new(),
+            tail: VecDeque::with_capacity(FULL_ANALYSIS_LAGS),
+            lag_products: [0; FULL_ANALYSIS_LAGS],
+            longest_run: 0,
+            current_run: 0,
+            total_runs: 0,
+            blocked_len: match expected_len / STATIONARITY_WINDOWS {
+                size if size >= 10 => (size * STATIONARITY_WINDOWS) as u64,
+                _ => 0,
+            },
+            blocks: Vec::with_capacity(STATIONARITY_WINDOWS * STATIONARITY_BLOCKS),
+            block: (0, 0),
+        }
+    }
+
+    /// End offset of block `j` (1-based). Every [`STATIONARITY_BLOCKS`]th
+    /// block ends exactly on an `expected_len` window edge.
+    fn block_end(&self, j: usize) -> u64 {
+        (j as u128 * self.blocked_len as u128
+            / (STATIONARITY_WINDOWS * STATIONARITY_BLOCKS) as u128) as u64
+    }
+
+    /// Bytes pushed so far.
+    pub fn len(&self) -> usize {
+        self.n as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Feed the next chunk of the stream.
+    pub fn push(&mut self, chunk: &[u8]) {
+        for &b in chunk {
+            let x = b as u64;
+            self.histogram[b as usize] += 1;
+            if self.head.len() < SPECTRAL_WINDOW {
+                self.head.push(b);
+            }
+            for (i, &prev) in self.tail.iter().rev().enumerate() {
+                self.lag_products[i] += x * prev as u64;
+            }
+
+            if self.tail.back() == Some(&b) {
+                self.current_run += 1;
+                self.longest_run = self.longest_run.max(self.current_run);
+            } else {
+                self.total_runs += 1;
+                self.current_run = 1;
+                self.longest_run = self.longest_run.max(1);
+            }
+            if self.tail.len() == FULL_ANALYSIS_LAGS {
+                self.tail.pop_front();
+            }
+            self.tail.push_back(b);
+
+            self.n += 1;
+            if self.n <= self.blocked_len {
+                self.block.0 += x;
+                self.block.1 += x * x;
+                // Blocks can be empty when `expected_len` is under a block per byte.
+                while self.blocks.len() < STATIONARITY_WINDOWS * STATIONARITY_BLOCKS
+                    && self.block_end(self.blocks.len() + 1) == self.n
+                {
+                    let (sum, sum_sq) = std::mem::take(&mut self.block);
+                    self.blocks.push((self.n, sum, sum_sq));
+                }
+            }
+        }
+    }
+
+    /// Metrics over everything pushed so far, as [`full_analysis`] reports them.
+    pub fn finish(&self, source_name: &str) -> SourceAnalysis {
+        if self.n as usize == self.head.len() {
+            // Short stream: everything is still in `head`.
+            return full_analysis(source_name, &self.head);
+        }
+        let n = self.n as f64;
+        let shannon_entropy = self
+            .histogram
+            .iter()
+            .filter(|&&c| c > 0)
+            .map(|&c| c as f64 / n)
+            .fold(0.0, |h, p| h - p * p.log2());
+
+        let mut bit_counts = [0u64; 8];
+        for (v, &c) in self.histogram.iter().enumerate() {
+            for (bit, count) in bit_counts.iter_mut().enumerate() {
+                if v & (1 << bit) != 0 {
+                    *count += c;
+                }
+            }
+        }
+
+        SourceAnalysis {
+            source_name: source_name.to_string(),
+            sample_size: self.n as usize,
+            shannon_entropy,
+            min_entropy: crate::conditioning::mcv_from_histogram(&self.histogram, self.n).0,
+            autocorrelation: self.autocorrelation(),
+            spectral: spectral_analysis(&self.head),
+            bit_bias: bit_bias_from_counts(&bit_counts, n),
+            distribution: distribution_from_histogram(&self.histogram, n),
+            stationarity: self.stationarity(),
+            runs: runs_result(self.longest_run, self.total_runs, n),
+        }
+    }
+
+    /// [`autocorrelation_profile`] from the running lag products. Sums are
+    /// kept exact in integers and scaled by `n^2` so the only rounding is
+    /// the final division.
+    fn autocorrelation(&self) -> AutocorrResult {
+        let n = self.n as usize;
+        let max_lag = FULL_ANALYSIS_LAGS.min(n / 2);
+        let nn = self.n as i128;
+        let (total, total_sq) =
+            self.histogram
+                .iter()
+                .enumerate()
+                .fold((0i128, 0i128), |(s, sq), (v, &c)| {
+                    let (v, c) = (v as i128, c as i128);
+                    (s + v * c, sq + v * v * c)
+                });
+        // n^2 * variance
+        let var_n2 = nn * total_sq - total * total;
+        let var = var_n2 as f64 / (nn * nn) as f64;
+
+        let corrs = (1..=max_lag).map(|lag| {
+            if var < 1e-10 {
+                return 0.0;
+            }
+            let count = (n - lag) as i128;
+            let skip_tail: i128 = self.tail.iter().rev().take(lag).map(|&b| b as i128).sum();
+            let skip_head: i128 = self.head[..lag].iter().map(|&b| b as i128).sum();
+            // sum (x[i] - m)(x[i + lag] - m) * n^2, with m = total / n
+            let cov_n2 = nn * nn * self.lag_products[lag - 1] as i128
+                - nn * total * ((total - skip_tail) + (total - skip_head))
+                + count * total * total;
+            cov_n2 as f64 / (count as f64 * var_n2 as f64)
+        });
+        summarize_lags(corrs, n)
+    }
+
+    /// [`stationarity_test`] over windows rebinned from the blocks.
+    fn stationarity(&self) -> StationarityResult {
+        let window_size = self.n.min(self.blocked_len) / STATIONARITY_WINDOWS as u64;
+        if window_size < 10 {
+            return stationary_by_default();
+        }
+        // Running `(offset, sum, sum of squares)` at each block edge, with the
+        // partial block closing at `n` for a short stream.
+        let mut prefix = vec![(0u64, 0u64, 0u64)];
+        let partial = (self.n < self.blocked_len).then_some((self.n, self.block.0, self.block.1));
+        for (end, sum, sum_sq) in self.blocks.iter().copied().chain(partial) {
+            let &(_, total, total_sq) = prefix.last().unwrap();
+            prefix.push((end, total + sum, total_sq + sum_sq));
+        }
+        // The block edge nearest each window edge.
+        let edge = |offset: u64| {
+            let i = prefix.partition_point(|&(end, _, _)| end < offset);
+            match prefix.get(i) {
+                Some(&after) if i == 0 || after.0 - offset <= offset - prefix[i - 1].0 => after,
+                _ => prefix[i - 1],
+            }
+        };
+        let mut means = Vec::with_capacity(STATIONARITY_WINDOWS);
+        let mut std_devs = Vec::with_capacity(STATIONARITY_WINDOWS);
+        for w in 0..STATIONARITY_WINDOWS as u64 {
+            let (start, sum0, sq0) = edge(w * window_size);
+            let (end, sum1, sq1) = edge((w + 1) * window_size);
+            if end == start {
+                // Far shorter than expected: the blocks are wider than a window.
+                return stationary_by_default();
+            }
+            let (sum, sum_sq, size) = ((sum1 - sum0) as u128, (sq1 - sq0) as u128, end - start);
+            let w = size as f64;
+            let var = (size as u128 * sum_sq - sum * sum) as f64 / (w * w);
+            means.push(sum as f64 / w);
+            std_devs.push(var.sqrt());
+        }
+        stationarity_from_windows(means, std_devs, window_size as usize)
+    }
+}
+
+/// Analyze bytes that were captured elsewhere, e.g. read from a file.
+///
+/// Runs the same metrics as [`full_analysis`] without touching any hardware;
+/// re-exported at the crate root.
+pub fn analyze_bytes(name: &str, data: &[u8]) -> SourceAnalysis {
+    full_analysis(name, data)
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -707,6 +985,100 @@ mod tests {
         assert!(result.max_abs_correlation < 0.1);
     }
 
+    #[test]
+    fn test_online_analysis_matches_full_analysis() {
+        let close = |a: f64, b: f64| (a - b).abs() <= 1e-9 * b.abs().max(1.0);
+        // Random, plus a ramp with long runs and strong autocorrelation.
+        let ramp: Vec<u8> = (0..30_000u32).map(|i| (i / 50) as u8).collect();
+        for data in [random_data(50_000), ramp] {
+            let mut online = OnlineAnalysis::new(data.len());
+            for chunk in data.chunks(777) {
+                online.push(chunk);
+            }
+            assert_eq!(online.len(), data.len());
+            let got = online.finish("s");
+            let want = full_analysis("s", &data);
+
+            assert_eq!(got.sample_size, want.sample_size);
+            assert_eq!(got.shannon_entropy, want.shannon_entropy);
+            assert_eq!(got.min_entropy, want.min_entropy);
+            assert_eq!(
+                got.autocorrelation.lags.len(),
+                want.autocorrelation.lags.len()
+            );
+            for (g, w) in got
+                .autocorrelation
+                .lags
+                .iter()
+                .zip(&want.autocorrelation.lags)
+            {
+                assert!(close(g.correlation, w.correlation), "lag {}", w.lag);
+            }
+            assert_eq!(
+                got.autocorrelation.violations,
+                want.autocorrelation.violations
+            );
+            assert_eq!(
+                got.autocorrelation.max_abs_lag,
+                want.autocorrelation.max_abs_lag
+            );
+            assert_eq!(got.spectral.flatness, want.spectral.flatness);
+            assert_eq!(
+                got.bit_bias.bit_probabilities,
+                want.bit_bias.bit_probabilities
+            );
+            assert_eq!(got.distribution.histogram, want.distribution.histogram);
+            assert_eq!(
+                got.distribution.ks_statistic,
+                want.distribution.ks_statistic
+            );
+            assert_eq!(got.distribution.mean, want.distribution.mean);
+            assert!(close(got.distribution.kurtosis, want.distribution.kurtosis));
+            assert_eq!(got.stationarity.n_windows, want.stationarity.n_windows);
+            assert!(close(
+                got.stationarity.f_statistic,
+                want.stationarity.f_statistic
+            ));
+            assert_eq!(
+                got.stationarity.is_stationary,
+                want.stationarity.is_stationary
+            );
+            assert_eq!(got.runs.longest_run, want.runs.longest_run);
+            assert_eq!(got.runs.total_runs, want.runs.total_runs);
+        }
+    }
+
+    #[test]
+    fn test_online_analysis_rebins_a_short_stream() {
+        // Asked for 100_000 bytes but the source stopped at 60_000, so the
+        // windows are 6_000 bytes here rather than 10_000.
+        let data: Vec<u8> = (0..60_000u32).map(|i| (i / 7_000) as u8 * 20).collect();
+        let mut online = OnlineAnalysis::new(100_000);
+        for chunk in data.chunks(777) {
+            online.push(chunk);
+        }
+        let got = online.finish("s").stationarity;
+        let want = stationarity_test(&data);
+        assert_eq!(got.n_windows, want.n_windows);
+        assert_eq!(got.window_means, want.window_means);
+        for (g, w) in got.window_std_devs.iter().zip(&want.window_std_devs) {
+            assert!((g - w).abs() <= 1e-9 * w.max(1.0));
+        }
+        assert!((got.f_statistic - want.f_statistic).abs() <= 1e-9 * want.f_statistic);
+        assert!(!got.is_stationary);
+    }
+
+    #[test]
+    fn test_analyze_bytes_structured_data() {
+        // A slow ramp repeats each value, so neighbours are nearly identical.
+        let data: Vec<u8> = (0..20_000).map(|i| (i / 16) as u8).collect();
+        let result = analyze_bytes("ramp", &data);
+        assert_eq!(result.source_name, "ramp");
+        assert_eq!(result.sample_size, data.len());
+        assert!(result.autocorrelation.lags[0].correlation > 0.9);
+        assert!(result.autocorrelation.violations > 0);
+    }
+
     #[test]
     fn test_autocorrelation_correlated() {
         // Data with strong lag-1 correlation.
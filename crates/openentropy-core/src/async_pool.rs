@@ -0,0 +1,196 @@
+//! Async wrapper around [`EntropyPool`] for tokio applications.
+//!
+//! Source collection is blocking: it spawns worker threads and waits up to the
+//! collection timeout for slow sources. Calling it directly from an async task
+//! stalls that executor thread. [`AsyncEntropyPool`] moves every potentially
+//! blocking call onto tokio's blocking thread pool via
+//! [`tokio::task::spawn_blocking`] so async callers only ever await.
+//!
+//! Enabled with the `async` feature.
+
+use std::sync::Arc;
+
+use crate::conditioning::ConditioningMode;
+use crate::pool::{EntropyPool, HealthReport, PoolError, SourceInfoSnapshot};
+use crate::source::EntropySource;
+
+/// Cheaply cloneable async handle to a shared [`EntropyPool`].
+#[derive(Clone)]
+pub struct AsyncEntropyPool {
+    inner: Arc<EntropyPool>,
+}
+
+impl AsyncEntropyPool {
+    /// Wrap an existing pool.
+    pub fn new(pool: EntropyPool) -> Self {
+        Self {
+            inner: Arc::new(pool),
+        }
+    }
+
+    /// Auto-detect available sources without blocking the executor.
+    pub async fn auto() -> Self {
+        Self::new(
+            tokio::task::spawn_blocking(EntropyPool::auto)
+                .await
+                .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic())),
+        )
+    }
+
+    /// Borrow the underlying pool for calls that never collect
+    /// (e.g. [`EntropyPool::source_count`]).
+    pub fn pool(&self) -> &EntropyPool {
+        &self.inner
+    }
+
+    /// Run `f` against the pool on the blocking thread pool.
+    ///
+    /// Panics inside `f` are propagated to the awaiting task.
+    async fn run_blocking<T, F>(&self, f: F) -> T
+    where
+        T: Send + 'static,
+        F: FnOnce(&EntropyPool) -> T + Send + 'static,
+    {
+        let pool = Arc::clone(&self.inner);
+        match tokio::task::spawn_blocking(move || f(&pool)).await {
+            Ok(value) => value,
+            Err(e) => std::panic::resume_unwind(e.into_panic()),
+        }
+    }
+
+    /// [`EntropyPool::set_prefill`]. Returns immediately; the refill worker
+    /// is a plain thread, not a tokio task.
+    pub fn set_prefill(&self, target_bytes: usize) {
+        self.inner.set_prefill(target_bytes);
+    }
+
+    /// Async [`EntropyPool::collect_all`].
+    pub async fn collect_all(&self) -> usize {
+        self.run_blocking(|pool| pool.collect_all()).await
+    }
+
+    /// Async [`EntropyPool::warm`].
+    pub async fn warm(&self, warmup_bytes: usize, timeout_secs: f64) {
+        self.run_blocking(move |pool| pool.warm(warmup_bytes, timeout_secs))
+            .await
+    }
+
+    /// Async [`EntropyPool::get_bytes`].
+    pub async fn get_bytes(&self, n_bytes: usize, mode: ConditioningMode) -> Vec<u8> {
+        self.run_blocking(move |pool| pool.get_bytes(n_bytes, mode))
+            .await
+    }
+
+    /// Several [`EntropyPool::get_bytes`] draws in one blocking task, in
+    /// order, each with its own length and mode.
+    pub async fn get_bytes_batch(&self, draws: Vec<(usize, ConditioningMode)>) -> Vec<Vec<u8>> {
+        self.run_blocking(move |pool| {
+            draws
+                .into_iter()
+                .map(|(n_bytes, mode)| pool.get_bytes(n_bytes, mode))
+                .collect()
+        })
+        .await
+    }
+
+    /// Async [`EntropyPool::get_bytes_checked`].
+    pub async fn get_bytes_checked(
+        &self,
+        n_bytes: usize,
+        mode: ConditioningMode,
+    ) -> Result<Vec<u8>, PoolError> {
+        self.run_blocking(move |pool| pool.get_bytes_checked(n_bytes, mode))
+            .await
+    }
+
+    /// Async [`EntropyPool::get_source_bytes`].
+    pub async fn get_source_bytes(
+        &self,
+        source_name: &str,
+        n_bytes: usize,
+        mode: ConditioningMode,
+    ) -> Option<Vec<u8>> {
+        let name = source_name.to_string();
+        self.run_blocking(move |pool| pool.get_source_bytes(&name, n_bytes, mode))
+            .await
+    }
+
+    /// Async [`EntropyPool::health_report`].
+    ///
+    /// The report locks each source, which waits on any in-progress collection.
+    pub async fn health_report(&self) -> HealthReport {
+        self.run_blocking(|pool| pool.health_report()).await
+    }
+
+    /// Async [`EntropyPool::source_infos`].
+    pub async fn source_infos(&self) -> Vec<SourceInfoSnapshot> {
+        self.run_blocking(|pool| pool.source_infos()).await
+    }
+
+    /// Async [`EntropyPool::refresh_sources`]. `detect` also runs on the
+    /// blocking pool, since availability probes may spawn subprocesses.
+    pub async fn refresh_sources<F>(&self, detect: F) -> usize
+    where
+        F: FnOnce() -> Vec<(Box<dyn EntropySource>, f64)> + Send + 'static,
+    {
+        self.run_blocking(move |pool| pool.refresh_sources(detect()))
+            .await
+    }
+
+    /// Async [`EntropyPool::source_names`].
+    pub async fn source_names(&self) -> Vec<String> {
+        self.run_blocking(|pool| pool.source_names()).await
+    }
+}
+
+impl From<EntropyPool> for AsyncEntropyPool {
+    fn from(pool: EntropyPool) -> Self {
+        Self::new(pool)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ReplaySource;
+    use std::time::{Duration, Instant};
+
+    const SLOW_COLLECT: Duration = Duration::from_millis(400);
+
+    #[tokio::test(flavor = "current_thread")]
+    async fn concurrent_get_bytes_do_not_serialize() {
+        // Both tasks share one pool; a wrapper that blocked the executor or
+        // held the pool across a collection would take 2x.
+        let mut pool = EntropyPool::new(Some(b"async test"));
+        pool.add_source(
+            Box::new(ReplaySource::from_seed("slow", 0).with_delay(SLOW_COLLECT)),
+            1.0,
+        );
+        let pool = AsyncEntropyPool::new(pool);
+
+        let start = Instant::now();
+        let tasks = [pool.clone(), pool.clone()].map(|pool| {
+            tokio::spawn(async move { pool.get_bytes(32, ConditioningMode::Sha256).await })
+        });
+        for task in tasks {
+            assert_eq!(task.await.unwrap().len(), 32);
+        }
+        let elapsed = start.elapsed();
+
+        assert!(
+            elapsed < SLOW_COLLECT * 2,
+            "calls serialized: took {elapsed:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn checked_get_bytes_reports_empty_pool() {
+        let pool = AsyncEntropyPool::new(EntropyPool::new(Some(b"empty")));
+        assert_eq!(
+            pool.get_bytes_checked(8, ConditioningMode::Sha256).await,
+            Err(PoolError::NoSources)
+        );
+        assert!(pool.source_names().await.is_empty());
+        assert!(pool.health_report().await.degraded);
+    }
+}
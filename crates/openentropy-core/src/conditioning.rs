@@ -18,6 +18,12 @@
 //!   the noise structure. Output is shorter than input (~25% yield).
 //! - **Sha256**: Full SHA-256 conditioning with counter and timestamp mixing.
 //!   Produces cryptographically strong output but destroys the raw signal.
+//! - **Toeplitz**: Seeded Toeplitz-matrix hashing, a 2-universal extractor.
+//!   Output length is sized from the input's min-entropy estimate, so the
+//!   leftover hash lemma bounds its distance from uniform.
+//! - **Keyed**: SipHash-1-3 counter-mode mixing under a caller key. Fast,
+//!   reproducible output for simulations and games. **Not cryptographically
+//!   secure.**
 //!
 //! Most QRNG APIs (ANU, Outshift/Cisco) apply DRBG post-processing that makes
 //! output indistinguishable from PRNG. The `Raw` mode here is what makes
@@ -36,6 +42,19 @@ pub enum ConditioningMode {
     /// SHA-256 hash conditioning (default). Cryptographically strong output.
     #[default]
     Sha256,
+    /// Toeplitz-matrix extraction emitting `output_bits` per input block;
+    /// see [`toeplitz_condition`].
+    Toeplitz {
+        /// Output bits per extracted block (matrix height).
+        output_bits: usize,
+    },
+    /// SipHash-1-3 keyed mixing; see [`keyed_condition`]. Much faster than
+    /// `Sha256` but **not cryptographically secure**: for simulation and
+    /// gaming, never for keys, tokens or nonces.
+    Keyed {
+        /// 128-bit SipHash key; the same key and input give the same output.
+        key: [u8; 16],
+    },
 }
 
 impl std::fmt::Display for ConditioningMode {
@@ -44,6 +63,8 @@ impl std::fmt::Display for ConditioningMode {
             Self::Raw => write!(f, "raw"),
             Self::VonNeumann => write!(f, "von_neumann"),
             Self::Sha256 => write!(f, "sha256"),
+            Self::Toeplitz { .. } => write!(f, "toeplitz"),
+            Self::Keyed { .. } => write!(f, "keyed"),
         }
     }
 }
@@ -61,6 +82,10 @@ impl std::fmt::Display for ConditioningMode {
 /// - `Raw`: returns the input unchanged (truncated to `n_output`)
 /// - `VonNeumann`: debiases then truncates to `n_output`
 /// - `Sha256`: chained SHA-256 hashing to produce exactly `n_output` bytes
+/// - `Toeplitz`: seeded 2-universal hashing; at most `n_output` bytes, fewer
+///   when the input's min-entropy can't support more
+/// - `Keyed`: SipHash-1-3 counter mode to exactly `n_output` bytes (not
+///   cryptographically secure)
 pub fn condition(raw: &[u8], n_output: usize, mode: ConditioningMode) -> Vec<u8> {
     match mode {
         ConditioningMode::Raw => {
@@ -75,6 +100,10 @@ pub fn condition(raw: &[u8], n_output: usize, mode: ConditioningMode) -> Vec<u8>
             out
         }
         ConditioningMode::Sha256 => sha256_condition_bytes(raw, n_output),
+        ConditioningMode::Toeplitz { output_bits } => {
+            toeplitz_condition(raw, n_output, output_bits)
+        }
+        ConditioningMode::Keyed { key } => keyed_condition(raw, n_output, &key),
     }
 }
 
@@ -138,6 +167,145 @@ pub fn sha256_condition(
     (digest, digest)
 }
 
+// ---------------------------------------------------------------------------
+// Keyed SipHash conditioning
+// ---------------------------------------------------------------------------
+
+/// SipHash-1-3 counter-mode stretch of `raw` to exactly `n_output` bytes.
+///
+/// Each 8-byte output block is SipHash-1-3(key, state || raw[i..i + 8] ||
+/// counter), stepping through `raw` 8 bytes at a time and wrapping, with the
+/// state chained from the previous block as in [`sha256_condition_bytes`].
+/// Output is a deterministic function of `key` and `raw`, so a fixed key
+/// replays a simulation from the same input.
+///
+/// **Not cryptographically secure.** SipHash is a keyed PRF for hash tables,
+/// not an extractor or DRBG: it has a 64-bit state, and anyone who knows the
+/// key can predict output from input. Use `Sha256` or `Toeplitz` for
+/// anything security-relevant.
+pub fn keyed_condition(raw: &[u8], n_output: usize, key: &[u8; 16]) -> Vec<u8> {
+    if raw.is_empty() {
+        return Vec::new();
+    }
+    let mut output = Vec::with_capacity(n_output + 8);
+    let mut state = 0u64;
+    let mut offset = 0;
+    let mut counter = 0u64;
+    let mut msg = [0u8; 24];
+    while output.len() < n_output {
+        let end = (offset + 8).min(raw.len());
+        let chunk = &raw[offset..end];
+        msg[..8].copy_from_slice(&state.to_le_bytes());
+        msg[8..8 + chunk.len()].copy_from_slice(chunk);
+        let len = 8 + chunk.len();
+        msg[len..len + 8].copy_from_slice(&counter.to_le_bytes());
+        state = siphash13(key, &msg[..len + 8]);
+        output.extend_from_slice(&state.to_le_bytes());
+        offset += 8;
+        counter += 1;
+        if offset >= raw.len() {
+            offset = 0;
+        }
+    }
+    output.truncate(n_output);
+    output
+}
+
+/// SipHash-1-3 of `msg`; `key` is k0 || k1, little-endian.
+pub fn siphash13(key: &[u8; 16], msg: &[u8]) -> u64 {
+    let k0 = u64::from_le_bytes(key[..8].try_into().unwrap());
+    let k1 = u64::from_le_bytes(key[8..].try_into().unwrap());
+    let mut v = [
+        k0 ^ 0x736f_6d65_7073_6575,
+        k1 ^ 0x646f_7261_6e64_6f6d,
+        k0 ^ 0x6c79_6765_6e65_7261,
+        k1 ^ 0x7465_6462_7974_6573,
+    ];
+    let mut words = msg.chunks_exact(8);
+    for word in &mut words {
+        let m = u64::from_le_bytes(word.try_into().unwrap());
+        v[3] ^= m;
+        sip_round(&mut v);
+        v[0] ^= m;
+    }
+    let mut last = (msg.len() as u64) << 56;
+    for (i, &b) in words.remainder().iter().enumerate() {
+        last |= (b as u64) << (8 * i);
+    }
+    v[3] ^= last;
+    sip_round(&mut v);
+    v[0] ^= last;
+    v[2] ^= 0xff;
+    for _ in 0..3 {
+        sip_round(&mut v);
+    }
+    v[0] ^ v[1] ^ v[2] ^ v[3]
+}
+
+fn sip_round(v: &mut [u64; 4]) {
+    v[0] = v[0].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(13) ^ v[0];
+    v[0] = v[0].rotate_left(32);
+    v[2] = v[2].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(16) ^ v[2];
+    v[0] = v[0].wrapping_add(v[3]);
+    v[3] = v[3].rotate_left(21) ^ v[0];
+    v[2] = v[2].wrapping_add(v[1]);
+    v[1] = v[1].rotate_left(17) ^ v[2];
+    v[2] = v[2].rotate_left(32);
+}
+
+// ---------------------------------------------------------------------------
+// HKDF domain separation (RFC 5869, HMAC-SHA256)
+// ---------------------------------------------------------------------------
+
+/// Maximum HKDF-Expand output length: 255 SHA-256 blocks.
+pub const HKDF_MAX_OUTPUT: usize = 255 * 32;
+
+const HMAC_BLOCK: usize = 64;
+
+/// HMAC-SHA256 over the concatenation of `parts`.
+fn hmac_sha256(key: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let mut block = [0u8; HMAC_BLOCK];
+    if key.len() > HMAC_BLOCK {
+        block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+    let mut inner = Sha256::new();
+    inner.update(block.map(|b| b ^ 0x36));
+    for part in parts {
+        inner.update(part);
+    }
+    let mut outer = Sha256::new();
+    outer.update(block.map(|b| b ^ 0x5c));
+    outer.update(inner.finalize());
+    outer.finalize().into()
+}
+
+/// HKDF-Extract: condense input key material into a 32-byte PRK.
+pub fn hkdf_extract(salt: &[u8], ikm: &[u8]) -> [u8; 32] {
+    hmac_sha256(salt, &[ikm])
+}
+
+/// HKDF-Expand: derive `n_output` bytes from `prk`, bound to the `info` label.
+///
+/// Distinct labels give independent outputs from the same PRK.
+/// `n_output` is capped at [`HKDF_MAX_OUTPUT`].
+pub fn hkdf_expand(prk: &[u8], info: &[u8], n_output: usize) -> Vec<u8> {
+    let n_output = n_output.min(HKDF_MAX_OUTPUT);
+    let mut output = Vec::with_capacity(n_output);
+    let mut t: Vec<u8> = Vec::new();
+    let mut counter = 1u8;
+    while output.len() < n_output {
+        t = hmac_sha256(prk, &[&t, info, &[counter]]).to_vec();
+        output.extend_from_slice(&t);
+        counter = counter.wrapping_add(1);
+    }
+    output.truncate(n_output);
+    output
+}
+
 // ---------------------------------------------------------------------------
 // Von Neumann debiasing
 // ---------------------------------------------------------------------------
@@ -183,6 +351,128 @@ pub fn xor_fold(data: &[u8]) -> Vec<u8> {
     (0..half).map(|i| data[i] ^ data[half + i]).collect()
 }
 
+// ---------------------------------------------------------------------------
+// Toeplitz extraction
+// ---------------------------------------------------------------------------
+
+/// Security parameter of [`toeplitz_condition`]: each block's output is
+/// within ε = 2^-64 of uniform (statistical distance).
+pub const TOEPLITZ_SECURITY_BITS: usize = 64;
+
+/// `output_bits` used when Toeplitz conditioning is selected by name
+/// (`--conditioning toeplitz`, `conditioning="toeplitz"`).
+pub const TOEPLITZ_DEFAULT_OUTPUT_BITS: usize = 256;
+
+/// Input bytes per Toeplitz block at `min_entropy` bits per byte, or `None`
+/// when the estimate is too low to extract anything.
+///
+/// By the leftover hash lemma, hashing an input with `k` bits of min-entropy
+/// through a random 2-universal function yields `m = k - 2·log2(1/ε)` bits
+/// within ε of uniform. So a block must carry `output_bits + 2 ·`
+/// [`TOEPLITZ_SECURITY_BITS`] bits of min-entropy.
+pub fn toeplitz_block_bytes(output_bits: usize, min_entropy: f64) -> Option<usize> {
+    let per_byte = min_entropy.min(8.0);
+    if output_bits == 0 || !per_byte.is_finite() || per_byte < 0.01 {
+        return None;
+    }
+    let needed = (output_bits + 2 * TOEPLITZ_SECURITY_BITS) as f64;
+    Some((needed / per_byte).ceil() as usize)
+}
+
+/// Seed bytes needed for a Toeplitz matrix of `output_bits` rows over
+/// `block_bytes` of input: one bit per diagonal, `m + n - 1`.
+pub fn toeplitz_seed_bytes(output_bits: usize, block_bytes: usize) -> usize {
+    (output_bits + block_bytes * 8 - 1).div_ceil(8)
+}
+
+/// Toeplitz extraction with a fresh OS-random seed matrix.
+///
+/// Sizes blocks from the [`quick_min_entropy`] estimate of `raw` (see
+/// [`toeplitz_block_bytes`]) and hashes each full block to `output_bits`
+/// bits until `n_output` bytes are produced or `raw` runs out; a trailing
+/// partial block is dropped. Returns fewer than `n_output` bytes, possibly
+/// none, when `raw` is too short or its min-entropy too low.
+///
+/// Unlike [`sha256_condition_bytes`], whose output "looks" uniform whatever
+/// went in, this is an information-theoretic extractor: given the min-entropy
+/// estimate holds, the output is provably ε-close to uniform without any
+/// assumption about SHA-256. The seed need not be secret, only independent of
+/// the input. The guarantee is only as good as the estimate, which is
+/// heuristic for non-IID sources.
+pub fn toeplitz_condition(raw: &[u8], n_output: usize, output_bits: usize) -> Vec<u8> {
+    let Some(block) = toeplitz_block_bytes(output_bits, quick_min_entropy(raw)) else {
+        return Vec::new();
+    };
+    if raw.len() < block || n_output == 0 {
+        return Vec::new();
+    }
+    let mut seed = vec![0u8; toeplitz_seed_bytes(output_bits, block)];
+    getrandom::fill(&mut seed).expect("OS CSPRNG failed");
+    toeplitz_condition_with_seed(raw, n_output, output_bits, block, &seed)
+}
+
+/// [`toeplitz_condition`] with a caller-chosen block size and seed matrix.
+///
+/// Deterministic: the same arguments always give the same output. Returns
+/// an empty vector if `seed` is shorter than [`toeplitz_seed_bytes`].
+pub fn toeplitz_condition_with_seed(
+    raw: &[u8],
+    n_output: usize,
+    output_bits: usize,
+    block_bytes: usize,
+    seed: &[u8],
+) -> Vec<u8> {
+    if output_bits == 0
+        || block_bytes == 0
+        || seed.len() < toeplitz_seed_bytes(output_bits, block_bytes)
+    {
+        return Vec::new();
+    }
+    let seed_bits = unpack_bits(seed);
+    let target_bits = n_output * 8;
+    let mut bits = Vec::with_capacity(target_bits);
+    for chunk in raw.chunks_exact(block_bytes) {
+        if bits.len() >= target_bits {
+            break;
+        }
+        toeplitz_hash_bits(chunk, output_bits, &seed_bits, &mut bits);
+    }
+    bits.truncate(target_bits);
+
+    let mut out: Vec<u8> = bits
+        .chunks(8)
+        .map(|c| {
+            c.iter()
+                .enumerate()
+                .fold(0u8, |b, (i, &bit)| b | (bit << (7 - i)))
+        })
+        .collect();
+    // A partial final byte is padding, not output.
+    out.truncate(bits.len() / 8);
+    out
+}
+
+/// MSB-first bits of `data`, one per element.
+fn unpack_bits(data: &[u8]) -> Vec<u8> {
+    data.iter()
+        .flat_map(|&b| (0..8).rev().map(move |i| (b >> i) & 1))
+        .collect()
+}
+
+/// Append `T·x` over GF(2) to `out`, where `x` is the block's bits and `T`
+/// the `output_bits × n` Toeplitz matrix `T[i][j] = seed[i + n - 1 - j]`.
+fn toeplitz_hash_bits(block: &[u8], output_bits: usize, seed: &[u8], out: &mut Vec<u8>) {
+    // Reversing x turns each row into a contiguous window of the seed.
+    let mut x = unpack_bits(block);
+    x.reverse();
+    let n = x.len();
+    for i in 0..output_bits {
+        let row = &seed[i..i + n];
+        let bit = row.iter().zip(&x).fold(0u8, |acc, (&s, &b)| acc ^ (s & b));
+        out.push(bit);
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Quick analysis utilities
 // ---------------------------------------------------------------------------
@@ -228,7 +518,12 @@ pub fn mcv_estimate(data: &[u8]) -> (f64, f64) {
     for &b in data {
         counts[b as usize] += 1;
     }
-    let n = data.len() as f64;
+    mcv_from_histogram(&counts, data.len() as u64)
+}
+
+/// [`mcv_estimate`] from a byte histogram over `n > 0` bytes.
+pub(crate) fn mcv_from_histogram(counts: &[u64; 256], n: u64) -> (f64, f64) {
+    let n = n as f64;
     let max_count = *counts.iter().max().unwrap() as f64;
     let p_hat = max_count / n;
 
@@ -621,22 +916,7 @@ pub fn quick_min_entropy(data: &[u8]) -> f64 {
 
 /// Quick Shannon entropy in bits/byte for a byte slice.
 pub fn quick_shannon(data: &[u8]) -> f64 {
-    if data.is_empty() {
-        return 0.0;
-    }
-    let mut counts = [0u64; 256];
-    for &b in data {
-        counts[b as usize] += 1;
-    }
-    let n = data.len() as f64;
-    let mut h = 0.0;
-    for &c in &counts {
-        if c > 0 {
-            let p = c as f64 / n;
-            h -= p * p.log2();
-        }
-    }
-    h
+    openentropy_primitives::shannon_entropy(data)
 }
 
 /// Grade a source based on its min-entropy (H∞) value.
@@ -739,6 +1019,74 @@ mod tests {
     // Conditioning mode tests
     // -----------------------------------------------------------------------
 
+    #[test]
+    fn test_toeplitz_output_matches_output_bits() {
+        let mut raw = vec![0u8; 8192];
+        getrandom::fill(&mut raw).unwrap();
+        for output_bits in [256, 512] {
+            let mode = ConditioningMode::Toeplitz { output_bits };
+            let out = condition(&raw, output_bits / 8, mode);
+            assert_eq!(out.len(), output_bits / 8, "{mode:?}");
+        }
+        // Several blocks are concatenated up to n_output.
+        let out = condition(&raw, 100, ConditioningMode::Toeplitz { output_bits: 256 });
+        assert_eq!(out.len(), 100);
+    }
+
+    #[test]
+    fn test_toeplitz_reproducible_with_fixed_seed() {
+        let raw: Vec<u8> = (0..4096u32)
+            .map(|i| (i.wrapping_mul(2654435761) >> 13) as u8)
+            .collect();
+        let block = 64;
+        let seed: Vec<u8> = (0..toeplitz_seed_bytes(256, block))
+            .map(|i| (i * 37 + 11) as u8)
+            .collect();
+        let a = toeplitz_condition_with_seed(&raw, 64, 256, block, &seed);
+        let b = toeplitz_condition_with_seed(&raw, 64, 256, block, &seed);
+        assert_eq!(a.len(), 64);
+        assert_eq!(a, b);
+
+        let mut other = seed.clone();
+        other[0] ^= 0x80;
+        assert_ne!(
+            a,
+            toeplitz_condition_with_seed(&raw, 64, 256, block, &other)
+        );
+        assert!(toeplitz_condition_with_seed(&raw, 64, 256, block, &seed[1..]).is_empty());
+    }
+
+    #[test]
+    fn test_toeplitz_is_linear_over_gf2() {
+        let block = 48;
+        let seed: Vec<u8> = (0..toeplitz_seed_bytes(128, block))
+            .map(|i| (i * 73 + 5) as u8)
+            .collect();
+        let x: Vec<u8> = (0..block).map(|i| (i * 29 + 3) as u8).collect();
+        let y: Vec<u8> = (0..block).map(|i| (i * 101 + 7) as u8).collect();
+        let xy: Vec<u8> = x.iter().zip(&y).map(|(a, b)| a ^ b).collect();
+        let hash = |d: &[u8]| toeplitz_condition_with_seed(d, 16, 128, block, &seed);
+
+        let expected: Vec<u8> = hash(&x).iter().zip(hash(&y)).map(|(a, b)| a ^ b).collect();
+        assert_eq!(hash(&xy), expected);
+        assert_eq!(hash(&[0u8; 48]), vec![0u8; 16]);
+    }
+
+    #[test]
+    fn test_toeplitz_refuses_low_entropy_input() {
+        assert!(
+            condition(
+                &[0u8; 4096],
+                32,
+                ConditioningMode::Toeplitz { output_bits: 256 }
+            )
+            .is_empty()
+        );
+        assert_eq!(toeplitz_block_bytes(0, 7.0), None);
+        // 256 + 2·64 bits at 4 bits/byte.
+        assert_eq!(toeplitz_block_bytes(256, 4.0), Some(96));
+    }
+
     #[test]
     fn test_condition_raw_passthrough() {
         let data = vec![1, 2, 3, 4, 5];
@@ -796,6 +1144,39 @@ mod tests {
         assert!(out.is_empty(), "Empty input should produce no output");
     }
 
+    #[test]
+    fn test_hkdf_rfc5869_case1() {
+        let hex = |s: &str| -> Vec<u8> {
+            (0..s.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+                .collect()
+        };
+        let ikm = [0x0bu8; 22];
+        let salt = hex("000102030405060708090a0b0c");
+        let info = hex("f0f1f2f3f4f5f6f7f8f9");
+        let prk = hkdf_extract(&salt, &ikm);
+        assert_eq!(
+            prk.to_vec(),
+            hex("077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5")
+        );
+        assert_eq!(
+            hkdf_expand(&prk, &info, 42),
+            hex(
+                "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865"
+            )
+        );
+    }
+
+    #[test]
+    fn test_hkdf_labels_separate_and_repeat() {
+        let prk = [7u8; 32];
+        let keys = hkdf_expand(&prk, b"keys", 64);
+        assert_eq!(keys, hkdf_expand(&prk, b"keys", 64));
+        assert_ne!(keys, hkdf_expand(&prk, b"nonces", 64));
+        assert_eq!(hkdf_expand(&prk, b"x", usize::MAX).len(), HKDF_MAX_OUTPUT);
+    }
+
     #[test]
     fn test_von_neumann_reduces_size() {
         let input = vec![0b10101010u8; 128];
@@ -1333,4 +1714,48 @@ mod tests {
     fn test_grade_negative() {
         assert_eq!(grade_min_entropy(-1.0), 'F');
     }
+
+    #[test]
+    fn test_siphash13_reference_vectors() {
+        // Published SipHash-1-3 vectors: key 00..0f, message 00..len-1,
+        // output as little-endian bytes.
+        let key: [u8; 16] = std::array::from_fn(|i| i as u8);
+        let vectors: [(usize, [u8; 8]); 7] = [
+            (0, [0xdc, 0xc4, 0x0f, 0x05, 0x58, 0x01, 0xac, 0xab]),
+            (1, [0x93, 0xca, 0x57, 0x7d, 0xf3, 0x9b, 0xf4, 0xc9]),
+            (7, [0x40, 0x11, 0xb1, 0x9b, 0x98, 0x7d, 0x92, 0xd3]),
+            (8, [0x8e, 0x9a, 0x29, 0x8d, 0x11, 0x95, 0x90, 0x36]),
+            (15, [0x56, 0x99, 0x51, 0x2a, 0x6d, 0xd8, 0x20, 0xd3]),
+            (16, [0x66, 0x8b, 0x90, 0x7d, 0x1a, 0xdd, 0x4f, 0xcc]),
+            (63, [0xa8, 0xb3, 0xbb, 0xb7, 0x62, 0x90, 0x19, 0x9d]),
+        ];
+        for (len, expected) in vectors {
+            let msg: Vec<u8> = (0..len as u8).collect();
+            assert_eq!(
+                siphash13(&key, &msg),
+                u64::from_le_bytes(expected),
+                "len {len}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_keyed_deterministic_for_fixed_key() {
+        let raw: Vec<u8> = (0..100).collect();
+        let key = *b"0123456789abcdef";
+        let mode = ConditioningMode::Keyed { key };
+        let a = condition(&raw, 1000, mode);
+        assert_eq!(a.len(), 1000);
+        assert_eq!(a, condition(&raw, 1000, mode));
+        assert_eq!(&a[..37], &condition(&raw, 37, mode)[..]);
+
+        let other = condition(&raw, 1000, ConditioningMode::Keyed { key: [0; 16] });
+        assert_ne!(a, other);
+        let mut raw2 = raw.clone();
+        raw2[50] ^= 1;
+        assert_ne!(a, condition(&raw2, 1000, mode));
+        assert!(condition(&[], 10, mode).is_empty());
+        assert!(quick_shannon(&a) > 7.5);
+        assert_eq!(mode.to_string(), "keyed");
+    }
 }
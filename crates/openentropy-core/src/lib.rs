@@ -25,46 +25,77 @@
 //!
 //! ## Architecture
 //!
-//! Sources → Pool (concatenate) → Conditioning → Output
+//! Sources → Pool (mix, default concatenate) → Conditioning → Output
 //!
 //! Three output modes:
 //! - **Sha256** (default): SHA-256 conditioning mixes all source bytes with state,
 //!   counter, timestamp, and OS entropy. Cryptographically strong output.
 //! - **VonNeumann**: debiases raw bytes without destroying noise structure.
 //! - **Raw** (`get_raw_bytes`): source bytes pass through unchanged — no hashing,
-//!   no whitening, no mixing between sources.
+//!   no whitening; sources are only combined per [`MixStrategy`].
 //!
 //! Raw mode preserves the actual hardware noise signal for researchers studying
 //! device entropy characteristics. Most QRNG APIs (ANU, Outshift) run DRBG
 //! post-processing that destroys the raw hardware signal. We don't.
 //!
 //! Every source implements the [`EntropySource`] trait. The [`EntropyPool`]
-//! collects from all registered sources and concatenates their byte streams.
+//! collects from all registered sources and concatenates their byte streams
+//! (or XOR-folds / interleaves them, see [`EntropyPool::set_mix_strategy`]).
+//! Downstream crates can plug in their own sources with
+//! [`EntropyPool::register_external`] or, for auto-detection, [`register_source`].
+//!
+//! With the `async` feature, `AsyncEntropyPool` wraps a pool for tokio
+//! applications, running collection on the blocking thread pool.
+//!
+//! [`EntropyPool::reader`] exposes conditioned output as an [`std::io::Read`]
+//! stream; the `rand` feature also makes it a fallible `rand_core`
+//! `TryRngCore`. [`EntropyPool::crypto_reader`] is the SHA-256-only variant
+//! that is an infallible `RngCore` + `CryptoRng`.
 
 pub mod analysis;
+#[cfg(feature = "async")]
+pub mod async_pool;
 pub mod conditioning;
 pub mod platform;
 pub mod pool;
+pub mod reader;
+pub mod replay;
 pub mod session;
 pub mod source;
 pub mod sources;
 pub mod telemetry;
 
+pub use analysis::analyze_bytes;
+#[cfg(feature = "async")]
+pub use async_pool::AsyncEntropyPool;
 pub use conditioning::{
     ConditioningMode, MinEntropyReport, QualityReport, condition, grade_min_entropy,
     min_entropy_estimate, quick_min_entropy, quick_quality, quick_shannon,
 };
-pub use platform::{detect_available_sources, platform_info};
-pub use pool::{EntropyPool, HealthReport, SourceHealth, SourceInfoSnapshot};
+pub use platform::{
+    detect_available_sources, detect_available_sources_filtered, platform_info,
+    refresh_source_availability, source_availability,
+};
+pub use pool::{
+    CancellationToken, EntropyBudget, EntropyPool, HealthEvent, HealthReport, MixStrategy,
+    PoolError, SourceHealth, SourceInfoSnapshot,
+};
+pub use reader::{CryptoEntropyReader, EntropyReader};
+pub use replay::{ReplayFrame, ReplayHeader, ReplayReader, ReplaySource};
 pub use session::{
-    MachineInfo, SessionConfig, SessionMeta, SessionSourceAnalysis, SessionWriter,
-    detect_machine_info,
+    FileDigest, IntegrityIssue, JsonLinesSink, MachineInfo, RecordSink, SessionConfig, SessionMeta,
+    SessionSourceAnalysis, SessionWriter, detect_machine_info, verify_session,
+};
+pub use source::{
+    Availability, CostClass, EntropySource, Platform, Requirement, SourceCategory, SourceInfo,
 };
-pub use source::{EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
+pub use sources::{SourceFactory, register_source};
 pub use telemetry::{
-    MODEL_ID as TELEMETRY_MODEL_ID, MODEL_VERSION as TELEMETRY_MODEL_VERSION, TelemetryMetric,
-    TelemetryMetricDelta, TelemetrySnapshot, TelemetryWindowReport, build_telemetry_window,
-    collect_telemetry_snapshot, collect_telemetry_window,
+    MODEL_ID as TELEMETRY_MODEL_ID, MODEL_VERSION as TELEMETRY_MODEL_VERSION, TelemetryConfound,
+    TelemetryMetric, TelemetryMetricDelta, TelemetryMetricStats, TelemetrySeriesReport,
+    TelemetrySeriesStep, TelemetrySnapshot, TelemetryWindowReport, build_telemetry_series_window,
+    build_telemetry_window, collect_telemetry_series, collect_telemetry_snapshot,
+    collect_telemetry_window, telemetry_confound_from_window,
 };
 
 /// Library version (from Cargo.toml).
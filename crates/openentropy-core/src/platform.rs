@@ -1,16 +1,86 @@
 //! Platform detection and source discovery.
 
-use crate::source::EntropySource;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use crate::source::{Availability, EntropySource, Requirement};
 use crate::sources::all_sources;
 
+/// Cached `availability` results by source name, shared by every
+/// [`detect_available_sources`] call in the process.
+static AVAILABILITY: OnceLock<Mutex<HashMap<&'static str, Availability>>> = OnceLock::new();
+
+fn availability_cache() -> &'static Mutex<HashMap<&'static str, Availability>> {
+    AVAILABILITY.get_or_init(Mutex::default)
+}
+
 /// Discover all entropy sources available on this machine.
+///
+/// Each source's `is_available` probe (some touch the network or Bluetooth)
+/// runs once per process; later calls, including every
+/// [`EntropyPool::auto`](crate::EntropyPool::auto), reuse the cached answer.
+/// Long-lived processes that need to notice hardware being plugged in or
+/// removed must call [`refresh_source_availability`] first.
 pub fn detect_available_sources() -> Vec<Box<dyn EntropySource>> {
+    detect_available_sources_filtered(&[])
+}
+
+/// [`detect_available_sources`] minus every source whose
+/// [`SourceInfo::requirements`](crate::SourceInfo::requirements) lists one of
+/// `exclude_requirements`, e.g. `&[Requirement::Network]` on an air-gapped
+/// host. Excluded sources are never probed.
+pub fn detect_available_sources_filtered(
+    exclude_requirements: &[Requirement],
+) -> Vec<Box<dyn EntropySource>> {
     all_sources()
         .into_iter()
-        .filter(|s| s.is_available())
+        .filter(|s| !needs_any(s.as_ref(), exclude_requirements))
+        .filter(|s| cached_availability(s.as_ref()).is_available())
         .collect()
 }
 
+/// Every known source with its [`Availability`] on this machine, so callers
+/// can explain why a source is missing from [`detect_available_sources`].
+/// Shares the same per-process probe cache.
+pub fn source_availability() -> Vec<(Box<dyn EntropySource>, Availability)> {
+    all_sources()
+        .into_iter()
+        .map(|s| {
+            let availability = cached_availability(s.as_ref());
+            (s, availability)
+        })
+        .collect()
+}
+
+fn cached_availability(source: &dyn EntropySource) -> Availability {
+    let cache = availability_cache();
+    if let Some(availability) = cache.lock().unwrap().get(source.name()) {
+        return availability.clone();
+    }
+    // Probe without holding the lock; a concurrent probe of the same
+    // source just writes the same answer.
+    let availability = source.availability();
+    cache
+        .lock()
+        .unwrap()
+        .insert(source.name(), availability.clone());
+    availability
+}
+
+fn needs_any(source: &dyn EntropySource, requirements: &[Requirement]) -> bool {
+    source
+        .info()
+        .requirements
+        .iter()
+        .any(|r| requirements.contains(r))
+}
+
+/// Forget cached availability so the next [`detect_available_sources`]
+/// probes every source again.
+pub fn refresh_source_availability() {
+    availability_cache().lock().unwrap().clear();
+}
+
 /// Platform information.
 pub fn platform_info() -> PlatformInfo {
     PlatformInfo {
@@ -26,3 +96,57 @@ pub struct PlatformInfo {
     pub machine: String,
     pub family: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detection_populates_availability_cache() {
+        refresh_source_availability();
+        let first: Vec<&str> = detect_available_sources()
+            .iter()
+            .map(|s| s.name())
+            .collect();
+
+        let cache = availability_cache().lock().unwrap().clone();
+        for source in all_sources() {
+            assert!(cache.contains_key(source.name()), "{}", source.name());
+        }
+
+        let second: Vec<&str> = detect_available_sources()
+            .iter()
+            .map(|s| s.name())
+            .collect();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    #[cfg(not(target_os = "macos"))]
+    fn macos_only_sources_explain_why_they_are_missing() {
+        let availability = source_availability();
+        let wifi = availability
+            .iter()
+            .find(|(s, _)| s.name() == "wifi_rssi")
+            .map(|(_, a)| a)
+            .expect("wifi_rssi is a known source");
+        assert_eq!(wifi, &Availability::Unavailable("requires macOS".into()));
+        assert_eq!(wifi.reason(), Some("requires macOS"));
+    }
+
+    #[test]
+    fn excluding_requirements_drops_network_sources() {
+        let exclude = [Requirement::Network, Requirement::Wifi];
+        let mut dropped: Vec<&str> = all_sources()
+            .iter()
+            .filter(|s| needs_any(s.as_ref(), &exclude))
+            .map(|s| s.name())
+            .collect();
+        dropped.sort_unstable();
+        assert_eq!(dropped, ["dns_timing", "tcp_connect_timing", "wifi_rssi"]);
+
+        for source in detect_available_sources_filtered(&exclude) {
+            assert!(!dropped.contains(&source.name()), "{}", source.name());
+        }
+    }
+}
@@ -3,36 +3,257 @@
 //! Architecture:
 //! 1. Auto-discover available sources on this machine
 //! 2. Collect raw entropy from each source in parallel
-//! 3. Concatenate source bytes into a shared buffer
+//! 3. Mix source bytes into a shared buffer ([`MixStrategy`], default concatenate)
 //! 4. Apply conditioning (Raw / VonNeumann / SHA-256) on output
 //! 5. Continuous health monitoring per source
 //! 6. Graceful degradation when sources fail
 //! 7. Thread-safe for concurrent access
+//! 8. Optional background pre-fill of conditioned output ([`EntropyPool::set_prefill`])
 
-use std::collections::{HashMap, HashSet};
-use std::sync::{Arc, Mutex};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex, PoisonError, RwLock, Weak};
 use std::time::{Duration, Instant};
 
 use sha2::{Digest, Sha256};
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 use crate::conditioning::{quick_min_entropy, quick_shannon};
 use crate::source::{EntropySource, SourceState};
 
+/// How often a waiting collection cycle checks its [`CancellationToken`].
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
 /// Thread-safe multi-source entropy pool.
+///
+/// On drop the conditioning state, counter, raw buffer and pre-fill buffer
+/// are zeroized ([`ZeroizeOnDrop`]). This is a best-effort wipe of live
+/// state only: copies the buffers left behind when they reallocated, and
+/// temporaries made while collecting and conditioning, are not wiped, so a
+/// later memory disclosure (core dump, freed heap pages) may still reveal
+/// recent output. Bytes already handed to callers are theirs to wipe.
 pub struct EntropyPool {
-    sources: Vec<Arc<Mutex<SourceState>>>,
+    // Behind a lock so `refresh_sources` can swap the set through `&self`.
+    sources: RwLock<Vec<Arc<Mutex<SourceState>>>>,
     buffer: Mutex<Vec<u8>>,
     state: Mutex<[u8; 32]>,
     counter: Mutex<u64>,
     total_output: Mutex<u64>,
-    // Per-source collection coordination for timeout-safe parallel collection.
+    ledger: Mutex<EntropyLedger>,
+    expansion: Mutex<ExpansionWindow>,
+    max_expansion: f64,
+    // Per-source collection coordination for timeout-safe parallel collection,
+    // keyed by `source_key`.
     in_flight: Arc<Mutex<HashSet<usize>>>,
     backoff_until: Arc<Mutex<HashMap<usize, Instant>>>,
+    prefill: Arc<Prefill>,
+    mix: MixStrategy,
+    parallel_workers: usize,
+    cancel: CancellationToken,
+    health_events: Mutex<HealthNotifier>,
+}
+
+/// A source's health changed between collection cycles; see
+/// [`EntropyPool::subscribe_health`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HealthEvent {
+    /// The named source was unhealthy and is healthy again.
+    SourceHealthy(String),
+    /// The named source became unhealthy (failed, timed out, stuck, or its
+    /// entropy dropped to 1 bit/byte or less).
+    SourceDegraded(String),
+}
+
+/// Subscribers and the last health state reported to them, by source name.
+#[derive(Default)]
+struct HealthNotifier {
+    subscribers: Vec<Sender<HealthEvent>>,
+    reported: HashMap<&'static str, bool>,
+}
+
+/// Shared flag that stops an in-progress collection cycle.
+///
+/// Obtained from [`EntropyPool::cancellation_token`]; clones share the flag,
+/// so a signal handler or another thread can call [`cancel`](Self::cancel)
+/// while a collection runs. Cancellation is checked between sources: no new
+/// source is started, and [`EntropyPool::collect_all_parallel`] stops waiting
+/// at once and keeps the chunks already received. A source that is mid-
+/// collection is not interrupted; its detached worker finishes in the
+/// background and its output is dropped. The flag stays set (later cycles
+/// return immediately) until [`reset`](Self::reset).
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// A new, uncancelled token.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation of current and future collection cycles.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Whether [`cancel`](Self::cancel) has been called since the last reset.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Clear the flag so collection runs normally again.
+    pub fn reset(&self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
+/// How one collection cycle's per-source chunks are combined before they
+/// enter the raw buffer.
+///
+/// `Concatenate` and `Interleave` keep every byte, so the buffer carries the
+/// sum of the sources' entropy. `XorFold` keeps only the shortest chunk's
+/// length: XOR of *independent* sources is at least as unpredictable as the
+/// best one, but correlated sources can cancel each other and reduce
+/// entropy, and the extra bytes of longer chunks are discarded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MixStrategy {
+    /// Chunks back to back (length = sum).
+    #[default]
+    Concatenate,
+    /// Byte-wise XOR of all chunks, truncated to the shortest (length = min).
+    XorFold,
+    /// Round-robin bytes from each chunk, then any leftovers (length = sum).
+    Interleave,
+}
+
+impl MixStrategy {
+    /// Combine per-source chunks; empty chunks are ignored.
+    pub fn mix(self, chunks: &[Vec<u8>]) -> Vec<u8> {
+        let chunks: Vec<&[u8]> = chunks
+            .iter()
+            .map(Vec::as_slice)
+            .filter(|c| !c.is_empty())
+            .collect();
+        match self {
+            Self::Concatenate => chunks.concat(),
+            Self::XorFold => {
+                let len = chunks.iter().map(|c| c.len()).min().unwrap_or(0);
+                let mut out = vec![0u8; len];
+                for chunk in &chunks {
+                    for (o, &b) in out.iter_mut().zip(*chunk) {
+                        *o ^= b;
+                    }
+                }
+                out
+            }
+            Self::Interleave => {
+                let total = chunks.iter().map(|c| c.len()).sum();
+                let longest = chunks.iter().map(|c| c.len()).max().unwrap_or(0);
+                let mut out = Vec::with_capacity(total);
+                for i in 0..longest {
+                    out.extend(chunks.iter().filter_map(|c| c.get(i)));
+                }
+                out
+            }
+        }
+    }
+}
+
+impl MixStrategy {
+    /// Conservative min-entropy, in bits, of `mixed_len` bytes mixed from
+    /// `chunks`: the sum of each chunk's MCV estimate times its length, or
+    /// for `XorFold` only the best chunk's rate over the folded length.
+    fn credited_min_entropy(self, chunks: &[Vec<u8>], mixed_len: usize) -> f64 {
+        let rates = chunks
+            .iter()
+            .filter(|c| !c.is_empty())
+            .map(|c| (c.len(), quick_min_entropy(c).clamp(0.0, 8.0)));
+        match self {
+            Self::Concatenate | Self::Interleave => rates.map(|(len, h)| len as f64 * h).sum(),
+            Self::XorFold => rates.map(|(_, h)| h).fold(0.0, f64::max) * mixed_len as f64,
+        }
+    }
+}
+
+/// Running input-entropy credit behind [`EntropyPool::entropy_budget`].
+#[derive(Default)]
+struct EntropyLedger {
+    input_bits: f64,
+    /// A deficit has been logged and not yet cleared.
+    deficit_warned: bool,
+}
+
+/// Entropy claimed by collected input vs entropy handed out, from
+/// [`EntropyPool::entropy_budget`].
+///
+/// Output beyond the input credit is not necessarily predictable (SHA-256
+/// output also mixes OS entropy and chains state), but it is no longer
+/// backed bit-for-bit by measured hardware noise: the DRBG over-extraction
+/// signal an auditor looks for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntropyBudget {
+    /// Min-entropy credited to every collection mixed into the buffer.
+    pub input_min_entropy_bits: f64,
+    /// Output served so far, in bits (`output_bytes * 8`, all modes).
+    pub output_bits: u64,
+}
+
+impl EntropyBudget {
+    /// Output bits not covered by input credit (0 when within budget).
+    pub fn deficit_bits(&self) -> f64 {
+        (self.output_bits as f64 - self.input_min_entropy_bits).max(0.0)
+    }
+
+    /// More output has been served than input entropy credited.
+    pub fn over_extracted(&self) -> bool {
+        self.deficit_bits() > 0.0
+    }
+}
+
+/// Raw input vs SHA-256 output since the last forced reseed, behind
+/// [`EntropyPool::set_max_expansion`].
+#[derive(Default)]
+struct ExpansionWindow {
+    input_bytes: u64,
+    output_bytes: u64,
+    /// Requests that would have exceeded the ratio, since the pool was created.
+    violations: u64,
+}
+
+/// Consecutive byte-identical collections after which a source is flagged
+/// [`SourceHealth::stuck`] and treated as unhealthy.
+pub const STUCK_AFTER: usize = 3;
+
+/// Largest chunk the pre-fill worker conditions before publishing it, so
+/// readers never wait long for the buffer lock.
+const PREFILL_CHUNK: usize = 4096;
+
+/// Pre-conditioned SHA-256 output kept topped up by a background worker.
+#[derive(Default)]
+struct Prefill {
+    state: Mutex<PrefillState>,
+    wake: Condvar,
+}
+
+#[derive(Default)]
+struct PrefillState {
+    target: usize,
+    buf: Vec<u8>,
+    worker: bool,
 }
 
 impl EntropyPool {
     /// Create an empty pool.
     pub fn new(seed: Option<&[u8]>) -> Self {
+        Self::with_personalization(seed, &[])
+    }
+
+    /// Create an empty pool whose conditioning state also absorbs a
+    /// personalization string (SP 800-90A style instance separation).
+    ///
+    /// Pools sharing a seed but not `pers` start from unrelated chaining
+    /// states. An empty `pers` is identical to [`EntropyPool::new`].
+    pub fn with_personalization(seed: Option<&[u8]>, pers: &[u8]) -> Self {
         let initial_state = {
             let mut h = Sha256::new();
             if let Some(s) = seed {
@@ -43,21 +264,188 @@ impl EntropyPool {
                 getrandom(&mut os_random);
                 h.update(os_random);
             }
+            if !pers.is_empty() {
+                // Tagged and length-prefixed so distinct `pers` values under
+                // one seed can't collide. The seed is hashed raw, as in
+                // `new`, so a crafted seed can still reproduce this input.
+                h.update(b"openentropy-pers");
+                h.update((pers.len() as u64).to_le_bytes());
+                h.update(pers);
+            }
             let digest: [u8; 32] = h.finalize().into();
             digest
         };
 
         Self {
-            sources: Vec::new(),
+            sources: RwLock::new(Vec::new()),
             buffer: Mutex::new(Vec::new()),
             state: Mutex::new(initial_state),
             counter: Mutex::new(0),
             total_output: Mutex::new(0),
+            ledger: Mutex::default(),
+            expansion: Mutex::default(),
+            max_expansion: 0.0,
             in_flight: Arc::new(Mutex::new(HashSet::new())),
             backoff_until: Arc::new(Mutex::new(HashMap::new())),
+            prefill: Arc::default(),
+            mix: MixStrategy::default(),
+            parallel_workers: std::thread::available_parallelism().map_or(4, |n| n.get()),
+            cancel: CancellationToken::new(),
+            health_events: Mutex::default(),
         }
     }
 
+    /// Choose how each collection cycle combines per-source output
+    /// (default [`MixStrategy::Concatenate`]). Collection methods return the
+    /// number of bytes added to the buffer after mixing.
+    pub fn set_mix_strategy(&mut self, mix: MixStrategy) {
+        self.mix = mix;
+    }
+
+    /// Current mixing strategy.
+    pub fn mix_strategy(&self) -> MixStrategy {
+        self.mix
+    }
+
+    /// Cap how many sources collect at the same time in
+    /// [`collect_all_parallel`](Self::collect_all_parallel) and
+    /// [`collect_enabled`](Self::collect_enabled) (default: available cores,
+    /// minimum 1).
+    ///
+    /// Most sources measure timing jitter. With more collecting threads than
+    /// cores, part of what they measure is the scheduler swapping them out,
+    /// which is shared across sources and inflates their apparent entropy.
+    /// Capping at the core count keeps each measurement on a core of its own.
+    pub fn set_parallel_workers(&mut self, workers: usize) {
+        self.parallel_workers = workers.max(1);
+    }
+
+    /// Current cap on concurrent source collections.
+    pub fn parallel_workers(&self) -> usize {
+        self.parallel_workers
+    }
+
+    /// Cap SHA-256 output at `ratio` bytes per raw byte collected within a
+    /// reseed window (default 0: no cap).
+    ///
+    /// SHA-256 conditioning keeps producing output from its chained state
+    /// once the raw buffer is empty, so a few collected bytes can be
+    /// stretched into any amount of output. With a cap set, a request that
+    /// would take the window past `ratio` first forces a reseed: a fresh
+    /// collection from every source, which starts a new window.
+    /// [`get_bytes_checked`](Self::get_bytes_checked) errors instead of
+    /// serving if the reseed did not collect enough. Raw, VonNeumann and
+    /// Toeplitz output never exceeds its input and is not counted; Keyed
+    /// output makes no security claim and is not counted either. Each
+    /// forced reseed shows up in
+    /// [`HealthReport::expansion_violations`].
+    pub fn set_max_expansion(&mut self, ratio: f64) {
+        self.max_expansion = if ratio > 0.0 { ratio } else { 0.0 };
+    }
+
+    /// Current expansion cap (0 = none).
+    pub fn max_expansion(&self) -> f64 {
+        self.max_expansion
+    }
+
+    /// Apply the [`set_max_expansion`](Self::set_max_expansion) cap to a
+    /// request for `n_bytes` of SHA-256 output, reseeding if it would be
+    /// exceeded, and count the request against the window.
+    fn enforce_expansion(&self, n_bytes: usize) -> Result<(), PoolError> {
+        if self.max_expansion == 0.0 {
+            return Ok(());
+        }
+        let n = n_bytes as u64;
+        let exceeds = |w: &ExpansionWindow| {
+            (w.output_bytes + n) as f64 > w.input_bytes as f64 * self.max_expansion
+        };
+        {
+            let mut window = self.expansion.lock().unwrap();
+            if !exceeds(&window) {
+                window.output_bytes += n;
+                return Ok(());
+            }
+            window.violations += 1;
+            window.input_bytes = 0;
+            window.output_bytes = 0;
+        }
+        log::debug!(
+            "conditioning output over {}x input, reseeding",
+            self.max_expansion
+        );
+        self.collect_all();
+        let mut window = self.expansion.lock().unwrap();
+        if exceeds(&window) {
+            return Err(PoolError::ExpansionExceeded {
+                requested: n,
+                input_bytes: window.input_bytes,
+            });
+        }
+        window.output_bytes += n;
+        Ok(())
+    }
+
+    /// Handle that cancels this pool's collection cycles; see
+    /// [`CancellationToken`].
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancel.clone()
+    }
+
+    /// Receive a [`HealthEvent`] whenever a source's health flips.
+    ///
+    /// Transitions are checked at the end of every collection cycle
+    /// (`collect_all*`, `collect_enabled*`, `collect_until`) against the last
+    /// state reported; sources start out healthy, so a source that fails its
+    /// first collection reports `SourceDegraded`. A source still held by a
+    /// timed-out collection is checked on a later cycle. Dropping the
+    /// receiver unsubscribes it.
+    pub fn subscribe_health(&self) -> Receiver<HealthEvent> {
+        let (tx, rx) = std::sync::mpsc::channel();
+        self.health_events.lock().unwrap().subscribers.push(tx);
+        rx
+    }
+
+    /// Send [`HealthEvent`]s for sources whose health changed since the last
+    /// check. A no-op without subscribers.
+    fn publish_health_transitions(&self) {
+        let mut notifier = self.health_events.lock().unwrap();
+        if notifier.subscribers.is_empty() {
+            return;
+        }
+        let mut events = Vec::new();
+        for ss_mutex in self.source_handles() {
+            let Ok(ss) = ss_mutex.try_lock() else {
+                continue;
+            };
+            let name = ss.source.name();
+            let was_healthy = notifier.reported.insert(name, ss.healthy).unwrap_or(true);
+            match (was_healthy, ss.healthy) {
+                (true, false) => events.push(HealthEvent::SourceDegraded(name.to_string())),
+                (false, true) => events.push(HealthEvent::SourceHealthy(name.to_string())),
+                _ => {}
+            }
+        }
+        for event in events {
+            notifier
+                .subscribers
+                .retain(|tx| tx.send(event.clone()).is_ok());
+        }
+    }
+
+    /// Mix one cycle's chunks into the raw buffer and credit their
+    /// min-entropy to the ledger; returns bytes added. Ends the cycle by
+    /// publishing health transitions.
+    fn push_chunks(&self, chunks: &[Vec<u8>]) -> usize {
+        let mixed = self.mix.mix(chunks);
+        let n = mixed.len();
+        let credit = self.mix.credited_min_entropy(chunks, n);
+        self.buffer.lock().unwrap().extend_from_slice(&mixed);
+        self.ledger.lock().unwrap().input_bits += credit;
+        self.expansion.lock().unwrap().input_bytes += n as u64;
+        self.publish_health_transitions();
+        n
+    }
+
     /// Create a pool with all available sources on this machine.
     pub fn auto() -> Self {
         let mut pool = Self::new(None);
@@ -67,15 +455,115 @@ impl EntropyPool {
         pool
     }
 
+    /// [`auto`](Self::auto), then [`warm`](Self::warm) every source with
+    /// `warmup_bytes` samples, waiting at most `timeout_secs`.
+    ///
+    /// Recommended for servers and other long-lived pools: the first
+    /// collection after construction sees cold caches, branch predictors and
+    /// clocks, and its anomalous timing would otherwise feed the first
+    /// request.
+    pub fn auto_warm(warmup_bytes: usize, timeout_secs: f64) -> Self {
+        let pool = Self::auto();
+        pool.warm(warmup_bytes, timeout_secs);
+        pool
+    }
+
+    /// Run one discarded collection of `warmup_bytes` samples per source.
+    ///
+    /// Sources collect exactly as in
+    /// [`collect_all_parallel_n`](Self::collect_all_parallel_n): in parallel,
+    /// with sources still collecting after `timeout_secs` abandoned and
+    /// backed off, and health counters updated as usual. The output never
+    /// reaches the buffer and is not credited to the
+    /// [`entropy_budget`](Self::entropy_budget).
+    pub fn warm(&self, warmup_bytes: usize, timeout_secs: f64) {
+        let chunks = self.collect_chunks_parallel(timeout_secs, warmup_bytes, |_, _| {});
+        let warmed = chunks.len();
+        for mut discarded in chunks {
+            discarded.zeroize();
+        }
+        log::debug!("warmed {warmed} source(s) with {warmup_bytes} samples each");
+    }
+
     /// Register an entropy source.
     pub fn add_source(&mut self, source: Box<dyn EntropySource>, weight: f64) {
         self.sources
+            .get_mut()
+            .unwrap()
             .push(Arc::new(Mutex::new(SourceState::new(source, weight))));
     }
 
+    /// Add a source implemented outside this crate to this pool only.
+    ///
+    /// The source participates in collection, health reporting, and
+    /// per-source queries exactly like a built-in one. To make a source visible
+    /// to every pool built via [`EntropyPool::auto`], register a constructor
+    /// with [`crate::register_source`] instead.
+    pub fn register_external(&mut self, source: Box<dyn EntropySource>, weight: f64) {
+        self.add_source(source, weight);
+    }
+
     /// Number of registered sources.
     pub fn source_count(&self) -> usize {
-        self.sources.len()
+        self.sources.read().unwrap().len()
+    }
+
+    /// Snapshot of the current source handles.
+    ///
+    /// Each operation works on one snapshot so indices stay consistent even if
+    /// [`refresh_sources`](Self::refresh_sources) runs concurrently.
+    fn source_handles(&self) -> Vec<Arc<Mutex<SourceState>>> {
+        self.sources.read().unwrap().clone()
+    }
+
+    /// Key for a source's `in_flight` and `backoff_until` entries: the
+    /// address of its shared state. Unlike an index it is unchanged by
+    /// [`refresh_sources`](Self::refresh_sources) and readable without the
+    /// source lock a hung worker holds. A worker keeps its source alive until
+    /// it has removed its own entry, so an address is never reused while
+    /// still listed.
+    fn source_key(ss_mutex: &Arc<Mutex<SourceState>>) -> usize {
+        Arc::as_ptr(ss_mutex) as usize
+    }
+
+    /// Replace the registered source set in place, e.g. after re-running
+    /// [`crate::detect_available_sources`] when hardware changes (call
+    /// [`crate::refresh_source_availability`] first so it probes again).
+    ///
+    /// Sources already registered under the same name keep their runtime
+    /// state (weight, byte and failure counters, health, timeout backoff),
+    /// and a collection still in flight stays tracked. New names are added
+    /// with the weight paired with them; names absent from `sources` are
+    /// dropped. Conditioning state (output buffer, chaining state, counter) is
+    /// untouched. Returns the new source count.
+    pub fn refresh_sources(&self, sources: Vec<(Box<dyn EntropySource>, f64)>) -> usize {
+        let existing: HashMap<&'static str, Arc<Mutex<SourceState>>> = self
+            .source_handles()
+            .into_iter()
+            .map(|ss_mutex| {
+                let name = ss_mutex.lock().unwrap().source.name();
+                (name, ss_mutex)
+            })
+            .collect();
+        let rebuilt: Vec<Arc<Mutex<SourceState>>> = sources
+            .into_iter()
+            .map(|(source, weight)| match existing.get(source.name()) {
+                Some(ss_mutex) => Arc::clone(ss_mutex),
+                None => Arc::new(Mutex::new(SourceState::new(source, weight))),
+            })
+            .collect();
+        let n = rebuilt.len();
+
+        let mut current = self.sources.write().unwrap();
+        // Kept sources keep their backoff; in-flight entries are cleared by
+        // their own workers. Dropped sources' backoff goes with them.
+        let kept: HashSet<usize> = rebuilt.iter().map(Self::source_key).collect();
+        self.backoff_until
+            .lock()
+            .unwrap()
+            .retain(|key, _| kept.contains(key));
+        *current = rebuilt;
+        n
     }
 
     /// Collect entropy from every registered source in parallel.
@@ -99,104 +587,182 @@ impl EntropyPool {
     /// - `timeout_secs`: max wall-clock time to wait for a collection cycle.
     /// - `n_samples`: samples requested from each source in this cycle.
     ///
-    /// Slow or hung sources are skipped after `timeout_secs`. Timed-out sources
-    /// enter a backoff window to avoid thread buildup on repeated calls.
+    /// At most [`parallel_workers`](Self::parallel_workers) threads collect,
+    /// taking sources from a shared queue. Slow or hung sources are skipped
+    /// after `timeout_secs`. Timed-out sources enter a backoff window to avoid
+    /// thread buildup on repeated calls; sources still queued at the deadline
+    /// were never started and are not backed off.
+    ///
+    /// If the [`cancellation_token`](Self::cancellation_token) is cancelled,
+    /// returns promptly with whatever chunks have arrived; sources still
+    /// collecting are abandoned without being backed off.
     pub fn collect_all_parallel_n(&self, timeout_secs: f64, n_samples: usize) -> usize {
+        self.collect_all_parallel_n_with_progress(timeout_secs, n_samples, |_, _| {})
+    }
+
+    /// Like [`collect_all_parallel_n`](Self::collect_all_parallel_n), calling
+    /// `on_source(name, bytes)` on the caller's thread as each source finishes
+    /// within the deadline, in completion order. Sources that time out, or are
+    /// skipped for backoff, are not reported.
+    pub fn collect_all_parallel_n_with_progress(
+        &self,
+        timeout_secs: f64,
+        n_samples: usize,
+        on_source: impl FnMut(&'static str, usize),
+    ) -> usize {
+        let chunks = self.collect_chunks_parallel(timeout_secs, n_samples, on_source);
+        let n = self.push_chunks(&chunks);
+        log::debug!("collected {n} bytes from {} source(s)", chunks.len());
+        n
+    }
+
+    /// The collection cycle behind
+    /// [`collect_all_parallel_n_with_progress`](Self::collect_all_parallel_n_with_progress),
+    /// returning one chunk per source that finished in time instead of
+    /// buffering them.
+    fn collect_chunks_parallel(
+        &self,
+        timeout_secs: f64,
+        n_samples: usize,
+        mut on_source: impl FnMut(&'static str, usize),
+    ) -> Vec<Vec<u8>> {
         let timeout = Duration::from_secs_f64(timeout_secs.max(0.0));
-        if timeout.is_zero() || n_samples == 0 {
-            return 0;
+        if timeout.is_zero() || n_samples == 0 || self.cancel.is_cancelled() {
+            return Vec::new();
         }
 
-        let (tx, rx) = std::sync::mpsc::channel::<(usize, Vec<u8>)>();
+        let (tx, rx) = std::sync::mpsc::channel::<(usize, &'static str, Vec<u8>)>();
         let now = Instant::now();
         let mut scheduled: Vec<usize> = Vec::new();
+        let mut queue = VecDeque::new();
+        let sources = self.source_handles();
 
-        for (idx, ss_mutex) in self.sources.iter().enumerate() {
+        for (idx, ss_mutex) in sources.iter().enumerate() {
+            let key = Self::source_key(ss_mutex);
             // Skip sources still in backoff.
             let in_backoff = {
                 let backoff = self.backoff_until.lock().unwrap();
-                backoff.get(&idx).is_some_and(|until| now < *until)
+                backoff.get(&key).is_some_and(|until| now < *until)
             };
             if in_backoff {
                 continue;
             }
 
             // Skip sources with an in-flight worker from a prior timeout.
-            {
-                let mut in_flight = self.in_flight.lock().unwrap();
-                if in_flight.contains(&idx) {
-                    continue;
-                }
-                in_flight.insert(idx);
+            if !self.in_flight.lock().unwrap().insert(key) {
+                continue;
             }
 
             scheduled.push(idx);
+            queue.push_back((idx, Arc::clone(ss_mutex)));
+        }
 
+        if scheduled.is_empty() {
+            return Vec::new();
+        }
+        let scheduled_count = scheduled.len();
+
+        let queue = Arc::new(Mutex::new(queue));
+        for _ in 0..self.parallel_workers.min(scheduled_count) {
             let tx = tx.clone();
-            let src = Arc::clone(ss_mutex);
+            let queue = Arc::clone(&queue);
             let in_flight = Arc::clone(&self.in_flight);
             let backoff = Arc::clone(&self.backoff_until);
+            let cancel = self.cancel.clone();
 
             std::thread::spawn(move || {
-                let data = Self::collect_one_n(&src, n_samples);
-                {
-                    let mut in_flight = in_flight.lock().unwrap();
-                    in_flight.remove(&idx);
+                loop {
+                    if cancel.is_cancelled() {
+                        break;
+                    }
+                    let Some((idx, src)) = queue.lock().unwrap().pop_front() else {
+                        break;
+                    };
+                    let data = Self::collect_one_n(&src, n_samples);
+                    let name = src.lock().unwrap().source.name();
+                    let key = Self::source_key(&src);
+                    in_flight.lock().unwrap().remove(&key);
+                    backoff.lock().unwrap().remove(&key);
+                    if tx.send((idx, name, data)).is_err() {
+                        break;
+                    }
                 }
-                let mut bo = backoff.lock().unwrap();
-                bo.remove(&idx);
-                let _ = tx.send((idx, data));
             });
         }
         drop(tx);
 
-        if scheduled.is_empty() {
-            return 0;
-        }
-
         let deadline = Instant::now() + timeout;
         let mut received = HashSet::new();
-        let mut results = Vec::new();
+        let mut chunks = Vec::new();
 
-        while received.len() < scheduled.len() {
+        while received.len() < scheduled.len() && !self.cancel.is_cancelled() {
             let remaining = deadline.saturating_duration_since(Instant::now());
             if remaining.is_zero() {
                 break;
             }
-            match rx.recv_timeout(remaining) {
-                Ok((idx, data)) => {
+            // Wake periodically so cancellation is noticed promptly.
+            match rx.recv_timeout(remaining.min(CANCEL_POLL_INTERVAL)) {
+                Ok((idx, name, data)) => {
                     received.insert(idx);
-                    if !data.is_empty() {
-                        results.extend_from_slice(&data);
-                    }
+                    on_source(name, data.len());
+                    chunks.push(data);
                 }
-                Err(std::sync::mpsc::RecvTimeoutError::Timeout) => break,
+                Err(std::sync::mpsc::RecvTimeoutError::Timeout) => continue,
                 Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => break,
             }
         }
+        let cancelled = self.cancel.is_cancelled();
+
+        // Sources that never left the queue did not time out; release them.
+        let unstarted: HashSet<usize> = queue
+            .lock()
+            .unwrap()
+            .drain(..)
+            .map(|(idx, _)| idx)
+            .collect();
+        if !unstarted.is_empty() {
+            let mut in_flight = self.in_flight.lock().unwrap();
+            for &idx in &unstarted {
+                in_flight.remove(&Self::source_key(&sources[idx]));
+            }
+            log::debug!(
+                "{} source(s) not started before the {timeout:?} deadline",
+                unstarted.len()
+            );
+        }
 
-        // Back off any sources that did not respond in time.
+        // Back off any sources that did not respond in time. After a
+        // cancellation the rest had no chance to finish, so none are blamed.
         let backoff_for = Duration::from_secs(30);
         let timeout_mark = Instant::now() + backoff_for;
         for idx in scheduled {
-            if received.contains(&idx) {
+            if cancelled || received.contains(&idx) || unstarted.contains(&idx) {
                 continue;
             }
 
             {
                 let mut bo = self.backoff_until.lock().unwrap();
-                bo.insert(idx, timeout_mark);
+                bo.insert(Self::source_key(&sources[idx]), timeout_mark);
             }
 
-            if let Ok(mut ss) = self.sources[idx].try_lock() {
+            if let Ok(mut ss) = sources[idx].try_lock() {
                 ss.failures += 1;
                 ss.healthy = false;
+                log::warn!(
+                    "source {} timed out after {timeout:?}; backing off for {backoff_for:?}",
+                    ss.source.name()
+                );
+            } else {
+                log::warn!("source #{idx} timed out after {timeout:?}; backing off");
             }
         }
 
-        let n = results.len();
-        self.buffer.lock().unwrap().extend_from_slice(&results);
-        n
+        log::debug!(
+            "{}/{} scheduled sources finished in time",
+            received.len(),
+            scheduled_count
+        );
+        chunks
     }
 
     /// Collect entropy only from sources whose names are in the given list.
@@ -207,38 +773,80 @@ impl EntropyPool {
 
     /// Collect `n_samples` of entropy from sources whose names are in the list.
     /// Smaller `n_samples` values are faster — use this for interactive/TUI contexts.
+    /// At most [`parallel_workers`](Self::parallel_workers) sources collect at once.
     pub fn collect_enabled_n(&self, enabled_names: &[String], n_samples: usize) -> usize {
-        use std::sync::Arc;
-        let results: Arc<Mutex<Vec<u8>>> = Arc::new(Mutex::new(Vec::new()));
-
-        std::thread::scope(|s| {
-            let handles: Vec<_> = self
-                .sources
+        let results: Mutex<Vec<Vec<u8>>> = Mutex::new(Vec::new());
+        let sources = self.source_handles();
+        let enabled: Mutex<VecDeque<&Arc<Mutex<SourceState>>>> = Mutex::new(
+            sources
                 .iter()
                 .filter(|ss_mutex| {
                     let ss = ss_mutex.lock().unwrap();
                     enabled_names.iter().any(|n| n == ss.source.info().name)
                 })
-                .map(|ss_mutex| {
-                    let results = Arc::clone(&results);
-                    s.spawn(move || {
-                        let data = Self::collect_one_n(ss_mutex, n_samples);
-                        if !data.is_empty() {
-                            results.lock().unwrap().extend_from_slice(&data);
-                        }
-                    })
-                })
-                .collect();
+                .collect(),
+        );
+        let workers = self.parallel_workers.min(enabled.lock().unwrap().len());
 
-            for handle in handles {
-                let _ = handle.join();
+        std::thread::scope(|s| {
+            for _ in 0..workers {
+                s.spawn(|| {
+                    loop {
+                        if self.cancel.is_cancelled() {
+                            break;
+                        }
+                        let Some(ss_mutex) = enabled.lock().unwrap().pop_front() else {
+                            break;
+                        };
+                        let data = Self::collect_one_n(ss_mutex, n_samples);
+                        results.lock().unwrap().push(data);
+                    }
+                });
             }
         });
 
-        let results = Arc::try_unwrap(results).unwrap().into_inner().unwrap();
-        let n = results.len();
-        self.buffer.lock().unwrap().extend_from_slice(&results);
-        n
+        self.push_chunks(&results.into_inner().unwrap())
+    }
+
+    /// Collect from sources one at a time, in registration order, until the
+    /// collected bytes carry about `target_min_entropy` bits or `max_sources`
+    /// sources have been tried. Returns the number of bytes added to the
+    /// buffer after mixing.
+    ///
+    /// Progress is `Σ len × H∞` using the per-chunk [`quick_min_entropy`]
+    /// estimate, so a small request can be served from the first few good
+    /// sources instead of waiting on all of them. This is a latency
+    /// optimization, not a security guarantee: the estimate is heuristic and
+    /// can overstate the entropy of structured data. Runs on the calling
+    /// thread without a timeout; sources backed off by a timed-out parallel
+    /// cycle are skipped. The running total assumes bytes are kept, so it
+    /// overstates what [`MixStrategy::XorFold`] retains.
+    pub fn collect_until(&self, target_min_entropy: f64, max_sources: usize) -> usize {
+        let now = Instant::now();
+        let mut bits = 0.0;
+        let mut tried = 0usize;
+        let mut chunks = Vec::new();
+
+        for ss_mutex in &self.source_handles() {
+            if bits >= target_min_entropy || tried >= max_sources || self.cancel.is_cancelled() {
+                break;
+            }
+            let key = Self::source_key(ss_mutex);
+            let in_backoff = {
+                let backoff = self.backoff_until.lock().unwrap();
+                backoff.get(&key).is_some_and(|until| now < *until)
+            };
+            if in_backoff || self.in_flight.lock().unwrap().contains(&key) {
+                continue;
+            }
+
+            tried += 1;
+            let data = Self::collect_one_n(ss_mutex, 1000);
+            bits += quick_min_entropy(&data) * data.len() as f64;
+            chunks.push(data);
+        }
+
+        self.push_chunks(&chunks)
     }
 
     fn collect_one_n(ss_mutex: &Arc<Mutex<SourceState>>, n_samples: usize) -> Vec<u8> {
@@ -252,7 +860,23 @@ impl EntropyPool {
                 ss.total_bytes += data.len() as u64;
                 ss.last_entropy = quick_shannon(&data);
                 ss.last_min_entropy = quick_min_entropy(&data);
-                ss.healthy = ss.last_entropy > 1.0;
+                let digest: [u8; 32] = Sha256::digest(&data).into();
+                ss.identical_runs = if ss.last_digest == Some(digest) {
+                    ss.identical_runs + 1
+                } else {
+                    1
+                };
+                ss.last_digest = Some(digest);
+                let was_stuck = ss.stuck;
+                ss.stuck = ss.identical_runs >= STUCK_AFTER;
+                if ss.stuck && !was_stuck {
+                    log::warn!(
+                        "source {} returned identical output {} times in a row (stuck?)",
+                        ss.source.name(),
+                        ss.identical_runs
+                    );
+                }
+                ss.healthy = ss.last_entropy > 1.0 && !ss.stuck;
                 data
             }
             Ok(_) => {
@@ -265,12 +889,14 @@ impl EntropyPool {
                 ss.last_collect_time = t0.elapsed();
                 ss.failures += 1;
                 ss.healthy = false;
+                log::warn!("source {} panicked during collect", ss.source.name());
                 Vec::new()
             }
         }
     }
 
-    /// Return up to `n_bytes` of raw, unconditioned entropy (XOR-combined only).
+    /// Return up to `n_bytes` of raw, unconditioned entropy (mixed per
+    /// [`MixStrategy`] only).
     ///
     /// No SHA-256, no DRBG, no whitening. Preserves the raw hardware noise
     /// signal for researchers studying actual device entropy characteristics.
@@ -306,7 +932,95 @@ impl EntropyPool {
     }
 
     /// Return `n_bytes` of conditioned random output.
+    ///
+    /// Served from the pre-fill buffer first when one is configured; only the
+    /// remainder, if any, is collected and conditioned on the calling thread.
+    ///
+    /// Reseeds first if the request would exceed the
+    /// [`set_max_expansion`](Self::set_max_expansion) cap.
     pub fn get_random_bytes(&self, n_bytes: usize) -> Vec<u8> {
+        if self.enforce_expansion(n_bytes).is_err() {
+            // Unchecked: serve even though the reseed fell short.
+            self.expansion.lock().unwrap().output_bytes += n_bytes as u64;
+        }
+        self.serve_random(n_bytes)
+    }
+
+    /// [`get_random_bytes`](Self::get_random_bytes) without the expansion check.
+    fn serve_random(&self, n_bytes: usize) -> Vec<u8> {
+        let mut output = self.take_prefilled(n_bytes);
+        if output.len() < n_bytes {
+            output.extend(self.condition_fresh(n_bytes - output.len()));
+        }
+        *self.total_output.lock().unwrap() += n_bytes as u64;
+        output
+    }
+
+    /// Keep up to `target_bytes` of conditioned (SHA-256) output ready in the
+    /// background so [`get_random_bytes`](Self::get_random_bytes) and
+    /// `get_bytes(.., Sha256)` return without collecting until it drains.
+    ///
+    /// Spawns one worker thread on first use; it refills whenever a reader
+    /// drains the buffer and exits when the target is set back to 0 or the
+    /// pool is dropped. Bytes already buffered stay available after
+    /// disabling. Raw and VonNeumann output never comes from this buffer.
+    pub fn set_prefill(self: &Arc<Self>, target_bytes: usize) {
+        let mut state = self.prefill.state.lock().unwrap();
+        state.target = target_bytes;
+        if target_bytes > 0 && !state.worker {
+            state.worker = true;
+            let pool = Arc::downgrade(self);
+            let prefill = Arc::clone(&self.prefill);
+            std::thread::spawn(move || Self::prefill_worker(pool, prefill));
+        }
+        drop(state);
+        self.prefill.wake.notify_all();
+    }
+
+    fn prefill_worker(pool: Weak<Self>, prefill: Arc<Prefill>) {
+        loop {
+            let need = {
+                let mut state = prefill.state.lock().unwrap();
+                loop {
+                    if state.target == 0 {
+                        state.worker = false;
+                        return;
+                    }
+                    if state.buf.len() < state.target {
+                        break state.target - state.buf.len();
+                    }
+                    state = prefill.wake.wait(state).unwrap();
+                }
+            };
+            let Some(pool) = pool.upgrade() else {
+                prefill.state.lock().unwrap().worker = false;
+                return;
+            };
+            let chunk = pool.condition_fresh(need.min(PREFILL_CHUNK));
+            drop(pool);
+            prefill.state.lock().unwrap().buf.extend_from_slice(&chunk);
+        }
+    }
+
+    /// Drain up to `n_bytes` from the pre-fill buffer and wake the worker.
+    fn take_prefilled(&self, n_bytes: usize) -> Vec<u8> {
+        let mut state = self.prefill.state.lock().unwrap();
+        let take = n_bytes.min(state.buf.len());
+        if take == 0 {
+            return Vec::new();
+        }
+        let output: Vec<u8> = state.buf.drain(..take).collect();
+        let refill = state.target > 0;
+        drop(state);
+        if refill {
+            self.prefill.wake.notify_all();
+        }
+        output
+    }
+
+    /// Collect as needed and SHA-256 condition `n_bytes` of fresh output.
+    /// Does not count toward `output_bytes`; callers that serve it do.
+    fn condition_fresh(&self, n_bytes: usize) -> Vec<u8> {
         // Auto-collect if buffer is low
         {
             let buf = self.buffer.lock().unwrap();
@@ -354,16 +1068,19 @@ impl EntropyPool {
             output.extend_from_slice(&digest);
         }
 
-        *self.total_output.lock().unwrap() += n_bytes as u64;
         output.truncate(n_bytes);
         output
     }
 
     /// Return `n_bytes` of entropy with the specified conditioning mode.
     ///
-    /// - `Raw`: XOR-combined source bytes, no whitening
+    /// - `Raw`: mixed source bytes ([`MixStrategy`]), no whitening
     /// - `VonNeumann`: debiased but structure-preserving
     /// - `Sha256`: full cryptographic conditioning (default)
+    /// - `Toeplitz`: min-entropy-sized 2-universal extraction; may return
+    ///   fewer than `n_bytes` when the raw min-entropy is low
+    /// - `Keyed`: fast SipHash-1-3 mixing for simulations; **not**
+    ///   cryptographically secure
     pub fn get_bytes(
         &self,
         n_bytes: usize,
@@ -378,16 +1095,131 @@ impl EntropyPool {
                 crate::conditioning::condition(&raw, n_bytes, ConditioningMode::VonNeumann)
             }
             ConditioningMode::Sha256 => self.get_random_bytes(n_bytes),
+            ConditioningMode::Toeplitz { output_bits } => {
+                toeplitz_fill(n_bytes, output_bits, |n| self.get_raw_bytes(n))
+            }
+            ConditioningMode::Keyed { .. } => {
+                // One raw byte per output byte keeps every block fresh.
+                let raw = self.get_raw_bytes(n_bytes.max(8));
+                crate::conditioning::condition(&raw, n_bytes, mode)
+            }
         }
     }
 
+    /// Return `n_bytes` derived for the purpose named by `info`.
+    ///
+    /// Draws 32 bytes of key material with `mode`, runs HKDF-Extract on it,
+    /// then HKDF-Expand with `info` as the label. Callers drawing keys and
+    /// nonces from the same pool get independent streams per label. Fresh key
+    /// material is drawn for every [`HKDF_MAX_OUTPUT`] bytes. Output is shorter
+    /// than `n_bytes` only if `Raw` mode has no buffered entropy to draw.
+    ///
+    /// [`HKDF_MAX_OUTPUT`]: crate::conditioning::HKDF_MAX_OUTPUT
+    pub fn get_bytes_labeled(
+        &self,
+        n_bytes: usize,
+        mode: crate::conditioning::ConditioningMode,
+        info: &[u8],
+    ) -> Vec<u8> {
+        use crate::conditioning::{HKDF_MAX_OUTPUT, hkdf_expand, hkdf_extract};
+        let mut output = Vec::with_capacity(n_bytes);
+        while output.len() < n_bytes {
+            let ikm = self.get_bytes(32, mode);
+            if ikm.is_empty() {
+                break;
+            }
+            let prk = hkdf_extract(&[], &ikm);
+            let take = (n_bytes - output.len()).min(HKDF_MAX_OUTPUT);
+            output.extend(hkdf_expand(&prk, info, take));
+        }
+        output
+    }
+
+    /// [`get_bytes`](Self::get_bytes), also returning the wall time spent
+    /// collecting and conditioning, for throughput figures that do not rely
+    /// on caller-side timing.
+    pub fn collect_timed(
+        &self,
+        n_bytes: usize,
+        mode: crate::conditioning::ConditioningMode,
+    ) -> (Vec<u8>, Duration) {
+        let start = Instant::now();
+        let data = self.get_bytes(n_bytes, mode);
+        (data, start.elapsed())
+    }
+
+    /// An [`io::Read`](std::io::Read) stream of `mode`-conditioned bytes
+    /// drawn from this pool on demand (see [`EntropyReader`]).
+    ///
+    /// [`EntropyReader`]: crate::reader::EntropyReader
+    pub fn reader(
+        &self,
+        mode: crate::conditioning::ConditioningMode,
+    ) -> crate::reader::EntropyReader<'_> {
+        crate::reader::EntropyReader::new(self, mode)
+    }
+
+    /// A SHA-256 [`reader`](Self::reader) that is also a `CryptoRng` with
+    /// the `rand` feature (see [`CryptoEntropyReader`]).
+    ///
+    /// [`CryptoEntropyReader`]: crate::reader::CryptoEntropyReader
+    pub fn crypto_reader(&self) -> crate::reader::CryptoEntropyReader<'_> {
+        crate::reader::CryptoEntropyReader::new(self)
+    }
+
+    /// Like [`get_bytes`](Self::get_bytes), but refuses to serve output when
+    /// no hardware source is registered, or when SHA-256 output would exceed
+    /// the [`set_max_expansion`](Self::set_max_expansion) cap even after a
+    /// reseed.
+    ///
+    /// `get_bytes` on a sourceless pool silently falls back to OS entropy
+    /// alone; use this variant when the caller needs hardware contribution.
+    pub fn get_bytes_checked(
+        &self,
+        n_bytes: usize,
+        mode: crate::conditioning::ConditioningMode,
+    ) -> Result<Vec<u8>, PoolError> {
+        if self.source_count() == 0 {
+            return Err(PoolError::NoSources);
+        }
+        if mode == crate::conditioning::ConditioningMode::Sha256 {
+            self.enforce_expansion(n_bytes)?;
+            return Ok(self.serve_random(n_bytes));
+        }
+        Ok(self.get_bytes(n_bytes, mode))
+    }
+
+    /// Cumulative input min-entropy credit vs output served.
+    ///
+    /// Logs a warning the first time output exceeds the credit (and again
+    /// after the pool has collected its way back into budget and overdrawn
+    /// once more).
+    pub fn entropy_budget(&self) -> EntropyBudget {
+        let output_bits = *self.total_output.lock().unwrap() * 8;
+        let mut ledger = self.ledger.lock().unwrap();
+        let budget = EntropyBudget {
+            input_min_entropy_bits: ledger.input_bits,
+            output_bits,
+        };
+        let over = budget.over_extracted();
+        if over && !ledger.deficit_warned {
+            log::warn!(
+                "entropy budget overdrawn: {output_bits} output bits vs {:.0} bits of input min-entropy",
+                budget.input_min_entropy_bits
+            );
+        }
+        ledger.deficit_warned = over;
+        budget
+    }
+
     /// Health report as structured data.
     pub fn health_report(&self) -> HealthReport {
         let mut sources = Vec::new();
         let mut healthy_count = 0;
         let mut total_raw = 0u64;
 
-        for ss_mutex in &self.sources {
+        let handles = self.source_handles();
+        for ss_mutex in &handles {
             let ss = ss_mutex.lock().unwrap();
             if ss.healthy {
                 healthy_count += 1;
@@ -401,15 +1233,31 @@ impl EntropyPool {
                 min_entropy: ss.last_min_entropy,
                 time: ss.last_collect_time.as_secs_f64(),
                 failures: ss.failures,
+                stuck: ss.stuck,
             });
         }
 
+        sources.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let (prefill_bytes, prefill_target) = {
+            let state = self.prefill.state.lock().unwrap();
+            (state.buf.len(), state.target)
+        };
+        let entropy_budget = self.entropy_budget();
+        let expansion_violations = self.expansion.lock().unwrap().violations;
+
         HealthReport {
             healthy: healthy_count,
-            total: self.sources.len(),
+            total: handles.len(),
+            degraded: healthy_count == 0,
             raw_bytes: total_raw,
             output_bytes: *self.total_output.lock().unwrap(),
             buffer_size: self.buffer.lock().unwrap().len(),
+            prefill_bytes,
+            prefill_target,
+            entropy_budget,
+            max_expansion: self.max_expansion,
+            expansion_violations,
             sources,
         }
     }
@@ -426,13 +1274,39 @@ impl EntropyPool {
             "Output: {} bytes | Buffer: {} bytes",
             r.output_bytes, r.buffer_size
         );
+        if r.prefill_target > 0 {
+            println!("Pre-fill: {}/{} bytes", r.prefill_bytes, r.prefill_target);
+        }
+        if r.max_expansion > 0.0 {
+            println!(
+                "Max expansion: {}x ({} forced reseeds)",
+                r.max_expansion, r.expansion_violations
+            );
+        }
+        let budget = r.entropy_budget;
+        println!(
+            "Entropy budget: {:.0} bits in / {} bits out{}",
+            budget.input_min_entropy_bits,
+            budget.output_bits,
+            if budget.over_extracted() {
+                format!(" (deficit {:.0} bits)", budget.deficit_bits())
+            } else {
+                String::new()
+            }
+        );
         println!(
             "\n{:<25} {:>4} {:>10} {:>6} {:>6} {:>7} {:>5}",
             "Source", "OK", "Bytes", "H", "H∞", "Time", "Fail"
         );
         println!("{}", "-".repeat(68));
         for s in &r.sources {
-            let ok = if s.healthy { "✓" } else { "✗" };
+            let ok = if s.stuck {
+                "stk"
+            } else if s.healthy {
+                "✓"
+            } else {
+                "✗"
+            };
             println!(
                 "{:<25} {:>4} {:>10} {:>5.2} {:>5.2} {:>6.3}s {:>5}",
                 s.name, ok, s.bytes, s.entropy, s.min_entropy, s.time, s.failures
@@ -453,19 +1327,18 @@ impl EntropyPool {
             return Some(Vec::new());
         }
 
-        let ss_mutex = self
-            .sources
-            .iter()
-            .find(|ss_mutex| {
-                let ss = ss_mutex.lock().unwrap();
-                ss.source.info().name == source_name
-            })
-            .cloned()?;
+        let ss_mutex = self.find_source(source_name)?;
 
         let n_samples = match mode {
             crate::conditioning::ConditioningMode::Raw => n_bytes,
             crate::conditioning::ConditioningMode::VonNeumann => n_bytes * 6,
             crate::conditioning::ConditioningMode::Sha256 => n_bytes * 4 + 64,
+            crate::conditioning::ConditioningMode::Toeplitz { output_bits } => {
+                return Some(toeplitz_fill(n_bytes, output_bits, |n| {
+                    Self::collect_one_n(&ss_mutex, n)
+                }));
+            }
+            crate::conditioning::ConditioningMode::Keyed { .. } => n_bytes.max(8),
         };
         let raw = Self::collect_one_n(&ss_mutex, n_samples);
         let output = crate::conditioning::condition(&raw, n_bytes, mode);
@@ -476,29 +1349,66 @@ impl EntropyPool {
     ///
     /// Returns `None` if no source matches the name.
     pub fn get_source_raw_bytes(&self, source_name: &str, n_samples: usize) -> Option<Vec<u8>> {
-        let ss_mutex = self.sources.iter().find(|ss_mutex| {
+        let ss_mutex = self.find_source(source_name)?;
+        let raw = Self::collect_one_n(&ss_mutex, n_samples);
+        Some(raw)
+    }
+
+    /// Collect `total` raw bytes from a single named source, `chunk` bytes at
+    /// a time, so callers can process a long stream without holding it all.
+    ///
+    /// Each `next()` collects one chunk (the last may be shorter). The
+    /// iterator ends early if the source returns nothing. Returns `None` if
+    /// no source matches the name.
+    pub fn collect_source_chunks(
+        &self,
+        source_name: &str,
+        chunk: usize,
+        total: usize,
+    ) -> Option<impl Iterator<Item = Vec<u8>> + use<>> {
+        let ss_mutex = self.find_source(source_name)?;
+        let chunk = chunk.max(1);
+        let mut remaining = total;
+        Some(std::iter::from_fn(move || {
+            if remaining == 0 {
+                return None;
+            }
+            let mut raw = Self::collect_one_n(&ss_mutex, chunk.min(remaining));
+            if raw.is_empty() {
+                remaining = 0;
+                return None;
+            }
+            raw.truncate(remaining);
+            remaining -= raw.len();
+            Some(raw)
+        }))
+    }
+
+    fn find_source(&self, source_name: &str) -> Option<Arc<Mutex<SourceState>>> {
+        self.source_handles().into_iter().find(|ss_mutex| {
             let ss = ss_mutex.lock().unwrap();
             ss.source.info().name == source_name
-        })?;
-
-        let raw = Self::collect_one_n(ss_mutex, n_samples);
-        Some(raw)
+        })
     }
 
-    /// List all registered source names.
+    /// List all registered source names, sorted alphabetically.
     pub fn source_names(&self) -> Vec<String> {
-        self.sources
+        let mut names: Vec<String> = self
+            .source_handles()
             .iter()
             .map(|ss_mutex| {
                 let ss = ss_mutex.lock().unwrap();
                 ss.source.info().name.to_string()
             })
-            .collect()
+            .collect();
+        names.sort();
+        names
     }
 
-    /// Get source info for each registered source.
+    /// Get source info for each registered source, sorted by name.
     pub fn source_infos(&self) -> Vec<SourceInfoSnapshot> {
-        self.sources
+        let mut infos: Vec<SourceInfoSnapshot> = self
+            .source_handles()
             .iter()
             .map(|ss_mutex| {
                 let ss = ss_mutex.lock().unwrap();
@@ -507,14 +1417,57 @@ impl EntropyPool {
                     name: info.name.to_string(),
                     description: info.description.to_string(),
                     physics: info.physics.to_string(),
+                    failure_hint: info.failure_hint.to_string(),
                     category: info.category.to_string(),
                     platform: info.platform.to_string(),
                     requirements: info.requirements.iter().map(|r| r.to_string()).collect(),
                     entropy_rate_estimate: info.entropy_rate_estimate,
+                    typical_latency_ms: info.typical_latency_ms,
+                    cost_class: info.cost_class.to_string(),
                     composite: info.composite,
                 }
             })
-            .collect()
+            .collect();
+        infos.sort_by(|a, b| a.name.cmp(&b.name));
+        infos
+    }
+}
+
+impl Drop for EntropyPool {
+    fn drop(&mut self) {
+        // Stop the pre-fill worker; it holds only a weak pool reference.
+        {
+            let mut prefill = self
+                .prefill
+                .state
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            prefill.target = 0;
+            prefill.buf.zeroize();
+        }
+        self.prefill.wake.notify_all();
+        self.zeroize_state();
+    }
+}
+
+impl ZeroizeOnDrop for EntropyPool {}
+
+impl EntropyPool {
+    /// Wipe the conditioning state and raw buffer. Poisoned locks are still
+    /// wiped: a panic elsewhere must not leave secrets behind.
+    fn zeroize_state(&mut self) {
+        self.state
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .zeroize();
+        self.counter
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .zeroize();
+        self.buffer
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .zeroize();
     }
 }
 
@@ -527,6 +1480,44 @@ fn getrandom(buf: &mut [u8]) {
     getrandom::fill(buf).expect("OS CSPRNG failed");
 }
 
+/// Toeplitz-extract `n_bytes`, drawing raw input from `collect` until enough
+/// is produced.
+///
+/// Output shrinks with the raw min-entropy, so each draw is 4x the shortfall
+/// (covers >= ~3 bits/byte) but never less than one extractor block at
+/// 1 bit/byte. A draw too short for the block its own estimate asks for is
+/// retried at that size. Stops short, possibly empty, once a draw long
+/// enough for its block still yields nothing.
+fn toeplitz_fill(
+    n_bytes: usize,
+    output_bits: usize,
+    mut collect: impl FnMut(usize) -> Vec<u8>,
+) -> Vec<u8> {
+    use crate::conditioning::{ConditioningMode, condition, toeplitz_block_bytes};
+    let mode = ConditioningMode::Toeplitz { output_bits };
+    let Some(mut min_draw) = toeplitz_block_bytes(output_bits, 1.0) else {
+        return Vec::new();
+    };
+    let mut output = Vec::with_capacity(n_bytes);
+    while output.len() < n_bytes {
+        let remaining = n_bytes - output.len();
+        let draw = (remaining * 4 + 64).max(min_draw);
+        let raw = collect(draw);
+        let chunk = condition(&raw, remaining, mode);
+        if chunk.is_empty() {
+            match toeplitz_block_bytes(output_bits, quick_min_entropy(&raw)) {
+                Some(block) if block > draw => {
+                    min_draw = block;
+                    continue;
+                }
+                _ => break,
+            }
+        }
+        output.extend_from_slice(&chunk);
+    }
+    output
+}
+
 /// Overall health report for the entropy pool.
 #[derive(Debug, Clone)]
 pub struct HealthReport {
@@ -534,22 +1525,68 @@ pub struct HealthReport {
     pub healthy: usize,
     /// Total number of registered sources.
     pub total: usize,
+    /// True when no healthy hardware source is contributing (including a
+    /// pool with zero sources), so output relies on OS entropy alone.
+    pub degraded: bool,
     /// Total raw bytes collected across all sources.
     pub raw_bytes: u64,
     /// Total conditioned output bytes produced.
     pub output_bytes: u64,
     /// Current internal buffer size in bytes.
     pub buffer_size: usize,
+    /// Conditioned bytes ready in the pre-fill buffer.
+    pub prefill_bytes: usize,
+    /// Pre-fill target set by [`EntropyPool::set_prefill`] (0 = disabled).
+    pub prefill_target: usize,
+    /// Input min-entropy credit vs output; see [`EntropyPool::entropy_budget`].
+    pub entropy_budget: EntropyBudget,
+    /// Expansion cap from [`EntropyPool::set_max_expansion`] (0 = none).
+    pub max_expansion: f64,
+    /// Requests that exceeded the expansion cap and forced a reseed.
+    pub expansion_violations: u64,
     /// Per-source health details.
     pub sources: Vec<SourceHealth>,
 }
 
+/// Errors returned by checked pool operations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolError {
+    /// The pool has no registered sources.
+    NoSources,
+    /// SHA-256 output would exceed [`EntropyPool::set_max_expansion`] times
+    /// the raw bytes collected, even after a reseed.
+    ExpansionExceeded {
+        /// Bytes requested.
+        requested: u64,
+        /// Raw bytes collected in the current reseed window.
+        input_bytes: u64,
+    },
+}
+
+impl std::fmt::Display for PoolError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoSources => write!(f, "entropy pool has no registered sources"),
+            Self::ExpansionExceeded {
+                requested,
+                input_bytes,
+            } => write!(
+                f,
+                "{requested} conditioned bytes requested from {input_bytes} raw bytes exceeds the maximum expansion ratio"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PoolError {}
+
 /// Health status of a single entropy source.
 #[derive(Debug, Clone)]
 pub struct SourceHealth {
     /// Source name.
     pub name: String,
-    /// Whether the source is currently healthy (entropy > 1.0 bits/byte).
+    /// Whether the source is currently healthy (entropy > 1.0 bits/byte and
+    /// not [`stuck`](Self::stuck)).
     pub healthy: bool,
     /// Total bytes collected from this source.
     pub bytes: u64,
@@ -561,6 +1598,9 @@ pub struct SourceHealth {
     pub time: f64,
     /// Number of collection failures.
     pub failures: u64,
+    /// The last [`STUCK_AFTER`] collections returned byte-identical buffers,
+    /// a stuck-at hardware fault statistical tests can miss on small samples.
+    pub stuck: bool,
 }
 
 /// Snapshot of source metadata for external consumption.
@@ -572,6 +1612,8 @@ pub struct SourceInfoSnapshot {
     pub description: String,
     /// Physics explanation.
     pub physics: String,
+    /// What an unhealthy reading usually looks like.
+    pub failure_hint: String,
     /// Source category.
     pub category: String,
     /// Target platform.
@@ -580,6 +1622,10 @@ pub struct SourceInfoSnapshot {
     pub requirements: Vec<String>,
     /// Estimated entropy rate.
     pub entropy_rate_estimate: f64,
+    /// Typical collection latency in milliseconds.
+    pub typical_latency_ms: f64,
+    /// Cost tier (`"fast"`, `"medium"`, `"slow"`).
+    pub cost_class: String,
     /// Whether this is a composite source.
     pub composite: bool,
 }
@@ -587,7 +1633,7 @@ pub struct SourceInfoSnapshot {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::source::{Platform, SourceCategory, SourceInfo};
+    use crate::source::{CostClass, Platform, SourceCategory, SourceInfo};
 
     // -----------------------------------------------------------------------
     // Mock entropy source for testing
@@ -606,10 +1652,13 @@ mod tests {
                     name,
                     description: "mock source",
                     physics: "deterministic test data",
+                    failure_hint: "repeats its fixed data",
                     category: SourceCategory::System,
                     platform: Platform::Any,
                     requirements: &[],
                     entropy_rate_estimate: 1.0,
+                    typical_latency_ms: 0.0,
+                    cost_class: CostClass::Fast,
                     composite: false,
                 },
                 data,
@@ -641,10 +1690,13 @@ mod tests {
                     name,
                     description: "failing mock",
                     physics: "always fails",
+                    failure_hint: "always returns no data",
                     category: SourceCategory::System,
                     platform: Platform::Any,
                     requirements: &[],
                     entropy_rate_estimate: 0.0,
+                    typical_latency_ms: 0.0,
+                    cost_class: CostClass::Fast,
                     composite: false,
                 },
             }
@@ -663,6 +1715,29 @@ mod tests {
         }
     }
 
+    /// A mock source that sleeps for `delay` before returning data.
+    fn sleepy_source(name: &'static str, delay: Duration) -> crate::ReplaySource {
+        crate::ReplaySource::from_seed(name, 0).with_delay(delay)
+    }
+
+    /// Tracks how many instances are collecting at the same moment.
+    #[derive(Default)]
+    struct Concurrency {
+        active: std::sync::atomic::AtomicUsize,
+        peak: std::sync::atomic::AtomicUsize,
+    }
+
+    /// A mock source that records concurrent collections in a shared counter.
+    fn counting_source(name: &'static str, concurrency: Arc<Concurrency>) -> crate::ReplaySource {
+        crate::ReplaySource::from_seed(name, 0).on_collect(move || {
+            use std::sync::atomic::Ordering::SeqCst;
+            let now = concurrency.active.fetch_add(1, SeqCst) + 1;
+            concurrency.peak.fetch_max(now, SeqCst);
+            std::thread::sleep(Duration::from_millis(20));
+            concurrency.active.fetch_sub(1, SeqCst);
+        })
+    }
+
     // -----------------------------------------------------------------------
     // Pool creation tests
     // -----------------------------------------------------------------------
@@ -710,6 +1785,158 @@ mod tests {
         assert!(n > 0, "Should have collected some bytes");
     }
 
+    #[test]
+    fn test_warm_collects_per_source_but_leaves_buffer_empty() {
+        let mut pool = EntropyPool::new(Some(b"test"));
+        pool.add_source(Box::new(MockSource::new("mock1", vec![1, 2, 3])), 1.0);
+        pool.add_source(Box::new(MockSource::new("mock2", vec![4, 5, 6])), 1.0);
+
+        pool.warm(2048, 10.0);
+
+        let report = pool.health_report();
+        for s in &report.sources {
+            assert!(s.bytes >= 2048, "{} collected {}", s.name, s.bytes);
+        }
+        assert_eq!(report.buffer_size, 0);
+        assert_eq!(report.output_bytes, 0);
+        assert_eq!(pool.entropy_budget().input_min_entropy_bits, 0.0);
+    }
+
+    #[test]
+    fn test_warm_gives_up_on_hung_source_at_deadline() {
+        let mut pool = EntropyPool::new(Some(b"test"));
+        pool.add_source(Box::new(MockSource::new("mock1", vec![1, 2, 3])), 1.0);
+        pool.add_source(Box::new(sleepy_source("hung", Duration::from_secs(5))), 1.0);
+
+        let start = Instant::now();
+        pool.warm(64, 0.2);
+        // The hung worker still holds its source, so only the deadline is
+        // checkable here; health_report would wait for it.
+        assert!(
+            start.elapsed() < Duration::from_secs(2),
+            "{:?}",
+            start.elapsed()
+        );
+    }
+
+    #[test]
+    fn test_keyed_output_replays_for_fixed_key_and_seed() {
+        use crate::conditioning::ConditioningMode;
+        let mode = ConditioningMode::Keyed { key: [7; 16] };
+        let draw = || {
+            let mut pool = EntropyPool::new(Some(b"sim-seed"));
+            pool.add_source(Box::new(MockSource::new("mock1", (0..=255).collect())), 1.0);
+            pool.get_bytes(4096, mode)
+        };
+        let a = draw();
+        assert_eq!(a.len(), 4096);
+        assert_eq!(a, draw());
+    }
+
+    #[test]
+    fn test_toeplitz_fills_small_and_odd_requests() {
+        use crate::conditioning::{ConditioningMode, TOEPLITZ_DEFAULT_OUTPUT_BITS};
+        let mode = ConditioningMode::Toeplitz {
+            output_bits: TOEPLITZ_DEFAULT_OUTPUT_BITS,
+        };
+        let mut pool = EntropyPool::new(None);
+        pool.add_source(Box::new(crate::ReplaySource::from_seed("seeded", 7)), 1.0);
+        for n in [1, 4, 33, 4100] {
+            assert_eq!(pool.get_bytes(n, mode).len(), n);
+            assert_eq!(pool.get_source_bytes("seeded", n, mode).unwrap().len(), n);
+        }
+
+        // A stuck source has nothing to extract: short output, no hang.
+        let mut stuck = EntropyPool::new(None);
+        stuck.add_source(Box::new(MockSource::new("stuck", vec![0])), 1.0);
+        assert!(stuck.get_source_bytes("stuck", 4, mode).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_collect_source_chunks_yields_total() {
+        let mut pool = EntropyPool::new(None);
+        pool.add_source(Box::new(MockSource::new("mock1", (0..=255).collect())), 1.0);
+        let chunks: Vec<Vec<u8>> = pool
+            .collect_source_chunks("mock1", 1000, 4500)
+            .unwrap()
+            .collect();
+        let sizes: Vec<usize> = chunks.iter().map(Vec::len).collect();
+        assert_eq!(sizes, [1000, 1000, 1000, 1000, 500]);
+        let joined: Vec<u8> = chunks.concat();
+        let expected: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+        assert_eq!(&joined[..1000], &expected[..]);
+        assert!(pool.collect_source_chunks("missing", 10, 10).is_none());
+
+        pool.add_source(Box::new(FailingSource::new("dead")), 1.0);
+        assert_eq!(
+            pool.collect_source_chunks("dead", 10, 100).unwrap().count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_health_subscribers_see_degrade_and_recovery() {
+        let failing = Arc::new(AtomicBool::new(false));
+        let mut pool = EntropyPool::new(Some(b"events"));
+        pool.add_source(
+            Box::new(
+                crate::ReplaySource::from_seed("flaky", 0).failing_while(Arc::clone(&failing)),
+            ),
+            1.0,
+        );
+        let events = pool.subscribe_health();
+
+        pool.collect_all();
+        assert!(events.try_recv().is_err(), "healthy from the start");
+
+        failing.store(true, Ordering::SeqCst);
+        pool.collect_all();
+        assert_eq!(
+            events.try_recv(),
+            Ok(HealthEvent::SourceDegraded("flaky".into()))
+        );
+        pool.collect_all();
+        assert!(events.try_recv().is_err(), "no repeat while still failing");
+
+        failing.store(false, Ordering::SeqCst);
+        pool.collect_all();
+        assert_eq!(
+            events.try_recv(),
+            Ok(HealthEvent::SourceHealthy("flaky".into()))
+        );
+
+        drop(events);
+        failing.store(true, Ordering::SeqCst);
+        pool.collect_all();
+        assert!(pool.health_events.lock().unwrap().subscribers.is_empty());
+    }
+
+    #[test]
+    fn test_cancel_returns_partial_collection_promptly() {
+        let mut pool = EntropyPool::new(Some(b"test"));
+        pool.set_parallel_workers(2);
+        pool.add_source(Box::new(MockSource::new("fast", vec![1, 2, 3])), 1.0);
+        pool.add_source(Box::new(sleepy_source("slow", Duration::from_secs(5))), 1.0);
+
+        let token = pool.cancellation_token();
+        let canceller = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(100));
+            token.cancel();
+        });
+        let t0 = Instant::now();
+        let n = pool.collect_all_parallel(10.0);
+        canceller.join().unwrap();
+
+        assert!(t0.elapsed() < Duration::from_secs(2), "{:?}", t0.elapsed());
+        assert_eq!(n, 1000, "only the fast source's chunk is kept");
+        // Abandoned, not timed out: the slow source is not backed off.
+        assert!(pool.backoff_until.lock().unwrap().is_empty());
+        // Stays cancelled until reset.
+        assert_eq!(pool.collect_all(), 0);
+        pool.cancellation_token().reset();
+        assert!(!pool.cancellation_token().is_cancelled());
+    }
+
     #[test]
     fn test_collect_all_parallel_with_timeout() {
         let mut pool = EntropyPool::new(Some(b"test"));
@@ -719,6 +1946,20 @@ mod tests {
         assert!(n > 0);
     }
 
+    #[test]
+    fn test_collect_all_parallel_reports_each_source() {
+        let mut pool = EntropyPool::new(Some(b"test"));
+        pool.add_source(Box::new(MockSource::new("mock1", vec![1, 2])), 1.0);
+        pool.add_source(Box::new(MockSource::new("mock2", vec![3, 4])), 1.0);
+        let mut seen = Vec::new();
+        let n = pool.collect_all_parallel_n_with_progress(5.0, 8, |name, bytes| {
+            seen.push((name, bytes));
+        });
+        assert!(n > 0);
+        seen.sort();
+        assert_eq!(seen, [("mock1", 8), ("mock2", 8)]);
+    }
+
     #[test]
     fn test_collect_enabled_filters_sources() {
         let mut pool = EntropyPool::new(Some(b"test"));
@@ -795,6 +2036,74 @@ mod tests {
         assert!(bytes.len() <= 16);
     }
 
+    #[test]
+    fn test_get_bytes_labeled_separates_domains() {
+        use crate::conditioning::ConditioningMode;
+        // Raw mode over a deterministic mock fixes the key material, so
+        // only the label varies between identically built pools.
+        let pool_for = || {
+            let mut pool = EntropyPool::new(Some(b"test"));
+            pool.add_source(Box::new(MockSource::new("mock", (0..=255).collect())), 1.0);
+            pool
+        };
+        let keys = pool_for().get_bytes_labeled(64, ConditioningMode::Raw, b"keys");
+        let nonces = pool_for().get_bytes_labeled(64, ConditioningMode::Raw, b"nonces");
+        assert_eq!(keys.len(), 64);
+        assert_ne!(keys, nonces);
+        assert_eq!(
+            keys,
+            pool_for().get_bytes_labeled(64, ConditioningMode::Raw, b"keys")
+        );
+
+        let live = pool_for();
+        let a = live.get_bytes_labeled(32, ConditioningMode::Sha256, b"a");
+        let b = live.get_bytes_labeled(32, ConditioningMode::Sha256, b"b");
+        assert_eq!(a.len(), 32);
+        assert_ne!(a, b);
+    }
+
+    // -----------------------------------------------------------------------
+    // Mix strategy tests
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_mix_strategy_lengths() {
+        let chunks = vec![vec![0x0F; 4], Vec::new(), vec![0xF0, 0x01]];
+        assert_eq!(
+            MixStrategy::Concatenate.mix(&chunks),
+            [0x0F, 0x0F, 0x0F, 0x0F, 0xF0, 0x01]
+        );
+        assert_eq!(MixStrategy::XorFold.mix(&chunks), [0xFF, 0x0E]);
+        assert_eq!(
+            MixStrategy::Interleave.mix(&chunks),
+            [0x0F, 0xF0, 0x0F, 0x01, 0x0F, 0x0F]
+        );
+        for mix in [
+            MixStrategy::Concatenate,
+            MixStrategy::XorFold,
+            MixStrategy::Interleave,
+        ] {
+            assert!(mix.mix(&[]).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_pool_collects_with_mix_strategy() {
+        for (mix, expected) in [
+            (MixStrategy::Concatenate, 2000),
+            (MixStrategy::XorFold, 1000),
+            (MixStrategy::Interleave, 2000),
+        ] {
+            let mut pool = EntropyPool::new(Some(b"test"));
+            pool.add_source(Box::new(MockSource::new("a", (0..=255).collect())), 1.0);
+            pool.add_source(Box::new(MockSource::new("b", vec![7; 10])), 1.0);
+            pool.set_mix_strategy(mix);
+            assert_eq!(pool.mix_strategy(), mix);
+            assert_eq!(pool.collect_all(), expected, "{mix:?}");
+            assert_eq!(pool.health_report().buffer_size, expected, "{mix:?}");
+        }
+    }
+
     // -----------------------------------------------------------------------
     // Health report tests
     // -----------------------------------------------------------------------
@@ -809,6 +2118,30 @@ mod tests {
         assert_eq!(report.output_bytes, 0);
         assert_eq!(report.buffer_size, 0);
         assert!(report.sources.is_empty());
+        assert!(report.degraded);
+    }
+
+    #[test]
+    fn test_get_bytes_checked_empty_pool_errors() {
+        let pool = EntropyPool::new(Some(b"test"));
+        assert_eq!(
+            pool.get_bytes_checked(32, crate::conditioning::ConditioningMode::Sha256),
+            Err(PoolError::NoSources)
+        );
+    }
+
+    #[test]
+    fn test_get_bytes_checked_with_source() {
+        let mut pool = EntropyPool::new(Some(b"test"));
+        pool.add_source(
+            Box::new(MockSource::new("good_source", (0..=255).collect())),
+            1.0,
+        );
+        let bytes = pool
+            .get_bytes_checked(32, crate::conditioning::ConditioningMode::Sha256)
+            .unwrap();
+        assert_eq!(bytes.len(), 32);
+        assert!(!pool.health_report().degraded);
     }
 
     #[test]
@@ -835,10 +2168,32 @@ mod tests {
         let report = pool.health_report();
         assert_eq!(report.total, 1);
         assert_eq!(report.healthy, 0);
+        assert!(report.degraded);
         assert!(!report.sources[0].healthy);
         assert_eq!(report.sources[0].failures, 1);
     }
 
+    #[test]
+    fn test_health_report_flags_stuck_source() {
+        // Uniform bytes pass the entropy check; only the repetition is wrong.
+        let mut pool = EntropyPool::new(Some(b"test"));
+        pool.add_source(
+            Box::new(MockSource::new("frozen", (0..=255).collect())),
+            1.0,
+        );
+        for _ in 1..STUCK_AFTER {
+            pool.collect_all();
+            let source = &pool.health_report().sources[0];
+            assert!(!source.stuck);
+            assert!(source.healthy);
+        }
+        pool.collect_all();
+        let report = pool.health_report();
+        assert!(report.sources[0].stuck);
+        assert!(!report.sources[0].healthy);
+        assert!(report.degraded);
+    }
+
     #[test]
     fn test_health_report_mixed_sources() {
         let mut pool = EntropyPool::new(Some(b"test"));
@@ -882,6 +2237,99 @@ mod tests {
         assert_eq!(infos[0].description, "mock source");
         assert_eq!(infos[0].category, "system");
         assert!((infos[0].entropy_rate_estimate - 1.0).abs() < f64::EPSILON);
+        assert_eq!(infos[0].cost_class, "fast");
+    }
+
+    #[test]
+    fn test_listings_sorted_by_name() {
+        let mut pool = EntropyPool::new(Some(b"test"));
+        pool.add_source(Box::new(MockSource::new("zeta", vec![1])), 1.0);
+        pool.add_source(Box::new(FailingSource::new("alpha")), 1.0);
+        pool.add_source(Box::new(MockSource::new("mid", vec![2])), 1.0);
+        let expected = vec!["alpha", "mid", "zeta"];
+        assert_eq!(pool.source_names(), expected);
+        let infos: Vec<_> = pool.source_infos().into_iter().map(|i| i.name).collect();
+        assert_eq!(infos, expected);
+        pool.collect_all();
+        let health: Vec<_> = pool
+            .health_report()
+            .sources
+            .into_iter()
+            .map(|s| s.name)
+            .collect();
+        assert_eq!(health, expected);
+    }
+
+    #[test]
+    fn test_refresh_sources_keeps_conditioning_state() {
+        let mut pool = EntropyPool::new(Some(b"test"));
+        pool.add_source(Box::new(MockSource::new("kept", (0..=255).collect())), 1.0);
+        pool.add_source(Box::new(MockSource::new("unplugged", vec![7])), 1.0);
+        pool.get_random_bytes(64);
+        let counter = *pool.counter.lock().unwrap();
+        let state = *pool.state.lock().unwrap();
+        let kept_bytes = pool.health_report().sources[0].bytes;
+        assert!(counter > 0);
+        assert!(kept_bytes > 0);
+
+        let n = pool.refresh_sources(vec![
+            (Box::new(MockSource::new("kept", vec![0])), 3.0),
+            (Box::new(MockSource::new("plugged_in", vec![1, 2, 3])), 2.0),
+        ]);
+        assert_eq!(n, 2);
+        assert_eq!(pool.source_names(), vec!["kept", "plugged_in"]);
+        assert_eq!(*pool.counter.lock().unwrap(), counter);
+        assert_eq!(*pool.state.lock().unwrap(), state);
+        let report = pool.health_report();
+        assert_eq!(report.sources[0].bytes, kept_bytes);
+        assert_eq!(report.sources[1].bytes, 0);
+        // The kept source keeps its weight; the new one takes its own.
+        let weights: Vec<f64> = pool
+            .source_handles()
+            .iter()
+            .map(|ss| ss.lock().unwrap().weight)
+            .collect();
+        assert_eq!(weights, vec![1.0, 2.0]);
+
+        pool.get_random_bytes(32);
+        assert!(*pool.counter.lock().unwrap() > counter);
+    }
+
+    #[test]
+    fn test_refresh_during_timed_out_collection_keys_by_source() {
+        let delay = Duration::from_millis(300);
+        let mut pool = EntropyPool::new(Some(b"test"));
+        pool.set_parallel_workers(3);
+        pool.add_source(Box::new(sleepy_source("slow", delay)), 1.0);
+        pool.add_source(Box::new(MockSource::new("gone", vec![1, 2, 3])), 1.0);
+        pool.add_source(Box::new(sleepy_source("gone_slow", delay)), 1.0);
+        let handles = pool.source_handles();
+        let key = |i: usize| EntropyPool::source_key(&handles[i]);
+
+        // Both slow sources time out and stay in flight.
+        assert_eq!(pool.collect_all_parallel(0.05), 1000);
+        assert!(pool.in_flight.lock().unwrap().contains(&key(0)));
+        assert!(pool.backoff_until.lock().unwrap().contains_key(&key(2)));
+
+        // "fresh" takes index 0, where the in-flight "slow" used to be.
+        pool.refresh_sources(vec![
+            (Box::new(MockSource::new("fresh", vec![4, 5, 6])), 1.0),
+            (Box::new(sleepy_source("slow", delay)), 1.0),
+        ]);
+        let fresh = EntropyPool::source_key(&pool.source_handles()[0]);
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !pool.in_flight.lock().unwrap().is_empty() {
+            assert!(Instant::now() < deadline, "stale workers never finished");
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        let backoff = pool.backoff_until.lock().unwrap().clone();
+        assert!(!backoff.contains_key(&fresh));
+        assert!(
+            !backoff.contains_key(&key(2)),
+            "dropped source kept its backoff"
+        );
+
+        assert_eq!(pool.collect_all_parallel(5.0), 2000);
     }
 
     // -----------------------------------------------------------------------
@@ -903,6 +2351,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_personalization_separates_instances() {
+        let plain = *EntropyPool::new(Some(b"seed")).state.lock().unwrap();
+        let empty = EntropyPool::with_personalization(Some(b"seed"), b"");
+        assert_eq!(*empty.state.lock().unwrap(), plain);
+
+        let mut pool_a = EntropyPool::with_personalization(Some(b"seed"), b"instance-a");
+        let mut pool_b = EntropyPool::with_personalization(Some(b"seed"), b"instance-b");
+        assert_ne!(*pool_a.state.lock().unwrap(), plain);
+        assert_ne!(*pool_a.state.lock().unwrap(), *pool_b.state.lock().unwrap());
+
+        pool_a.add_source(Box::new(MockSource::new("m", vec![42; 100])), 1.0);
+        pool_b.add_source(Box::new(MockSource::new("m", vec![42; 100])), 1.0);
+        assert_ne!(pool_a.get_random_bytes(32), pool_b.get_random_bytes(32));
+    }
+
     // -----------------------------------------------------------------------
     // Edge case tests
     // -----------------------------------------------------------------------
@@ -921,4 +2385,232 @@ mod tests {
         let n = pool.collect_enabled(&[]);
         assert_eq!(n, 0);
     }
+
+    // -----------------------------------------------------------------------
+    // Timed collection tests
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_source_collect_timed_measures_wall_time() {
+        let delay = Duration::from_millis(20);
+        let source = sleepy_source("sleepy", delay);
+        let (data, elapsed) = source.collect_timed(16);
+        assert_eq!(data.len(), 16);
+        assert!(elapsed >= delay, "{elapsed:?}");
+        assert!(elapsed < Duration::from_secs(5), "{elapsed:?}");
+    }
+
+    #[test]
+    fn test_pool_collect_timed_reports_duration() {
+        use crate::conditioning::ConditioningMode;
+        let delay = Duration::from_millis(20);
+        for mode in [ConditioningMode::Raw, ConditioningMode::Sha256] {
+            // Fresh pool each time so the call has to collect.
+            let mut pool = EntropyPool::new(Some(b"timed"));
+            pool.add_source(Box::new(sleepy_source("sleepy", delay)), 1.0);
+            let (data, elapsed) = pool.collect_timed(64, mode);
+            assert_eq!(data.len(), 64);
+            assert!(elapsed >= delay, "{mode}: {elapsed:?}");
+            assert!(elapsed < Duration::from_secs(5), "{mode}: {elapsed:?}");
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Pre-fill tests
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_prefill_serves_without_collecting() {
+        use crate::conditioning::ConditioningMode;
+        let delay = Duration::from_millis(300);
+        let mut pool = EntropyPool::new(Some(b"prefill"));
+        pool.add_source(Box::new(sleepy_source("sleepy", delay)), 1.0);
+        let pool = Arc::new(pool);
+        pool.set_prefill(256);
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while pool.health_report().prefill_bytes < 256 {
+            assert!(Instant::now() < deadline, "pre-fill never filled");
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        let report = pool.health_report();
+        assert_eq!(report.prefill_target, 256);
+        // Pre-filled bytes count as output only once served.
+        assert_eq!(report.output_bytes, 0);
+
+        // A fresh collection would sleep for `delay`.
+        let (data, elapsed) = pool.collect_timed(64, ConditioningMode::Sha256);
+        assert_eq!(data.len(), 64);
+        assert!(
+            elapsed < delay,
+            "collected on the calling thread: {elapsed:?}"
+        );
+        assert_eq!(pool.health_report().output_bytes, 64);
+
+        pool.set_prefill(0);
+        assert_eq!(pool.health_report().prefill_target, 0);
+    }
+
+    // -----------------------------------------------------------------------
+    // Early-exit collection tests
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_collect_until_stops_on_high_entropy_source() {
+        let mut pool = EntropyPool::new(Some(b"test"));
+        pool.add_source(Box::new(MockSource::new("alpha", (0..=255).collect())), 1.0);
+        pool.add_source(Box::new(MockSource::new("beta", (0..=255).collect())), 1.0);
+
+        let n = pool.collect_until(256.0, 8);
+        assert_eq!(n, 1000);
+        let report = pool.health_report();
+        assert_eq!(report.sources[0].bytes, 1000);
+        assert_eq!(report.sources[1].bytes, 0, "beta should not be collected");
+        assert_eq!(report.buffer_size, 1000);
+    }
+
+    #[test]
+    fn test_collect_until_respects_source_cap() {
+        let mut pool = EntropyPool::new(Some(b"test"));
+        for name in ["a", "b", "c"] {
+            pool.add_source(Box::new(MockSource::new(name, vec![7])), 1.0);
+        }
+        // Constant bytes carry no min-entropy, so only the cap stops it.
+        assert_eq!(pool.collect_until(1.0, 2), 2000);
+        let collected = pool
+            .health_report()
+            .sources
+            .iter()
+            .filter(|s| s.bytes > 0)
+            .count();
+        assert_eq!(collected, 2);
+        assert_eq!(pool.collect_until(1.0, 0), 0);
+    }
+
+    #[test]
+    fn test_parallel_collection_respects_worker_cap() {
+        const NAMES: [&str; 8] = ["c0", "c1", "c2", "c3", "c4", "c5", "c6", "c7"];
+        let concurrency = Arc::new(Concurrency::default());
+        let mut pool = EntropyPool::new(Some(b"workers"));
+        for name in NAMES {
+            pool.add_source(
+                Box::new(counting_source(name, Arc::clone(&concurrency))),
+                1.0,
+            );
+        }
+        assert!(pool.parallel_workers() >= 1);
+        pool.set_parallel_workers(2);
+
+        let n = pool.collect_all_parallel_n(10.0, 16);
+        assert_eq!(n, NAMES.len() * 16);
+        assert_eq!(
+            concurrency.peak.load(std::sync::atomic::Ordering::SeqCst),
+            2
+        );
+
+        concurrency
+            .peak
+            .store(0, std::sync::atomic::Ordering::SeqCst);
+        let names: Vec<String> = NAMES.iter().map(|n| n.to_string()).collect();
+        assert_eq!(pool.collect_enabled_n(&names, 16), NAMES.len() * 16);
+        assert_eq!(
+            concurrency.peak.load(std::sync::atomic::Ordering::SeqCst),
+            2
+        );
+
+        pool.set_parallel_workers(0);
+        assert_eq!(pool.parallel_workers(), 1);
+    }
+
+    #[test]
+    fn test_drop_zeroizes_state_and_buffers() {
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<EntropyPool>();
+
+        let mut pool = EntropyPool::new(Some(b"zeroize"));
+        pool.add_source(Box::new(MockSource::new("mock1", vec![7; 64])), 1.0);
+        pool.collect_all();
+        pool.get_random_bytes(64);
+        assert_ne!(*pool.state.lock().unwrap(), [0u8; 32]);
+        assert!(!pool.buffer.lock().unwrap().is_empty());
+
+        pool.zeroize_state();
+        assert_eq!(*pool.state.lock().unwrap(), [0u8; 32]);
+        assert_eq!(*pool.counter.lock().unwrap(), 0);
+        assert!(pool.buffer.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_max_expansion_forces_reseed() {
+        use crate::conditioning::ConditioningMode;
+        let make_pool = || {
+            let mut pool = EntropyPool::new(Some(b"expansion"));
+            let data: Vec<u8> = (0..=255).collect();
+            pool.add_source(Box::new(MockSource::new("uniform", data)), 1.0);
+            pool.set_max_expansion(10.0);
+            pool
+        };
+
+        let pool = make_pool();
+        let collected = pool.collect_all();
+        assert!(collected > 0);
+        assert_eq!(pool.get_random_bytes(collected).len(), collected);
+        assert_eq!(pool.health_report().expansion_violations, 0);
+
+        let raw_before = pool.health_report().raw_bytes;
+        assert_eq!(
+            pool.get_random_bytes(collected * 100).len(),
+            collected * 100
+        );
+        let report = pool.health_report();
+        assert_eq!(report.expansion_violations, 1);
+        assert!(report.raw_bytes > raw_before, "reseed should collect");
+
+        // One reseed collects `collected` bytes again, which cannot cover 100x.
+        let pool = make_pool();
+        pool.collect_all();
+        let err = pool
+            .get_bytes_checked(collected * 100, ConditioningMode::Sha256)
+            .unwrap_err();
+        assert!(matches!(err, PoolError::ExpansionExceeded { .. }));
+        assert_eq!(pool.health_report().expansion_violations, 1);
+        assert!(pool.get_bytes_checked(64, ConditioningMode::Sha256).is_ok());
+    }
+
+    #[test]
+    fn test_entropy_budget_flags_over_extraction() {
+        let mut pool = EntropyPool::new(Some(b"ledger"));
+        let data: Vec<u8> = (0..=255).collect();
+        pool.add_source(Box::new(MockSource::new("uniform", data)), 1.0);
+        pool.collect_all();
+        let budget = pool.entropy_budget();
+        assert!(budget.input_min_entropy_bits > 0.0);
+        assert!(!budget.over_extracted());
+
+        pool.get_raw_bytes(64);
+        let budget = pool.health_report().entropy_budget;
+        assert_eq!(budget.output_bits, 64 * 8);
+        assert!(!budget.over_extracted());
+
+        // A constant source credits nothing, so conditioned output runs far
+        // past the input however often the pool collects.
+        let mut pool = EntropyPool::new(Some(b"ledger"));
+        pool.add_source(Box::new(MockSource::new("constant", vec![7; 64])), 1.0);
+        pool.get_random_bytes(4096);
+        let budget = pool.health_report().entropy_budget;
+        assert_eq!(budget.input_min_entropy_bits, 0.0);
+        assert!(budget.over_extracted());
+        assert_eq!(budget.deficit_bits(), 4096.0 * 8.0);
+    }
+
+    #[test]
+    fn test_xor_fold_credits_only_best_chunk() {
+        let uniform: Vec<u8> = (0..=255).collect();
+        let chunks = vec![uniform.clone(), uniform];
+        let concat = MixStrategy::Concatenate.credited_min_entropy(&chunks, 512);
+        let xor = MixStrategy::XorFold.credited_min_entropy(&chunks, 256);
+        assert!((concat - 2.0 * xor).abs() < 1e-9);
+        let best = quick_min_entropy(&chunks[0]) * 256.0;
+        assert!(xor > 0.0 && (xor - best).abs() < 1e-9);
+    }
 }
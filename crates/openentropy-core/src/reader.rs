@@ -0,0 +1,209 @@
+//! [`std::io::Read`] adapter over an [`EntropyPool`].
+//!
+//! [`EntropyPool::reader`] returns an [`EntropyReader`] that conditions bytes
+//! on demand, straight into the caller's buffer, so the pool can feed any
+//! `io::Read` consumer. With the `rand` feature it is also a fallible
+//! `rand_core` RNG, and [`EntropyPool::crypto_reader`]'s
+//! [`CryptoEntropyReader`] a `CryptoRng`.
+
+use std::io::{self, Read};
+
+use crate::conditioning::ConditioningMode;
+use crate::pool::EntropyPool;
+
+/// Largest request a single [`Read::read`] call makes to the pool, so huge
+/// buffers are filled over several calls instead of one giant collection.
+pub const READ_CHUNK: usize = 64 * 1024;
+
+/// Conditioned byte stream from an [`EntropyPool`].
+///
+/// Each `read` draws `min(buf.len(), READ_CHUNK)` bytes with the reader's
+/// [`ConditioningMode`]. `Sha256` reads always fill the requested length.
+/// `Raw` and `VonNeumann` reads may return fewer bytes, and `Ok(0)` (EOF)
+/// once the sources yield nothing: `VonNeumann` keeps only unequal bit
+/// pairs, so a biased or stuck source can debias to little or no output.
+pub struct EntropyReader<'a> {
+    pool: &'a EntropyPool,
+    mode: ConditioningMode,
+}
+
+impl<'a> EntropyReader<'a> {
+    pub(crate) fn new(pool: &'a EntropyPool, mode: ConditioningMode) -> Self {
+        Self { pool, mode }
+    }
+
+    /// Conditioning mode applied to every read.
+    pub fn mode(&self) -> ConditioningMode {
+        self.mode
+    }
+}
+
+impl Read for EntropyReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let want = buf.len().min(READ_CHUNK);
+        if want == 0 {
+            return Ok(0);
+        }
+        let data = self.pool.get_bytes(want, self.mode);
+        let n = data.len().min(want);
+        buf[..n].copy_from_slice(&data[..n]);
+        Ok(n)
+    }
+}
+
+/// Fallible RNG over the reader: `Raw` and `VonNeumann` output can run dry,
+/// so errors are returned instead of panicking. Not a `CryptoRng`; use
+/// [`CryptoEntropyReader`] for that.
+#[cfg(feature = "rand")]
+impl rand_core::TryRngCore for EntropyReader<'_> {
+    type Error = io::Error;
+
+    fn try_next_u32(&mut self) -> io::Result<u32> {
+        let mut buf = [0u8; 4];
+        self.read_exact(&mut buf)?;
+        Ok(u32::from_le_bytes(buf))
+    }
+
+    fn try_next_u64(&mut self) -> io::Result<u64> {
+        let mut buf = [0u8; 8];
+        self.read_exact(&mut buf)?;
+        Ok(u64::from_le_bytes(buf))
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> io::Result<()> {
+        self.read_exact(dest)
+    }
+}
+
+/// SHA-256-conditioned byte stream from an [`EntropyPool`], from
+/// [`EntropyPool::crypto_reader`].
+///
+/// Unlike [`EntropyReader`], the mode is fixed, so every read fills the
+/// requested length and never reaches EOF. With the `rand` feature it is an
+/// infallible `RngCore` + `CryptoRng`.
+pub struct CryptoEntropyReader<'a> {
+    inner: EntropyReader<'a>,
+}
+
+impl<'a> CryptoEntropyReader<'a> {
+    pub(crate) fn new(pool: &'a EntropyPool) -> Self {
+        Self {
+            inner: EntropyReader::new(pool, ConditioningMode::Sha256),
+        }
+    }
+}
+
+impl Read for CryptoEntropyReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand_core::RngCore for CryptoEntropyReader<'_> {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    /// SHA-256 output is never short, so `dest` is always filled.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(READ_CHUNK) {
+            let data = self
+                .inner
+                .pool
+                .get_bytes(chunk.len(), ConditioningMode::Sha256);
+            chunk.copy_from_slice(&data);
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand_core::CryptoRng for CryptoEntropyReader<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_exact_fills_buffer() {
+        let pool = EntropyPool::new(Some(b"reader"));
+        let mut reader = pool.reader(ConditioningMode::Sha256);
+        let mut buf = [0u8; 1000];
+        reader.read_exact(&mut buf).unwrap();
+        assert!(buf.iter().any(|&b| b != 0));
+        assert_eq!(pool.health_report().output_bytes, 1000);
+    }
+
+    #[test]
+    fn large_reads_are_chunked() {
+        let pool = EntropyPool::new(Some(b"reader"));
+        let mut buf = vec![0u8; READ_CHUNK + 1];
+        let n = pool
+            .reader(ConditioningMode::Sha256)
+            .read(&mut buf)
+            .unwrap();
+        assert_eq!(n, READ_CHUNK);
+    }
+
+    #[test]
+    fn raw_reader_hits_eof_without_sources() {
+        let pool = EntropyPool::new(Some(b"reader"));
+        let mut buf = [0u8; 16];
+        let err = pool
+            .reader(ConditioningMode::Raw)
+            .read_exact(&mut buf)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn von_neumann_reads_run_short_on_a_biased_source() {
+        let mut pool = EntropyPool::new(Some(b"reader"));
+        let nearly_zero: Vec<u8> = (0..4096).map(|i| u8::from(i % 64 == 0)).collect();
+        pool.add_source(
+            Box::new(crate::ReplaySource::from_bytes("biased", nearly_zero)),
+            1.0,
+        );
+        let mut buf = [0u8; 64];
+        let n = pool
+            .reader(ConditioningMode::VonNeumann)
+            .read(&mut buf)
+            .unwrap();
+        assert!(n < buf.len(), "read {n} bytes");
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn raw_rng_reports_eof_instead_of_panicking() {
+        use rand_core::TryRngCore;
+
+        let pool = EntropyPool::new(Some(b"reader"));
+        let mut rng = pool.reader(ConditioningMode::Raw);
+        let err = rng.try_next_u64().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        assert!(rng.try_fill_bytes(&mut [0u8; 16]).is_err());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn reader_is_a_crypto_rng() {
+        use rand_core::{CryptoRng, RngCore};
+
+        fn fill<R: CryptoRng>(rng: &mut R) -> [u8; 64] {
+            let mut out = [0u8; 64];
+            rng.fill_bytes(&mut out);
+            out
+        }
+
+        let pool = EntropyPool::new(Some(b"reader"));
+        let mut rng = pool.crypto_reader();
+        let a = fill(&mut rng);
+        let b = fill(&mut rng);
+        assert_ne!(a, b);
+        assert_ne!(rng.next_u64(), rng.next_u64());
+    }
+}
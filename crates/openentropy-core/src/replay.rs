@@ -0,0 +1,648 @@
+//! `.oerec` — single-file binary export of a recorded session.
+//!
+//! A session directory (`session.json` + `raw.bin` + `raw_index.csv`) is
+//! convenient for the CLI but awkward to replay elsewhere. [`export_session`]
+//! flattens it into one self-describing file that [`ReplayReader`] streams
+//! back frame by frame.
+//!
+//! Layout (all integers little-endian):
+//!
+//! ```text
+//! magic        8 bytes   b"OEREC\0\0\0"
+//! version      u16       OEREC_VERSION
+//! header_len   u32
+//! header       header_len bytes of JSON (ReplayHeader)
+//! frames       repeated until EOF:
+//!   source     u32       index into header.sources
+//!   timestamp  u64       ns since the Unix epoch
+//!   len        u32
+//!   data       len bytes of raw (unconditioned) samples
+//! ```
+//!
+//! [`ReplaySource`] is the in-process counterpart: an [`EntropySource`] that
+//! plays back fixed bytes or a seeded PRNG stream, for deterministic pool and
+//! conditioning tests without hardware. Its `with_*` builders make it slow,
+//! intermittently failing or observable, so tests need no hand-written mocks.
+
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::session::{MachineInfo, SessionMeta};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
+
+/// File magic at offset 0.
+pub const OEREC_MAGIC: [u8; 8] = *b"OEREC\0\0\0";
+
+/// Format version written by [`OerecWriter`]; readers reject newer files.
+pub const OEREC_VERSION: u16 = 1;
+
+/// Upper bound on the JSON header and on a single frame, so a corrupt length
+/// field fails fast instead of allocating gigabytes.
+const MAX_CHUNK: u32 = 64 * 1024 * 1024;
+
+// ---------------------------------------------------------------------------
+// Header and frames
+// ---------------------------------------------------------------------------
+
+/// Session description stored at the start of an `.oerec` file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplayHeader {
+    pub session_id: String,
+    pub started_at: String,
+    pub conditioning: String,
+    pub machine: MachineInfo,
+    /// Frame source indices point into this list.
+    pub sources: Vec<String>,
+    pub openentropy_version: String,
+}
+
+impl ReplayHeader {
+    fn from_meta(meta: &SessionMeta, sources: Vec<String>) -> Self {
+        Self {
+            session_id: meta.id.clone(),
+            started_at: meta.started_at.clone(),
+            conditioning: meta.conditioning.clone(),
+            machine: meta.machine.clone(),
+            sources,
+            openentropy_version: meta.openentropy_version.clone(),
+        }
+    }
+}
+
+/// One recorded sample.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayFrame {
+    pub source: String,
+    /// Collection time, nanoseconds since the Unix epoch.
+    pub timestamp_ns: u64,
+    pub data: Vec<u8>,
+}
+
+fn invalid(msg: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.into())
+}
+
+fn len_u32(len: usize, what: &str) -> io::Result<u32> {
+    u32::try_from(len)
+        .ok()
+        .filter(|&n| n <= MAX_CHUNK)
+        .ok_or_else(|| invalid(format!("{what} too large ({len} bytes)")))
+}
+
+// ---------------------------------------------------------------------------
+// Writer
+// ---------------------------------------------------------------------------
+
+/// Streams an `.oerec` file: the header on construction, then one frame per
+/// [`write_frame`](Self::write_frame) call.
+pub struct OerecWriter<W: Write> {
+    out: W,
+    sources: usize,
+    frames: u64,
+}
+
+impl<W: Write> OerecWriter<W> {
+    /// Write the magic, version and header.
+    pub fn new(mut out: W, header: &ReplayHeader) -> io::Result<Self> {
+        let json = serde_json::to_vec(header).map_err(io::Error::other)?;
+        out.write_all(&OEREC_MAGIC)?;
+        out.write_all(&OEREC_VERSION.to_le_bytes())?;
+        out.write_all(&len_u32(json.len(), "header")?.to_le_bytes())?;
+        out.write_all(&json)?;
+        Ok(Self {
+            out,
+            sources: header.sources.len(),
+            frames: 0,
+        })
+    }
+
+    /// Append a frame for `header.sources[source]`.
+    pub fn write_frame(&mut self, source: u32, timestamp_ns: u64, data: &[u8]) -> io::Result<()> {
+        if source as usize >= self.sources {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("source index {source} out of range"),
+            ));
+        }
+        self.out.write_all(&source.to_le_bytes())?;
+        self.out.write_all(&timestamp_ns.to_le_bytes())?;
+        self.out
+            .write_all(&len_u32(data.len(), "frame")?.to_le_bytes())?;
+        self.out.write_all(data)?;
+        self.frames += 1;
+        Ok(())
+    }
+
+    /// Frames written so far.
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    /// Flush and return the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+/// Convert a recorded session directory into an `.oerec` file.
+///
+/// Frames come from `raw.bin` in `raw_index.csv` order. Returns the number of
+/// frames written.
+///
+/// # Errors
+///
+/// Returns an error if a session file is missing or malformed, or if writing
+/// `out_path` fails.
+pub fn export_session(session_dir: &Path, out_path: &Path) -> io::Result<u64> {
+    let meta: SessionMeta =
+        serde_json::from_str(&fs::read_to_string(session_dir.join("session.json"))?)
+            .map_err(|e| invalid(format!("session.json: {e}")))?;
+    let raw = fs::read(session_dir.join("raw.bin"))?;
+    let index = fs::read_to_string(session_dir.join("raw_index.csv"))?;
+
+    // Format: offset,length,timestamp_ns,source
+    let mut rows = Vec::new();
+    for (n, line) in index.lines().enumerate().skip(1) {
+        let parts: Vec<&str> = line.splitn(4, ',').collect();
+        let parsed = match parts.as_slice() {
+            [offset, length, ts, source] => offset
+                .parse::<usize>()
+                .ok()
+                .zip(length.parse::<usize>().ok())
+                .zip(ts.parse::<u64>().ok())
+                .map(|((offset, length), ts)| (offset, length, ts, *source)),
+            _ => None,
+        };
+        let Some((offset, length, ts, source)) = parsed else {
+            return Err(invalid(format!("raw_index.csv line {}: malformed", n + 1)));
+        };
+        if offset.checked_add(length).is_none_or(|end| end > raw.len()) {
+            return Err(invalid(format!(
+                "raw_index.csv line {}: range past end of raw.bin",
+                n + 1
+            )));
+        }
+        rows.push((offset, length, ts, source));
+    }
+
+    // Recorded source order first, then anything only the index mentions.
+    let mut sources = meta.sources.clone();
+    for &(_, _, _, source) in &rows {
+        if !sources.iter().any(|s| s == source) {
+            sources.push(source.to_string());
+        }
+    }
+    let positions: HashMap<&str, u32> = sources
+        .iter()
+        .enumerate()
+        .map(|(i, s)| (s.as_str(), i as u32))
+        .collect();
+
+    let header = ReplayHeader::from_meta(&meta, sources.clone());
+    let mut writer = OerecWriter::new(BufWriter::new(File::create(out_path)?), &header)?;
+    for (offset, length, ts, source) in rows {
+        writer.write_frame(positions[source], ts, &raw[offset..offset + length])?;
+    }
+    let frames = writer.frames();
+    writer.finish()?;
+    Ok(frames)
+}
+
+// ---------------------------------------------------------------------------
+// Reader
+// ---------------------------------------------------------------------------
+
+/// Reads an `.oerec` file back as a stream of [`ReplayFrame`]s.
+///
+/// Iteration stops at a clean end of file; a frame cut short yields an
+/// `UnexpectedEof` error.
+pub struct ReplayReader<R: Read> {
+    input: R,
+    header: ReplayHeader,
+    version: u16,
+}
+
+impl ReplayReader<BufReader<File>> {
+    /// Open an `.oerec` file from disk.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read> ReplayReader<R> {
+    /// Read and validate the magic, version and header.
+    pub fn new(mut input: R) -> io::Result<Self> {
+        let mut magic = [0u8; 8];
+        input.read_exact(&mut magic)?;
+        if magic != OEREC_MAGIC {
+            return Err(invalid("not an .oerec file (bad magic)"));
+        }
+        let mut version = [0u8; 2];
+        input.read_exact(&mut version)?;
+        let version = u16::from_le_bytes(version);
+        if version == 0 || version > OEREC_VERSION {
+            return Err(invalid(format!("unsupported .oerec version {version}")));
+        }
+        let len = read_len(&mut input, "header")?;
+        let mut json = vec![0u8; len];
+        input.read_exact(&mut json)?;
+        let header = serde_json::from_slice(&json).map_err(|e| invalid(format!("header: {e}")))?;
+        Ok(Self {
+            input,
+            header,
+            version,
+        })
+    }
+
+    /// Session description from the file header.
+    pub fn header(&self) -> &ReplayHeader {
+        &self.header
+    }
+
+    /// Format version of the file being read.
+    pub fn version(&self) -> u16 {
+        self.version
+    }
+
+    /// Read the next frame; `Ok(None)` at end of file.
+    pub fn next_frame(&mut self) -> io::Result<Option<ReplayFrame>> {
+        let mut source = [0u8; 4];
+        match self.input.read(&mut source[..1])? {
+            0 => return Ok(None),
+            _ => self.input.read_exact(&mut source[1..])?,
+        }
+        let source = u32::from_le_bytes(source) as usize;
+        let mut ts = [0u8; 8];
+        self.input.read_exact(&mut ts)?;
+        let len = read_len(&mut self.input, "frame")?;
+        let mut data = vec![0u8; len];
+        self.input.read_exact(&mut data)?;
+
+        let source = self
+            .header
+            .sources
+            .get(source)
+            .ok_or_else(|| invalid(format!("frame source index {source} out of range")))?
+            .clone();
+        Ok(Some(ReplayFrame {
+            source,
+            timestamp_ns: u64::from_le_bytes(ts),
+            data,
+        }))
+    }
+}
+
+impl<R: Read> Iterator for ReplayReader<R> {
+    type Item = io::Result<ReplayFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_frame().transpose()
+    }
+}
+
+fn read_len(input: &mut impl Read, what: &str) -> io::Result<usize> {
+    let mut len = [0u8; 4];
+    input.read_exact(&mut len)?;
+    let len = u32::from_le_bytes(len);
+    if len > MAX_CHUNK {
+        return Err(invalid(format!("{what} length {len} exceeds limit")));
+    }
+    Ok(len as usize)
+}
+
+// ---------------------------------------------------------------------------
+// Replay source
+// ---------------------------------------------------------------------------
+
+/// An [`EntropySource`] that replays known bytes instead of measuring
+/// hardware. It is never detected on its own; add it with
+/// [`EntropyPool::add_source`](crate::EntropyPool::add_source).
+///
+/// Not an entropy source: the output is fully predictable by design.
+pub struct ReplaySource {
+    info: SourceInfo,
+    state: Mutex<ReplayState>,
+    delay: Duration,
+    failing: Option<Arc<AtomicBool>>,
+    on_collect: Option<Box<dyn Fn() + Send + Sync>>,
+}
+
+enum ReplayState {
+    /// Fixed bytes and the offset of the next one to return.
+    Bytes { data: Vec<u8>, pos: usize },
+    /// SplitMix64 state and the unread tail of its last output word, so the
+    /// stream does not depend on how callers chunk it.
+    Seeded { state: u64, spare: Vec<u8> },
+}
+
+impl ReplaySource {
+    /// Replay `data` in order, cycling back to the start when exhausted.
+    /// Empty `data` collects nothing, like a failed source.
+    pub fn from_bytes(name: &'static str, data: Vec<u8>) -> Self {
+        Self::new(
+            name,
+            "Replays fixed bytes (testing only)",
+            ReplayState::Bytes { data, pos: 0 },
+        )
+    }
+
+    /// Replay an endless SplitMix64 stream from `seed`. The same seed gives
+    /// the same bytes on every platform and release.
+    pub fn from_seed(name: &'static str, seed: u64) -> Self {
+        Self::new(
+            name,
+            "Replays a seeded PRNG stream (testing only)",
+            ReplayState::Seeded {
+                state: seed,
+                spare: Vec::new(),
+            },
+        )
+    }
+
+    fn new(name: &'static str, description: &'static str, state: ReplayState) -> Self {
+        Self {
+            info: SourceInfo {
+                name,
+                description,
+                physics: "Not an entropy source: replays predetermined bytes.",
+                failure_hint: "Output is fully predictable; use only in tests.",
+                category: SourceCategory::System,
+                platform: Platform::Any,
+                requirements: &[],
+                entropy_rate_estimate: 0.0,
+                typical_latency_ms: 0.0,
+                cost_class: CostClass::Fast,
+                composite: false,
+            },
+            state: Mutex::new(state),
+            delay: Duration::ZERO,
+            failing: None,
+            on_collect: None,
+        }
+    }
+
+    /// Sleep for `delay` at the start of every collection, like a slow or
+    /// hung source. Also sets `typical_latency_ms` to match.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self.info.typical_latency_ms = delay.as_secs_f64() * 1000.0;
+        self
+    }
+
+    /// Collect nothing while `failing` is set, like a source that drops out
+    /// and recovers. The replayed stream does not advance meanwhile.
+    pub fn failing_while(mut self, failing: Arc<AtomicBool>) -> Self {
+        self.failing = Some(failing);
+        self
+    }
+
+    /// Call `hook` at the start of every collection, before any delay, e.g.
+    /// to count calls or track how many collections overlap.
+    pub fn on_collect(mut self, hook: impl Fn() + Send + Sync + 'static) -> Self {
+        self.on_collect = Some(Box::new(hook));
+        self
+    }
+}
+
+impl EntropySource for ReplaySource {
+    fn info(&self) -> &SourceInfo {
+        &self.info
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn collect(&self, n_samples: usize) -> Vec<u8> {
+        if let Some(hook) = &self.on_collect {
+            hook();
+        }
+        if !self.delay.is_zero() {
+            std::thread::sleep(self.delay);
+        }
+        if self
+            .failing
+            .as_ref()
+            .is_some_and(|failing| failing.load(Ordering::SeqCst))
+        {
+            return Vec::new();
+        }
+        let mut state = self.state.lock().unwrap();
+        match &mut *state {
+            ReplayState::Bytes { data, .. } if data.is_empty() => Vec::new(),
+            ReplayState::Bytes { data, pos } => {
+                let out: Vec<u8> = data[*pos..]
+                    .iter()
+                    .chain(data.iter().cycle())
+                    .copied()
+                    .take(n_samples)
+                    .collect();
+                *pos = (*pos + n_samples) % data.len();
+                out
+            }
+            ReplayState::Seeded { state, spare } => {
+                let mut out = std::mem::take(spare);
+                while out.len() < n_samples {
+                    out.extend_from_slice(&splitmix64(state).to_le_bytes());
+                }
+                *spare = out.split_off(n_samples);
+                out
+            }
+        }
+    }
+}
+
+/// One SplitMix64 step (Steele, Lea & Flood 2014).
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+// ---------------------------------------------------------------------------
+// Tests
+// ---------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::{SessionConfig, SessionWriter, detect_machine_info};
+
+    fn header(sources: &[&str]) -> ReplayHeader {
+        ReplayHeader {
+            session_id: "test".to_string(),
+            started_at: "1970-01-01T00:00:00Z".to_string(),
+            conditioning: "raw".to_string(),
+            machine: detect_machine_info(),
+            sources: sources.iter().map(|s| s.to_string()).collect(),
+            openentropy_version: crate::VERSION.to_string(),
+        }
+    }
+
+    #[test]
+    fn frames_round_trip() {
+        let mut writer = OerecWriter::new(Vec::new(), &header(&["a", "b"])).unwrap();
+        writer.write_frame(0, 10, &[1, 2, 3]).unwrap();
+        writer.write_frame(1, 20, &[]).unwrap();
+        writer.write_frame(0, u64::MAX, &[0xff; 300]).unwrap();
+        let bytes = writer.finish().unwrap();
+
+        let reader = ReplayReader::new(bytes.as_slice()).unwrap();
+        assert_eq!(reader.version(), OEREC_VERSION);
+        assert_eq!(reader.header().sources, ["a", "b"]);
+        let frames: Vec<ReplayFrame> = reader.map(Result::unwrap).collect();
+        assert_eq!(frames.len(), 3);
+        assert_eq!(
+            (frames[0].source.as_str(), frames[0].timestamp_ns),
+            ("a", 10)
+        );
+        assert_eq!(frames[0].data, [1, 2, 3]);
+        assert_eq!(
+            (frames[1].source.as_str(), frames[1].timestamp_ns),
+            ("b", 20)
+        );
+        assert!(frames[1].data.is_empty());
+        assert_eq!(frames[2].timestamp_ns, u64::MAX);
+        assert_eq!(frames[2].data, [0xff; 300]);
+    }
+
+    #[test]
+    fn writer_rejects_unknown_source_index() {
+        let mut writer = OerecWriter::new(Vec::new(), &header(&["a"])).unwrap();
+        let err = writer.write_frame(1, 0, &[1]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn reader_rejects_bad_magic_and_truncation() {
+        let err = ReplayReader::new(&b"NOTOEREC\x01\x00"[..]).err().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+        let mut writer = OerecWriter::new(Vec::new(), &header(&["a"])).unwrap();
+        writer.write_frame(0, 1, &[9; 16]).unwrap();
+        let mut bytes = writer.finish().unwrap();
+        bytes.truncate(bytes.len() - 4);
+        let mut reader = ReplayReader::new(bytes.as_slice()).unwrap();
+        let err = reader.next().unwrap().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn export_session_round_trips_recorded_samples() {
+        let tmp = tempfile::tempdir().unwrap();
+        let config = SessionConfig {
+            sources: vec!["alpha".to_string(), "beta".to_string()],
+            output_dir: tmp.path().to_path_buf(),
+            ..Default::default()
+        };
+        let mut writer = SessionWriter::new(config).unwrap();
+        writer
+            .write_sample("alpha", &[1, 2, 3, 4], &[0; 4])
+            .unwrap();
+        writer.write_sample("beta", &[5, 6], &[0; 2]).unwrap();
+        writer.write_sample("alpha", &[7], &[0]).unwrap();
+        let dir = writer.finish().unwrap();
+
+        let out = tmp.path().join("session.oerec");
+        assert_eq!(export_session(&dir, &out).unwrap(), 3);
+
+        let index = fs::read_to_string(dir.join("raw_index.csv")).unwrap();
+        let stamps: Vec<u64> = index
+            .lines()
+            .skip(1)
+            .map(|l| l.split(',').nth(2).unwrap().parse().unwrap())
+            .collect();
+
+        let reader = ReplayReader::open(&out).unwrap();
+        assert_eq!(reader.header().sources, ["alpha", "beta"]);
+        let frames: Vec<ReplayFrame> = reader.map(Result::unwrap).collect();
+        let got: Vec<(&str, &[u8])> = frames
+            .iter()
+            .map(|f| (f.source.as_str(), f.data.as_slice()))
+            .collect();
+        assert_eq!(
+            got,
+            [
+                ("alpha", &[1, 2, 3, 4][..]),
+                ("beta", &[5, 6][..]),
+                ("alpha", &[7][..])
+            ]
+        );
+        let got_stamps: Vec<u64> = frames.iter().map(|f| f.timestamp_ns).collect();
+        assert_eq!(got_stamps, stamps);
+    }
+
+    #[test]
+    fn replay_bytes_cycle_through_pool_raw_path() {
+        let mut pool = crate::EntropyPool::new(None);
+        pool.add_source(
+            Box::new(ReplaySource::from_bytes("replay", vec![1, 2, 3, 4, 5])),
+            1.0,
+        );
+        assert_eq!(
+            pool.get_source_raw_bytes("replay", 7).unwrap(),
+            [1, 2, 3, 4, 5, 1, 2]
+        );
+        // The next collection picks up where the last one stopped.
+        assert_eq!(
+            pool.get_source_raw_bytes("replay", 12).unwrap(),
+            [3, 4, 5, 1, 2, 3, 4, 5, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn replay_seed_is_deterministic_and_chunking_invariant() {
+        let whole = ReplaySource::from_seed("seeded", 42).collect(100);
+        let chunked = ReplaySource::from_seed("seeded", 42);
+        let pieces: Vec<u8> = [3, 10, 1, 86]
+            .into_iter()
+            .flat_map(|n| chunked.collect(n))
+            .collect();
+        assert_eq!(pieces, whole);
+        assert_ne!(ReplaySource::from_seed("seeded", 43).collect(100), whole);
+        // First SplitMix64 output for seed 0.
+        assert_eq!(
+            ReplaySource::from_seed("seeded", 0).collect(8),
+            0xe220_a839_7b1d_cdaf_u64.to_le_bytes()
+        );
+        assert!(
+            ReplaySource::from_bytes("empty", Vec::new())
+                .collect(8)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn replay_builders_delay_fail_and_observe() {
+        let delay = Duration::from_millis(20);
+        let failing = Arc::new(AtomicBool::new(false));
+        let calls = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let source = ReplaySource::from_bytes("mock", vec![1, 2, 3])
+            .with_delay(delay)
+            .failing_while(Arc::clone(&failing))
+            .on_collect(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            });
+        assert_eq!(source.info().typical_latency_ms, 20.0);
+
+        let (data, elapsed) = source.collect_timed(2);
+        assert_eq!(data, [1, 2]);
+        assert!(elapsed >= delay, "{elapsed:?}");
+        failing.store(true, Ordering::SeqCst);
+        assert!(source.collect(2).is_empty());
+        failing.store(false, Ordering::SeqCst);
+        assert_eq!(source.collect(2), [3, 1]);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+}
@@ -13,14 +13,18 @@
 //! - `raw_index.csv` — byte offset index into raw.bin
 //! - `conditioned.bin` — concatenated conditioned bytes
 //! - `conditioned_index.csv` — byte offset index into conditioned.bin
+//!
+//! `session.json` records the byte count and SHA-256 of each `.bin` file so
+//! [`verify_session`] can detect truncated or corrupted recordings.
 
 use std::collections::{HashMap, VecDeque};
 use std::fs::{self, File};
-use std::io::{BufWriter, Write};
+use std::io::{BufWriter, Read, Write};
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
 use crate::analysis;
@@ -247,6 +251,45 @@ pub struct SessionMeta {
     pub analysis: Option<HashMap<String, SessionSourceAnalysis>>,
     #[serde(default, skip_serializing_if = "Option::is_none", alias = "telemetry")]
     pub telemetry_v1: Option<TelemetryWindowReport>,
+    /// Byte count and SHA-256 per data file, keyed by file name (e.g. `raw.bin`).
+    /// Empty for sessions recorded before integrity data was added.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub files: HashMap<String, FileDigest>,
+}
+
+/// Size and content hash of one session data file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileDigest {
+    pub bytes: u64,
+    /// Lowercase hex SHA-256 of the file contents.
+    pub sha256: String,
+}
+
+/// Running digest of bytes appended to a session data file.
+struct DigestTracker {
+    hasher: Sha256,
+    bytes: u64,
+}
+
+impl DigestTracker {
+    fn new() -> Self {
+        Self {
+            hasher: Sha256::new(),
+            bytes: 0,
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+        self.bytes += data.len() as u64;
+    }
+
+    fn digest(&self) -> FileDigest {
+        FileDigest {
+            bytes: self.bytes,
+            sha256: hex_encode(&self.hasher.clone().finalize()),
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -293,6 +336,139 @@ impl Default for SessionConfig {
 /// (data written to disk) against performance (fewer syscalls).
 const FLUSH_INTERVAL: u64 = 64;
 
+/// Destination for a recording session's samples and final metadata.
+///
+/// [`SessionWriter`] is the filesystem implementation; [`JsonLinesSink`]
+/// streams to any writer (e.g. stdout) for containerized pipelines.
+pub trait RecordSink {
+    /// Record one sample from `source`. Empty `raw_bytes` are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying storage rejects the write.
+    fn write_chunk(
+        &mut self,
+        source: &str,
+        raw_bytes: &[u8],
+        conditioned_bytes: &[u8],
+    ) -> std::io::Result<()>;
+
+    /// Total samples accepted so far.
+    fn total_samples(&self) -> u64;
+
+    /// Flush outstanding data and emit the session metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if flushing or writing the metadata fails.
+    fn finalize(&mut self) -> std::io::Result<SessionMeta>;
+}
+
+/// Session bookkeeping shared by every [`RecordSink`] implementation.
+struct SessionLedger {
+    session_id: String,
+    started_at: SystemTime,
+    started_instant: Instant,
+    config: SessionConfig,
+    machine: MachineInfo,
+    total_samples: u64,
+    samples_per_source: HashMap<String, u64>,
+    /// Retains last 128 KiB per source for optional end-of-session analysis.
+    analysis_buffer: Option<AnalysisBuffer>,
+    /// Optional telemetry snapshot captured at session start.
+    telemetry_start: Option<TelemetrySnapshot>,
+}
+
+impl SessionLedger {
+    fn new(config: SessionConfig) -> Self {
+        let samples_per_source: HashMap<String, u64> =
+            config.sources.iter().map(|s| (s.clone(), 0)).collect();
+        let analysis_buffer = if config.include_analysis {
+            Some(AnalysisBuffer::new(&config.sources, 128 * 1024))
+        } else {
+            None
+        };
+        let telemetry_start = config.include_telemetry.then(collect_telemetry_snapshot);
+
+        Self {
+            session_id: Uuid::new_v4().to_string(),
+            started_at: SystemTime::now(),
+            started_instant: Instant::now(),
+            machine: detect_machine_info(),
+            config,
+            total_samples: 0,
+            samples_per_source,
+            analysis_buffer,
+            telemetry_start,
+        }
+    }
+
+    fn record(&mut self, source: &str, raw_bytes: &[u8]) {
+        self.total_samples += 1;
+        if let Some(buffer) = &mut self.analysis_buffer {
+            buffer.push(source, raw_bytes);
+        }
+        *self
+            .samples_per_source
+            .entry(source.to_string())
+            .or_insert(0) += 1;
+    }
+
+    /// Build the session metadata from current state.
+    #[allow(clippy::cast_possible_truncation)] // durations won't overflow u64 in practice
+    fn build_meta(&self, files: HashMap<String, FileDigest>) -> SessionMeta {
+        let ended_at = SystemTime::now();
+        let duration = self.started_instant.elapsed();
+
+        let analysis = self.analysis_buffer.as_ref().and_then(|buffer| {
+            let analysis_map = buffer.analyze();
+            if analysis_map.is_empty() {
+                None
+            } else {
+                Some(analysis_map)
+            }
+        });
+        let telemetry = self
+            .telemetry_start
+            .as_ref()
+            .cloned()
+            .map(collect_telemetry_window);
+
+        SessionMeta {
+            version: 2,
+            id: self.session_id.clone(),
+            started_at: format_iso8601(
+                self.started_at
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default(),
+            ),
+            ended_at: format_iso8601(ended_at.duration_since(UNIX_EPOCH).unwrap_or_default()),
+            duration_ms: duration.as_millis() as u64,
+            sources: self.config.sources.clone(),
+            conditioning: self.config.conditioning.to_string(),
+            interval_ms: self.config.interval.map(|d| d.as_millis() as u64),
+            total_samples: self.total_samples,
+            samples_per_source: self.samples_per_source.clone(),
+            machine: self.machine.clone(),
+            tags: self.config.tags.clone(),
+            note: self.config.note.clone(),
+            openentropy_version: crate::VERSION.to_string(),
+            analysis,
+            telemetry_v1: telemetry,
+            files,
+        }
+    }
+}
+
+/// Current wall-clock time in nanoseconds since the Unix epoch.
+#[allow(clippy::cast_possible_truncation)] // ns won't overflow u64 until ~2554
+fn now_ns() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_nanos() as u64
+}
+
 /// Handles incremental file I/O for a recording session.
 ///
 /// Implements `Drop` to flush buffers and write a best-effort session.json
@@ -306,19 +482,11 @@ pub struct SessionWriter {
     conditioned_index_writer: BufWriter<File>,
     raw_offset: u64,
     conditioned_offset: u64,
-    total_samples: u64,
-    samples_per_source: HashMap<String, u64>,
-    started_at: SystemTime,
-    started_instant: Instant,
-    session_id: String,
-    config: SessionConfig,
-    machine: MachineInfo,
-    /// Retains last 128 KiB per source for optional end-of-session analysis.
-    analysis_buffer: Option<AnalysisBuffer>,
-    /// Optional telemetry snapshot captured at session start.
-    telemetry_start: Option<TelemetrySnapshot>,
+    ledger: SessionLedger,
     /// Set to true after `finish()` succeeds so `Drop` doesn't double-write.
     finished: bool,
+    raw_digest: DigestTracker,
+    conditioned_digest: DigestTracker,
 }
 
 impl SessionWriter {
@@ -328,17 +496,18 @@ impl SessionWriter {
     ///
     /// Returns an error if the session directory or any output files cannot be created.
     pub fn new(config: SessionConfig) -> std::io::Result<Self> {
-        let machine = detect_machine_info();
-        let session_id = Uuid::new_v4().to_string();
-        let started_at = SystemTime::now();
+        let ledger = SessionLedger::new(config);
 
         // Build directory name: bounded and filesystem-safe to avoid ENAMETOOLONG
         // when many sources are recorded.
-        let ts = started_at.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let ts = ledger
+            .started_at
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
         let dt = format_iso8601_compact(ts);
-        let dir_name = build_session_dir_name(&dt, &config.sources, &session_id);
+        let dir_name = build_session_dir_name(&dt, &ledger.config.sources, &ledger.session_id);
 
-        let session_dir = config.output_dir.join(&dir_name);
+        let session_dir = ledger.config.output_dir.join(&dir_name);
         fs::create_dir_all(&session_dir)?;
 
         // Create samples.csv with header
@@ -373,15 +542,6 @@ impl SessionWriter {
         )?;
         conditioned_index_writer.flush()?;
 
-        let samples_per_source: HashMap<String, u64> =
-            config.sources.iter().map(|s| (s.clone(), 0)).collect();
-        let analysis_buffer = if config.include_analysis {
-            Some(AnalysisBuffer::new(&config.sources, 128 * 1024))
-        } else {
-            None
-        };
-        let telemetry_start = config.include_telemetry.then(collect_telemetry_snapshot);
-
         Ok(Self {
             session_dir,
             csv_writer,
@@ -391,16 +551,10 @@ impl SessionWriter {
             conditioned_index_writer,
             raw_offset: 0,
             conditioned_offset: 0,
-            total_samples: 0,
-            samples_per_source,
-            started_at,
-            started_instant: Instant::now(),
-            session_id,
-            config,
-            machine,
-            analysis_buffer,
-            telemetry_start,
+            ledger,
             finished: false,
+            raw_digest: DigestTracker::new(),
+            conditioned_digest: DigestTracker::new(),
         })
     }
 
@@ -423,11 +577,7 @@ impl SessionWriter {
             return Ok(());
         }
 
-        #[allow(clippy::cast_possible_truncation)] // ns won't overflow u64 until ~2554
-        let timestamp_ns = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_nanos() as u64;
+        let timestamp_ns = now_ns();
 
         let raw_shannon = quick_shannon(raw_bytes);
         // Clamp to 0.0 to avoid displaying "-0.00" in CSV
@@ -446,6 +596,8 @@ impl SessionWriter {
         // Write raw bytes
         self.raw_writer.write_all(raw_bytes)?;
         self.conditioned_writer.write_all(conditioned_bytes)?;
+        self.raw_digest.update(raw_bytes);
+        self.conditioned_digest.update(conditioned_bytes);
 
         // Write index row
         writeln!(
@@ -463,17 +615,10 @@ impl SessionWriter {
 
         self.raw_offset += raw_bytes.len() as u64;
         self.conditioned_offset += conditioned_bytes.len() as u64;
-        self.total_samples += 1;
-        if let Some(buffer) = &mut self.analysis_buffer {
-            buffer.push(source, raw_bytes);
-        }
-        *self
-            .samples_per_source
-            .entry(source.to_string())
-            .or_insert(0) += 1;
+        self.ledger.record(source, raw_bytes);
 
         // Periodic flush for crash-safety without per-sample syscall overhead
-        if self.total_samples.is_multiple_of(FLUSH_INTERVAL) {
+        if self.ledger.total_samples.is_multiple_of(FLUSH_INTERVAL) {
             self.flush_all()?;
         }
 
@@ -491,47 +636,14 @@ impl SessionWriter {
     }
 
     /// Build the session metadata from current state.
-    #[allow(clippy::cast_possible_truncation)] // durations won't overflow u64 in practice
     fn build_meta(&self) -> SessionMeta {
-        let ended_at = SystemTime::now();
-        let duration = self.started_instant.elapsed();
-
-        let analysis = self.analysis_buffer.as_ref().and_then(|buffer| {
-            let analysis_map = buffer.analyze();
-            if analysis_map.is_empty() {
-                None
-            } else {
-                Some(analysis_map)
-            }
-        });
-        let telemetry = self
-            .telemetry_start
-            .as_ref()
-            .cloned()
-            .map(collect_telemetry_window);
-
-        SessionMeta {
-            version: 2,
-            id: self.session_id.clone(),
-            started_at: format_iso8601(
-                self.started_at
-                    .duration_since(UNIX_EPOCH)
-                    .unwrap_or_default(),
+        self.ledger.build_meta(HashMap::from([
+            ("raw.bin".to_string(), self.raw_digest.digest()),
+            (
+                "conditioned.bin".to_string(),
+                self.conditioned_digest.digest(),
             ),
-            ended_at: format_iso8601(ended_at.duration_since(UNIX_EPOCH).unwrap_or_default()),
-            duration_ms: duration.as_millis() as u64,
-            sources: self.config.sources.clone(),
-            conditioning: self.config.conditioning.to_string(),
-            interval_ms: self.config.interval.map(|d| d.as_millis() as u64),
-            total_samples: self.total_samples,
-            samples_per_source: self.samples_per_source.clone(),
-            machine: self.machine.clone(),
-            tags: self.config.tags.clone(),
-            note: self.config.note.clone(),
-            openentropy_version: crate::VERSION.to_string(),
-            analysis,
-            telemetry_v1: telemetry,
-        }
+        ]))
     }
 
     /// Write session.json to disk.
@@ -546,10 +658,7 @@ impl SessionWriter {
     ///
     /// Returns an error if flushing buffers or writing session.json fails.
     pub fn finish(mut self) -> std::io::Result<PathBuf> {
-        self.flush_all()?;
-        let meta = self.build_meta();
-        self.write_session_json(&meta)?;
-        self.finished = true;
+        self.finalize()?;
         Ok(self.session_dir.clone())
     }
 
@@ -562,19 +671,43 @@ impl SessionWriter {
     /// Get total samples recorded so far.
     #[must_use]
     pub fn total_samples(&self) -> u64 {
-        self.total_samples
+        self.ledger.total_samples
     }
 
     /// Get elapsed time since recording started.
     #[must_use]
     pub fn elapsed(&self) -> Duration {
-        self.started_instant.elapsed()
+        self.ledger.started_instant.elapsed()
     }
 
     /// Get per-source sample counts.
     #[must_use]
     pub fn samples_per_source(&self) -> &HashMap<String, u64> {
-        &self.samples_per_source
+        &self.ledger.samples_per_source
+    }
+}
+
+impl RecordSink for SessionWriter {
+    fn write_chunk(
+        &mut self,
+        source: &str,
+        raw_bytes: &[u8],
+        conditioned_bytes: &[u8],
+    ) -> std::io::Result<()> {
+        self.write_sample(source, raw_bytes, conditioned_bytes)
+    }
+
+    fn total_samples(&self) -> u64 {
+        self.ledger.total_samples
+    }
+
+    /// Flush all files and write session.json.
+    fn finalize(&mut self) -> std::io::Result<SessionMeta> {
+        self.flush_all()?;
+        let meta = self.build_meta();
+        self.write_session_json(&meta)?;
+        self.finished = true;
+        Ok(meta)
     }
 }
 
@@ -591,6 +724,195 @@ impl Drop for SessionWriter {
     }
 }
 
+// ---------------------------------------------------------------------------
+// JSON Lines sink
+// ---------------------------------------------------------------------------
+
+/// One line of [`JsonLinesSink`] output.
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum JsonLine<'a> {
+    Sample {
+        timestamp_ns: u64,
+        source: &'a str,
+        raw_hex: String,
+        conditioned_hex: String,
+    },
+    Session {
+        meta: &'a SessionMeta,
+    },
+}
+
+/// [`RecordSink`] that streams samples as JSON Lines to any writer.
+///
+/// Each sample is a `{"type":"sample",...}` line with hex payloads; the
+/// final line is `{"type":"session","meta":{...}}` carrying the same
+/// [`SessionMeta`] the filesystem sink writes to session.json (without file
+/// digests). Suited to stdout in pipelines that ship data elsewhere.
+pub struct JsonLinesSink<W: Write> {
+    out: W,
+    ledger: SessionLedger,
+}
+
+impl JsonLinesSink<std::io::Stdout> {
+    /// Stream the session to stdout.
+    pub fn stdout(config: SessionConfig) -> Self {
+        Self::new(config, std::io::stdout())
+    }
+}
+
+impl<W: Write> JsonLinesSink<W> {
+    /// Stream the session to `out`.
+    pub fn new(config: SessionConfig, out: W) -> Self {
+        Self {
+            out,
+            ledger: SessionLedger::new(config),
+        }
+    }
+
+    fn write_line(&mut self, line: &JsonLine<'_>) -> std::io::Result<()> {
+        serde_json::to_writer(&mut self.out, line).map_err(std::io::Error::other)?;
+        self.out.write_all(b"\n")
+    }
+}
+
+impl<W: Write> RecordSink for JsonLinesSink<W> {
+    fn write_chunk(
+        &mut self,
+        source: &str,
+        raw_bytes: &[u8],
+        conditioned_bytes: &[u8],
+    ) -> std::io::Result<()> {
+        if raw_bytes.is_empty() {
+            return Ok(());
+        }
+        self.write_line(&JsonLine::Sample {
+            timestamp_ns: now_ns(),
+            source,
+            raw_hex: hex_encode(raw_bytes),
+            conditioned_hex: hex_encode(conditioned_bytes),
+        })?;
+        self.ledger.record(source, raw_bytes);
+        if self.ledger.total_samples.is_multiple_of(FLUSH_INTERVAL) {
+            self.out.flush()?;
+        }
+        Ok(())
+    }
+
+    fn total_samples(&self) -> u64 {
+        self.ledger.total_samples
+    }
+
+    fn finalize(&mut self) -> std::io::Result<SessionMeta> {
+        let meta = self.ledger.build_meta(HashMap::new());
+        self.write_line(&JsonLine::Session { meta: &meta })?;
+        self.out.flush()?;
+        Ok(meta)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Integrity verification
+// ---------------------------------------------------------------------------
+
+/// A discrepancy between a session's data files and its `session.json`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntegrityIssue {
+    /// `session.json` has no file digests (recorded by an older version).
+    NoDigests,
+    /// A file listed in `session.json` is missing or unreadable.
+    Missing { file: String },
+    /// The file's length differs from the recorded byte count.
+    SizeMismatch {
+        file: String,
+        expected: u64,
+        actual: u64,
+    },
+    /// The file's SHA-256 differs from the recorded digest.
+    DigestMismatch {
+        file: String,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl std::fmt::Display for IntegrityIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoDigests => write!(f, "session.json has no file digests to verify against"),
+            Self::Missing { file } => write!(f, "{file}: missing or unreadable"),
+            Self::SizeMismatch {
+                file,
+                expected,
+                actual,
+            } => write!(f, "{file}: expected {expected} bytes, found {actual}"),
+            Self::DigestMismatch {
+                file,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{file}: SHA-256 {actual} does not match recorded {expected}"
+            ),
+        }
+    }
+}
+
+/// Hash a file's contents, returning its digest.
+fn digest_file(path: &Path) -> std::io::Result<FileDigest> {
+    let mut file = File::open(path)?;
+    let mut tracker = DigestTracker::new();
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        tracker.update(&buf[..n]);
+    }
+    Ok(tracker.digest())
+}
+
+/// Recompute each data file's size and SHA-256 and compare them with the
+/// values recorded in `session.json`. Returns an empty list when the
+/// session is intact.
+///
+/// # Errors
+///
+/// Returns an error if `session.json` cannot be read or parsed.
+pub fn verify_session(session_dir: &Path) -> std::io::Result<Vec<IntegrityIssue>> {
+    let json = fs::read_to_string(session_dir.join("session.json"))?;
+    let meta: SessionMeta = serde_json::from_str(&json).map_err(std::io::Error::other)?;
+    if meta.files.is_empty() {
+        return Ok(vec![IntegrityIssue::NoDigests]);
+    }
+
+    let mut names: Vec<&String> = meta.files.keys().collect();
+    names.sort();
+    let mut issues = Vec::new();
+    for name in names {
+        let expected = &meta.files[name];
+        let Ok(actual) = digest_file(&session_dir.join(name)) else {
+            issues.push(IntegrityIssue::Missing { file: name.clone() });
+            continue;
+        };
+        if actual.bytes != expected.bytes {
+            issues.push(IntegrityIssue::SizeMismatch {
+                file: name.clone(),
+                expected: expected.bytes,
+                actual: actual.bytes,
+            });
+        } else if actual.sha256 != expected.sha256 {
+            issues.push(IntegrityIssue::DigestMismatch {
+                file: name.clone(),
+                expected: expected.sha256.clone(),
+                actual: actual.sha256,
+            });
+        }
+    }
+    Ok(issues)
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -867,6 +1189,46 @@ mod tests {
         assert_eq!(meta.conditioning, "raw");
     }
 
+    #[test]
+    fn test_verify_session_detects_tampering() {
+        let tmp = tempfile::tempdir().unwrap();
+        let config = SessionConfig {
+            sources: vec!["mock_source".to_string()],
+            output_dir: tmp.path().to_path_buf(),
+            ..Default::default()
+        };
+        let mut writer = SessionWriter::new(config).unwrap();
+        writer
+            .write_sample("mock_source", &[7; 100], &[8; 100])
+            .unwrap();
+        let dir = writer.finish().unwrap();
+
+        let meta: SessionMeta =
+            serde_json::from_str(&std::fs::read_to_string(dir.join("session.json")).unwrap())
+                .unwrap();
+        assert_eq!(meta.files["raw.bin"].bytes, 100);
+        assert!(verify_session(&dir).unwrap().is_empty());
+
+        // Same length, different content.
+        let mut raw = std::fs::read(dir.join("raw.bin")).unwrap();
+        raw[10] ^= 0xFF;
+        std::fs::write(dir.join("raw.bin"), &raw).unwrap();
+        let issues = verify_session(&dir).unwrap();
+        assert_eq!(issues.len(), 1);
+        assert!(
+            matches!(&issues[0], IntegrityIssue::DigestMismatch { file, .. } if file == "raw.bin")
+        );
+
+        // Truncation.
+        std::fs::write(dir.join("conditioned.bin"), [8u8; 40]).unwrap();
+        let issues = verify_session(&dir).unwrap();
+        assert!(issues.contains(&IntegrityIssue::SizeMismatch {
+            file: "conditioned.bin".to_string(),
+            expected: 100,
+            actual: 40,
+        }));
+    }
+
     #[test]
     fn test_session_writer_multiple_sources() {
         let tmp = tempfile::tempdir().unwrap();
@@ -892,6 +1254,100 @@ mod tests {
         assert_eq!(meta.total_samples, 3);
     }
 
+    /// In-memory sink that keeps every chunk and the final metadata.
+    struct MemorySink {
+        inner: JsonLinesSink<Vec<u8>>,
+        chunks: Vec<(String, Vec<u8>, Vec<u8>)>,
+        meta: Option<SessionMeta>,
+    }
+
+    impl RecordSink for MemorySink {
+        fn write_chunk(
+            &mut self,
+            source: &str,
+            raw: &[u8],
+            conditioned: &[u8],
+        ) -> std::io::Result<()> {
+            self.chunks
+                .push((source.to_string(), raw.to_vec(), conditioned.to_vec()));
+            self.inner.write_chunk(source, raw, conditioned)
+        }
+
+        fn total_samples(&self) -> u64 {
+            self.inner.total_samples()
+        }
+
+        fn finalize(&mut self) -> std::io::Result<SessionMeta> {
+            let meta = self.inner.finalize()?;
+            self.meta = Some(meta.clone());
+            Ok(meta)
+        }
+    }
+
+    #[test]
+    fn test_record_sink_captures_chunks_and_metadata() {
+        let config = SessionConfig {
+            sources: vec!["source_a".to_string(), "source_b".to_string()],
+            tags: HashMap::from([("rig".to_string(), "bench".to_string())]),
+            ..Default::default()
+        };
+        let mut sink = MemorySink {
+            inner: JsonLinesSink::new(config, Vec::new()),
+            chunks: Vec::new(),
+            meta: None,
+        };
+
+        {
+            let dyn_sink: &mut dyn RecordSink = &mut sink;
+            dyn_sink
+                .write_chunk("source_a", &[1, 2, 3], &[9, 9])
+                .unwrap();
+            dyn_sink.write_chunk("source_b", &[], &[]).unwrap();
+            dyn_sink.write_chunk("source_b", &[4, 5], &[8]).unwrap();
+            assert_eq!(dyn_sink.total_samples(), 2);
+            dyn_sink.finalize().unwrap();
+        }
+
+        assert_eq!(sink.chunks.len(), 3);
+        assert_eq!(
+            sink.chunks[0],
+            ("source_a".to_string(), vec![1, 2, 3], vec![9, 9])
+        );
+        let meta = sink.meta.as_ref().unwrap();
+        assert_eq!(meta.total_samples, 2);
+        assert_eq!(meta.samples_per_source["source_b"], 1);
+        assert_eq!(meta.tags["rig"], "bench");
+        assert!(meta.files.is_empty());
+
+        // JSON Lines output: one line per non-empty sample, then the session.
+        let text = String::from_utf8(sink.inner.out.clone()).unwrap();
+        let lines: Vec<serde_json::Value> = text
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0]["type"], "sample");
+        assert_eq!(lines[0]["raw_hex"], "010203");
+        assert_eq!(lines[1]["conditioned_hex"], "08");
+        assert_eq!(lines[2]["type"], "session");
+        assert_eq!(lines[2]["meta"]["id"], meta.id.as_str());
+    }
+
+    #[test]
+    fn test_session_writer_as_record_sink() {
+        let tmp = tempfile::tempdir().unwrap();
+        let config = SessionConfig {
+            sources: vec!["test".to_string()],
+            output_dir: tmp.path().to_path_buf(),
+            ..Default::default()
+        };
+        let mut sink: Box<dyn RecordSink> = Box::new(SessionWriter::new(config).unwrap());
+        sink.write_chunk("test", &[7; 10], &[3; 10]).unwrap();
+        let meta = sink.finalize().unwrap();
+        assert_eq!(meta.total_samples, 1);
+        assert_eq!(meta.files["raw.bin"].bytes, 10);
+    }
+
     #[test]
     fn test_session_writer_with_tags_and_note() {
         let tmp = tempfile::tempdir().unwrap();
@@ -946,6 +1402,7 @@ mod tests {
             openentropy_version: env!("CARGO_PKG_VERSION").to_string(),
             analysis: None,
             telemetry_v1: None,
+            files: HashMap::new(),
         };
 
         let json = serde_json::to_string_pretty(&meta).unwrap();
@@ -984,6 +1441,7 @@ mod tests {
             openentropy_version: env!("CARGO_PKG_VERSION").to_string(),
             analysis: None,
             telemetry_v1: None,
+            files: HashMap::new(),
         };
 
         let window = TelemetryWindowReport {
@@ -3,7 +3,7 @@
 //! Every entropy source implements the [`EntropySource`] trait, which provides
 //! metadata via [`SourceInfo`], availability checking, and raw sample collection.
 
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 /// Category of entropy source based on physical mechanism.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -64,6 +64,17 @@ pub enum Platform {
     Linux,
 }
 
+impl Platform {
+    /// Whether this build targets the platform.
+    pub fn is_current(self) -> bool {
+        match self {
+            Self::Any => true,
+            Self::MacOS => cfg!(target_os = "macos"),
+            Self::Linux => cfg!(target_os = "linux"),
+        }
+    }
+}
+
 impl std::fmt::Display for Platform {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -97,6 +108,8 @@ pub enum Requirement {
     IOSurface,
     /// Security framework (Keychain).
     SecurityFramework,
+    /// Outbound network access (DNS queries, TCP connections).
+    Network,
 }
 
 impl std::fmt::Display for Requirement {
@@ -112,6 +125,49 @@ impl std::fmt::Display for Requirement {
             Self::IOKit => write!(f, "iokit"),
             Self::IOSurface => write!(f, "iosurface"),
             Self::SecurityFramework => write!(f, "security_framework"),
+            Self::Network => write!(f, "network"),
+        }
+    }
+}
+
+/// Rough collection cost of an entropy source.
+///
+/// Derived from [`SourceInfo::typical_latency_ms`] via
+/// [`CostClass::for_latency_ms`]. Default CLI pools use only `Fast` sources.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CostClass {
+    /// Under 250 ms per collection; safe for real-time use.
+    Fast,
+    /// Under 2 s; typically spawns a subprocess or waits on I/O.
+    Medium,
+    /// 2 s or more; external hardware, network, or long command timeouts.
+    Slow,
+}
+
+impl CostClass {
+    /// Upper bound (exclusive) of a `Fast` collection, in milliseconds.
+    pub const FAST_MAX_MS: f64 = 250.0;
+    /// Upper bound (exclusive) of a `Medium` collection, in milliseconds.
+    pub const MEDIUM_MAX_MS: f64 = 2000.0;
+
+    /// Classify a typical collection latency.
+    pub fn for_latency_ms(latency_ms: f64) -> Self {
+        if latency_ms < Self::FAST_MAX_MS {
+            Self::Fast
+        } else if latency_ms < Self::MEDIUM_MAX_MS {
+            Self::Medium
+        } else {
+            Self::Slow
+        }
+    }
+}
+
+impl std::fmt::Display for CostClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fast => write!(f, "fast"),
+            Self::Medium => write!(f, "medium"),
+            Self::Slow => write!(f, "slow"),
         }
     }
 }
@@ -120,7 +176,7 @@ impl std::fmt::Display for Requirement {
 ///
 /// Each source declares its name, a human-readable description, a physics
 /// explanation of how it harvests entropy, its category, platform requirements,
-/// and an estimated entropy rate in bits per sample.
+/// an estimated entropy rate in bits per sample, and its collection cost.
 #[derive(Debug, Clone)]
 pub struct SourceInfo {
     /// Unique identifier (e.g. `"clock_jitter"`).
@@ -129,6 +185,8 @@ pub struct SourceInfo {
     pub description: &'static str,
     /// Physics explanation of the entropy mechanism.
     pub physics: &'static str,
+    /// What an unhealthy reading from this source usually looks like and why.
+    pub failure_hint: &'static str,
     /// Source category for classification.
     pub category: SourceCategory,
     /// Target platform.
@@ -137,6 +195,10 @@ pub struct SourceInfo {
     pub requirements: &'static [Requirement],
     /// Estimated entropy rate in bits per sample.
     pub entropy_rate_estimate: f64,
+    /// Typical wall-clock time of one `collect` call, in milliseconds.
+    pub typical_latency_ms: f64,
+    /// Cost tier matching `typical_latency_ms`.
+    pub cost_class: CostClass,
     /// Whether this is a composite source (combines multiple standalone sources).
     ///
     /// Composite sources don't measure a single independent entropy domain.
@@ -145,6 +207,48 @@ pub struct SourceInfo {
     pub composite: bool,
 }
 
+/// Whether a source can operate on this machine, with a reason when not.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Availability {
+    /// The source can collect here.
+    Available,
+    /// The source cannot collect here (e.g. `"requires macOS"`).
+    Unavailable(String),
+}
+
+impl Availability {
+    /// True for [`Availability::Available`].
+    pub fn is_available(&self) -> bool {
+        matches!(self, Self::Available)
+    }
+
+    /// The reason for [`Availability::Unavailable`], if any.
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            Self::Available => None,
+            Self::Unavailable(reason) => Some(reason),
+        }
+    }
+}
+
+/// Best-guess reason an unavailable source cannot run, from its declared
+/// platform and requirements.
+fn unavailable_reason(info: &SourceInfo) -> String {
+    match info.platform {
+        Platform::MacOS if !Platform::MacOS.is_current() => return "requires macOS".into(),
+        Platform::Linux if !Platform::Linux.is_current() => return "requires Linux".into(),
+        _ => {}
+    }
+    if info.requirements.contains(&Requirement::Network) {
+        return "no network".into();
+    }
+    if info.requirements.is_empty() {
+        return "probe failed on this machine".into();
+    }
+    let needs: Vec<String> = info.requirements.iter().map(|r| r.to_string()).collect();
+    format!("requires {}", needs.join(", "))
+}
+
 /// Trait that every entropy source must implement.
 pub trait EntropySource: Send + Sync {
     /// Source metadata.
@@ -153,9 +257,30 @@ pub trait EntropySource: Send + Sync {
     /// Check if this source can operate on the current machine.
     fn is_available(&self) -> bool;
 
+    /// [`is_available`](Self::is_available) with a human-readable reason
+    /// when the answer is no.
+    ///
+    /// The default derives the reason from [`SourceInfo::platform`] and
+    /// [`SourceInfo::requirements`]; override it when the probe knows more
+    /// (e.g. "needs root"). Overrides must agree with `is_available`.
+    fn availability(&self) -> Availability {
+        if self.is_available() {
+            Availability::Available
+        } else {
+            Availability::Unavailable(unavailable_reason(self.info()))
+        }
+    }
+
     /// Collect raw entropy samples. Returns a `Vec<u8>` of up to `n_samples` bytes.
     fn collect(&self, n_samples: usize) -> Vec<u8>;
 
+    /// [`collect`](Self::collect), also returning the wall time it took.
+    fn collect_timed(&self, n_samples: usize) -> (Vec<u8>, Duration) {
+        let start = Instant::now();
+        let data = self.collect(n_samples);
+        (data, start.elapsed())
+    }
+
     /// Convenience: name from info.
     fn name(&self) -> &'static str {
         self.info().name
@@ -172,6 +297,14 @@ pub struct SourceState {
     pub last_min_entropy: f64,
     pub last_collect_time: Duration,
     pub healthy: bool,
+    /// SHA-256 of the last non-empty collection.
+    pub last_digest: Option<[u8; 32]>,
+    /// Consecutive collections whose bytes matched `last_digest`
+    /// (1 = the last one was new).
+    pub identical_runs: usize,
+    /// The last [`STUCK_AFTER`](crate::pool::STUCK_AFTER) collections were
+    /// byte-identical (stuck-at fault).
+    pub stuck: bool,
 }
 
 impl SourceState {
@@ -185,6 +318,9 @@ impl SourceState {
             last_min_entropy: 0.0,
             last_collect_time: Duration::ZERO,
             healthy: true,
+            last_digest: None,
+            identical_runs: 0,
+            stuck: false,
         }
     }
 }
@@ -4,7 +4,7 @@
 //! avfoundation backend, then extracts the lower 4 bits of each int16 sample.
 //! These LSBs are dominated by Johnson-Nyquist thermal noise.
 
-use crate::source::{EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
 
 use super::helpers::{command_exists, pack_nibbles};
 
@@ -24,10 +24,13 @@ static AUDIO_NOISE_INFO: SourceInfo = SourceInfo {
               audio codecs use CMOS input stages where channel thermal noise and 1/f \
               flicker noise dominate; shot noise is negligible. \
               Voltage noise \u{221d} \u{221a}(4kT R \u{0394}f).",
+    failure_hint: "All-zero or constant samples mean the microphone is muted, missing, or ffmpeg lacks permission; a strong periodic spectrum points to mains hum rather than thermal noise.",
     category: SourceCategory::Sensor,
     platform: Platform::MacOS,
     requirements: &[Requirement::AudioUnit],
     entropy_rate_estimate: 10000.0,
+    typical_latency_ms: 500.0,
+    cost_class: CostClass::Medium,
     composite: false,
 };
 
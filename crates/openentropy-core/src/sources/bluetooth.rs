@@ -9,7 +9,7 @@
 use std::process::Command;
 use std::time::{Duration, Instant};
 
-use crate::source::{EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
 
 /// Path to system_profiler on macOS.
 const SYSTEM_PROFILER_PATH: &str = "/usr/sbin/system_profiler";
@@ -24,10 +24,13 @@ static BLUETOOTH_NOISE_INFO: SourceInfo = SourceInfo {
               nearby devices. Each RSSI reading reflects: 2.4 GHz multipath propagation, \
               frequency hopping across 40 channels, advertising interval jitter (\u{00b1}10ms), \
               transmit power variation, and receiver thermal noise.",
+    failure_hint: "Empty output means Bluetooth is off or scanning is not permitted; repeated identical RSSI values suggest no devices in range.",
     category: SourceCategory::Sensor,
     platform: Platform::MacOS,
     requirements: &[Requirement::Bluetooth],
     entropy_rate_estimate: 50.0,
+    typical_latency_ms: 5000.0,
+    cost_class: CostClass::Slow,
     composite: false,
 };
 
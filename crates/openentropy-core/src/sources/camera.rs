@@ -14,7 +14,7 @@ use std::process::{Child, Command, Stdio};
 use std::sync::{Arc, Mutex};
 use std::thread::{self, JoinHandle};
 
-use crate::source::{EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
 
 use super::helpers::{command_exists, pack_nibbles};
 
@@ -31,10 +31,13 @@ static CAMERA_NOISE_INFO: SourceInfo = SourceInfo {
               in silicon \u{2014} classical at sensor operating temperatures; (3) dark current \
               shot noise (Poisson counting) \u{2014} ~1-5% of variance in typical webcams. \
               The LSBs of pixel values mix all three components.",
+    failure_hint: "All-zero or constant frames mean the camera is missing or covered, or ffmpeg lacks permission; fixed-pattern noise alone shows as strong autocorrelation.",
     category: SourceCategory::Sensor,
     platform: Platform::MacOS,
     requirements: &[Requirement::Camera],
     entropy_rate_estimate: 4000.0,
+    typical_latency_ms: 2500.0,
+    cost_class: CostClass::Slow,
     composite: false,
 };
 
@@ -19,7 +19,7 @@ use flate2::Compression;
 use flate2::write::ZlibEncoder;
 use sha2::{Digest, Sha256};
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
 
 use super::helpers::{extract_timing_entropy, mach_time};
 
@@ -35,10 +35,13 @@ static COMPRESSION_TIMING_INFO: SourceInfo = SourceInfo {
               traversal, LZ77 match finding). The CPU\u{2019}s branch predictor state from \
               ALL running code affects prediction accuracy for these branches. Pipeline \
               stalls from mispredictions create timing variation.",
+    failure_hint: "Low entropy or a narrow delta histogram means a coarse timer or an idle, fixed-frequency core; expect this under strict VM timer virtualization.",
     category: SourceCategory::Signal,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 1800.0,
+    typical_latency_ms: 20.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -109,10 +112,13 @@ static HASH_TIMING_INFO: SourceInfo = SourceInfo {
               memory access patterns for the message schedule, cache line alignment, TLB \
               state, and CPU frequency scaling. The timing also captures micro-architectural \
               side effects from other processes.",
+    failure_hint: "Low entropy or a narrow delta histogram means a coarse timer or a fixed-frequency core with warm caches; expect this under strict VM timer virtualization.",
     category: SourceCategory::Signal,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 2000.0,
+    typical_latency_ms: 5.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -8,9 +8,10 @@ use std::io::Write;
 
 use tempfile::NamedTempFile;
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
 
-use super::helpers::{extract_timing_entropy, mach_time};
+use super::frontier::ExtractionMode;
+use super::helpers::{TimingConfig, TimingParams, TunableSource, mach_time};
 
 // ---------------------------------------------------------------------------
 // CPUIOBeatSource
@@ -24,10 +25,13 @@ static CPU_IO_BEAT_INFO: SourceInfo = SourceInfo {
               with separate PLLs. When operations cross domains, the beat frequency of their \
               PLLs creates timing jitter. This is analogous to the acoustic beat frequency \
               between two tuning forks.",
+    failure_hint: "Low entropy when disk reads are cached, since the I/O domain then beats at a fixed CPU-side rate.",
     category: SourceCategory::Composite,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 1500.0,
+    typical_latency_ms: 20.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -44,6 +48,16 @@ impl EntropySource for CPUIOBeatSource {
     }
 
     fn collect(&self, n_samples: usize) -> Vec<u8> {
+        self.collect_tuned(n_samples, Self::DEFAULT_TUNING)
+    }
+}
+
+impl TunableSource for CPUIOBeatSource {
+    type Tuning = TimingParams;
+    const DEFAULT_TUNING: TimingParams =
+        TimingParams::new(TimingConfig::new(10, 64), ExtractionMode::XorFold);
+
+    fn collect_tuned(&self, n_samples: usize, tuning: TimingParams) -> Vec<u8> {
         let mut tmpfile = match NamedTempFile::new() {
             Ok(f) => f,
             Err(_) => return Vec::new(),
@@ -51,7 +65,7 @@ impl EntropySource for CPUIOBeatSource {
 
         // Over-collect raw timings: we need 8 bits per byte, and XOR/LSB
         // extraction reduces the count.
-        let raw_count = n_samples * 10 + 64;
+        let raw_count = tuning.timing.raw_count(n_samples);
         let mut timings: Vec<u64> = Vec::with_capacity(raw_count);
 
         for i in 0..raw_count {
@@ -80,7 +94,7 @@ impl EntropySource for CPUIOBeatSource {
             timings.push(t2.wrapping_sub(t1)); // I/O domain
         }
 
-        extract_timing_entropy(&timings, n_samples)
+        tuning.extraction.extract(&timings, n_samples)
     }
 }
 
@@ -99,10 +113,13 @@ static CPU_MEMORY_BEAT_INFO: SourceInfo = SourceInfo {
               force the CPU to wait for the memory controller\u{2019}s arbitration, whose timing \
               depends on: DRAM refresh state, competing DMA from GPU/ANE, and row buffer \
               conflicts.",
+    failure_hint: "Low entropy when the working set fits in cache and memory latency stops varying.",
     category: SourceCategory::Composite,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 2500.0,
+    typical_latency_ms: 10.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -120,6 +137,16 @@ impl EntropySource for CPUMemoryBeatSource {
     }
 
     fn collect(&self, n_samples: usize) -> Vec<u8> {
+        self.collect_tuned(n_samples, Self::DEFAULT_TUNING)
+    }
+}
+
+impl TunableSource for CPUMemoryBeatSource {
+    type Tuning = TimingParams;
+    const DEFAULT_TUNING: TimingParams =
+        TimingParams::new(TimingConfig::new(10, 64), ExtractionMode::XorFold);
+
+    fn collect_tuned(&self, n_samples: usize, tuning: TimingParams) -> Vec<u8> {
         // Allocate a 16 MB buffer to force DRAM access (exceeds L2 cache).
         let mut buffer = vec![0u8; MEM_BUFFER_SIZE];
 
@@ -128,7 +155,7 @@ impl EntropySource for CPUMemoryBeatSource {
             *byte = i as u8;
         }
 
-        let raw_count = n_samples * 10 + 64;
+        let raw_count = tuning.timing.raw_count(n_samples);
         let mut timings: Vec<u64> = Vec::with_capacity(raw_count);
 
         // Use an LCG to generate pseudo-random indices into the buffer.
@@ -161,7 +188,7 @@ impl EntropySource for CPUMemoryBeatSource {
             timings.push(t2.wrapping_sub(t1)); // Memory domain
         }
 
-        extract_timing_entropy(&timings, n_samples)
+        tuning.extraction.extract(&timings, n_samples)
     }
 }
 
@@ -206,6 +233,18 @@ mod tests {
         assert!(data.len() <= 64);
     }
 
+    #[test]
+    fn cpu_memory_beat_tuned_collects() {
+        let src = CPUMemoryBeatSource.tuned(TimingParams::new(
+            TimingConfig::new(20, 128),
+            ExtractionMode::Variance,
+        ));
+        assert_eq!(src.name(), "cpu_memory_beat");
+        let data = src.collect(64);
+        assert!(!data.is_empty());
+        assert!(data.len() <= 64);
+    }
+
     #[test]
     fn extract_lsbs_basic() {
         let deltas = vec![1u64, 2, 3, 4, 5, 6, 7, 8];
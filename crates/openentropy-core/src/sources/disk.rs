@@ -11,7 +11,7 @@ use std::time::Instant;
 
 use tempfile::NamedTempFile;
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
 
 /// Size of the temporary file used for random reads.
 const TEMP_FILE_SIZE: usize = 64 * 1024; // 64 KB
@@ -26,10 +26,13 @@ static DISK_IO_INFO: SourceInfo = SourceInfo {
               flash translation layer (FTL) remapping, wear leveling, garbage collection, \
               read disturb mitigation, NAND page read latency variation (depends on charge \
               level in floating-gate transistors), and NVMe controller queue arbitration.",
+    failure_hint: "A near-constant latency means reads are served from the page cache; very low entropy is typical on RAM disks and some VMs.",
     category: SourceCategory::IO,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 800.0,
+    typical_latency_ms: 20.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
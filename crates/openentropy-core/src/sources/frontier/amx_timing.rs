@@ -1,6 +1,6 @@
 //! AMX coprocessor timing — entropy from the Apple Matrix eXtensions unit.
 
-use crate::source::{EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
 #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
 use crate::sources::helpers::{extract_timing_entropy, mach_time};
 
@@ -107,10 +107,13 @@ static AMX_TIMING_INFO: SourceInfo = SourceInfo {
               memory bandwidth contention, AMX power state transitions, and SLC cache state. \
               Von Neumann debiasing corrects heavy LSB bias. Interleaved memory operations \
               disrupt pipeline steady-state for higher min-entropy.",
+    failure_hint: "Empty output means no AMX coprocessor; near-constant timings after debiasing indicate a quiet, fixed-frequency unit.",
     category: SourceCategory::Microarch,
     platform: Platform::MacOS,
     requirements: &[Requirement::AppleSilicon],
     entropy_rate_estimate: 2500.0,
+    typical_latency_ms: 20.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -10,9 +10,10 @@
 //! - Reference oscillator crystal phase noise
 //!
 
-use crate::source::{EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
-#[cfg(target_os = "macos")]
-use crate::sources::helpers::extract_timing_entropy;
+use crate::source::{CostClass, EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
+use crate::sources::helpers::{TimingParams, TunableSource};
+
+use super::{ExtractionMode, FRONTIER_TIMING};
 
 static AUDIO_PLL_TIMING_INFO: SourceInfo = SourceInfo {
     name: "audio_pll_timing",
@@ -23,10 +24,13 @@ static AUDIO_PLL_TIMING_INFO: SourceInfo = SourceInfo {
               noise, charge pump shot noise, and crystal reference jitter. Each query \
               timing captures the instantaneous phase relationship between these \
               independent clock domains.",
+    failure_hint: "Empty output without an audio device; constant timings indicate cached CoreAudio property reads.",
     category: SourceCategory::Thermal,
     platform: Platform::MacOS,
     requirements: &[Requirement::AudioUnit],
     entropy_rate_estimate: 4000.0,
+    typical_latency_ms: 50.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -134,9 +138,19 @@ impl EntropySource for AudioPLLTimingSource {
     }
 
     fn collect(&self, n_samples: usize) -> Vec<u8> {
+        self.collect_tuned(n_samples, Self::DEFAULT_TUNING)
+    }
+}
+
+impl TunableSource for AudioPLLTimingSource {
+    type Tuning = TimingParams;
+    const DEFAULT_TUNING: TimingParams =
+        TimingParams::new(FRONTIER_TIMING, ExtractionMode::XorFold);
+
+    fn collect_tuned(&self, n_samples: usize, tuning: TimingParams) -> Vec<u8> {
         #[cfg(not(target_os = "macos"))]
         {
-            let _ = n_samples;
+            let _ = (n_samples, tuning);
             Vec::new()
         }
 
@@ -147,7 +161,7 @@ impl EntropySource for AudioPLLTimingSource {
                 return Vec::new();
             }
 
-            let raw_count = n_samples * 4 + 64;
+            let raw_count = tuning.timing.raw_count(n_samples);
             let mut timings: Vec<u64> = Vec::with_capacity(raw_count);
 
             // Cycle through different property queries to exercise different
@@ -173,7 +187,7 @@ impl EntropySource for AudioPLLTimingSource {
                 timings.push(elapsed.as_nanos() as u64);
             }
 
-            extract_timing_entropy(&timings, n_samples)
+            tuning.extraction.extract(&timings, n_samples)
         }
     }
 }
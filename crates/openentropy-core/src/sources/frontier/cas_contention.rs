@@ -4,7 +4,7 @@
 //! The hardware coherence engine's arbitration order is physically nondeterministic.
 //!
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
 use crate::sources::helpers::{mach_time, xor_fold_u64};
 
 use std::sync::Arc;
@@ -74,10 +74,13 @@ static CAS_CONTENTION_INFO: SourceInfo = SourceInfo {
               variations, thermal state, and traffic from other cores/devices. \
               XOR-combining timing measurements from all threads amplifies the \
               arbitration entropy.",
+    failure_hint: "Low entropy on single-core or heavily throttled machines, where the threads cannot actually contend.",
     category: SourceCategory::Microarch,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 2000.0,
+    typical_latency_ms: 20.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
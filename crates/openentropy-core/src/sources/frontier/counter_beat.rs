@@ -38,11 +38,14 @@
 //! independent oscillator. This version fixes that by using the audio PLL as the
 //! genuinely independent second clock domain, validated by `audio_pll_timing`'s
 
-use crate::source::{EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
 #[cfg(target_os = "macos")]
 use crate::sources::helpers::read_cntvct;
 #[cfg(target_os = "macos")]
 use crate::sources::helpers::xor_fold_u64;
+use crate::sources::helpers::{TimingConfig, TunableSource};
+
+use super::FRONTIER_TIMING;
 
 static COUNTER_BEAT_INFO: SourceInfo = SourceInfo {
     name: "counter_beat",
@@ -57,10 +60,13 @@ static COUNTER_BEAT_INFO: SourceInfo = SourceInfo {
               Entropy arises from independent \
               Johnson-Nyquist thermal noise in each crystal's sustaining amplifier. \
               The raw physical signal is preserved for statistical analysis.",
+    failure_hint: "Empty output without an audio device; a clean periodic pattern means the two clocks share a reference.",
     category: SourceCategory::Thermal,
     platform: Platform::MacOS,
     requirements: &[Requirement::AppleSilicon, Requirement::AudioUnit],
     entropy_rate_estimate: 2000.0,
+    typical_latency_ms: 5.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -172,9 +178,18 @@ impl EntropySource for CounterBeatSource {
     }
 
     fn collect(&self, n_samples: usize) -> Vec<u8> {
+        self.collect_tuned(n_samples, Self::DEFAULT_TUNING)
+    }
+}
+
+impl TunableSource for CounterBeatSource {
+    type Tuning = TimingConfig;
+    const DEFAULT_TUNING: TimingConfig = FRONTIER_TIMING;
+
+    fn collect_tuned(&self, n_samples: usize, tuning: TimingConfig) -> Vec<u8> {
         #[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
         {
-            let _ = n_samples;
+            let _ = (n_samples, tuning);
             Vec::new()
         }
 
@@ -203,7 +218,7 @@ impl EntropySource for CounterBeatSource {
             ];
 
             // Over-collect: delta + XOR + fold reduces count.
-            let raw_count = n_samples * 4 + 64;
+            let raw_count = tuning.raw_count(n_samples);
             let mut beats: Vec<u64> = Vec::with_capacity(raw_count);
 
             for i in 0..raw_count {
@@ -9,8 +9,10 @@
 //! On Apple Silicon, denormal handling is fast (no microcode penalty),
 //! but residual pipeline state and cache effects still create jitter.
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
-use crate::sources::helpers::{extract_timing_entropy, mach_time};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::sources::helpers::{TimingParams, TunableSource, mach_time};
+
+use super::{ExtractionMode, FRONTIER_TIMING};
 
 /// Number of floating-point operations per timing measurement.
 const OPS_PER_SAMPLE: usize = 100;
@@ -24,10 +26,13 @@ static DENORMAL_TIMING_INFO: SourceInfo = SourceInfo {
               Even on Apple Silicon where denormal handling is fast in hardware, \
               residual timing jitter comes from FPU pipeline state, cache line \
               alignment, and memory controller arbitration.",
+    failure_hint: "Near-constant timings mean denormals are flushed to zero (FTZ/DAZ) or handled at full speed by the FPU.",
     category: SourceCategory::Thermal,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 300.0,
+    typical_latency_ms: 10.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -44,7 +49,17 @@ impl EntropySource for DenormalTimingSource {
     }
 
     fn collect(&self, n_samples: usize) -> Vec<u8> {
-        let raw_count = n_samples * 4 + 64;
+        self.collect_tuned(n_samples, Self::DEFAULT_TUNING)
+    }
+}
+
+impl TunableSource for DenormalTimingSource {
+    type Tuning = TimingParams;
+    const DEFAULT_TUNING: TimingParams =
+        TimingParams::new(FRONTIER_TIMING, ExtractionMode::XorFold);
+
+    fn collect_tuned(&self, n_samples: usize, tuning: TimingParams) -> Vec<u8> {
+        let raw_count = tuning.timing.raw_count(n_samples);
         let mut timings: Vec<u64> = Vec::with_capacity(raw_count);
 
         // Pre-generate denormal values with varying mantissa patterns.
@@ -77,7 +92,7 @@ impl EntropySource for DenormalTimingSource {
             timings.push(t1.wrapping_sub(t0));
         }
 
-        extract_timing_entropy(&timings, n_samples)
+        tuning.extraction.extract(&timings, n_samples)
     }
 }
 
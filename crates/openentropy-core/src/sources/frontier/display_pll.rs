@@ -30,9 +30,12 @@
 //! CoreGraphics query collection is slower than pure syscall-based sources.
 //! We oversample and extract timing deltas to recover usable entropy density.
 
-use crate::source::{EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
 #[cfg(target_os = "macos")]
-use crate::sources::helpers::{extract_timing_entropy, read_cntvct};
+use crate::sources::helpers::read_cntvct;
+use crate::sources::helpers::{TimingParams, TunableSource};
+
+use super::{ExtractionMode, FRONTIER_TIMING};
 
 static DISPLAY_PLL_INFO: SourceInfo = SourceInfo {
     name: "display_pll",
@@ -44,10 +47,13 @@ static DISPLAY_PLL_INFO: SourceInfo = SourceInfo {
               Johnson-Nyquist noise and charge pump shot noise in the display PLL. \
               Reading CNTVCT_EL0 before and after each query captures the beat between \
               CPU crystal and display PLL.",
+    failure_hint: "Empty output on headless machines; constant readings mean the display is asleep.",
     category: SourceCategory::Thermal,
     platform: Platform::MacOS,
     requirements: &[Requirement::AppleSilicon],
     entropy_rate_estimate: 2500.0,
+    typical_latency_ms: 50.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -156,15 +162,25 @@ impl EntropySource for DisplayPllSource {
     }
 
     fn collect(&self, n_samples: usize) -> Vec<u8> {
+        self.collect_tuned(n_samples, Self::DEFAULT_TUNING)
+    }
+}
+
+impl TunableSource for DisplayPllSource {
+    type Tuning = TimingParams;
+    const DEFAULT_TUNING: TimingParams =
+        TimingParams::new(FRONTIER_TIMING, ExtractionMode::XorFold);
+
+    fn collect_tuned(&self, n_samples: usize, tuning: TimingParams) -> Vec<u8> {
         #[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
         {
-            let _ = n_samples;
+            let _ = (n_samples, tuning);
             Vec::new()
         }
 
         #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
         {
-            let raw_count = n_samples * 4 + 64;
+            let raw_count = tuning.timing.raw_count(n_samples);
             let mut beats: Vec<u64> = Vec::with_capacity(raw_count);
 
             for i in 0..raw_count {
@@ -185,7 +201,7 @@ impl EntropySource for DisplayPllSource {
                 beats.push(duration);
             }
 
-            extract_timing_entropy(&beats, n_samples)
+            tuning.extraction.extract(&beats, n_samples)
         }
     }
 }
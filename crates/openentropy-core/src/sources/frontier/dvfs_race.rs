@@ -6,13 +6,15 @@
 //! P-core vs E-core clusters.
 //!
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
-use crate::sources::helpers::{mach_time, xor_fold_u64};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::sources::helpers::{TimingConfig, TunableSource, mach_time, xor_fold_u64};
 
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::thread;
 
+use super::FRONTIER_TIMING;
+
 /// Cross-core DVFS race entropy source.
 ///
 /// Spawns two threads that race via tight counting loops. The absolute
@@ -41,10 +43,13 @@ static DVFS_RACE_INFO: SourceInfo = SourceInfo {
               E-core clusters have separate frequency domains, but the 2\u{00b5}s window is \
               too short for DVFS transitions (~100\u{00b5}s-1ms); the primary entropy comes \
               from scheduling and cache-coherence nondeterminism.",
+    failure_hint: "Low entropy means frequency scaling is pinned (performance governor, VM) so the thread pairs run in lockstep.",
     category: SourceCategory::Microarch,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 5000.0,
+    typical_latency_ms: 30.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -58,9 +63,18 @@ impl EntropySource for DVFSRaceSource {
     }
 
     fn collect(&self, n_samples: usize) -> Vec<u8> {
+        self.collect_tuned(n_samples, Self::DEFAULT_TUNING)
+    }
+}
+
+impl TunableSource for DVFSRaceSource {
+    type Tuning = TimingConfig;
+    const DEFAULT_TUNING: TimingConfig = FRONTIER_TIMING;
+
+    fn collect_tuned(&self, n_samples: usize, tuning: TimingConfig) -> Vec<u8> {
         // We need enough race differentials to extract n_samples bytes.
         // Each race produces one u64 differential; XOR-fold pairs → bytes.
-        let raw_count = n_samples * 4 + 64;
+        let raw_count = tuning.raw_count(n_samples);
         let mut diffs: Vec<u64> = Vec::with_capacity(raw_count);
 
         // Get timebase for ~2μs window calculation.
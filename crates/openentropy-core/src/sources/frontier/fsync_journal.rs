@@ -16,8 +16,10 @@
 
 use std::io::Write;
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
-use crate::sources::helpers::extract_timing_entropy;
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::sources::helpers::{TimingParams, TunableSource};
+
+use super::{ExtractionMode, FRONTIER_TIMING};
 
 static FSYNC_JOURNAL_INFO: SourceInfo = SourceInfo {
     name: "fsync_journal",
@@ -29,10 +31,13 @@ static FSYNC_JOURNAL_INFO: SourceInfo = SourceInfo {
               injection timing, wear-dependent oxide characteristics) \u{2192} barrier flush \
               (controller firmware scheduling). Every layer contributes independent \
               timing noise from physically distinct sources.",
+    failure_hint: "Near-constant commit times mean fsync is a no-op (tmpfs, disabled barriers) or the device is RAM-backed.",
     category: SourceCategory::IO,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 2000.0,
+    typical_latency_ms: 1000.0,
+    cost_class: CostClass::Medium,
     composite: false,
 };
 
@@ -49,7 +54,17 @@ impl EntropySource for FsyncJournalSource {
     }
 
     fn collect(&self, n_samples: usize) -> Vec<u8> {
-        let raw_count = n_samples * 4 + 64;
+        self.collect_tuned(n_samples, Self::DEFAULT_TUNING)
+    }
+}
+
+impl TunableSource for FsyncJournalSource {
+    type Tuning = TimingParams;
+    const DEFAULT_TUNING: TimingParams =
+        TimingParams::new(FRONTIER_TIMING, ExtractionMode::XorFold);
+
+    fn collect_tuned(&self, n_samples: usize, tuning: TimingParams) -> Vec<u8> {
+        let raw_count = tuning.timing.raw_count(n_samples);
         let mut timings: Vec<u64> = Vec::with_capacity(raw_count);
         let write_data = [0xAAu8; 512];
 
@@ -84,7 +99,7 @@ impl EntropySource for FsyncJournalSource {
             // tmpfile is automatically deleted on drop.
         }
 
-        extract_timing_entropy(&timings, n_samples)
+        tuning.extraction.extract(&timings, n_samples)
     }
 }
 
@@ -14,13 +14,16 @@
 //! process spawning. Each dispatch completes in microseconds.
 //!
 
-use crate::source::{EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
 #[cfg(target_os = "macos")]
 use crate::sources::helpers::extract_timing_entropy;
 #[cfg(target_os = "macos")]
 use crate::sources::helpers::mach_time;
 #[cfg(target_os = "macos")]
 use crate::sources::helpers::xor_fold_u64;
+use crate::sources::helpers::{TimingConfig, TunableSource};
+
+use super::FRONTIER_TIMING;
 
 static GPU_DIVERGENCE_INFO: SourceInfo = SourceInfo {
     name: "gpu_divergence",
@@ -31,10 +34,13 @@ static GPU_DIVERGENCE_INFO: SourceInfo = SourceInfo {
               coalescing failures, L2 cache bank conflicts, thermal-dependent GPU clock \
               frequency variation, and warp scheduler arbitration. Each dispatch produces \
               a different execution ordering due to physical nondeterminism in the GPU.",
+    failure_hint: "Empty output means no usable GPU; an identical execution order every run means the scheduler is deterministic.",
     category: SourceCategory::GPU,
     platform: Platform::MacOS,
     requirements: &[Requirement::Metal],
     entropy_rate_estimate: 6000.0,
+    typical_latency_ms: 150.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -347,9 +353,18 @@ impl EntropySource for GPUDivergenceSource {
     }
 
     fn collect(&self, n_samples: usize) -> Vec<u8> {
+        self.collect_tuned(n_samples, Self::DEFAULT_TUNING)
+    }
+}
+
+impl TunableSource for GPUDivergenceSource {
+    type Tuning = TimingConfig;
+    const DEFAULT_TUNING: TimingConfig = FRONTIER_TIMING;
+
+    fn collect_tuned(&self, n_samples: usize, tuning: TimingConfig) -> Vec<u8> {
         #[cfg(not(target_os = "macos"))]
         {
-            let _ = n_samples;
+            let _ = (n_samples, tuning);
             Vec::new()
         }
 
@@ -360,7 +375,7 @@ impl EntropySource for GPUDivergenceSource {
                 None => return Vec::new(),
             };
 
-            let raw_count = n_samples * 4 + 64;
+            let raw_count = tuning.raw_count(n_samples);
             let mut timings: Vec<u64> = Vec::with_capacity(raw_count);
             let mut gpu_entropy: Vec<u8> = Vec::with_capacity(raw_count);
 
@@ -11,11 +11,12 @@
 //! Each create/lock/write/unlock/destroy cycle completes in microseconds.
 //!
 
-use crate::source::{EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
-#[cfg(target_os = "macos")]
-use crate::sources::helpers::extract_timing_entropy;
+use crate::source::{CostClass, EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
 #[cfg(target_os = "macos")]
 use crate::sources::helpers::mach_time;
+use crate::sources::helpers::{TimingParams, TunableSource};
+
+use super::{ExtractionMode, FRONTIER_TIMING};
 
 static IOSURFACE_CROSSING_INFO: SourceInfo = SourceInfo {
     name: "iosurface_crossing",
@@ -27,10 +28,13 @@ static IOSURFACE_CROSSING_INFO: SourceInfo = SourceInfo {
               interconnect scheduling, and cross-clock-domain synchronizer metastability. \
               The combined multi-domain crossing creates high entropy from physically \
               independent noise sources.",
+    failure_hint: "Empty output means IOSurface is unavailable; a narrow spread indicates the crossing is served from a coherent cache.",
     category: SourceCategory::GPU,
     platform: Platform::MacOS,
     requirements: &[Requirement::IOSurface],
     entropy_rate_estimate: 3000.0,
+    typical_latency_ms: 100.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -236,15 +240,25 @@ impl EntropySource for IOSurfaceCrossingSource {
     }
 
     fn collect(&self, n_samples: usize) -> Vec<u8> {
+        self.collect_tuned(n_samples, Self::DEFAULT_TUNING)
+    }
+}
+
+impl TunableSource for IOSurfaceCrossingSource {
+    type Tuning = TimingParams;
+    const DEFAULT_TUNING: TimingParams =
+        TimingParams::new(FRONTIER_TIMING, ExtractionMode::XorFold);
+
+    fn collect_tuned(&self, n_samples: usize, tuning: TimingParams) -> Vec<u8> {
         #[cfg(not(target_os = "macos"))]
         {
-            let _ = n_samples;
+            let _ = (n_samples, tuning);
             Vec::new()
         }
 
         #[cfg(target_os = "macos")]
         {
-            let raw_count = n_samples * 4 + 64;
+            let raw_count = tuning.timing.raw_count(n_samples);
             let mut timings: Vec<u64> = Vec::with_capacity(raw_count);
 
             for i in 0..raw_count {
@@ -255,7 +269,7 @@ impl EntropySource for IOSurfaceCrossingSource {
                 }
             }
 
-            extract_timing_entropy(&timings, n_samples)
+            tuning.extraction.extract(&timings, n_samples)
         }
     }
 }
@@ -1,7 +1,7 @@
 //! Keychain/securityd IPC timing — entropy from the Security framework's
 //! multi-domain round-trip through securityd, SEP, and APFS.
 
-use crate::source::{EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
 #[cfg(target_os = "macos")]
 use crate::sources::helpers::mach_time;
 
@@ -69,10 +69,13 @@ static KEYCHAIN_TIMING_INFO: SourceInfo = SourceInfo {
               switches) contributes independent jitter. Variance extraction removes serial \
               correlation (lag-1 autocorrelation ~0.43 in raw timings). First 500 samples \
               discarded to avoid warm-up transient from securityd cold caches.",
+    failure_hint: "Empty output means securityd is unreachable; uniform timings indicate cached replies.",
     category: SourceCategory::IPC,
     platform: Platform::MacOS,
     requirements: &[Requirement::SecurityFramework],
     entropy_rate_estimate: 6500.0,
+    typical_latency_ms: 1000.0,
+    cost_class: CostClass::Medium,
     composite: false,
 };
 
@@ -22,14 +22,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 ))]
 use std::thread;
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
-#[cfg(any(
-    target_os = "macos",
-    target_os = "freebsd",
-    target_os = "netbsd",
-    target_os = "openbsd"
-))]
-use crate::sources::helpers::extract_timing_entropy;
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
 #[cfg(any(
     target_os = "macos",
     target_os = "freebsd",
@@ -38,6 +31,8 @@ use crate::sources::helpers::extract_timing_entropy;
 ))]
 use crate::sources::helpers::mach_time;
 
+use super::ExtractionMode;
+
 /// Configuration for kqueue events entropy collection.
 ///
 /// # Example
@@ -48,6 +43,7 @@ use crate::sources::helpers::mach_time;
 ///     num_timers: 16,         // more timers for richer interference
 ///     num_sockets: 4,         // default socket pairs
 ///     timeout_ms: 2,          // slightly longer timeout
+///     ..Default::default()
 /// };
 /// ```
 #[derive(Debug, Clone)]
@@ -87,6 +83,11 @@ pub struct KqueueEventsConfig {
     ///
     /// **Range:** 1+. **Default:** `1`
     pub timeout_ms: u32,
+
+    /// Extractor applied to raw timings.
+    ///
+    /// **Default:** [`ExtractionMode::XorFold`]
+    pub extraction: ExtractionMode,
 }
 
 impl Default for KqueueEventsConfig {
@@ -96,6 +97,7 @@ impl Default for KqueueEventsConfig {
             num_timers: 8,
             num_sockets: 4,
             timeout_ms: 1,
+            extraction: ExtractionMode::XorFold,
         }
     }
 }
@@ -146,10 +148,13 @@ static KQUEUE_EVENTS_INFO: SourceInfo = SourceInfo {
               paths. Socket events capture mbuf allocator timing. Multiple simultaneous watchers \
               create knote lock contention and dispatch queue interference. The combination of \
               independent event sources produces high min-entropy.",
+    failure_hint: "Narrow timing spreads mean timers are coalesced; expect lower entropy on idle systems.",
     category: SourceCategory::IPC,
     platform: Platform::MacOS,
     requirements: &[],
     entropy_rate_estimate: 2500.0,
+    typical_latency_ms: 30.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -348,7 +353,7 @@ impl EntropySource for KqueueEventsSource {
             libc::close(kq);
         }
 
-        extract_timing_entropy(&timings, n_samples)
+        self.config.extraction.extract(&timings, n_samples)
     }
 
     #[cfg(not(any(
@@ -391,9 +396,11 @@ mod tests {
                 num_timers: 4,
                 num_sockets: 2,
                 timeout_ms: 5,
+                extraction: ExtractionMode::Lsb,
             },
         };
         assert_eq!(src.config.num_timers, 4);
+        assert_eq!(src.config.extraction, ExtractionMode::Lsb);
     }
 
     #[test]
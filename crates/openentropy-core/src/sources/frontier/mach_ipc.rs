@@ -7,9 +7,11 @@ use std::sync::atomic::{AtomicBool, Ordering};
 #[cfg(target_os = "macos")]
 use std::thread;
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
 #[cfg(target_os = "macos")]
-use crate::sources::helpers::{extract_timing_entropy, mach_time};
+use crate::sources::helpers::mach_time;
+
+use super::ExtractionMode;
 
 /// Configuration for Mach IPC entropy collection.
 ///
@@ -20,6 +22,7 @@ use crate::sources::helpers::{extract_timing_entropy, mach_time};
 ///     num_ports: 16,               // more ports = more contention
 ///     ool_size: 8192,              // larger OOL = more VM work
 ///     use_complex_messages: true,  // OOL messages (recommended)
+///     ..Default::default()
 /// };
 /// ```
 #[derive(Debug, Clone)]
@@ -50,6 +53,11 @@ pub struct MachIPCConfig {
     ///
     /// **Default:** `true`
     pub use_complex_messages: bool,
+
+    /// Extractor applied to raw timings.
+    ///
+    /// **Default:** [`ExtractionMode::XorFold`]
+    pub extraction: ExtractionMode,
 }
 
 impl Default for MachIPCConfig {
@@ -58,6 +66,7 @@ impl Default for MachIPCConfig {
             num_ports: 8,
             ool_size: 4096,
             use_complex_messages: true,
+            extraction: ExtractionMode::XorFold,
         }
     }
 }
@@ -104,10 +113,13 @@ static MACH_IPC_INFO: SourceInfo = SourceInfo {
               Round-robin across ports with varied queue depths creates namespace contention. \
               Timing captures: OOL VM remap latency, port namespace splay tree operations, \
               per-port lock contention, and cross-core scheduling nondeterminism.",
+    failure_hint: "A narrow timing spread means the IPC path is uncontended; expect reduced entropy on idle systems.",
     category: SourceCategory::IPC,
     platform: Platform::MacOS,
     requirements: &[],
     entropy_rate_estimate: 2000.0,
+    typical_latency_ms: 20.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -243,7 +255,7 @@ impl EntropySource for MachIPCSource {
                 }
             }
 
-            extract_timing_entropy(&timings, n_samples)
+            self.config.extraction.extract(&timings, n_samples)
         }
     }
 }
@@ -268,7 +280,7 @@ impl MachIPCSource {
             let t1 = mach_time();
             timings.push(t1.wrapping_sub(t0));
         }
-        extract_timing_entropy(&timings, n_samples)
+        self.config.extraction.extract(&timings, n_samples)
     }
 }
 
@@ -368,6 +380,7 @@ mod tests {
                 num_ports: 4,
                 ool_size: 8192,
                 use_complex_messages: false,
+                ..Default::default()
             },
         };
         assert_eq!(src.config.num_ports, 4);
@@ -34,6 +34,18 @@
 //! `Default::default()` for standard behavior, or construct a custom config
 //! to tune for specific hardware or entropy requirements. See each source's
 //! config struct documentation for field descriptions and valid ranges.
+//!
+//! Sources without a dedicated config are unit structs that collect with
+//! [`FRONTIER_TIMING`]. They implement
+//! [`TunableSource`](super::helpers::TunableSource); call `.tuned(..)` with a
+//! [`TimingConfig`] to change how many raw timings are collected per output
+//! byte.
+//!
+//! Sources that turn raw timestamps into bytes also take an
+//! [`ExtractionMode`] (on their config, or in the
+//! [`TimingParams`](super::helpers::TimingParams) passed to `.tuned(..)`),
+//! defaulting to the extractor they have always used. Swap it to compare how
+//! each extractor affects min-entropy for a given physical domain.
 
 // Standalone sources — one independent entropy domain each.
 mod amx_timing;
@@ -79,11 +91,17 @@ pub use thread_lifecycle::ThreadLifecycleSource;
 pub use tlb_shootdown::{TLBShootdownConfig, TLBShootdownSource};
 pub use usb_timing::USBTimingSource;
 
+use super::helpers::TimingConfig;
+
+/// Default oversampling for frontier timing sources: 4 raw timings per output
+/// byte plus 64.
+pub const FRONTIER_TIMING: TimingConfig = TimingConfig::new(4, 64);
+
 // ---------------------------------------------------------------------------
 // Shared extraction helpers (used by multiple frontier sources)
 // ---------------------------------------------------------------------------
 
-use super::helpers::xor_fold_u64;
+use super::helpers::{extract_lsbs_u64, extract_timing_entropy, xor_fold_u64};
 
 /// Von Neumann debiased timing extraction.
 ///
@@ -92,7 +110,6 @@ use super::helpers::xor_fold_u64;
 /// removes bias from the raw timing stream at the cost of ~50% data loss.
 ///
 /// Used by [`AMXTimingSource`] to correct its severe min-entropy bias.
-#[cfg(all(target_os = "macos", target_arch = "aarch64"))]
 pub(crate) fn extract_timing_entropy_debiased(timings: &[u64], n_samples: usize) -> Vec<u8> {
     if timings.len() < 4 {
         return Vec::new();
@@ -156,6 +173,42 @@ pub(crate) fn extract_timing_entropy_variance(timings: &[u64], n_samples: usize)
     raw
 }
 
+/// Strategy for turning raw timestamps into output bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ExtractionMode {
+    /// Pack the LSB of each delta, 8 deltas per byte. Yields ~1/8 as many
+    /// bytes as the other modes for the same raw input.
+    Lsb,
+    /// XOR adjacent deltas and fold each to one byte
+    /// ([`extract_timing_entropy`]).
+    #[default]
+    XorFold,
+    /// Delta-of-deltas, then XOR-fold; removes systematic drift.
+    Variance,
+    /// Von Neumann debiasing of delta pairs; one bit per unequal pair.
+    VonNeumann,
+}
+
+impl ExtractionMode {
+    /// Extract at most `n_samples` bytes from raw timestamps.
+    pub fn extract(self, timings: &[u64], n_samples: usize) -> Vec<u8> {
+        match self {
+            Self::Lsb => {
+                let deltas: Vec<u64> = timings
+                    .windows(2)
+                    .map(|w| w[1].wrapping_sub(w[0]))
+                    .collect();
+                let mut bytes = extract_lsbs_u64(&deltas);
+                bytes.truncate(n_samples);
+                bytes
+            }
+            Self::XorFold => extract_timing_entropy(timings, n_samples),
+            Self::Variance => extract_timing_entropy_variance(timings, n_samples),
+            Self::VonNeumann => extract_timing_entropy_debiased(timings, n_samples),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests for shared helpers
 // ---------------------------------------------------------------------------
@@ -165,7 +218,6 @@ mod tests {
     use super::*;
 
     // Von Neumann debiasing
-    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
     #[test]
     fn debiased_extraction_basic() {
         let timings: Vec<u64> = (0..200).map(|i| 100 + (i * 7 + i * i) % 50).collect();
@@ -173,14 +225,12 @@ mod tests {
         assert!(result.len() <= 10);
     }
 
-    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
     #[test]
     fn debiased_extraction_too_few() {
         assert!(extract_timing_entropy_debiased(&[1, 2, 3], 10).is_empty());
         assert!(extract_timing_entropy_debiased(&[], 10).is_empty());
     }
 
-    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
     #[test]
     fn debiased_extraction_constant_input() {
         let timings = vec![42u64; 100];
@@ -202,6 +252,49 @@ mod tests {
         assert!(extract_timing_entropy_variance(&[1, 2, 3], 10).is_empty());
     }
 
+    // Extraction modes
+    #[test]
+    fn extraction_modes_produce_different_streams() {
+        let timings: Vec<u64> = (0..2000u64)
+            .scan(1000u64, |t, i| {
+                *t += 100 + (i * 2_654_435_761 % 97) + (i * i % 13);
+                Some(*t)
+            })
+            .collect();
+        let modes = [
+            ExtractionMode::Lsb,
+            ExtractionMode::XorFold,
+            ExtractionMode::Variance,
+            ExtractionMode::VonNeumann,
+        ];
+        let outputs: Vec<Vec<u8>> = modes.iter().map(|m| m.extract(&timings, 64)).collect();
+        for (mode, out) in modes.iter().zip(&outputs) {
+            assert!(!out.is_empty(), "{mode:?} produced nothing");
+            assert!(out.len() <= 64);
+        }
+        for i in 0..outputs.len() {
+            for j in i + 1..outputs.len() {
+                assert_ne!(outputs[i], outputs[j], "{:?} == {:?}", modes[i], modes[j]);
+            }
+        }
+        assert_eq!(
+            ExtractionMode::default().extract(&timings, 64),
+            extract_timing_entropy(&timings, 64)
+        );
+    }
+
+    #[test]
+    fn von_neumann_mode_rejects_constant_input() {
+        // Constant timestamps and a constant rate both give equal delta pairs.
+        assert!(
+            ExtractionMode::VonNeumann
+                .extract(&[42u64; 500], 16)
+                .is_empty()
+        );
+        let steady: Vec<u64> = (0..500).map(|i| i * 10).collect();
+        assert!(ExtractionMode::VonNeumann.extract(&steady, 16).is_empty());
+    }
+
     // All frontier sources have valid metadata
     #[test]
     fn all_frontier_sources_have_valid_names() {
@@ -14,8 +14,10 @@
 use std::io::{Read, Seek, SeekFrom, Write};
 use std::time::Instant;
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
-use crate::sources::helpers::extract_timing_entropy;
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::sources::helpers::{TimingParams, TunableSource};
+
+use super::{ExtractionMode, FRONTIER_TIMING};
 
 /// Number of distinct offsets to cycle through (hitting different NAND pages).
 const N_OFFSETS: usize = 8;
@@ -33,10 +35,13 @@ static NVME_LATENCY_INFO: SourceInfo = SourceInfo {
               firmware scheduling (garbage collection, wear leveling background tasks), \
               and interrupt delivery latency. Note: freshly-written data typically resides \
               in SSD DRAM cache, not NAND cells.",
+    failure_hint: "A near-constant latency means requests hit a cache; very low entropy is typical on virtual or RAM-backed disks.",
     category: SourceCategory::IO,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 1000.0,
+    typical_latency_ms: 50.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -53,6 +58,16 @@ impl EntropySource for NVMeLatencySource {
     }
 
     fn collect(&self, n_samples: usize) -> Vec<u8> {
+        self.collect_tuned(n_samples, Self::DEFAULT_TUNING)
+    }
+}
+
+impl TunableSource for NVMeLatencySource {
+    type Tuning = TimingParams;
+    const DEFAULT_TUNING: TimingParams =
+        TimingParams::new(FRONTIER_TIMING, ExtractionMode::XorFold);
+
+    fn collect_tuned(&self, n_samples: usize, tuning: TimingParams) -> Vec<u8> {
         // Create a temp file with varied data across multiple offsets.
         let mut tmpfile = match tempfile::NamedTempFile::new() {
             Ok(f) => f,
@@ -87,7 +102,7 @@ impl EntropySource for NVMeLatencySource {
             }
         }
 
-        let raw_count = n_samples * 4 + 64;
+        let raw_count = tuning.timing.raw_count(n_samples);
         let mut timings: Vec<u64> = Vec::with_capacity(raw_count);
         let mut read_buf = vec![0u8; BLOCK_SIZE];
 
@@ -102,7 +117,7 @@ impl EntropySource for NVMeLatencySource {
             timings.push(elapsed.as_nanos() as u64);
         }
 
-        extract_timing_entropy(&timings, n_samples)
+        tuning.extraction.extract(&timings, n_samples)
     }
 }
 
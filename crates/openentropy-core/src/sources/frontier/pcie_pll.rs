@@ -27,9 +27,10 @@
 //! - **Deep kernel path**: IOKit traversal exercises more kernel subsystems than
 //!   simple syscalls
 
-use crate::source::{EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
-#[cfg(target_os = "macos")]
-use crate::sources::helpers::extract_timing_entropy;
+use crate::source::{CostClass, EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
+use crate::sources::helpers::{TimingParams, TunableSource};
+
+use super::{ExtractionMode, FRONTIER_TIMING};
 
 static PCIE_PLL_INFO: SourceInfo = SourceInfo {
     name: "pcie_pll",
@@ -41,10 +42,13 @@ static PCIE_PLL_INFO: SourceInfo = SourceInfo {
               from VCO thermal noise, spread-spectrum clocking modulation, and lane skew. \
               CNTVCT_EL0 timestamps before/after each IOKit call capture the beat between \
               CPU crystal and PCIe clock domain.",
+    failure_hint: "Empty output means the IOKit properties are unavailable; repeated identical timings suggest cached property reads.",
     category: SourceCategory::Thermal,
     platform: Platform::MacOS,
     requirements: &[Requirement::AppleSilicon, Requirement::IOKit],
     entropy_rate_estimate: 2000.0,
+    typical_latency_ms: 50.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -256,9 +260,19 @@ impl EntropySource for PciePllSource {
     }
 
     fn collect(&self, n_samples: usize) -> Vec<u8> {
+        self.collect_tuned(n_samples, Self::DEFAULT_TUNING)
+    }
+}
+
+impl TunableSource for PciePllSource {
+    type Tuning = TimingParams;
+    const DEFAULT_TUNING: TimingParams =
+        TimingParams::new(FRONTIER_TIMING, ExtractionMode::XorFold);
+
+    fn collect_tuned(&self, n_samples: usize, tuning: TimingParams) -> Vec<u8> {
         #[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
         {
-            let _ = n_samples;
+            let _ = (n_samples, tuning);
             Vec::new()
         }
 
@@ -269,7 +283,7 @@ impl EntropySource for PciePllSource {
                 return Vec::new();
             }
 
-            let raw_count = n_samples * 4 + 64;
+            let raw_count = tuning.timing.raw_count(n_samples);
             let mut beats: Vec<u64> = Vec::with_capacity(raw_count);
 
             for i in 0..raw_count {
@@ -278,7 +292,7 @@ impl EntropySource for PciePllSource {
                 beats.push(duration);
             }
 
-            extract_timing_entropy(&beats, n_samples)
+            tuning.extraction.extract(&beats, n_samples)
         }
     }
 }
@@ -8,8 +8,10 @@
 //! on the current thread, we capture PDN voltage noise from cross-core coupling.
 //!
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
-use crate::sources::helpers::{extract_timing_entropy, mach_time};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::sources::helpers::{TimingParams, TunableSource, mach_time};
+
+use super::{ExtractionMode, FRONTIER_TIMING};
 
 /// Number of iterations per timing measurement.
 const MEASUREMENT_ITERS: usize = 100;
@@ -23,10 +25,13 @@ static PDN_RESONANCE_INFO: SourceInfo = SourceInfo {
               LC resonances in PCB power planes, voltage droop from bursty current draw, \
               and cross-core power supply coupling. Each measurement thread combination \
               creates a different current profile exciting different PDN modes.",
+    failure_hint: "Low entropy when cores are parked or voltage regulation is very stiff; results vary strongly with load.",
     category: SourceCategory::Thermal,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 500.0,
+    typical_latency_ms: 30.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -43,7 +48,17 @@ impl EntropySource for PDNResonanceSource {
     }
 
     fn collect(&self, n_samples: usize) -> Vec<u8> {
-        let raw_count = n_samples * 4 + 64;
+        self.collect_tuned(n_samples, Self::DEFAULT_TUNING)
+    }
+}
+
+impl TunableSource for PDNResonanceSource {
+    type Tuning = TimingParams;
+    const DEFAULT_TUNING: TimingParams =
+        TimingParams::new(FRONTIER_TIMING, ExtractionMode::XorFold);
+
+    fn collect_tuned(&self, n_samples: usize, tuning: TimingParams) -> Vec<u8> {
+        let raw_count = tuning.timing.raw_count(n_samples);
         let mut timings: Vec<u64> = Vec::with_capacity(raw_count);
 
         // Spawn stress threads to excite PDN resonance.
@@ -102,7 +117,7 @@ impl EntropySource for PDNResonanceSource {
             let _ = h.join();
         }
 
-        extract_timing_entropy(&timings, n_samples)
+        tuning.extraction.extract(&timings, n_samples)
     }
 }
 
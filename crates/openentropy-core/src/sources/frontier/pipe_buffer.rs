@@ -1,7 +1,9 @@
 //! Pipe buffer timing — entropy from multi-pipe kernel zone allocator contention.
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
-use crate::sources::helpers::{extract_timing_entropy, mach_time};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::sources::helpers::mach_time;
+
+use super::ExtractionMode;
 
 /// Configuration for pipe buffer entropy collection.
 ///
@@ -13,6 +15,7 @@ use crate::sources::helpers::{extract_timing_entropy, mach_time};
 ///     min_write_size: 64,        // skip tiny writes
 ///     max_write_size: 2048,      // cap at 2KB
 ///     non_blocking: true,        // capture EAGAIN timing (recommended)
+///     ..Default::default()
 /// };
 /// ```
 #[derive(Debug, Clone)]
@@ -50,6 +53,11 @@ pub struct PipeBufferConfig {
     ///
     /// **Default:** `true`
     pub non_blocking: bool,
+
+    /// Extractor applied to raw timings.
+    ///
+    /// **Default:** [`ExtractionMode::XorFold`]
+    pub extraction: ExtractionMode,
 }
 
 impl Default for PipeBufferConfig {
@@ -59,6 +67,7 @@ impl Default for PipeBufferConfig {
             min_write_size: 1,
             max_write_size: 4096,
             non_blocking: true,
+            extraction: ExtractionMode::XorFold,
         }
     }
 }
@@ -109,10 +118,13 @@ static PIPE_BUFFER_INFO: SourceInfo = SourceInfo {
               contention. Variable write sizes exercise different mbuf paths. Non-blocking mode \
               captures EAGAIN timing on different kernel failure paths. Zone allocator timing \
               depends on zone fragmentation, magazine layer state, and cross-CPU transfers.",
+    failure_hint: "Narrow timing spread means the allocator is uncontended; expect lower entropy when the system is idle.",
     category: SourceCategory::IPC,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 1500.0,
+    typical_latency_ms: 20.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -205,7 +217,7 @@ impl EntropySource for PipeBufferSource {
             }
         }
 
-        extract_timing_entropy(&timings, n_samples)
+        self.config.extraction.extract(&timings, n_samples)
     }
 }
 
@@ -239,7 +251,7 @@ impl PipeBufferSource {
             timings.push(t1.wrapping_sub(t0));
         }
 
-        extract_timing_entropy(&timings, n_samples)
+        self.config.extraction.extract(&timings, n_samples)
     }
 }
 
@@ -272,6 +284,7 @@ mod tests {
                 min_write_size: 64,
                 max_write_size: 1024,
                 non_blocking: false,
+                extraction: ExtractionMode::Variance,
             },
         };
         assert_eq!(src.config.num_pipes, 8);
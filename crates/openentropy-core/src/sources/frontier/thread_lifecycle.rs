@@ -2,8 +2,10 @@
 
 use std::thread;
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
-use crate::sources::helpers::{extract_timing_entropy, mach_time};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::sources::helpers::{TimingParams, TunableSource, mach_time};
+
+use super::{ExtractionMode, FRONTIER_TIMING};
 
 /// Harvests timing jitter from thread creation and destruction.
 ///
@@ -28,7 +30,7 @@ use crate::sources::helpers::{extract_timing_entropy, mach_time};
 /// of any frontier source.
 ///
 /// # Configuration
-/// No configuration needed — this source has no tunable parameters.
+/// Only the raw oversampling is tunable, via `timing`.
 pub struct ThreadLifecycleSource;
 
 static THREAD_LIFECYCLE_INFO: SourceInfo = SourceInfo {
@@ -39,10 +41,13 @@ static THREAD_LIFECYCLE_INFO: SourceInfo = SourceInfo {
               CPU core selection (P-core vs E-core), stack page allocation, TLS setup, and \
               context switch on join. The scheduler\u{2019}s core selection depends on thermal \
               state, load from ALL processes, and QoS priorities.",
+    failure_hint: "Low entropy when thread creation is served from a warm pool and the scheduler is idle.",
     category: SourceCategory::Scheduling,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 3000.0,
+    typical_latency_ms: 50.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -56,7 +61,17 @@ impl EntropySource for ThreadLifecycleSource {
     }
 
     fn collect(&self, n_samples: usize) -> Vec<u8> {
-        let raw_count = n_samples * 4 + 64;
+        self.collect_tuned(n_samples, Self::DEFAULT_TUNING)
+    }
+}
+
+impl TunableSource for ThreadLifecycleSource {
+    type Tuning = TimingParams;
+    const DEFAULT_TUNING: TimingParams =
+        TimingParams::new(FRONTIER_TIMING, ExtractionMode::XorFold);
+
+    fn collect_tuned(&self, n_samples: usize, tuning: TimingParams) -> Vec<u8> {
+        let raw_count = tuning.timing.raw_count(n_samples);
         let mut timings: Vec<u64> = Vec::with_capacity(raw_count);
         let mut lcg: u64 = mach_time() | 1;
 
@@ -77,7 +92,7 @@ impl EntropySource for ThreadLifecycleSource {
             timings.push(t1.wrapping_sub(t0));
         }
 
-        extract_timing_entropy(&timings, n_samples)
+        tuning.extraction.extract(&timings, n_samples)
     }
 }
 
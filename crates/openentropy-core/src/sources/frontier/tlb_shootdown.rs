@@ -1,6 +1,6 @@
 //! TLB shootdown timing — entropy from mprotect-induced IPI broadcasts.
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
 use crate::sources::helpers::{extract_timing_entropy, mach_time};
 
 use super::extract_timing_entropy_variance;
@@ -104,10 +104,13 @@ static TLB_SHOOTDOWN_INFO: SourceInfo = SourceInfo {
               shootdowns captures relative timing with higher min-entropy. IPI latency depends \
               on: what each core is executing, P-core vs E-core cluster latency, core power \
               states, and concurrent IPI traffic.",
+    failure_hint: "Low entropy on single-core systems, where mprotect triggers no cross-core IPIs.",
     category: SourceCategory::Microarch,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 2000.0,
+    typical_latency_ms: 30.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -10,9 +10,10 @@
 //! - Oscillator circuit Johnson-Nyquist noise
 //!
 
-use crate::source::{EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
-#[cfg(target_os = "macos")]
-use crate::sources::helpers::extract_timing_entropy;
+use crate::source::{CostClass, EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
+use crate::sources::helpers::{TimingParams, TunableSource};
+
+use super::{ExtractionMode, FRONTIER_TIMING};
 
 static USB_TIMING_INFO: SourceInfo = SourceInfo {
     name: "usb_timing",
@@ -23,10 +24,13 @@ static USB_TIMING_INFO: SourceInfo = SourceInfo {
               from quartz lattice phonon excitations, load capacitance Johnson-Nyquist noise, \
               and oscillator circuit thermal fluctuations. Timing jitter also includes USB \
               bus arbitration contention.",
+    failure_hint: "Empty output means no USB devices or IORegistry access; constant timings indicate cached query results.",
     category: SourceCategory::IO,
     platform: Platform::MacOS,
     requirements: &[Requirement::Usb, Requirement::IOKit],
     entropy_rate_estimate: 1500.0,
+    typical_latency_ms: 50.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -175,9 +179,19 @@ impl EntropySource for USBTimingSource {
     }
 
     fn collect(&self, n_samples: usize) -> Vec<u8> {
+        self.collect_tuned(n_samples, Self::DEFAULT_TUNING)
+    }
+}
+
+impl TunableSource for USBTimingSource {
+    type Tuning = TimingParams;
+    const DEFAULT_TUNING: TimingParams =
+        TimingParams::new(FRONTIER_TIMING, ExtractionMode::XorFold);
+
+    fn collect_tuned(&self, n_samples: usize, tuning: TimingParams) -> Vec<u8> {
         #[cfg(not(target_os = "macos"))]
         {
-            let _ = n_samples;
+            let _ = (n_samples, tuning);
             Vec::new()
         }
 
@@ -188,7 +202,7 @@ impl EntropySource for USBTimingSource {
                 return Vec::new();
             }
 
-            let raw_count = n_samples * 4 + 64;
+            let raw_count = tuning.timing.raw_count(n_samples);
             let mut timings: Vec<u64> = Vec::with_capacity(raw_count);
 
             let property_keys: &[&[u8]] = &[b"sessionID\0", b"USB Address\0"];
@@ -208,7 +222,7 @@ impl EntropySource for USBTimingSource {
             }
             drop(devices);
 
-            extract_timing_entropy(&timings, n_samples)
+            tuning.extraction.extract(&timings, n_samples)
         }
     }
 }
@@ -10,7 +10,7 @@ use std::time::Instant;
 
 use tempfile::NamedTempFile;
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
 
 /// Path to the sips binary on macOS.
 const SIPS_PATH: &str = "/usr/bin/sips";
@@ -23,10 +23,13 @@ static GPU_TIMING_INFO: SourceInfo = SourceInfo {
               memory bank conflicts, warp/wavefront scheduling, power throttling, and memory \
               controller arbitration between GPU cores, CPU, and Neural Engine on Apple \
               Silicon's unified memory.",
+    failure_hint: "Empty output means sips or the GPU path is unavailable; a narrow timing spread suggests the work ran on a CPU fallback.",
     category: SourceCategory::GPU,
     platform: Platform::MacOS,
     requirements: &[],
     entropy_rate_estimate: 300.0,
+    typical_latency_ms: 1500.0,
+    cost_class: CostClass::Medium,
     composite: false,
 };
 
@@ -3,6 +3,10 @@
 //! This module prevents code duplication across sources that need common
 //! low-level primitives like high-resolution timestamps and LSB extraction.
 
+use crate::source::{Availability, EntropySource, SourceInfo};
+
+use super::frontier::ExtractionMode;
+
 // ---------------------------------------------------------------------------
 // High-resolution timing
 // ---------------------------------------------------------------------------
@@ -32,39 +36,14 @@ pub fn mach_time() -> u64 {
 }
 
 // ---------------------------------------------------------------------------
-// LSB extraction
+// Extraction primitives
 // ---------------------------------------------------------------------------
 
-/// Pack a stream of individual bits (0 or 1) into bytes (MSB-first packing).
-///
-/// For every 8 input bits, one output byte is produced.
-fn pack_bits_into_bytes(bits: &[u8]) -> Vec<u8> {
-    let mut bytes = Vec::with_capacity(bits.len() / 8 + 1);
-    for chunk in bits.chunks(8) {
-        let mut byte = 0u8;
-        for (i, &bit) in chunk.iter().enumerate() {
-            byte |= bit << (7 - i);
-        }
-        bytes.push(byte);
-    }
-    bytes
-}
-
-/// Extract the least-significant bit of each `u64` delta and pack into bytes.
-///
-/// For every 8 input values, one output byte is produced (MSB-first packing).
-pub fn extract_lsbs_u64(deltas: &[u64]) -> Vec<u8> {
-    let bits: Vec<u8> = deltas.iter().map(|d| (d & 1) as u8).collect();
-    pack_bits_into_bytes(&bits)
-}
-
-/// Extract the least-significant bit of each `i64` delta and pack into bytes.
-///
-/// Identical to [`extract_lsbs_u64`] but for signed deltas.
-pub fn extract_lsbs_i64(deltas: &[i64]) -> Vec<u8> {
-    let bits: Vec<u8> = deltas.iter().map(|d| (d & 1) as u8).collect();
-    pack_bits_into_bytes(&bits)
-}
+// Pure extraction helpers live in the `no_std` `openentropy-primitives`
+// crate; re-exported here so existing `helpers::` paths keep working.
+pub use openentropy_primitives::{
+    extract_lsbs_i64, extract_lsbs_u64, extract_timing_entropy, xor_fold_u64,
+};
 
 // ---------------------------------------------------------------------------
 // Shared command utilities
@@ -269,46 +248,140 @@ pub fn read_cntvct() -> u64 {
 // XOR-fold
 // ---------------------------------------------------------------------------
 
-/// XOR-fold all 8 bytes of a `u64` into a single byte.
+// ---------------------------------------------------------------------------
+// Timing oversampling
+// ---------------------------------------------------------------------------
+
+/// How many raw timings a timing source gathers per requested output byte.
+///
+/// Timing sources discard part of their raw stream during extraction
+/// (delta-of-deltas, debiasing, truncation), so they take more raw
+/// measurements than bytes requested. Raising `oversample` gives the
+/// extractor more raw material on hardware with coarse timers, at the cost
+/// of slower collection.
 ///
-/// Preserves entropy from every byte position instead of discarding the
-/// upper 7 bytes. Used by timing-based sources where entropy is spread
-/// across multiple byte positions.
-#[inline]
-pub fn xor_fold_u64(v: u64) -> u8 {
-    let b = v.to_le_bytes();
-    b[0] ^ b[1] ^ b[2] ^ b[3] ^ b[4] ^ b[5] ^ b[6] ^ b[7]
+/// `oversample` is clamped to [`MAX_OVERSAMPLE`] and `min_raw` to
+/// [`MAX_MIN_RAW`], so a misconfigured value cannot ask a source to
+/// preallocate an unbounded timing buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimingConfig {
+    /// Raw timings collected per requested output byte. Values outside
+    /// `1..=MAX_OVERSAMPLE` are clamped.
+    pub oversample: usize,
+    /// Extra raw timings added on top, so small requests still have enough
+    /// deltas to extract from. Clamped to [`MAX_MIN_RAW`].
+    pub min_raw: usize,
 }
 
-// ---------------------------------------------------------------------------
-// Timing entropy extraction
-// ---------------------------------------------------------------------------
+impl TimingConfig {
+    /// Construct a config; usable in `const` contexts.
+    pub const fn new(oversample: usize, min_raw: usize) -> Self {
+        Self {
+            oversample,
+            min_raw,
+        }
+    }
+
+    /// Number of raw timings to collect for `n_samples` output bytes.
+    pub fn raw_count(&self, n_samples: usize) -> usize {
+        n_samples
+            .saturating_mul(self.oversample.clamp(1, MAX_OVERSAMPLE))
+            .saturating_add(self.min_raw.min(MAX_MIN_RAW))
+    }
+}
+
+impl Default for TimingConfig {
+    fn default() -> Self {
+        Self::new(10, 64)
+    }
+}
+
+/// Largest effective [`TimingConfig::oversample`].
+pub const MAX_OVERSAMPLE: usize = 1024;
 
-/// Extract entropy bytes from a slice of raw timestamps.
+/// Largest effective [`TimingConfig::min_raw`].
+pub const MAX_MIN_RAW: usize = 1 << 16;
+
+/// Oversampling and extractor for a timing source that supports both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimingParams {
+    /// Raw timing oversampling.
+    pub timing: TimingConfig,
+    /// Extractor applied to raw timings.
+    pub extraction: ExtractionMode,
+}
+
+impl TimingParams {
+    /// Construct params; usable in `const` contexts.
+    pub const fn new(timing: TimingConfig, extraction: ExtractionMode) -> Self {
+        Self { timing, extraction }
+    }
+}
+
+/// A timing source whose collection can be tuned.
+///
+/// The source itself collects with [`DEFAULT_TUNING`](Self::DEFAULT_TUNING).
+/// Call [`tuned`](Self::tuned) to get a [`Tuned`] source that collects with
+/// other settings:
 ///
-/// Computes consecutive deltas, XORs adjacent deltas for mixing, then
-/// XOR-folds each 8-byte value into one output byte. Returns at most
-/// `n_samples` bytes.
+/// ```
+/// use openentropy_core::EntropySource;
+/// use openentropy_core::sources::cross_domain::CPUMemoryBeatSource;
+/// use openentropy_core::sources::frontier::ExtractionMode;
+/// use openentropy_core::sources::helpers::{TimingConfig, TimingParams, TunableSource};
 ///
-/// Requires at least 4 input timings to produce any output (2 deltas
-/// needed for the XOR mixing step).
-pub fn extract_timing_entropy(timings: &[u64], n_samples: usize) -> Vec<u8> {
-    if timings.len() < 2 {
-        return Vec::new();
+/// let src = CPUMemoryBeatSource.tuned(TimingParams::new(
+///     TimingConfig::new(20, 128),
+///     ExtractionMode::Variance,
+/// ));
+/// assert_eq!(src.name(), "cpu_memory_beat");
+/// ```
+pub trait TunableSource: EntropySource + Sized {
+    /// Tuning parameters: [`TimingConfig`], or [`TimingParams`] for sources
+    /// that also take an [`ExtractionMode`].
+    type Tuning: Copy + Send + Sync;
+
+    /// Tuning used by [`EntropySource::collect`] on the plain source.
+    const DEFAULT_TUNING: Self::Tuning;
+
+    /// Collect up to `n_samples` bytes using `tuning`.
+    fn collect_tuned(&self, n_samples: usize, tuning: Self::Tuning) -> Vec<u8>;
+
+    /// Wrap this source so it collects with `tuning`.
+    fn tuned(self, tuning: Self::Tuning) -> Tuned<Self> {
+        Tuned {
+            source: self,
+            tuning,
+        }
     }
+}
 
-    let deltas: Vec<u64> = timings
-        .windows(2)
-        .map(|w| w[1].wrapping_sub(w[0]))
-        .collect();
+/// A [`TunableSource`] paired with non-default tuning.
+///
+/// Reports the wrapped source's info and availability unchanged.
+pub struct Tuned<S: TunableSource> {
+    /// The wrapped source.
+    pub source: S,
+    /// Tuning passed to every collection.
+    pub tuning: S::Tuning,
+}
 
-    // XOR consecutive deltas for mixing (not conditioning — just combines adjacent values)
-    let xored: Vec<u64> = deltas.windows(2).map(|w| w[0] ^ w[1]).collect();
+impl<S: TunableSource> EntropySource for Tuned<S> {
+    fn info(&self) -> &SourceInfo {
+        self.source.info()
+    }
+
+    fn is_available(&self) -> bool {
+        self.source.is_available()
+    }
 
-    // XOR-fold all 8 bytes of each value into one byte
-    let mut raw: Vec<u8> = xored.iter().map(|&x| xor_fold_u64(x)).collect();
-    raw.truncate(n_samples);
-    raw
+    fn availability(&self) -> Availability {
+        self.source.availability()
+    }
+
+    fn collect(&self, n_samples: usize) -> Vec<u8> {
+        self.source.collect_tuned(n_samples, self.tuning)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -394,6 +467,71 @@ pub fn extract_delta_bytes_i64(deltas: &[i64], n_samples: usize) -> Vec<u8> {
 mod tests {
     use super::*;
 
+    // -----------------------------------------------------------------------
+    // Timing oversampling tests
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn timing_config_default() {
+        let cfg = TimingConfig::default();
+        assert_eq!(cfg.oversample, 10);
+        assert_eq!(cfg.min_raw, 64);
+        assert_eq!(cfg.raw_count(100), 1064);
+    }
+
+    #[test]
+    fn timing_config_higher_oversample_collects_more() {
+        let low = TimingConfig::new(4, 64);
+        let high = TimingConfig::new(16, 64);
+        assert!(high.raw_count(256) > low.raw_count(256));
+        // Zero oversample still collects one timing per byte.
+        assert_eq!(TimingConfig::new(0, 0).raw_count(32), 32);
+    }
+
+    #[test]
+    fn timing_config_clamps_huge_values() {
+        let cfg = TimingConfig::new(usize::MAX, usize::MAX);
+        assert_eq!(cfg.raw_count(2), 2 * MAX_OVERSAMPLE + MAX_MIN_RAW);
+    }
+
+    /// Returns one byte per raw timing it was asked to take.
+    struct RawCountSource;
+
+    impl EntropySource for RawCountSource {
+        fn info(&self) -> &SourceInfo {
+            // Borrow a real source's info; only the collected length matters.
+            let src: &'static crate::sources::cross_domain::CPUIOBeatSource =
+                &crate::sources::cross_domain::CPUIOBeatSource;
+            src.info()
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+
+        fn collect(&self, n_samples: usize) -> Vec<u8> {
+            self.collect_tuned(n_samples, Self::DEFAULT_TUNING)
+        }
+    }
+
+    impl TunableSource for RawCountSource {
+        type Tuning = TimingConfig;
+        const DEFAULT_TUNING: TimingConfig = TimingConfig::new(1, 0);
+
+        fn collect_tuned(&self, n_samples: usize, tuning: TimingConfig) -> Vec<u8> {
+            vec![0; tuning.raw_count(n_samples)]
+        }
+    }
+
+    #[test]
+    fn tuned_source_collects_with_its_tuning() {
+        assert_eq!(RawCountSource.collect(8).len(), 8);
+        let src = RawCountSource.tuned(TimingConfig::new(4, 16));
+        assert_eq!(src.collect(8).len(), 48);
+        let huge = RawCountSource.tuned(TimingConfig::new(usize::MAX, usize::MAX));
+        assert_eq!(huge.collect(2).len(), 2 * MAX_OVERSAMPLE + MAX_MIN_RAW);
+    }
+
     // -----------------------------------------------------------------------
     // LSB extraction tests
     // -----------------------------------------------------------------------
@@ -462,20 +600,20 @@ mod tests {
     }
 
     // -----------------------------------------------------------------------
-    // pack_bits_into_bytes tests
+    // pack_bits tests
     // -----------------------------------------------------------------------
 
     #[test]
     fn pack_bits_empty() {
         let bits: Vec<u8> = vec![];
-        let bytes = pack_bits_into_bytes(&bits);
+        let bytes = openentropy_primitives::pack_bits(&bits);
         assert!(bytes.is_empty());
     }
 
     #[test]
     fn pack_bits_full_byte() {
         let bits = vec![1, 0, 1, 0, 1, 0, 1, 0];
-        let bytes = pack_bits_into_bytes(&bits);
+        let bytes = openentropy_primitives::pack_bits(&bits);
         assert_eq!(bytes, vec![0b10101010]);
     }
 
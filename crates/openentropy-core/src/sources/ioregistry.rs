@@ -7,7 +7,7 @@ use std::collections::HashMap;
 use std::thread;
 use std::time::Duration;
 
-use crate::source::{EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
 
 use super::helpers::{extract_delta_bytes_i64, run_command};
 
@@ -28,10 +28,13 @@ static IOREGISTRY_INFO: SourceInfo = SourceInfo {
               buffer allocations, DART IOMMU activity, Mach port counts, and display \
               vsync counters. Each counter is driven by independent hardware subsystems. \
               The LSBs of their deltas capture silicon-level activity across the entire SoC.",
+    failure_hint: "Few changing counters or long repeated runs mean the machine is idle or IORegistry access is restricted.",
     category: SourceCategory::System,
     platform: Platform::MacOS,
     requirements: &[Requirement::IOKit],
     entropy_rate_estimate: 1000.0,
+    typical_latency_ms: 400.0,
+    cost_class: CostClass::Medium,
     composite: false,
 };
 
@@ -6,7 +6,7 @@
 use std::ptr;
 use std::time::Instant;
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
 
 /// Page size for mmap allocations (4 KB on most platforms).
 const PAGE_SIZE: usize = 4096;
@@ -18,10 +18,13 @@ static MEMORY_TIMING_INFO: SourceInfo = SourceInfo {
               comes from heap fragmentation, page fault handling, and kernel memory pressure. \
               Access timing varies with: DRAM refresh interference (~64ms cycle), cache \
               hierarchy state (L1/L2/L3 hits vs misses), and memory controller scheduling.",
+    failure_hint: "A narrow timing spread means allocations are served from a warm cache; expect reduced entropy in VMs.",
     category: SourceCategory::Timing,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 1500.0,
+    typical_latency_ms: 5.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -31,11 +31,45 @@ pub mod timing;
 pub mod vmstat;
 pub mod wifi;
 
+use std::sync::Mutex;
+
 use crate::source::EntropySource;
 
+/// Constructor for an externally provided entropy source.
+pub type SourceFactory = fn() -> Box<dyn EntropySource>;
+
+/// Factories registered by downstream crates via [`register_source`], keyed
+/// by the name of the source each one builds.
+static EXTERNAL_SOURCES: Mutex<Vec<(&'static str, SourceFactory)>> = Mutex::new(Vec::new());
+
+/// Register an external entropy source constructor.
+///
+/// Registered sources are appended to [`all_sources`] (and therefore picked up
+/// by `detect_available_sources()` and `EntropyPool::auto()` when their
+/// `is_available()` returns true). The factory is called once here to learn
+/// the source's name; registering a second factory for a name already
+/// registered is a no-op, so the first one wins. Source names should not
+/// collide with built-in sources.
+pub fn register_source(factory: SourceFactory) {
+    let name = factory().name();
+    let mut registry = EXTERNAL_SOURCES.lock().unwrap_or_else(|e| e.into_inner());
+    if !registry.iter().any(|&(registered, _)| registered == name) {
+        registry.push((name, factory));
+    }
+}
+
+/// Instantiate every externally registered source.
+fn external_sources() -> Vec<Box<dyn EntropySource>> {
+    let registry = EXTERNAL_SOURCES.lock().unwrap_or_else(|e| e.into_inner());
+    registry.iter().map(|(_, factory)| factory()).collect()
+}
+
 /// All entropy source constructors. Each returns a boxed source.
+///
+/// Built-in sources come first, followed by any registered via
+/// [`register_source`].
 pub fn all_sources() -> Vec<Box<dyn EntropySource>> {
-    vec![
+    let mut sources: Vec<Box<dyn EntropySource>> = vec![
         // Timing
         Box::new(timing::ClockJitterSource),
         Box::new(timing::MachTimingSource),
@@ -97,5 +131,10 @@ pub fn all_sources() -> Vec<Box<dyn EntropySource>> {
         // Frontier: independent oscillator/PLL sources (2026-02-15)
         Box::new(frontier::DisplayPllSource),
         Box::new(frontier::PciePllSource),
-    ]
+    ];
+    // Hardware RNG instruction (x86_64 only)
+    #[cfg(target_arch = "x86_64")]
+    sources.push(Box::new(silicon::RdseedSource));
+    sources.extend(external_sources());
+    sources
 }
@@ -3,12 +3,67 @@
 //! These sources exploit the inherent unpredictability in network round-trip
 //! times, which arise from queuing delays, congestion, server load, NIC
 //! interrupt coalescing, and electromagnetic propagation variations.
+//!
+//! Transient failures are retried according to [`RetryConfig`]. On an
+//! offline machine [`EntropySource::is_available`] probes once without
+//! retrying, so detection marks both sources unavailable quickly (usually
+//! immediately, at most one 2 s timeout) instead of hanging.
 
 use std::net::{SocketAddr, TcpStream, UdpSocket};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
+
+// ---------------------------------------------------------------------------
+// Retry policy
+// ---------------------------------------------------------------------------
+
+/// Retry policy for transient network failures.
+///
+/// Each query is attempted up to `attempts` times, sleeping `backoff_ms`
+/// before the first retry and doubling the delay after each further failure.
+/// Collection only gives up (returning a short buffer, which the pool counts
+/// as a failure) once every attempt for a query has failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// Total attempts per query. Values below 1 are treated as 1.
+    pub attempts: u32,
+    /// Delay before the first retry, in milliseconds.
+    pub backoff_ms: u64,
+}
+
+impl RetryConfig {
+    /// Construct a config; usable in `const` contexts.
+    pub const fn new(attempts: u32, backoff_ms: u64) -> Self {
+        Self {
+            attempts,
+            backoff_ms,
+        }
+    }
+
+    /// Run `op` until it returns `Some` or the attempts are exhausted.
+    pub fn run<T>(&self, mut op: impl FnMut() -> Option<T>) -> Option<T> {
+        let attempts = self.attempts.max(1);
+        let mut backoff = Duration::from_millis(self.backoff_ms);
+        for attempt in 1..=attempts {
+            if let Some(value) = op() {
+                return Some(value);
+            }
+            if attempt < attempts {
+                std::thread::sleep(backoff);
+                backoff = backoff.saturating_mul(2);
+            }
+        }
+        None
+    }
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self::new(3, 50)
+    }
+}
 
 // ---------------------------------------------------------------------------
 // DNS timing source
@@ -23,11 +78,13 @@ const DNS_TIMEOUT: Duration = Duration::from_secs(2);
 /// sent to public resolvers. Timing jitter in the nanosecond range is
 /// harvested as raw entropy.
 ///
-/// No tunable parameters — cycles through a fixed set of public DNS servers
-/// and hostnames automatically.
+/// Cycles through a fixed set of public DNS servers and hostnames; each retry
+/// moves on to the next pair.
 pub struct DNSTimingSource {
     /// Monotonically increasing index used to cycle through servers/hostnames.
     index: AtomicUsize,
+    /// Retry policy for failed queries.
+    pub retry: RetryConfig,
 }
 
 static DNS_TIMING_INFO: SourceInfo = SourceInfo {
@@ -37,17 +94,26 @@ static DNS_TIMING_INFO: SourceInfo = SourceInfo {
               Jitter comes from: network switch queuing, router buffer state, \
               ISP congestion, DNS server load, TCP/IP stack scheduling, NIC \
               interrupt coalescing, and electromagnetic propagation variations.",
+    failure_hint: "Empty output means the resolvers are unreachable; entropy that drops with a local caching resolver reflects cache hits, not network jitter.",
     category: SourceCategory::Network,
     platform: Platform::Any,
-    requirements: &[],
+    requirements: &[Requirement::Network],
     entropy_rate_estimate: 100.0,
+    typical_latency_ms: 3000.0,
+    cost_class: CostClass::Slow,
     composite: false,
 };
 
 impl DNSTimingSource {
     pub fn new() -> Self {
+        Self::with_retry(RetryConfig::default())
+    }
+
+    /// Create a source with a custom retry policy.
+    pub fn with_retry(retry: RetryConfig) -> Self {
         Self {
             index: AtomicUsize::new(0),
+            retry,
         }
     }
 }
@@ -127,44 +193,48 @@ impl EntropySource for DNSTimingSource {
         let mut prev_nanos: Option<u128> = None;
 
         while entropy.len() < n_samples {
-            let idx = self.index.fetch_add(1, Ordering::Relaxed);
-            let server = DNS_SERVERS[idx % server_count];
-            let hostname = DNS_HOSTNAMES[idx % hostname_count];
-
-            if let Some(nanos) = dns_query_rtt(server, hostname, DNS_TIMEOUT) {
-                // Extract least-significant bytes of the RTT (most jittery bits).
-                let nanos_bytes = nanos.to_le_bytes(); // 16 bytes (u128)
-
-                // LSB of the raw RTT
-                entropy.push(nanos_bytes[0]);
-                if entropy.len() >= n_samples {
-                    break;
-                }
+            let rtt = self.retry.run(|| {
+                let idx = self.index.fetch_add(1, Ordering::Relaxed);
+                let server = DNS_SERVERS[idx % server_count];
+                let hostname = DNS_HOSTNAMES[idx % hostname_count];
+                dns_query_rtt(server, hostname, DNS_TIMEOUT)
+            });
+            // Every attempt failed: return what we have rather than spin.
+            let Some(nanos) = rtt else {
+                break;
+            };
+
+            // Extract least-significant bytes of the RTT (most jittery bits).
+            let nanos_bytes = nanos.to_le_bytes(); // 16 bytes (u128)
+
+            // LSB of the raw RTT
+            entropy.push(nanos_bytes[0]);
+            if entropy.len() >= n_samples {
+                break;
+            }
 
-                // Second byte has some entropy too
-                entropy.push(nanos_bytes[1]);
-                if entropy.len() >= n_samples {
-                    break;
-                }
+            // Second byte has some entropy too
+            entropy.push(nanos_bytes[1]);
+            if entropy.len() >= n_samples {
+                break;
+            }
 
-                // XOR of byte 0 and byte 1 for mixing
-                entropy.push(nanos_bytes[0] ^ nanos_bytes[1]);
-                if entropy.len() >= n_samples {
-                    break;
-                }
+            // XOR of byte 0 and byte 1 for mixing
+            entropy.push(nanos_bytes[0] ^ nanos_bytes[1]);
+            if entropy.len() >= n_samples {
+                break;
+            }
 
-                // Timing delta from previous query (inter-query jitter)
-                if let Some(prev) = prev_nanos {
-                    let delta = nanos.abs_diff(prev);
-                    let delta_bytes = delta.to_le_bytes();
-                    entropy.push(delta_bytes[0]);
-                    if entropy.len() < n_samples {
-                        entropy.push(delta_bytes[1]);
-                    }
+            // Timing delta from previous query (inter-query jitter)
+            if let Some(prev) = prev_nanos {
+                let delta = nanos.abs_diff(prev);
+                let delta_bytes = delta.to_le_bytes();
+                entropy.push(delta_bytes[0]);
+                if entropy.len() < n_samples {
+                    entropy.push(delta_bytes[1]);
                 }
-                prev_nanos = Some(nanos);
             }
-            // On failure, just move on to the next server/hostname pair.
+            prev_nanos = Some(nanos);
         }
 
         entropy.truncate(n_samples);
@@ -183,11 +253,13 @@ const TCP_TIMEOUT: Duration = Duration::from_secs(2);
 /// The nanosecond-resolution timing captures NIC DMA jitter, kernel buffer
 /// allocation, remote server load, and network path congestion.
 ///
-/// No tunable parameters — cycles through a fixed set of TCP targets
-/// automatically.
+/// Cycles through a fixed set of TCP targets; each retry moves on to the next
+/// target.
 pub struct TCPConnectSource {
     /// Monotonically increasing index used to cycle through targets.
     index: AtomicUsize,
+    /// Retry policy for failed handshakes.
+    pub retry: RetryConfig,
 }
 
 static TCP_CONNECT_INFO: SourceInfo = SourceInfo {
@@ -197,17 +269,26 @@ static TCP_CONNECT_INFO: SourceInfo = SourceInfo {
               The timing captures: NIC DMA transfer jitter, kernel socket \
               buffer allocation, remote server load, network path congestion, \
               and router queuing delays.",
+    failure_hint: "Empty output means outbound connections are blocked; a tight latency cluster suggests a local proxy is answering the handshakes.",
     category: SourceCategory::Network,
     platform: Platform::Any,
-    requirements: &[],
+    requirements: &[Requirement::Network],
     entropy_rate_estimate: 50.0,
+    typical_latency_ms: 3000.0,
+    cost_class: CostClass::Slow,
     composite: false,
 };
 
 impl TCPConnectSource {
     pub fn new() -> Self {
+        Self::with_retry(RetryConfig::default())
+    }
+
+    /// Create a source with a custom retry policy.
+    pub fn with_retry(retry: RetryConfig) -> Self {
         Self {
             index: AtomicUsize::new(0),
+            retry,
         }
     }
 }
@@ -242,40 +323,43 @@ impl EntropySource for TCPConnectSource {
         let mut prev_nanos: Option<u128> = None;
 
         while entropy.len() < n_samples {
-            let idx = self.index.fetch_add(1, Ordering::Relaxed);
-            let target = TCP_TARGETS[idx % target_count];
-
-            if let Some(nanos) = tcp_connect_rtt(target, TCP_TIMEOUT) {
-                let nanos_bytes = nanos.to_le_bytes();
-
-                // Least-significant bytes of the handshake RTT
-                entropy.push(nanos_bytes[0]);
-                if entropy.len() >= n_samples {
-                    break;
-                }
+            let rtt = self.retry.run(|| {
+                let idx = self.index.fetch_add(1, Ordering::Relaxed);
+                tcp_connect_rtt(TCP_TARGETS[idx % target_count], TCP_TIMEOUT)
+            });
+            let Some(nanos) = rtt else {
+                break;
+            };
+
+            let nanos_bytes = nanos.to_le_bytes();
+
+            // Least-significant bytes of the handshake RTT
+            entropy.push(nanos_bytes[0]);
+            if entropy.len() >= n_samples {
+                break;
+            }
 
-                entropy.push(nanos_bytes[1]);
-                if entropy.len() >= n_samples {
-                    break;
-                }
+            entropy.push(nanos_bytes[1]);
+            if entropy.len() >= n_samples {
+                break;
+            }
 
-                // XOR mix
-                entropy.push(nanos_bytes[0] ^ nanos_bytes[1]);
-                if entropy.len() >= n_samples {
-                    break;
-                }
+            // XOR mix
+            entropy.push(nanos_bytes[0] ^ nanos_bytes[1]);
+            if entropy.len() >= n_samples {
+                break;
+            }
 
-                // Timing delta from previous handshake
-                if let Some(prev) = prev_nanos {
-                    let delta = nanos.abs_diff(prev);
-                    let delta_bytes = delta.to_le_bytes();
-                    entropy.push(delta_bytes[0]);
-                    if entropy.len() < n_samples {
-                        entropy.push(delta_bytes[1]);
-                    }
+            // Timing delta from previous handshake
+            if let Some(prev) = prev_nanos {
+                let delta = nanos.abs_diff(prev);
+                let delta_bytes = delta.to_le_bytes();
+                entropy.push(delta_bytes[0]);
+                if entropy.len() < n_samples {
+                    entropy.push(delta_bytes[1]);
                 }
-                prev_nanos = Some(nanos);
             }
+            prev_nanos = Some(nanos);
         }
 
         entropy.truncate(n_samples);
@@ -314,6 +398,37 @@ mod tests {
         assert_eq!(&pkt[len - 4..], &[0x00, 0x01, 0x00, 0x01]);
     }
 
+    #[test]
+    fn retry_succeeds_after_transient_failures() {
+        // Mock query that fails twice, then succeeds.
+        let mut calls = 0;
+        let mut flaky = || {
+            calls += 1;
+            (calls > 2).then_some(calls)
+        };
+        assert_eq!(RetryConfig::new(3, 0).run(&mut flaky), Some(3));
+        assert_eq!(calls, 3);
+
+        calls = 0;
+        let mut flaky = || {
+            calls += 1;
+            (calls > 2).then_some(calls)
+        };
+        assert_eq!(RetryConfig::new(2, 0).run(&mut flaky), None);
+        assert_eq!(calls, 2);
+    }
+
+    #[test]
+    fn retry_zero_attempts_tries_once() {
+        let mut calls = 0;
+        let result: Option<()> = RetryConfig::new(0, 0).run(|| {
+            calls += 1;
+            None
+        });
+        assert_eq!(result, None);
+        assert_eq!(calls, 1);
+    }
+
     #[test]
     fn dns_source_info() {
         let src = DNSTimingSource::new();
@@ -7,7 +7,7 @@ use std::sync::mpsc;
 use std::thread;
 use std::time::{Duration, Instant};
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
 
 use super::helpers::extract_timing_entropy;
 
@@ -23,10 +23,13 @@ static DISPATCH_QUEUE_INFO: SourceInfo = SourceInfo {
               E-cores (efficiency) based on thermal state and load. The migration decisions, \
               queue priority inversions, and QoS tier scheduling create non-deterministic \
               dispatch timing.",
+    failure_hint: "Low entropy means the scheduler is uncontended and wakeups are quantized; strong autocorrelation follows timer coalescing.",
     category: SourceCategory::Scheduling,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 1500.0,
+    typical_latency_ms: 20.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -103,10 +106,13 @@ static VM_PAGE_TIMING_INFO: SourceInfo = SourceInfo {
               (IPI interrupt), and physical page management. The timing depends on: \
               VM map fragmentation, physical memory pressure, and cross-core \
               synchronization latency.",
+    failure_hint: "A narrow fault-latency spread means pages are reused from a warm cache; expect reduced entropy inside VMs.",
     category: SourceCategory::Timing,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 1300.0,
+    typical_latency_ms: 10.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -195,10 +201,13 @@ static SPOTLIGHT_TIMING_INFO: SourceInfo = SourceInfo {
               on: index size, disk cache residency, concurrent indexing activity, and \
               filesystem metadata state. When Spotlight is actively indexing new files, \
               query latency becomes highly variable.",
+    failure_hint: "Empty output means mdls failed or indexing is disabled; uniform timings indicate cached metadata replies.",
     category: SourceCategory::Signal,
     platform: Platform::MacOS,
     requirements: &[],
     entropy_rate_estimate: 800.0,
+    typical_latency_ms: 4000.0,
+    cost_class: CostClass::Slow,
     composite: false,
 };
 
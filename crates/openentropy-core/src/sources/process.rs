@@ -6,7 +6,7 @@
 
 use std::time::Instant;
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
 
 use super::helpers::run_command_raw;
 
@@ -27,10 +27,13 @@ static PROCESS_INFO: SourceInfo = SourceInfo {
               entropy from the constantly-changing state. New PIDs are allocated \
               semi-randomly, CPU percentages fluctuate with scheduling decisions, and \
               resident memory sizes shift with page reclamation.",
+    failure_hint: "Low entropy means a quiet system with few processes; identical snapshots across collections mean the process table is not being re-read.",
     category: SourceCategory::System,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 400.0,
+    typical_latency_ms: 300.0,
+    cost_class: CostClass::Medium,
     composite: false,
 };
 
@@ -1,10 +1,11 @@
 //! Silicon-level entropy sources that exploit CPU and DRAM microarchitecture
 //! timing: row buffer contention, cache hierarchy interference, page fault
-//! resolution, and speculative execution pipeline state.
+//! resolution, and speculative execution pipeline state, plus the on-die
+//! hardware RNG (RDSEED) on x86_64.
 
 use rand::Rng;
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
 
 use super::helpers::{extract_timing_entropy, mach_time};
 
@@ -28,10 +29,13 @@ static DRAM_ROW_BUFFER_INFO: SourceInfo = SourceInfo {
               which is slower. The exact timing depends on: physical address mapping, \
               row buffer state from ALL system activity, memory controller scheduling, \
               and DRAM refresh interference.",
+    failure_hint: "A narrow hit/miss histogram means accesses stay in cache (buffer too small) or the timer is too coarse to see DRAM latency.",
     category: SourceCategory::Timing,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 3000.0,
+    typical_latency_ms: 10.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -102,10 +106,13 @@ static CACHE_CONTENTION_INFO: SourceInfo = SourceInfo {
               \u{2014} the cache is a shared resource whose state is fundamentally \
               unpredictable. A cache miss requires main memory access (100+ ns vs \
               1 ns for L1 hit).",
+    failure_hint: "Low entropy or a narrow delta histogram means a coarse timer or no contention on a quiet core.",
     category: SourceCategory::Timing,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 2500.0,
+    typical_latency_ms: 10.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -197,10 +204,13 @@ static PAGE_FAULT_TIMING_INFO: SourceInfo = SourceInfo {
               requires: TLB lookup, hardware page table walk (up to 4 levels on ARM64), \
               physical page allocation from the kernel free list, and zero-fill for \
               security. The timing depends on physical memory fragmentation.",
+    failure_hint: "A narrow fault-latency spread means pages are recycled warm; expect reduced entropy inside VMs.",
     category: SourceCategory::Timing,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 1500.0,
+    typical_latency_ms: 10.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -291,10 +301,13 @@ static SPECULATIVE_EXECUTION_INFO: SourceInfo = SourceInfo {
               previously executed code. Mispredictions cause pipeline flushes (~15 cycle \
               penalty on M4). By running data-dependent branches and measuring timing, \
               we capture the predictor's internal state.",
+    failure_hint: "Low entropy means the branch predictor has converged and the timer is too coarse to resolve mispredictions.",
     category: SourceCategory::Microarch,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 2000.0,
+    typical_latency_ms: 5.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -363,6 +376,83 @@ impl EntropySource for SpeculativeExecutionSource {
     }
 }
 
+// ---------------------------------------------------------------------------
+// 5. RdseedSource (x86_64 only)
+// ---------------------------------------------------------------------------
+
+/// Reads the CPU's digital random number generator via the RDSEED
+/// instruction. Unlike RDRAND, RDSEED returns output straight from the
+/// conditioned entropy source rather than from a DRBG, so each value is
+/// fresh seed material.
+#[cfg(target_arch = "x86_64")]
+pub struct RdseedSource;
+
+/// RDSEED can transiently report "not ready" when the on-die source is
+/// drained; Intel recommends retrying with a pause between attempts.
+#[cfg(target_arch = "x86_64")]
+const RDSEED_RETRIES: usize = 128;
+
+#[cfg(target_arch = "x86_64")]
+static RDSEED_INFO: SourceInfo = SourceInfo {
+    name: "rdseed",
+    description: "x86_64 RDSEED instruction (on-die hardware entropy source)",
+    physics: "Reads the CPU's digital random number generator. A metastable latch \
+              circuit is driven by thermal noise; its resolved bits are health-tested \
+              and conditioned on-die with AES-CBC-MAC before RDSEED returns them. \
+              Unlike RDRAND, RDSEED bypasses the DRBG stage, so every value is fresh \
+              entropy rather than expanded output.",
+    failure_hint: "Repeated retries or empty output mean RDSEED is unavailable or exhausted; any bias points to a faulty DRNG or hypervisor passthrough.",
+    category: SourceCategory::Thermal,
+    platform: Platform::Any,
+    requirements: &[],
+    entropy_rate_estimate: 50000.0,
+    typical_latency_ms: 1.0,
+    cost_class: CostClass::Fast,
+    composite: false,
+};
+
+/// One 64-bit RDSEED value, retrying while the hardware reports underflow.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "rdseed")]
+fn rdseed64() -> Option<u64> {
+    let mut value = 0u64;
+    for _ in 0..RDSEED_RETRIES {
+        if core::arch::x86_64::_rdseed64_step(&mut value) == 1 {
+            return Some(value);
+        }
+        std::hint::spin_loop();
+    }
+    None
+}
+
+#[cfg(target_arch = "x86_64")]
+impl EntropySource for RdseedSource {
+    fn info(&self) -> &SourceInfo {
+        &RDSEED_INFO
+    }
+
+    fn is_available(&self) -> bool {
+        std::arch::is_x86_feature_detected!("rdseed")
+    }
+
+    fn collect(&self, n_samples: usize) -> Vec<u8> {
+        if !self.is_available() {
+            return Vec::new();
+        }
+        let mut output = Vec::with_capacity(n_samples);
+        while output.len() < n_samples {
+            // SAFETY: rdseed support was verified by is_available() above.
+            let Some(value) = (unsafe { rdseed64() }) else {
+                // Source stayed drained through every retry; return what we have.
+                break;
+            };
+            let take = (n_samples - output.len()).min(8);
+            output.extend_from_slice(&value.to_le_bytes()[..take]);
+        }
+        output
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Tests
 // ---------------------------------------------------------------------------
@@ -448,4 +538,25 @@ mod tests {
         assert_eq!(PageFaultTimingSource.name(), "page_fault_timing");
         assert_eq!(SpeculativeExecutionSource.name(), "speculative_execution");
     }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn rdseed_collects_high_entropy_bytes() {
+        let src = RdseedSource;
+        if !src.is_available() {
+            return; // CPU without RDSEED
+        }
+        let data = src.collect(64);
+        assert_eq!(data.len(), 64);
+        let distinct = data.iter().collect::<std::collections::HashSet<_>>().len();
+        assert!(distinct > 40, "only {distinct} distinct bytes in 64");
+        assert!(crate::conditioning::quick_shannon(&data) > 5.0);
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn rdseed_info() {
+        assert_eq!(RdseedSource.name(), "rdseed");
+        assert_eq!(RdseedSource.info().category, SourceCategory::Thermal);
+    }
 }
@@ -6,7 +6,7 @@ use std::collections::HashMap;
 use std::thread;
 use std::time::Duration;
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
 
 use super::helpers::{extract_delta_bytes_i64, run_command};
 
@@ -31,10 +31,13 @@ static SYSCTL_INFO: SourceInfo = SourceInfo {
               switches, TCP segments, interrupts \u{2014} each driven by independent processes. \
               The LSBs of their deltas reflect the unpredictable micro-timing of the entire \
               operating system\u{2019}s activity.",
+    failure_hint: "Few changing counters or zero deltas mean the system is idle or sysctl output is restricted.",
     category: SourceCategory::System,
     platform: Platform::MacOS,
     requirements: &[],
     entropy_rate_estimate: 5000.0,
+    typical_latency_ms: 100.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
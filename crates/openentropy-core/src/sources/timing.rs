@@ -5,7 +5,7 @@
 use std::thread;
 use std::time::{Duration, Instant, SystemTime};
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
 
 // ---------------------------------------------------------------------------
 // ClockJitterSource
@@ -26,10 +26,13 @@ static CLOCK_JITTER_INFO: SourceInfo = SourceInfo {
               voltage-controlled oscillator causes random frequency drift — \
               the LSBs of their difference are genuine analog entropy from \
               crystal oscillator physics.",
+    failure_hint: "Constant or low-entropy deltas mean both clocks derive from the same counter with coarse resolution, common in VMs.",
     category: SourceCategory::Timing,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 0.5,
+    typical_latency_ms: 1.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -82,10 +85,13 @@ static MACH_TIMING_INFO: SourceInfo = SourceInfo {
               jitter comes from CPU pipeline state: instruction reordering, branch \
               prediction, cache state, interrupt coalescing, and power-state \
               transitions.",
+    failure_hint: "Long runs of identical LSBs mean the counter is coarse or virtualized; expect low entropy under a hypervisor.",
     category: SourceCategory::Timing,
     platform: Platform::MacOS,
     requirements: &[],
     entropy_rate_estimate: 0.3,
+    typical_latency_ms: 1.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -146,10 +152,13 @@ static SLEEP_JITTER_INFO: SourceInfo = SourceInfo {
               captures OS scheduler non-determinism: timer interrupt granularity (1-4ms), \
               thread priority decisions, runqueue length, and thermal-dependent clock \
               frequency scaling (DVFS).",
+    failure_hint: "Quantized wakeups in tight clusters mean timer slack or coalescing is hiding scheduler jitter.",
     category: SourceCategory::Scheduling,
     platform: Platform::Any,
     requirements: &[],
     entropy_rate_estimate: 0.4,
+    typical_latency_ms: 20.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -5,7 +5,7 @@ use std::collections::HashMap;
 use std::thread;
 use std::time::Duration;
 
-use crate::source::{EntropySource, Platform, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, SourceCategory, SourceInfo};
 
 use super::helpers::{extract_delta_bytes_i64, run_command};
 
@@ -29,10 +29,13 @@ static VMSTAT_INFO: SourceInfo = SourceInfo {
               compressions, decompressions, swap activity). These track physical memory \
               management \u{2014} each counter changes when hardware page table walks, TLB \
               misses, or memory pressure triggers compressor/swap.",
+    failure_hint: "Zero deltas between samples mean memory activity is idle; entropy rises under load.",
     category: SourceCategory::System,
     platform: Platform::MacOS,
     requirements: &[],
     entropy_rate_estimate: 1000.0,
+    typical_latency_ms: 100.0,
+    cost_class: CostClass::Fast,
     composite: false,
 };
 
@@ -9,7 +9,7 @@ use std::process::Command;
 use std::thread;
 use std::time::{Duration, Instant};
 
-use crate::source::{EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
+use crate::source::{CostClass, EntropySource, Platform, Requirement, SourceCategory, SourceInfo};
 
 const MEASUREMENT_DELAY: Duration = Duration::from_millis(10);
 const SAMPLES_PER_COLLECT: usize = 8;
@@ -40,10 +40,13 @@ static WIFI_RSSI_INFO: SourceInfo = SourceInfo {
               off walls/objects), constructive/destructive interference at \
               2.4/5/6 GHz, Rayleigh fading from moving objects, atmospheric \
               absorption, and thermal noise in the radio receiver's LNA.",
+    failure_hint: "Empty output means WiFi is off or the interface cannot be queried; a constant RSSI and noise floor is a stale reading.",
     category: SourceCategory::Network,
     platform: Platform::MacOS,
     requirements: &[Requirement::Wifi],
     entropy_rate_estimate: 30.0,
+    typical_latency_ms: 3000.0,
+    cost_class: CostClass::Slow,
     composite: false,
 };
 
@@ -6,16 +6,14 @@
 //! - leaves unavailable metrics as absent rather than guessing.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 #[cfg(target_os = "macos")]
 use std::io::Read;
 #[cfg(target_os = "linux")]
 use std::path::Path;
 #[cfg(target_os = "macos")]
 use std::process::Stdio;
-#[cfg(target_os = "macos")]
-use std::time::{Duration, Instant};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 /// Telemetry model identifier.
 pub const MODEL_ID: &str = "telemetry_v1";
@@ -70,6 +68,41 @@ pub struct TelemetryWindowReport {
     pub deltas: Vec<TelemetryMetricDelta>,
 }
 
+/// Deltas between consecutive samples of a telemetry series.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelemetrySeriesStep {
+    /// Time from the first sample to the end of this step.
+    pub elapsed_ms: u64,
+    /// Length of this step.
+    pub interval_ms: u64,
+    pub deltas: Vec<TelemetryMetricDelta>,
+}
+
+/// Min/max/mean of one metric over every sample that reported it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelemetryMetricStats {
+    pub domain: String,
+    pub name: String,
+    pub unit: String,
+    pub source: String,
+    pub samples: usize,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+}
+
+/// Periodically sampled telemetry window.
+///
+/// `window` holds first-to-last deltas, so existing window consumers (e.g.
+/// [`telemetry_confound_from_window`]) work on a series unchanged.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelemetrySeriesReport {
+    pub window: TelemetryWindowReport,
+    pub samples: Vec<TelemetrySnapshot>,
+    pub steps: Vec<TelemetrySeriesStep>,
+    pub stats: Vec<TelemetryMetricStats>,
+}
+
 fn unix_ms_now() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -1485,7 +1518,7 @@ fn collect_linux_freq_metrics(out: &mut Vec<TelemetryMetric>) {
     }
 
     let mut min_hz = f64::INFINITY;
-    let mut max_hz = 0.0;
+    let mut max_hz: f64 = 0.0;
     let mut sum_hz = 0.0;
     for (_, hz) in &values_hz {
         min_hz = min_hz.min(*hz);
@@ -1634,11 +1667,7 @@ fn delta_key(metric: &TelemetryMetric) -> String {
     )
 }
 
-/// Build a start/end telemetry report and aligned metric deltas.
-pub fn build_telemetry_window(
-    start: TelemetrySnapshot,
-    end: TelemetrySnapshot,
-) -> TelemetryWindowReport {
+fn aligned_deltas(start: &TelemetrySnapshot, end: &TelemetrySnapshot) -> Vec<TelemetryMetricDelta> {
     let end_map: HashMap<String, &TelemetryMetric> =
         end.metrics.iter().map(|m| (delta_key(m), m)).collect();
     let mut deltas = Vec::new();
@@ -1663,7 +1692,15 @@ pub fn build_telemetry_window(
             .then(a.name.cmp(&b.name))
             .then(a.source.cmp(&b.source))
     });
+    deltas
+}
 
+/// Build a start/end telemetry report and aligned metric deltas.
+pub fn build_telemetry_window(
+    start: TelemetrySnapshot,
+    end: TelemetrySnapshot,
+) -> TelemetryWindowReport {
+    let deltas = aligned_deltas(&start, &end);
     TelemetryWindowReport {
         model_id: MODEL_ID.to_string(),
         model_version: MODEL_VERSION,
@@ -1676,12 +1713,150 @@ pub fn build_telemetry_window(
     }
 }
 
+/// Build a series report: first-to-last window, per-step deltas, and
+/// per-metric min/max/mean across all samples.
+///
+/// Returns `None` for an empty series. A single sample yields a zero-length
+/// window and no steps.
+pub fn build_telemetry_series_window(
+    samples: Vec<TelemetrySnapshot>,
+) -> Option<TelemetrySeriesReport> {
+    let first = samples.first()?.clone();
+    let last = samples.last()?.clone();
+
+    let steps = samples
+        .windows(2)
+        .map(|pair| TelemetrySeriesStep {
+            elapsed_ms: pair[1]
+                .collected_unix_ms
+                .saturating_sub(first.collected_unix_ms),
+            interval_ms: pair[1]
+                .collected_unix_ms
+                .saturating_sub(pair[0].collected_unix_ms),
+            deltas: aligned_deltas(&pair[0], &pair[1]),
+        })
+        .collect();
+
+    // Keyed in the same domain/name/source order as deltas.
+    let mut acc: BTreeMap<[&str; 4], (usize, f64, f64, f64)> = BTreeMap::new();
+    for m in samples.iter().flat_map(|s| &s.metrics) {
+        let key = [
+            m.domain.as_str(),
+            m.name.as_str(),
+            m.source.as_str(),
+            m.unit.as_str(),
+        ];
+        let e = acc
+            .entry(key)
+            .or_insert((0, f64::INFINITY, f64::NEG_INFINITY, 0.0));
+        e.0 += 1;
+        e.1 = e.1.min(m.value);
+        e.2 = e.2.max(m.value);
+        e.3 += m.value;
+    }
+    let stats = acc
+        .into_iter()
+        .map(
+            |([domain, name, source, unit], (n, min, max, sum))| TelemetryMetricStats {
+                domain: domain.to_string(),
+                name: name.to_string(),
+                unit: unit.to_string(),
+                source: source.to_string(),
+                samples: n,
+                min,
+                max,
+                mean: sum / n as f64,
+            },
+        )
+        .collect();
+
+    Some(TelemetrySeriesReport {
+        window: build_telemetry_window(first, last),
+        samples,
+        steps,
+        stats,
+    })
+}
+
+/// Sample telemetry every `interval` until `duration` has elapsed.
+///
+/// The series starts with `start` and always ends with a sample taken at
+/// `duration`; the final step may be shorter than `interval`. A zero
+/// `interval` takes just that final sample.
+pub fn collect_telemetry_series(
+    start: TelemetrySnapshot,
+    duration: Duration,
+    interval: Duration,
+) -> Vec<TelemetrySnapshot> {
+    let interval = if interval.is_zero() {
+        duration
+    } else {
+        interval
+    };
+    let began = Instant::now();
+    let mut samples = vec![start];
+    for k in 1u32.. {
+        let target = interval.saturating_mul(k).min(duration);
+        if let Some(wait) = target.checked_sub(began.elapsed()) {
+            std::thread::sleep(wait);
+        }
+        samples.push(collect_telemetry_snapshot());
+        if target >= duration {
+            break;
+        }
+    }
+    samples
+}
+
 /// Capture the current end snapshot and compute a telemetry window.
 pub fn collect_telemetry_window(start: TelemetrySnapshot) -> TelemetryWindowReport {
     let end = collect_telemetry_snapshot();
     build_telemetry_window(start, end)
 }
 
+/// Environmental drift terms derived from a telemetry window.
+///
+/// Each term is `None` when the host did not expose the underlying metrics in
+/// both the start and end snapshots.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TelemetryConfound {
+    /// Largest temperature rise across thermal sensors (degrees C).
+    pub thermal_rise_c: Option<f64>,
+    /// Change in the 1-minute load average (end - start).
+    pub load_delta_1m: Option<f64>,
+    /// Largest absolute CPU frequency change, as a percentage of the start value.
+    pub freq_drift_pct: Option<f64>,
+}
+
+/// Summarize thermal, load, and frequency drift over a telemetry window.
+pub fn telemetry_confound_from_window(window: &TelemetryWindowReport) -> TelemetryConfound {
+    let thermal_rise_c = window
+        .deltas
+        .iter()
+        .filter(|d| d.domain == "thermal" && d.unit == "C" && d.delta_value.is_finite())
+        .map(|d| d.delta_value)
+        .reduce(f64::max);
+
+    let load_delta_1m = match (window.start.loadavg_1m, window.end.loadavg_1m) {
+        (Some(start), Some(end)) => Some(end - start),
+        _ => None,
+    };
+
+    let freq_drift_pct = window
+        .deltas
+        .iter()
+        .filter(|d| d.domain == "frequency" && d.unit == "Hz" && d.start_value > 0.0)
+        .map(|d| (d.delta_value / d.start_value).abs() * 100.0)
+        .filter(|v| v.is_finite())
+        .reduce(f64::max);
+
+    TelemetryConfound {
+        thermal_rise_c,
+        load_delta_1m,
+        freq_drift_pct,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1724,6 +1899,59 @@ mod tests {
         assert!((w.deltas[0].delta_value + 15.0).abs() < 1e-9);
     }
 
+    #[test]
+    fn series_steps_and_stats() {
+        let metric = |value: f64| TelemetryMetric {
+            domain: "memory".to_string(),
+            name: "free_bytes".to_string(),
+            value,
+            unit: "bytes".to_string(),
+            source: "test".to_string(),
+        };
+        let first = TelemetrySnapshot {
+            model_id: MODEL_ID.to_string(),
+            model_version: MODEL_VERSION,
+            collected_unix_ms: 1000,
+            os: "test".to_string(),
+            arch: "test".to_string(),
+            cpu_count: 1,
+            loadavg_1m: None,
+            loadavg_5m: None,
+            loadavg_15m: None,
+            metrics: vec![metric(100.0)],
+        };
+        let mut second = first.clone();
+        second.collected_unix_ms = 1400;
+        second.metrics[0] = metric(70.0);
+        let mut third = first.clone();
+        third.collected_unix_ms = 2000;
+        third.metrics[0] = metric(130.0);
+
+        let r = build_telemetry_series_window(vec![first, second, third]).unwrap();
+        assert_eq!(r.samples.len(), 3);
+        assert_eq!(r.steps.len(), 2);
+        assert_eq!(r.steps[0].interval_ms, 400);
+        assert_eq!(r.steps[1].interval_ms, 600);
+        assert_eq!(r.steps[1].elapsed_ms, 1000);
+        assert!((r.steps[0].deltas[0].delta_value + 30.0).abs() < 1e-9);
+        assert!((r.steps[1].deltas[0].delta_value - 60.0).abs() < 1e-9);
+
+        assert_eq!(r.window.elapsed_ms, 1000);
+        assert!((r.window.deltas[0].delta_value - 30.0).abs() < 1e-9);
+
+        assert_eq!(r.stats.len(), 1);
+        let st = &r.stats[0];
+        assert_eq!(st.samples, 3);
+        assert!((st.min - 70.0).abs() < 1e-9);
+        assert!((st.max - 130.0).abs() < 1e-9);
+        assert!((st.mean - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn series_of_nothing_is_none() {
+        assert!(build_telemetry_series_window(Vec::new()).is_none());
+    }
+
     #[test]
     fn window_delta_keeps_distinct_sources() {
         let start = TelemetrySnapshot {
@@ -1770,4 +1998,53 @@ mod tests {
                 .any(|d| d.source == "b" && (d.delta_value - 2.0).abs() < 1e-9)
         );
     }
+
+    #[test]
+    fn confound_picks_worst_drift_terms() {
+        let metric = |domain: &str, name: &str, value: f64, unit: &str| TelemetryMetric {
+            domain: domain.to_string(),
+            name: name.to_string(),
+            value,
+            unit: unit.to_string(),
+            source: "test".to_string(),
+        };
+        let start = TelemetrySnapshot {
+            model_id: MODEL_ID.to_string(),
+            model_version: MODEL_VERSION,
+            collected_unix_ms: 1000,
+            os: "test".to_string(),
+            arch: "test".to_string(),
+            cpu_count: 1,
+            loadavg_1m: Some(1.0),
+            loadavg_5m: None,
+            loadavg_15m: None,
+            metrics: vec![
+                metric("frequency", "cpu_hz_avg", 2.0e9, "Hz"),
+                metric("thermal", "cpu", 40.0, "C"),
+                metric("thermal", "gpu", 50.0, "C"),
+            ],
+        };
+        let mut end = start.clone();
+        end.loadavg_1m = Some(2.5);
+        end.metrics[0].value = 1.8e9;
+        end.metrics[1].value = 43.0;
+        end.metrics[2].value = 49.0;
+
+        let c = telemetry_confound_from_window(&build_telemetry_window(start, end));
+        assert!((c.thermal_rise_c.unwrap() - 3.0).abs() < 1e-9);
+        assert!((c.load_delta_1m.unwrap() - 1.5).abs() < 1e-9);
+        assert!((c.freq_drift_pct.unwrap() - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn confound_is_empty_without_metrics() {
+        let snapshot = collect_telemetry_snapshot();
+        let mut start = snapshot.clone();
+        start.metrics.clear();
+        start.loadavg_1m = None;
+        let c = telemetry_confound_from_window(&build_telemetry_window(start, snapshot));
+        assert!(c.thermal_rise_c.is_none());
+        assert!(c.load_delta_1m.is_none());
+        assert!(c.freq_drift_pct.is_none());
+    }
 }
@@ -4,9 +4,41 @@
 //! source discovery → pool creation → entropy collection → quality checks.
 
 use openentropy_core::{
-    EntropyPool, SessionConfig, SessionWriter, detect_available_sources, quick_shannon,
+    CostClass, EntropyPool, EntropySource, Platform, SessionConfig, SessionWriter, SourceCategory,
+    SourceInfo, detect_available_sources, quick_shannon,
 };
 
+/// Minimal downstream-style source used to exercise the external registry.
+struct CountingSource;
+
+static COUNTING_INFO: SourceInfo = SourceInfo {
+    name: "external_counting",
+    description: "Test-only external source",
+    physics: "Deterministic counter; not an entropy source.",
+    failure_hint: "Always a counter; never random.",
+    category: SourceCategory::System,
+    platform: Platform::Any,
+    requirements: &[],
+    entropy_rate_estimate: 0.0,
+    typical_latency_ms: 1.0,
+    cost_class: CostClass::Fast,
+    composite: false,
+};
+
+impl EntropySource for CountingSource {
+    fn info(&self) -> &SourceInfo {
+        &COUNTING_INFO
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn collect(&self, n_samples: usize) -> Vec<u8> {
+        (0..n_samples).map(|i| i as u8).collect()
+    }
+}
+
 #[test]
 fn detect_sources_finds_sources() {
     let sources = detect_available_sources();
@@ -18,6 +50,20 @@ fn detect_sources_finds_sources() {
     );
 }
 
+#[test]
+fn source_cost_class_matches_latency() {
+    for source in openentropy_core::sources::all_sources() {
+        let info = source.info();
+        assert!(info.typical_latency_ms > 0.0, "{}", info.name);
+        assert_eq!(
+            info.cost_class,
+            CostClass::for_latency_ms(info.typical_latency_ms),
+            "{}",
+            info.name
+        );
+    }
+}
+
 #[test]
 fn pool_auto_creates_with_sources() {
     let pool = EntropyPool::auto();
@@ -28,6 +74,26 @@ fn pool_auto_creates_with_sources() {
     );
 }
 
+#[test]
+fn auto_pools_list_sources_in_same_order() {
+    let first = EntropyPool::auto().source_names();
+    let second = EntropyPool::auto().source_names();
+    assert_eq!(first, second);
+    assert!(first.windows(2).all(|w| w[0] <= w[1]));
+}
+
+#[test]
+fn external_source_collects_through_pool() {
+    let mut pool = EntropyPool::new(Some(b"external"));
+    pool.register_external(Box::new(CountingSource), 1.0);
+    assert_eq!(pool.source_names(), vec!["external_counting"]);
+
+    let raw = pool
+        .get_source_raw_bytes("external_counting", 16)
+        .expect("registered source should be queryable by name");
+    assert_eq!(raw, (0..16).collect::<Vec<u8>>());
+}
+
 #[test]
 #[ignore] // Run with: cargo test -- --ignored
 fn pool_produces_requested_byte_count() {
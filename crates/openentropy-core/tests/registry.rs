@@ -0,0 +1,28 @@
+//! The external source registry is process-global, so its tests get their
+//! own binary instead of leaking registrations into other `auto()` pools.
+
+use openentropy_core::{ReplaySource, detect_available_sources, register_source};
+
+#[test]
+fn registered_source_is_detected_once_per_name() {
+    // Two distinct factories for the same source name: the second is ignored.
+    register_source(|| Box::new(ReplaySource::from_bytes("external_replay", vec![1, 2, 3])));
+    register_source(|| Box::new(ReplaySource::from_bytes("external_replay", vec![9])));
+    register_source(|| Box::new(ReplaySource::from_seed("external_seeded", 1)));
+
+    let sources = detect_available_sources();
+    let named: Vec<&dyn openentropy_core::EntropySource> = sources
+        .iter()
+        .map(|s| s.as_ref())
+        .filter(|s| s.name() == "external_replay")
+        .collect();
+    assert_eq!(named.len(), 1);
+    assert_eq!(named[0].collect(4), [1, 2, 3, 1], "first registration wins");
+    assert_eq!(
+        sources
+            .iter()
+            .filter(|s| s.name() == "external_seeded")
+            .count(),
+        1
+    );
+}
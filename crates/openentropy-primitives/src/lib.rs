@@ -0,0 +1,228 @@
+//! `no_std` extraction and entropy-estimation primitives.
+//!
+//! Pure computational helpers shared by `openentropy-core` (source
+//! extraction) and `openentropy-tests` (bit-level tests). Only `alloc` is
+//! required; disable the default `std` feature on embedded targets, where
+//! float math falls back to `libm`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+
+// ---------------------------------------------------------------------------
+// Bit packing
+// ---------------------------------------------------------------------------
+
+/// Pack a stream of individual bits (0 or 1) into bytes (MSB-first packing).
+///
+/// For every 8 input bits, one output byte is produced; a trailing partial
+/// byte is zero-padded on the right.
+pub fn pack_bits(bits: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(bits.len() / 8 + 1);
+    for chunk in bits.chunks(8) {
+        let mut byte = 0u8;
+        for (i, &bit) in chunk.iter().enumerate() {
+            byte |= bit << (7 - i);
+        }
+        bytes.push(byte);
+    }
+    bytes
+}
+
+/// Order in which the bits of each byte are read out of a stream.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BitOrder {
+    /// Bit 7 first (the convention of SP 800-22 and [`to_bits`]).
+    #[default]
+    MsbFirst,
+    /// Bit 0 first, as emitted by some serial hardware.
+    LsbFirst,
+}
+
+/// Unpack a byte slice into individual bits (MSB first per byte).
+pub fn to_bits(data: &[u8]) -> Vec<u8> {
+    to_bits_with(data, BitOrder::MsbFirst)
+}
+
+/// Unpack a byte slice into individual bits in the given per-byte order.
+pub fn to_bits_with(data: &[u8], order: BitOrder) -> Vec<u8> {
+    let mut bits = Vec::with_capacity(data.len() * 8);
+    for &byte in data {
+        let byte = match order {
+            BitOrder::MsbFirst => byte,
+            BitOrder::LsbFirst => byte.reverse_bits(),
+        };
+        for shift in (0..8).rev() {
+            bits.push((byte >> shift) & 1);
+        }
+    }
+    bits
+}
+
+// ---------------------------------------------------------------------------
+// LSB extraction
+// ---------------------------------------------------------------------------
+
+/// Extract the least-significant bit of each `u64` delta and pack into bytes.
+///
+/// For every 8 input values, one output byte is produced (MSB-first packing).
+pub fn extract_lsbs_u64(deltas: &[u64]) -> Vec<u8> {
+    let bits: Vec<u8> = deltas.iter().map(|d| (d & 1) as u8).collect();
+    pack_bits(&bits)
+}
+
+/// Extract the least-significant bit of each `i64` delta and pack into bytes.
+///
+/// Identical to [`extract_lsbs_u64`] but for signed deltas.
+pub fn extract_lsbs_i64(deltas: &[i64]) -> Vec<u8> {
+    let bits: Vec<u8> = deltas.iter().map(|d| (d & 1) as u8).collect();
+    pack_bits(&bits)
+}
+
+// ---------------------------------------------------------------------------
+// XOR folding
+// ---------------------------------------------------------------------------
+
+/// XOR-fold all 8 bytes of a `u64` into a single byte.
+///
+/// Preserves entropy from every byte position instead of discarding the
+/// upper 7 bytes. Used by timing-based sources where entropy is spread
+/// across multiple byte positions.
+#[inline]
+pub fn xor_fold_u64(v: u64) -> u8 {
+    let b = v.to_le_bytes();
+    b[0] ^ b[1] ^ b[2] ^ b[3] ^ b[4] ^ b[5] ^ b[6] ^ b[7]
+}
+
+// ---------------------------------------------------------------------------
+// Timing entropy extraction
+// ---------------------------------------------------------------------------
+
+/// Extract entropy bytes from a slice of raw timestamps.
+///
+/// Computes consecutive deltas, XORs adjacent deltas for mixing, then
+/// XOR-folds each 8-byte value into one output byte. Returns at most
+/// `n_samples` bytes.
+///
+/// Requires at least 4 input timings to produce any output (2 deltas
+/// needed for the XOR mixing step).
+pub fn extract_timing_entropy(timings: &[u64], n_samples: usize) -> Vec<u8> {
+    if timings.len() < 2 {
+        return Vec::new();
+    }
+
+    let deltas: Vec<u64> = timings
+        .windows(2)
+        .map(|w| w[1].wrapping_sub(w[0]))
+        .collect();
+
+    // XOR consecutive deltas for mixing (not conditioning — just combines adjacent values)
+    let xored: Vec<u64> = deltas.windows(2).map(|w| w[0] ^ w[1]).collect();
+
+    // XOR-fold all 8 bytes of each value into one byte
+    let mut raw: Vec<u8> = xored.iter().map(|&x| xor_fold_u64(x)).collect();
+    raw.truncate(n_samples);
+    raw
+}
+
+// ---------------------------------------------------------------------------
+// Byte statistics
+// ---------------------------------------------------------------------------
+
+/// Count occurrences of each byte value.
+pub fn byte_histogram(data: &[u8]) -> [u64; 256] {
+    let mut counts = [0u64; 256];
+    for &b in data {
+        counts[b as usize] += 1;
+    }
+    counts
+}
+
+/// Shannon entropy in bits/byte (0.0 for empty input, 8.0 maximum).
+pub fn shannon_entropy(data: &[u8]) -> f64 {
+    if data.is_empty() {
+        return 0.0;
+    }
+    let n = data.len() as f64;
+    let mut h = 0.0;
+    for &c in &byte_histogram(data) {
+        if c > 0 {
+            let p = c as f64 / n;
+            h -= p * log2(p);
+        }
+    }
+    h
+}
+
+#[cfg(feature = "std")]
+#[inline]
+fn log2(x: f64) -> f64 {
+    x.log2()
+}
+
+#[cfg(not(feature = "std"))]
+#[inline]
+fn log2(x: f64) -> f64 {
+    libm::log2(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn to_bits_round_trips_through_pack_bits() {
+        let data = [0x00, 0xFF, 0xA5, 0x3C];
+        let bits = to_bits(&data);
+        assert_eq!(bits.len(), 32);
+        assert_eq!(&bits[16..24], &[1, 0, 1, 0, 0, 1, 0, 1]);
+        assert_eq!(pack_bits(&bits), data);
+    }
+
+    #[test]
+    fn to_bits_with_honors_bit_order() {
+        let data = [0b1100_1010];
+        assert_eq!(
+            to_bits_with(&data, BitOrder::MsbFirst),
+            [1, 1, 0, 0, 1, 0, 1, 0]
+        );
+        assert_eq!(
+            to_bits_with(&data, BitOrder::LsbFirst),
+            [0, 1, 0, 1, 0, 0, 1, 1]
+        );
+        assert_eq!(to_bits_with(&data, BitOrder::default()), to_bits(&data));
+    }
+
+    #[test]
+    fn pack_bits_pads_partial_byte() {
+        assert_eq!(pack_bits(&[1, 1, 1]), vec![0b1110_0000]);
+    }
+
+    #[test]
+    fn byte_histogram_counts() {
+        let h = byte_histogram(&[1, 1, 2, 255]);
+        assert_eq!(h[1], 2);
+        assert_eq!(h[2], 1);
+        assert_eq!(h[255], 1);
+        assert_eq!(h.iter().sum::<u64>(), 4);
+    }
+
+    #[test]
+    fn shannon_entropy_bounds() {
+        assert_eq!(shannon_entropy(&[]), 0.0);
+        assert_eq!(shannon_entropy(&[7; 100]), 0.0);
+        let uniform: Vec<u8> = (0..=255).collect();
+        assert!((shannon_entropy(&uniform) - 8.0).abs() < 1e-12);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn libm_log2_matches_std() {
+        for x in [0.001, 0.5, 1.0, 3.0, 1024.0] {
+            assert!((libm::log2(x) - x.log2()).abs() < 1e-12);
+        }
+    }
+}
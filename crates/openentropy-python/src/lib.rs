@@ -2,24 +2,140 @@
 //!
 //! Provides the same API as the pure-Python package but backed by Rust.
 
+use numpy::PyArray1;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
-use pyo3::types::{PyBytes, PyDict, PyList};
+use pyo3::types::{PyBytes, PyDict, PyFloat, PyList, PyTuple};
 
-use openentropy_core::conditioning::ConditioningMode;
-use openentropy_core::pool::EntropyPool as RustPool;
+use openentropy_core::conditioning::{ConditioningMode, TOEPLITZ_DEFAULT_OUTPUT_BITS};
+use openentropy_core::pool::{EntropyPool as RustPool, MixStrategy};
+use openentropy_core::{EntropySource, Requirement};
 
-fn parse_conditioning_mode(conditioning: &str) -> PyResult<ConditioningMode> {
+/// Decode `data` in little-endian `N`-byte words; a short tail is dropped.
+fn decode_le<const N: usize, T>(data: &[u8], decode: impl Fn([u8; N]) -> T) -> Vec<T> {
+    data.chunks_exact(N)
+        .map(|chunk| decode(chunk.try_into().expect("N-byte chunk")))
+        .collect()
+}
+
+/// Parse `conditioning`; `key` (16 bytes) goes with "keyed" and nothing else.
+fn parse_conditioning_mode(conditioning: &str, key: Option<&[u8]>) -> PyResult<ConditioningMode> {
+    if conditioning == "keyed" {
+        let key =
+            key.ok_or_else(|| PyValueError::new_err("conditioning='keyed' needs key=<16 bytes>"))?;
+        let key: [u8; 16] = key.try_into().map_err(|_| {
+            PyValueError::new_err(format!("key must be 16 bytes, got {}", key.len()))
+        })?;
+        return Ok(ConditioningMode::Keyed { key });
+    }
+    if key.is_some() {
+        return Err(PyValueError::new_err(
+            "key only applies to conditioning='keyed'",
+        ));
+    }
     match conditioning {
         "raw" => Ok(ConditioningMode::Raw),
         "vonneumann" | "vn" | "von_neumann" => Ok(ConditioningMode::VonNeumann),
         "sha256" => Ok(ConditioningMode::Sha256),
+        "toeplitz" => Ok(ConditioningMode::Toeplitz {
+            output_bits: TOEPLITZ_DEFAULT_OUTPUT_BITS,
+        }),
+        _ => Err(PyValueError::new_err(format!(
+            "invalid conditioning mode '{conditioning}'. expected one of: raw, vonneumann|vn|von_neumann, sha256, toeplitz, keyed"
+        ))),
+    }
+}
+
+fn parse_mix_strategy(strategy: &str) -> PyResult<MixStrategy> {
+    match strategy {
+        "concatenate" | "concat" => Ok(MixStrategy::Concatenate),
+        "xor" | "xor_fold" => Ok(MixStrategy::XorFold),
+        "interleave" => Ok(MixStrategy::Interleave),
         _ => Err(PyValueError::new_err(format!(
-            "invalid conditioning mode '{conditioning}'. expected one of: raw, vonneumann|vn|von_neumann, sha256"
+            "invalid mix strategy '{strategy}'. expected one of: concatenate|concat, xor|xor_fold, interleave"
         ))),
     }
 }
 
+fn parse_requirement(requirement: &str) -> PyResult<Requirement> {
+    match requirement {
+        "metal" => Ok(Requirement::Metal),
+        "audio_unit" => Ok(Requirement::AudioUnit),
+        "wifi" => Ok(Requirement::Wifi),
+        "usb" => Ok(Requirement::Usb),
+        "camera" => Ok(Requirement::Camera),
+        "apple_silicon" => Ok(Requirement::AppleSilicon),
+        "bluetooth" => Ok(Requirement::Bluetooth),
+        "iokit" => Ok(Requirement::IOKit),
+        "iosurface" => Ok(Requirement::IOSurface),
+        "security_framework" => Ok(Requirement::SecurityFramework),
+        "network" => Ok(Requirement::Network),
+        _ => Err(PyValueError::new_err(format!(
+            "invalid requirement '{requirement}'. expected one of: metal, audio_unit, wifi, usb, camera, apple_silicon, bluetooth, iokit, iosurface, security_framework, network"
+        ))),
+    }
+}
+
+/// Decode a hex string (either case, whitespace ignored).
+fn decode_hex(s: &str) -> PyResult<Vec<u8>> {
+    let digits: Vec<u8> = s.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    if !digits.len().is_multiple_of(2) {
+        return Err(PyValueError::new_err(format!(
+            "invalid hex: odd number of digits ({})",
+            digits.len()
+        )));
+    }
+    let nibble = |i: usize| {
+        (digits[i] as char).to_digit(16).ok_or_else(|| {
+            PyValueError::new_err(format!(
+                "invalid hex: '{}' at digit {i}",
+                digits[i].escape_ascii()
+            ))
+        })
+    };
+    (0..digits.len())
+        .step_by(2)
+        .map(|i| Ok((nibble(i)? << 4 | nibble(i + 1)?) as u8))
+        .collect()
+}
+
+/// Decode standard base64 (`+/`, padding optional, whitespace ignored).
+fn decode_base64(s: &str) -> PyResult<Vec<u8>> {
+    let chars: Vec<u8> = s.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    let body = chars
+        .strip_suffix(b"==")
+        .or_else(|| chars.strip_suffix(b"="));
+    let body = body.unwrap_or(&chars);
+    if body.len() % 4 == 1 || (body.len() != chars.len() && !chars.len().is_multiple_of(4)) {
+        return Err(PyValueError::new_err(format!(
+            "invalid base64: bad length ({} characters)",
+            chars.len()
+        )));
+    }
+    let sextet = |i: usize| match body[i] {
+        c @ b'A'..=b'Z' => Ok(u32::from(c - b'A')),
+        c @ b'a'..=b'z' => Ok(u32::from(c - b'a') + 26),
+        c @ b'0'..=b'9' => Ok(u32::from(c - b'0') + 52),
+        b'+' => Ok(62),
+        b'/' => Ok(63),
+        c => Err(PyValueError::new_err(format!(
+            "invalid base64: '{}' at position {i}",
+            c.escape_ascii()
+        ))),
+    };
+    let mut out = Vec::with_capacity(body.len() * 3 / 4);
+    for start in (0..body.len()).step_by(4) {
+        let end = (start + 4).min(body.len());
+        let mut group = 0u32;
+        for i in start..end {
+            group = group << 6 | sextet(i)?;
+        }
+        group <<= 6 * (4 - (end - start)) as u32;
+        out.extend_from_slice(&group.to_be_bytes()[1..end - start]);
+    }
+    Ok(out)
+}
+
 /// Thread-safe multi-source entropy pool.
 #[pyclass(name = "EntropyPool")]
 struct PyEntropyPool {
@@ -28,11 +144,13 @@ struct PyEntropyPool {
 
 #[pymethods]
 impl PyEntropyPool {
+    /// `pers` is an optional personalization string that separates pools
+    /// created with the same seed.
     #[new]
-    #[pyo3(signature = (seed=None))]
-    fn new(seed: Option<&[u8]>) -> Self {
+    #[pyo3(signature = (seed=None, pers=None))]
+    fn new(seed: Option<&[u8]>, pers: Option<&[u8]>) -> Self {
         Self {
-            inner: RustPool::new(seed),
+            inner: RustPool::with_personalization(seed, pers.unwrap_or_default()),
         }
     }
 
@@ -44,12 +162,60 @@ impl PyEntropyPool {
         }
     }
 
+    /// `auto()` followed by `warm(warmup_bytes, timeout_secs)`; recommended
+    /// for servers.
+    #[staticmethod]
+    #[pyo3(signature = (warmup_bytes=4096, timeout_secs=10.0))]
+    fn auto_warm(py: Python<'_>, warmup_bytes: usize, timeout_secs: f64) -> Self {
+        Self {
+            inner: py.allow_threads(|| RustPool::auto_warm(warmup_bytes, timeout_secs)),
+        }
+    }
+
+    /// Run one discarded collection of `warmup_bytes` samples per source so
+    /// cold-start timing never reaches the output. Sources still collecting
+    /// after `timeout_secs` are abandoned.
+    #[pyo3(signature = (warmup_bytes=4096, timeout_secs=10.0))]
+    fn warm(&self, py: Python<'_>, warmup_bytes: usize, timeout_secs: f64) {
+        py.allow_threads(|| self.inner.warm(warmup_bytes, timeout_secs));
+    }
+
     /// Number of registered sources.
     #[getter]
     fn source_count(&self) -> usize {
         self.inner.source_count()
     }
 
+    /// How collections combine per-source output: "concatenate" (default),
+    /// "xor" (truncates to the shortest chunk; correlated sources can lose
+    /// entropy) or "interleave".
+    #[getter]
+    fn mix_strategy(&self) -> &'static str {
+        match self.inner.mix_strategy() {
+            MixStrategy::Concatenate => "concatenate",
+            MixStrategy::XorFold => "xor",
+            MixStrategy::Interleave => "interleave",
+        }
+    }
+
+    #[setter]
+    fn set_mix_strategy(&mut self, strategy: &str) -> PyResult<()> {
+        self.inner.set_mix_strategy(parse_mix_strategy(strategy)?);
+        Ok(())
+    }
+
+    /// Cap SHA-256 output per raw byte collected (0 = no cap). Requests
+    /// over the cap force a reseed; get_bytes_checked raises if it falls short.
+    #[getter]
+    fn max_expansion(&self) -> f64 {
+        self.inner.max_expansion()
+    }
+
+    #[setter]
+    fn set_max_expansion(&mut self, ratio: f64) {
+        self.inner.set_max_expansion(ratio);
+    }
+
     /// Collect entropy from all sources.
     #[pyo3(signature = (parallel=false, timeout=10.0))]
     fn collect_all(&self, parallel: bool, timeout: f64) -> usize {
@@ -68,20 +234,110 @@ impl PyEntropyPool {
 
     /// Return n_bytes with the specified conditioning mode.
     ///
-    /// Mode can be "raw", "vonneumann"/"vn", or "sha256" (default).
-    #[pyo3(signature = (n_bytes, conditioning="sha256"))]
+    /// Mode can be "raw", "vonneumann"/"vn", "sha256" (default), "toeplitz"
+    /// or "keyed". "keyed" takes a 16-byte `key` and mixes with SipHash-1-3:
+    /// fast, but NOT cryptographically secure (simulations and games only).
+    /// Passing `info` derives domain-separated output via HKDF (e.g.
+    /// `info=b"keys"` vs `info=b"nonces"`). With `return_elapsed=True`,
+    /// returns `(bytes, elapsed_seconds)` timed inside the call.
+    #[pyo3(signature = (n_bytes, conditioning="sha256", info=None, return_elapsed=false, key=None))]
     fn get_bytes<'py>(
         &self,
         py: Python<'py>,
         n_bytes: usize,
         conditioning: &str,
+        info: Option<&[u8]>,
+        return_elapsed: bool,
+        key: Option<&[u8]>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let mode = parse_conditioning_mode(conditioning, key)?;
+        let (data, elapsed) = match info {
+            Some(label) => {
+                let start = std::time::Instant::now();
+                let data = self.inner.get_bytes_labeled(n_bytes, mode, label);
+                (data, start.elapsed())
+            }
+            None => self.inner.collect_timed(n_bytes, mode),
+        };
+        let bytes = PyBytes::new(py, &data);
+        if return_elapsed {
+            Ok(PyTuple::new(
+                py,
+                [
+                    bytes.into_any(),
+                    PyFloat::new(py, elapsed.as_secs_f64()).into_any(),
+                ],
+            )?
+            .into_any())
+        } else {
+            Ok(bytes.into_any())
+        }
+    }
+
+    /// Like `get_bytes`, but raises ValueError if the pool has no sources or
+    /// SHA-256 output would exceed `max_expansion` even after a reseed.
+    #[pyo3(signature = (n_bytes, conditioning="sha256", key=None))]
+    fn get_bytes_checked<'py>(
+        &self,
+        py: Python<'py>,
+        n_bytes: usize,
+        conditioning: &str,
+        key: Option<&[u8]>,
     ) -> PyResult<Bound<'py, PyBytes>> {
-        let mode = parse_conditioning_mode(conditioning)?;
-        let data = self.inner.get_bytes(n_bytes, mode);
+        let mode = parse_conditioning_mode(conditioning, key)?;
+        let data = self
+            .inner
+            .get_bytes_checked(n_bytes, mode)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
         Ok(PyBytes::new(py, &data))
     }
 
-    /// Return n_bytes of raw, unconditioned entropy (XOR-combined only).
+    /// Return `n` entropy values as a numpy array.
+    ///
+    /// `dtype` is "uint8" (default), "uint16", "uint32" or "float64"; floats
+    /// are uniform in [0, 1) with 53 random bits each. Values are decoded
+    /// little-endian into a typed Rust `Vec` that the array takes ownership
+    /// of, without a further copy. Requires numpy. With conditioning="raw"
+    /// the array may hold fewer than `n` values.
+    #[pyo3(signature = (n, dtype="uint8", conditioning="sha256", key=None))]
+    fn get_ndarray<'py>(
+        &self,
+        py: Python<'py>,
+        n: usize,
+        dtype: &str,
+        conditioning: &str,
+        key: Option<&[u8]>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let mode = parse_conditioning_mode(conditioning, key)?;
+        let width = match dtype {
+            "uint8" => 1,
+            "uint16" => 2,
+            "uint32" => 4,
+            "float64" => 8,
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "invalid dtype '{dtype}'. expected one of: uint8, uint16, uint32, float64"
+                )));
+            }
+        };
+        let n_bytes = n
+            .checked_mul(width)
+            .ok_or_else(|| PyValueError::new_err("n is too large"))?;
+        let mut data = self.inner.get_bytes(n_bytes, mode);
+        data.truncate(data.len() / width * width);
+        let array = match width {
+            1 => PyArray1::from_vec(py, data).into_any(),
+            2 => PyArray1::from_vec(py, decode_le(&data, u16::from_le_bytes)).into_any(),
+            4 => PyArray1::from_vec(py, decode_le(&data, u32::from_le_bytes)).into_any(),
+            _ => {
+                let to_unit = |b| (u64::from_le_bytes(b) >> 11) as f64 / (1u64 << 53) as f64;
+                PyArray1::from_vec(py, decode_le(&data, to_unit)).into_any()
+            }
+        };
+        Ok(array)
+    }
+
+    /// Return n_bytes of raw, unconditioned entropy (mixed per `mix_strategy` only).
     ///
     /// No SHA-256, no DRBG, no whitening. Preserves the raw hardware noise
     /// signal for researchers studying actual device entropy characteristics.
@@ -96,9 +352,21 @@ impl PyEntropyPool {
         let dict = PyDict::new(py);
         dict.set_item("healthy", report.healthy)?;
         dict.set_item("total", report.total)?;
+        dict.set_item("degraded", report.degraded)?;
         dict.set_item("raw_bytes", report.raw_bytes)?;
         dict.set_item("output_bytes", report.output_bytes)?;
         dict.set_item("buffer_size", report.buffer_size)?;
+        dict.set_item("prefill_bytes", report.prefill_bytes)?;
+        dict.set_item("prefill_target", report.prefill_target)?;
+        let budget = report.entropy_budget;
+        let bd = PyDict::new(py);
+        bd.set_item("input_min_entropy_bits", budget.input_min_entropy_bits)?;
+        bd.set_item("output_bits", budget.output_bits)?;
+        bd.set_item("deficit_bits", budget.deficit_bits())?;
+        bd.set_item("over_extracted", budget.over_extracted())?;
+        dict.set_item("entropy_budget", bd)?;
+        dict.set_item("max_expansion", report.max_expansion)?;
+        dict.set_item("expansion_violations", report.expansion_violations)?;
 
         let sources = PyList::empty(py);
         for s in &report.sources {
@@ -110,6 +378,7 @@ impl PyEntropyPool {
             sd.set_item("min_entropy", s.min_entropy)?;
             sd.set_item("time", s.time)?;
             sd.set_item("failures", s.failures)?;
+            sd.set_item("stuck", s.stuck)?;
             sources.append(sd)?;
         }
         dict.set_item("sources", sources)?;
@@ -130,10 +399,13 @@ impl PyEntropyPool {
             d.set_item("name", &info.name)?;
             d.set_item("description", &info.description)?;
             d.set_item("physics", &info.physics)?;
+            d.set_item("failure_hint", &info.failure_hint)?;
             d.set_item("category", &info.category)?;
             d.set_item("platform", &info.platform)?;
             d.set_item("requirements", &info.requirements)?;
             d.set_item("entropy_rate_estimate", info.entropy_rate_estimate)?;
+            d.set_item("typical_latency_ms", info.typical_latency_ms)?;
+            d.set_item("cost_class", &info.cost_class)?;
             d.set_item("composite", info.composite)?;
             list.append(d)?;
         }
@@ -148,15 +420,16 @@ impl PyEntropyPool {
     /// Collect conditioned bytes from a single named source.
     ///
     /// Returns None if no source matches the given name.
-    #[pyo3(signature = (source_name, n_bytes, conditioning="sha256"))]
+    #[pyo3(signature = (source_name, n_bytes, conditioning="sha256", key=None))]
     fn get_source_bytes<'py>(
         &self,
         py: Python<'py>,
         source_name: &str,
         n_bytes: usize,
         conditioning: &str,
+        key: Option<&[u8]>,
     ) -> PyResult<Option<Bound<'py, PyBytes>>> {
-        let mode = parse_conditioning_mode(conditioning)?;
+        let mode = parse_conditioning_mode(conditioning, key)?;
         Ok(self
             .inner
             .get_source_bytes(source_name, n_bytes, mode)
@@ -178,27 +451,131 @@ impl PyEntropyPool {
     }
 }
 
-/// Run the full NIST test battery on a bytes object.
-#[pyfunction]
-fn run_all_tests<'py>(py: Python<'py>, data: &[u8]) -> PyResult<Bound<'py, PyList>> {
-    let results = openentropy_tests::run_all_tests(data);
+/// Convert a test result into a dict.
+fn test_result_to_dict<'py>(
+    py: Python<'py>,
+    r: &openentropy_tests::TestResult,
+) -> PyResult<Bound<'py, PyDict>> {
+    let d = PyDict::new(py);
+    d.set_item("name", &r.name)?;
+    d.set_item("passed", r.passed)?;
+    d.set_item("p_value", r.p_value)?;
+    d.set_item("statistic", r.statistic)?;
+    d.set_item("details", &r.details)?;
+    d.set_item("grade", r.grade.to_string())?;
+    d.set_item("skipped", r.skipped)?;
+    Ok(d)
+}
+
+/// Convert test results into a list of dicts.
+fn test_results_to_list<'py>(
+    py: Python<'py>,
+    results: &[openentropy_tests::TestResult],
+) -> PyResult<Bound<'py, PyList>> {
     let list = PyList::empty(py);
-    for r in &results {
-        let d = PyDict::new(py);
-        d.set_item("name", &r.name)?;
-        d.set_item("passed", r.passed)?;
-        d.set_item("p_value", r.p_value)?;
-        d.set_item("statistic", r.statistic)?;
-        d.set_item("details", &r.details)?;
-        d.set_item("grade", r.grade.to_string())?;
-        list.append(d)?;
+    for r in results {
+        list.append(test_result_to_dict(py, r)?)?;
     }
     Ok(list)
 }
 
-/// Calculate quality score from test results.
+/// Run the full NIST test battery on a bytes object.
+///
+/// With `parallel=True` the tests run across threads (GIL released); results
+/// and order are identical.
 #[pyfunction]
-fn calculate_quality_score(results: &Bound<'_, PyList>) -> PyResult<f64> {
+#[pyo3(signature = (data, parallel=false))]
+fn run_all_tests<'py>(
+    py: Python<'py>,
+    data: &[u8],
+    parallel: bool,
+) -> PyResult<Bound<'py, PyList>> {
+    let results = if parallel {
+        py.allow_threads(|| openentropy_tests::run_all_tests_parallel(data))
+    } else {
+        openentropy_tests::run_all_tests(data)
+    };
+    test_results_to_list(py, &results)
+}
+
+/// [`run_all_tests`] on hex-encoded data; raises `ValueError` if malformed.
+#[pyfunction]
+#[pyo3(signature = (s, parallel=false))]
+fn run_all_tests_hex<'py>(
+    py: Python<'py>,
+    s: &str,
+    parallel: bool,
+) -> PyResult<Bound<'py, PyList>> {
+    run_all_tests(py, &decode_hex(s)?, parallel)
+}
+
+/// [`run_all_tests`] on base64-encoded data; raises `ValueError` if malformed.
+#[pyfunction]
+#[pyo3(signature = (s, parallel=false))]
+fn run_all_tests_base64<'py>(
+    py: Python<'py>,
+    s: &str,
+    parallel: bool,
+) -> PyResult<Bound<'py, PyList>> {
+    run_all_tests(py, &decode_base64(s)?, parallel)
+}
+
+/// Run the battery, grading tests that lack enough data `'-'` instead of `'F'`.
+#[pyfunction]
+fn run_all_tests_applicable<'py>(py: Python<'py>, data: &[u8]) -> PyResult<Bound<'py, PyList>> {
+    test_results_to_list(py, &openentropy_tests::run_all_tests_applicable(data))
+}
+
+/// Run the battery on `blocks` sub-blocks and test per-block p-value uniformity
+/// (at least 55 blocks are needed for a verdict).
+#[pyfunction]
+#[pyo3(signature = (data, blocks=100))]
+fn run_all_tests_uniformity<'py>(
+    py: Python<'py>,
+    data: &[u8],
+    blocks: usize,
+) -> PyResult<Bound<'py, PyList>> {
+    test_results_to_list(
+        py,
+        &openentropy_tests::run_all_tests_uniformity(data, blocks),
+    )
+}
+
+/// Binary matrix rank test on `m`x`q` GF(2) matrices (default 32x32).
+#[pyfunction]
+#[pyo3(signature = (data, m=32, q=32))]
+fn binary_matrix_rank<'py>(
+    py: Python<'py>,
+    data: &[u8],
+    m: usize,
+    q: usize,
+) -> PyResult<Bound<'py, PyDict>> {
+    test_result_to_dict(py, &openentropy_tests::binary_matrix_rank_mq(data, m, q))
+}
+
+/// LZ76 Lempel-Ziv complexity, normalized so random bits score ~1.0.
+#[pyfunction]
+fn lempel_ziv_complexity<'py>(py: Python<'py>, data: &[u8]) -> PyResult<Bound<'py, PyDict>> {
+    test_result_to_dict(py, &openentropy_tests::lempel_ziv_complexity(data))
+}
+
+/// Run the full NIST test battery and return a report dict with
+/// `results`, `quality_score`, `combined_p_value`, and `generated_at`.
+#[pyfunction]
+fn battery_report<'py>(py: Python<'py>, data: &[u8]) -> PyResult<Bound<'py, PyDict>> {
+    let report = openentropy_tests::BatteryReport::from_data(data);
+    let d = PyDict::new(py);
+    d.set_item("results", test_results_to_list(py, &report.results)?)?;
+    d.set_item("quality_score", report.quality_score)?;
+    d.set_item("combined_p_value", report.combined_p_value)?;
+    d.set_item("generated_at", &report.generated_at)?;
+    Ok(d)
+}
+
+/// Convert result dicts (as returned by `run_all_tests`) back to Rust results.
+fn test_results_from_list(
+    results: &Bound<'_, PyList>,
+) -> PyResult<Vec<openentropy_tests::TestResult>> {
     let mut rust_results = Vec::new();
     for item in results.iter() {
         let d = item.downcast::<PyDict>()?;
@@ -225,23 +602,76 @@ fn calculate_quality_score(results: &Bound<'_, PyList>) -> PyResult<f64> {
                 .map(|v| v.extract::<String>())
                 .unwrap_or(Ok(String::new()))?,
             grade: grade.chars().next().unwrap_or('F'),
+            skipped: d
+                .get_item("skipped")?
+                .map(|v| v.extract::<bool>())
+                .unwrap_or(Ok(false))?,
         });
     }
-    Ok(openentropy_tests::calculate_quality_score(&rust_results))
+    Ok(rust_results)
+}
+
+/// Calculate quality score from test results.
+#[pyfunction]
+fn calculate_quality_score(results: &Bound<'_, PyList>) -> PyResult<f64> {
+    Ok(openentropy_tests::calculate_quality_score(
+        &test_results_from_list(results)?,
+    ))
+}
+
+/// Stouffer combined p-value over the results' p-values (NaN if none).
+/// A heuristic: the battery's tests are not independent.
+#[pyfunction]
+fn combined_pvalue(results: &Bound<'_, PyList>) -> PyResult<f64> {
+    Ok(openentropy_tests::combined_pvalue(&test_results_from_list(
+        results,
+    )?))
 }
 
 /// Detect available entropy sources on this machine.
 #[pyfunction]
 fn detect_available_sources<'py>(py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
-    let sources = openentropy_core::detect_available_sources();
+    source_list(py, &openentropy_core::detect_available_sources())
+}
+
+/// Detect available sources, skipping any that need one of
+/// `exclude_requirements` (e.g. `["network", "wifi"]`).
+#[pyfunction]
+fn detect_available_sources_filtered<'py>(
+    py: Python<'py>,
+    exclude_requirements: Vec<String>,
+) -> PyResult<Bound<'py, PyList>> {
+    let exclude = exclude_requirements
+        .iter()
+        .map(|r| parse_requirement(r))
+        .collect::<PyResult<Vec<_>>>()?;
+    source_list(
+        py,
+        &openentropy_core::detect_available_sources_filtered(&exclude),
+    )
+}
+
+fn source_list<'py>(
+    py: Python<'py>,
+    sources: &[Box<dyn EntropySource>],
+) -> PyResult<Bound<'py, PyList>> {
     let list = PyList::empty(py);
-    for s in &sources {
+    for s in sources {
         let info = s.info();
         let d = PyDict::new(py);
         d.set_item("name", info.name)?;
         d.set_item("description", info.description)?;
         d.set_item("category", info.category.to_string())?;
         d.set_item("entropy_rate_estimate", info.entropy_rate_estimate)?;
+        d.set_item("typical_latency_ms", info.typical_latency_ms)?;
+        d.set_item("cost_class", info.cost_class.to_string())?;
+        d.set_item(
+            "requirements",
+            info.requirements
+                .iter()
+                .map(|r| r.to_string())
+                .collect::<Vec<_>>(),
+        )?;
         list.append(d)?;
     }
     Ok(list)
@@ -270,16 +700,17 @@ fn detect_machine_info<'py>(py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
     Ok(d)
 }
 
-/// Apply conditioning mode to bytes.
+/// Apply conditioning mode to bytes ("keyed" takes a 16-byte `key`).
 #[pyfunction]
-#[pyo3(signature = (data, n_output, conditioning="sha256"))]
+#[pyo3(signature = (data, n_output, conditioning="sha256", key=None))]
 fn condition<'py>(
     py: Python<'py>,
     data: &[u8],
     n_output: usize,
     conditioning: &str,
+    key: Option<&[u8]>,
 ) -> PyResult<Bound<'py, PyBytes>> {
-    let mode = parse_conditioning_mode(conditioning)?;
+    let mode = parse_conditioning_mode(conditioning, key)?;
     let out = openentropy_core::condition(data, n_output, mode);
     Ok(PyBytes::new(py, &out))
 }
@@ -302,6 +733,18 @@ fn min_entropy_estimate<'py>(py: Python<'py>, data: &[u8]) -> PyResult<Bound<'py
     Ok(d)
 }
 
+/// [`min_entropy_estimate`] on hex-encoded data.
+#[pyfunction]
+fn min_entropy_estimate_hex<'py>(py: Python<'py>, s: &str) -> PyResult<Bound<'py, PyDict>> {
+    min_entropy_estimate(py, &decode_hex(s)?)
+}
+
+/// [`min_entropy_estimate`] on base64-encoded data.
+#[pyfunction]
+fn min_entropy_estimate_base64<'py>(py: Python<'py>, s: &str) -> PyResult<Bound<'py, PyDict>> {
+    min_entropy_estimate(py, &decode_base64(s)?)
+}
+
 /// Fast MCV min-entropy estimate.
 #[pyfunction]
 fn quick_min_entropy(data: &[u8]) -> f64 {
@@ -334,6 +777,18 @@ fn quick_quality<'py>(py: Python<'py>, data: &[u8]) -> PyResult<Bound<'py, PyDic
     Ok(d)
 }
 
+/// [`quick_quality`] on hex-encoded data.
+#[pyfunction]
+fn quick_quality_hex<'py>(py: Python<'py>, s: &str) -> PyResult<Bound<'py, PyDict>> {
+    quick_quality(py, &decode_hex(s)?)
+}
+
+/// [`quick_quality`] on base64-encoded data.
+#[pyfunction]
+fn quick_quality_base64<'py>(py: Python<'py>, s: &str) -> PyResult<Bound<'py, PyDict>> {
+    quick_quality(py, &decode_base64(s)?)
+}
+
 /// Library version.
 #[pyfunction]
 fn version() -> &'static str {
@@ -346,16 +801,29 @@ fn openentropy(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add("__version__", openentropy_core::VERSION)?;
     m.add_class::<PyEntropyPool>()?;
     m.add_function(wrap_pyfunction!(run_all_tests, m)?)?;
+    m.add_function(wrap_pyfunction!(run_all_tests_hex, m)?)?;
+    m.add_function(wrap_pyfunction!(run_all_tests_base64, m)?)?;
+    m.add_function(wrap_pyfunction!(run_all_tests_applicable, m)?)?;
+    m.add_function(wrap_pyfunction!(run_all_tests_uniformity, m)?)?;
+    m.add_function(wrap_pyfunction!(battery_report, m)?)?;
+    m.add_function(wrap_pyfunction!(binary_matrix_rank, m)?)?;
+    m.add_function(wrap_pyfunction!(lempel_ziv_complexity, m)?)?;
     m.add_function(wrap_pyfunction!(calculate_quality_score, m)?)?;
+    m.add_function(wrap_pyfunction!(combined_pvalue, m)?)?;
     m.add_function(wrap_pyfunction!(detect_available_sources, m)?)?;
+    m.add_function(wrap_pyfunction!(detect_available_sources_filtered, m)?)?;
     m.add_function(wrap_pyfunction!(platform_info, m)?)?;
     m.add_function(wrap_pyfunction!(detect_machine_info, m)?)?;
     m.add_function(wrap_pyfunction!(condition, m)?)?;
     m.add_function(wrap_pyfunction!(min_entropy_estimate, m)?)?;
+    m.add_function(wrap_pyfunction!(min_entropy_estimate_hex, m)?)?;
+    m.add_function(wrap_pyfunction!(min_entropy_estimate_base64, m)?)?;
     m.add_function(wrap_pyfunction!(quick_min_entropy, m)?)?;
     m.add_function(wrap_pyfunction!(quick_shannon, m)?)?;
     m.add_function(wrap_pyfunction!(grade_min_entropy, m)?)?;
     m.add_function(wrap_pyfunction!(quick_quality, m)?)?;
+    m.add_function(wrap_pyfunction!(quick_quality_hex, m)?)?;
+    m.add_function(wrap_pyfunction!(quick_quality_base64, m)?)?;
     m.add_function(wrap_pyfunction!(version, m)?)?;
     Ok(())
 }
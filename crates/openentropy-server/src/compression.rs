@@ -0,0 +1,28 @@
+//! Optional gzip/deflate response compression.
+//!
+//! Large `uint8` JSON arrays compress well, so the server can encode
+//! responses according to the client's `Accept-Encoding` header. Binary
+//! (`application/octet-stream`) bodies are passed through untouched: raw
+//! entropy does not compress and clients reading it may not expect an
+//! encoded stream.
+
+use tower_http::compression::predicate::{And, DefaultPredicate, NotForContentType, SizeAbove};
+use tower_http::compression::{CompressionLayer, Predicate};
+
+/// Bodies smaller than this are sent as-is; encoding overhead outweighs savings.
+const MIN_COMPRESS_BYTES: u16 = 256;
+
+/// Which responses get encoded.
+type CompressPredicate = And<And<DefaultPredicate, SizeAbove>, NotForContentType>;
+
+/// Streaming gzip/deflate layer for every compressible response.
+pub(crate) fn layer() -> CompressionLayer<CompressPredicate> {
+    CompressionLayer::new()
+        .gzip(true)
+        .deflate(true)
+        .compress_when(
+            DefaultPredicate::new()
+                .and(SizeAbove::new(MIN_COMPRESS_BYTES))
+                .and(NotForContentType::const_new("application/octet-stream")),
+        )
+}
@@ -2,32 +2,76 @@
 //!
 //! Serves random bytes via HTTP, compatible with the ANU QRNG API format for easy integration with
 //! QRNG backend and any client expecting the ANU API format.
+//!
+//! Requests are logged through `tracing` (method, path, `length`/`source`
+//! query params, status, latency); install a subscriber to see them.
+
+mod compression;
+mod openapi;
+mod rpc;
 
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 
 use axum::{
     Router,
     extract::{Query, State},
-    http::StatusCode,
+    http::{HeaderMap, HeaderValue, StatusCode},
     response::Json,
-    routing::get,
+    routing::{get, post},
 };
 use serde::{Deserialize, Serialize};
-use tokio::sync::Mutex;
+use tower_http::LatencyUnit;
+use tower_http::trace::{DefaultOnResponse, TraceLayer};
 
-use openentropy_core::conditioning::ConditioningMode;
-use openentropy_core::pool::EntropyPool;
+use openentropy_core::conditioning::{ConditioningMode, TOEPLITZ_DEFAULT_OUTPUT_BITS};
+use openentropy_core::pool::{EntropyPool, HealthReport};
 use openentropy_core::telemetry::{
     TelemetryWindowReport, collect_telemetry_snapshot, collect_telemetry_window,
 };
+use openentropy_core::{AsyncEntropyPool, EntropySource};
+use openentropy_tests::StreamHealth;
 
 /// Shared server state.
+///
+/// Collection runs on tokio's blocking pool, so handlers never stall the
+/// executor and concurrent requests don't queue behind one another.
 struct AppState {
-    pool: Mutex<EntropyPool>,
+    pool: AsyncEntropyPool,
     allow_raw: bool,
+    refresh: Option<SourceDetector>,
+    /// Running monobit/runs/byte-frequency over every byte served by
+    /// `/api/v1/random`; reported in the `x-openentropy-*-p` headers.
+    stream_health: Mutex<StreamHealth>,
+    /// Total bytes one `POST /api/v1/random/batch` may draw.
+    max_batch_bytes: usize,
 }
 
-#[derive(Deserialize)]
+/// Default for [`ServerConfig::max_batch_bytes`].
+pub const DEFAULT_MAX_BATCH_BYTES: usize = 1 << 20;
+
+/// Largest `length` one random request (or batch item) may ask for.
+const MAX_RANDOM_LENGTH: usize = 65536;
+
+/// Most items one `POST /api/v1/random/batch` (or calls one `POST /rpc`
+/// batch) may hold; larger batches are rejected without running anything.
+const MAX_BATCH_ITEMS: usize = 1024;
+
+/// Samples per source in [`run_server`]'s startup warm-up.
+const STARTUP_SAMPLES: usize = 1000;
+
+/// Deadline for that collection; the same 10s [`EntropyPool::collect_all`]
+/// allows.
+const STARTUP_TIMEOUT_SECS: f64 = 10.0;
+
+/// Re-detects the source set for `POST /sources/refresh`, each source with
+/// the weight it joins the pool at.
+///
+/// Typically `refresh_source_availability` then `detect_available_sources`
+/// plus the same filter and weights used at startup.
+pub type SourceDetector = Arc<dyn Fn() -> Vec<(Box<dyn EntropySource>, f64)> + Send + Sync>;
+
+#[derive(Deserialize, Default)]
 struct RandomParams {
     length: Option<usize>,
     #[serde(rename = "type")]
@@ -38,6 +82,67 @@ struct RandomParams {
     conditioning: Option<String>,
     /// Request entropy from a specific source by name.
     source: Option<String>,
+    /// Byte order for uint16/uint32: `le` (default) or `be`.
+    endian: Option<String>,
+}
+
+/// Byte order used to build multi-byte integers from pool output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Endian {
+    Little,
+    Big,
+}
+
+impl Endian {
+    fn parse(s: Option<&str>) -> Option<Self> {
+        match s {
+            None | Some("le") => Some(Self::Little),
+            Some("be") => Some(Self::Big),
+            Some(_) => None,
+        }
+    }
+}
+
+/// Encode pool output as the JSON `data` field for `?type=`. Trailing bytes
+/// that do not fill a whole uint16/uint32 are dropped.
+fn encode_data(raw: &[u8], data_type: &str, endian: Endian) -> serde_json::Value {
+    use serde_json::Value;
+    let big = endian == Endian::Big;
+    match data_type {
+        "hex16" => Value::Array(
+            raw.chunks_exact(2)
+                .map(|c| Value::String(format!("{:02x}{:02x}", c[0], c[1])))
+                .collect(),
+        ),
+        "uint8" => Value::Array(raw.iter().map(|&b| Value::from(b)).collect()),
+        "uint16" => Value::Array(
+            raw.chunks_exact(2)
+                .map(|c| {
+                    let bytes = [c[0], c[1]];
+                    let v = if big {
+                        u16::from_be_bytes(bytes)
+                    } else {
+                        u16::from_le_bytes(bytes)
+                    };
+                    Value::from(v)
+                })
+                .collect(),
+        ),
+        "uint32" => Value::Array(
+            raw.chunks_exact(4)
+                .map(|c| {
+                    let bytes = [c[0], c[1], c[2], c[3]];
+                    let v = if big {
+                        u32::from_be_bytes(bytes)
+                    } else {
+                        u32::from_le_bytes(bytes)
+                    };
+                    Value::from(v)
+                })
+                .collect(),
+        ),
+        _ => Value::String(hex::encode(raw)),
+    }
 }
 
 #[derive(Serialize)]
@@ -66,6 +171,13 @@ struct HealthResponse {
     output_bytes: u64,
 }
 
+#[derive(Serialize)]
+struct ReadyResponse {
+    ready: bool,
+    sources_healthy: usize,
+    sources_total: usize,
+}
+
 #[derive(Serialize)]
 struct SourcesResponse {
     sources: Vec<SourceEntry>,
@@ -82,6 +194,10 @@ struct SourceEntry {
     entropy: f64,
     time: f64,
     failures: u64,
+    /// Identical output across the last `STUCK_AFTER` collections.
+    stuck: bool,
+    typical_latency_ms: f64,
+    cost_class: String,
 }
 
 #[derive(Deserialize, Default)]
@@ -93,99 +209,226 @@ fn include_telemetry(params: &DiagnosticsParams) -> bool {
     params.telemetry.unwrap_or(false)
 }
 
+impl RandomParams {
+    /// Requested length, clamped to `1..=MAX_RANDOM_LENGTH`.
+    fn length(&self) -> usize {
+        self.length.unwrap_or(1024).clamp(1, MAX_RANDOM_LENGTH)
+    }
+
+    /// `?conditioning=` takes priority, then `?raw=true`; raw falls back to
+    /// SHA-256 unless the server allows it.
+    fn mode(&self, allow_raw: bool) -> ConditioningMode {
+        if let Some(ref c) = self.conditioning {
+            match c.as_str() {
+                "raw" if allow_raw => ConditioningMode::Raw,
+                "vonneumann" | "von_neumann" | "vn" => ConditioningMode::VonNeumann,
+                "toeplitz" => ConditioningMode::Toeplitz {
+                    output_bits: TOEPLITZ_DEFAULT_OUTPUT_BITS,
+                },
+                "raw" => ConditioningMode::Sha256, // raw not allowed
+                _ => ConditioningMode::Sha256,
+            }
+        } else if self.raw.unwrap_or(false) && allow_raw {
+            ConditioningMode::Raw
+        } else {
+            ConditioningMode::Sha256
+        }
+    }
+}
+
+impl RandomResponse {
+    fn failed(data_type: String, conditioned: bool, source: Option<String>, error: String) -> Self {
+        Self {
+            data_type,
+            length: 0,
+            data: serde_json::Value::Array(vec![]),
+            success: false,
+            conditioned,
+            source,
+            error: Some(error),
+        }
+    }
+
+    /// Encode `raw` per `data_type`; `length` counts the encoded elements.
+    fn served(
+        raw: &[u8],
+        data_type: String,
+        endian: Endian,
+        mode: ConditioningMode,
+        source: Option<String>,
+    ) -> Self {
+        let data = encode_data(raw, &data_type, endian);
+        let length = match &data {
+            serde_json::Value::Array(a) => a.len(),
+            _ => raw.len(),
+        };
+        Self {
+            data_type,
+            length,
+            data,
+            success: true,
+            conditioned: mode != ConditioningMode::Raw,
+            source,
+            error: None,
+        }
+    }
+}
+
+const INVALID_ENDIAN: &str = "Invalid endian: expected le or be";
+
 async fn handle_random(
     State(state): State<Arc<AppState>>,
     Query(params): Query<RandomParams>,
 ) -> (StatusCode, Json<RandomResponse>) {
-    let length = params.length.unwrap_or(1024).clamp(1, 65536);
+    let length = params.length();
+    let mode = params.mode(state.allow_raw);
     let data_type = params.data_type.unwrap_or_else(|| "hex16".to_string());
-
-    // Determine conditioning mode: ?conditioning= takes priority, then ?raw=true
-    let mode = if let Some(ref c) = params.conditioning {
-        match c.as_str() {
-            "raw" if state.allow_raw => ConditioningMode::Raw,
-            "vonneumann" | "von_neumann" | "vn" => ConditioningMode::VonNeumann,
-            "raw" => ConditioningMode::Sha256, // raw not allowed
-            _ => ConditioningMode::Sha256,
-        }
-    } else if params.raw.unwrap_or(false) && state.allow_raw {
-        ConditioningMode::Raw
-    } else {
-        ConditioningMode::Sha256
+    let Some(endian) = Endian::parse(params.endian.as_deref()) else {
+        return Json(RandomResponse::failed(
+            data_type,
+            true,
+            params.source,
+            INVALID_ENDIAN.to_string(),
+        ))
+        .with_status(StatusCode::BAD_REQUEST);
     };
 
-    let pool = state.pool.lock().await;
     let raw = if let Some(ref source_name) = params.source {
-        match pool.get_source_bytes(source_name, length, mode) {
+        match state.pool.get_source_bytes(source_name, length, mode).await {
             Some(bytes) => bytes,
             None => {
                 let err_msg = format!(
                     "Unknown source: {source_name}. Use /sources to list available sources."
                 );
-                return Json(RandomResponse {
+                return Json(RandomResponse::failed(
                     data_type,
-                    length: 0,
-                    data: serde_json::Value::Array(vec![]),
-                    success: false,
-                    conditioned: mode != ConditioningMode::Raw,
-                    source: Some(source_name.clone()),
-                    error: Some(err_msg),
-                })
+                    mode != ConditioningMode::Raw,
+                    Some(source_name.clone()),
+                    err_msg,
+                ))
                 .with_status(StatusCode::BAD_REQUEST);
             }
         }
     } else {
-        pool.get_bytes(length, mode)
-    };
-    let use_raw = mode == ConditioningMode::Raw;
-
-    let data = match data_type.as_str() {
-        "hex16" => {
-            let hex_pairs: Vec<String> = raw
-                .chunks(2)
-                .filter(|c| c.len() == 2)
-                .map(|c| format!("{:02x}{:02x}", c[0], c[1]))
-                .collect();
-            serde_json::Value::Array(
-                hex_pairs
-                    .into_iter()
-                    .map(serde_json::Value::String)
-                    .collect(),
-            )
-        }
-        "uint8" => {
-            serde_json::Value::Array(raw.iter().map(|&b| serde_json::Value::from(b)).collect())
-        }
-        "uint16" => {
-            let vals: Vec<u16> = raw
-                .chunks(2)
-                .filter(|c| c.len() == 2)
-                .map(|c| u16::from_le_bytes([c[0], c[1]]))
-                .collect();
-            serde_json::Value::Array(vals.into_iter().map(serde_json::Value::from).collect())
-        }
-        _ => serde_json::Value::String(hex::encode(&raw)),
-    };
-
-    let len = match &data {
-        serde_json::Value::Array(a) => a.len(),
-        _ => length,
+        state.pool.get_bytes(length, mode).await
     };
+    state.stream_health.lock().unwrap().push(&raw);
 
     (
         StatusCode::OK,
-        Json(RandomResponse {
+        Json(RandomResponse::served(
+            &raw,
             data_type,
-            length: len,
-            data,
-            success: true,
-            conditioned: !use_raw,
-            source: params.source,
-            error: None,
-        }),
+            endian,
+            mode,
+            params.source,
+        )),
+    )
+}
+
+#[derive(Deserialize)]
+struct BatchRequest {
+    requests: Vec<RandomParams>,
+}
+
+/// `POST /api/v1/random/batch`: one [`RandomResponse`] per item of
+/// `requests`, in order.
+///
+/// Items take the `/api/v1/random` query parameters except `source`. All
+/// draws run in one blocking task. Lengths are granted in order until the
+/// server's `max_batch_bytes` is spent: the item that crosses it is cut
+/// short and later items fail. Invalid items fail on their own without
+/// affecting the rest. More than [`MAX_BATCH_ITEMS`] items is a 400.
+async fn handle_random_batch(
+    State(state): State<Arc<AppState>>,
+    Json(batch): Json<BatchRequest>,
+) -> Result<Json<Vec<RandomResponse>>, (StatusCode, Json<serde_json::Value>)> {
+    if batch.requests.len() > MAX_BATCH_ITEMS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({
+                "error": format!(
+                    "batch of {} requests exceeds the limit of {MAX_BATCH_ITEMS}",
+                    batch.requests.len()
+                ),
+            })),
+        ));
+    }
+    let mut budget = state.max_batch_bytes;
+    let mut responses = Vec::with_capacity(batch.requests.len());
+    // Index into `responses` plus how to encode each granted draw.
+    let mut pending = Vec::new();
+    let mut draws = Vec::new();
+    for params in batch.requests {
+        let length = params.length().min(budget);
+        let mode = params.mode(state.allow_raw);
+        let endian = Endian::parse(params.endian.as_deref());
+        let data_type = params.data_type.unwrap_or_else(|| "hex16".to_string());
+        let error = if params.source.is_some() {
+            "source is not supported in batch requests".to_string()
+        } else if let Some(endian) = endian.filter(|_| length > 0) {
+            budget -= length;
+            pending.push((responses.len(), data_type.clone(), endian, mode));
+            draws.push((length, mode));
+            String::new()
+        } else if endian.is_none() {
+            INVALID_ENDIAN.to_string()
+        } else {
+            format!("batch byte limit of {} reached", state.max_batch_bytes)
+        };
+        // Placeholder for granted items; replaced once the draws return.
+        responses.push(RandomResponse::failed(
+            data_type,
+            mode != ConditioningMode::Raw,
+            params.source,
+            error,
+        ));
+    }
+
+    let outputs = state.pool.get_bytes_batch(draws).await;
+    let mut health = state.stream_health.lock().unwrap();
+    for ((index, data_type, endian, mode), raw) in pending.into_iter().zip(outputs) {
+        health.push(&raw);
+        responses[index] = RandomResponse::served(&raw, data_type, endian, mode, None);
+    }
+    Ok(Json(responses))
+}
+
+/// `GET /api/v1/random`: [`handle_random`] plus stream-health headers, one
+/// per test that has enough data, e.g. `x-openentropy-monobit-p: 0.4821`.
+async fn handle_random_http(
+    state: State<Arc<AppState>>,
+    params: Query<RandomParams>,
+) -> (StatusCode, HeaderMap, Json<RandomResponse>) {
+    let (status, body) = handle_random(State(Arc::clone(&state.0)), params).await;
+    (
+        status,
+        stream_health_headers(&state.stream_health.lock().unwrap()),
+        body,
     )
 }
 
+fn stream_health_headers(health: &StreamHealth) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        "x-openentropy-stream-bits",
+        HeaderValue::from(health.bits()),
+    );
+    let names = [
+        "x-openentropy-monobit-p",
+        "x-openentropy-runs-p",
+        "x-openentropy-byte-frequency-p",
+    ];
+    for (name, result) in names.into_iter().zip(health.snapshot()) {
+        if let Some(p) = result.p_value
+            && let Ok(value) = HeaderValue::from_str(&format!("{p:.4}"))
+        {
+            headers.insert(name, value);
+        }
+    }
+    headers
+}
+
 trait JsonWithStatus<T> {
     fn with_status(self, status: StatusCode) -> (StatusCode, Json<T>);
 }
@@ -197,13 +440,12 @@ impl<T> JsonWithStatus<T> for Json<T> {
 }
 
 async fn handle_health(State(state): State<Arc<AppState>>) -> Json<HealthResponse> {
-    let pool = state.pool.lock().await;
-    let report = pool.health_report();
+    let report = state.pool.health_report().await;
     Json(HealthResponse {
-        status: if report.healthy > 0 {
-            "healthy".to_string()
-        } else {
+        status: if report.degraded {
             "degraded".to_string()
+        } else {
+            "healthy".to_string()
         },
         sources_healthy: report.healthy,
         sources_total: report.total,
@@ -212,25 +454,63 @@ async fn handle_health(State(state): State<Arc<AppState>>) -> Json<HealthRespons
     })
 }
 
+/// Liveness probe (`/ping`, `/livez`): answers without touching the pool, so
+/// it stays responsive while a long collection holds source locks.
+async fn handle_ping() -> &'static str {
+    "ok"
+}
+
+/// Readiness probe: 200 once at least one source has collected data and was
+/// healthy doing so, 503 otherwise (including before the first collection).
+async fn handle_readyz(State(state): State<Arc<AppState>>) -> (StatusCode, Json<ReadyResponse>) {
+    let report = state.pool.health_report().await;
+    let serving = serving_sources(&report);
+    let ready = serving > 0;
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    (
+        status,
+        Json(ReadyResponse {
+            ready,
+            sources_healthy: serving,
+            sources_total: report.total,
+        }),
+    )
+}
+
 async fn handle_sources(
     State(state): State<Arc<AppState>>,
     Query(params): Query<DiagnosticsParams>,
 ) -> Json<SourcesResponse> {
     let telemetry_start = include_telemetry(&params).then(collect_telemetry_snapshot);
-    let pool = state.pool.lock().await;
-    let report = pool.health_report();
-    drop(pool);
+    let report = state.pool.health_report().await;
+    let costs: HashMap<String, (f64, String)> = state
+        .pool
+        .source_infos()
+        .await
+        .into_iter()
+        .map(|info| (info.name, (info.typical_latency_ms, info.cost_class)))
+        .collect();
     let telemetry_v1 = telemetry_start.map(collect_telemetry_window);
     let sources: Vec<SourceEntry> = report
         .sources
         .iter()
-        .map(|s| SourceEntry {
-            name: s.name.clone(),
-            healthy: s.healthy,
-            bytes: s.bytes,
-            entropy: s.entropy,
-            time: s.time,
-            failures: s.failures,
+        .map(|s| {
+            let (typical_latency_ms, cost_class) = costs.get(&s.name).cloned().unwrap_or_default();
+            SourceEntry {
+                name: s.name.clone(),
+                healthy: s.healthy,
+                bytes: s.bytes,
+                entropy: s.entropy,
+                time: s.time,
+                failures: s.failures,
+                stuck: s.stuck,
+                typical_latency_ms,
+                cost_class,
+            }
         })
         .collect();
     let total = sources.len();
@@ -241,14 +521,30 @@ async fn handle_sources(
     })
 }
 
+async fn handle_refresh_sources(
+    State(state): State<Arc<AppState>>,
+) -> (StatusCode, Json<serde_json::Value>) {
+    let Some(detect) = state.refresh.clone() else {
+        return (
+            StatusCode::FORBIDDEN,
+            Json(serde_json::json!({
+                "error": "Source refresh is disabled. Start the server with --allow-refresh.",
+            })),
+        );
+    };
+    let sources = state.pool.refresh_sources(move || detect()).await;
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({ "sources": sources })),
+    )
+}
+
 async fn handle_pool_status(
     State(state): State<Arc<AppState>>,
     Query(params): Query<DiagnosticsParams>,
 ) -> Json<serde_json::Value> {
     let telemetry_start = include_telemetry(&params).then(collect_telemetry_snapshot);
-    let pool = state.pool.lock().await;
-    let report = pool.health_report();
-    drop(pool);
+    let report = state.pool.health_report().await;
 
     let mut payload = serde_json::json!({
         "healthy": report.healthy,
@@ -256,6 +552,8 @@ async fn handle_pool_status(
         "raw_bytes": report.raw_bytes,
         "output_bytes": report.output_bytes,
         "buffer_size": report.buffer_size,
+        "prefill_bytes": report.prefill_bytes,
+        "prefill_target": report.prefill_target,
         "sources": report.sources.iter().map(|s| serde_json::json!({
             "name": s.name,
             "healthy": s.healthy,
@@ -263,6 +561,7 @@ async fn handle_pool_status(
             "entropy": s.entropy,
             "time": s.time,
             "failures": s.failures,
+            "stuck": s.stuck,
         })).collect::<Vec<_>>(),
     });
     if let Some(window) = telemetry_start.map(collect_telemetry_window) {
@@ -272,9 +571,7 @@ async fn handle_pool_status(
 }
 
 async fn handle_index(State(state): State<Arc<AppState>>) -> Json<serde_json::Value> {
-    let pool = state.pool.lock().await;
-    let source_names = pool.source_names();
-    drop(pool);
+    let source_names = state.pool.source_names().await;
 
     Json(serde_json::json!({
         "name": "OpenEntropy Server",
@@ -282,29 +579,47 @@ async fn handle_index(State(state): State<Arc<AppState>>) -> Json<serde_json::Va
         "sources": source_names.len(),
         "endpoints": {
             "/": "This API index",
+            "/openapi.json": "OpenAPI 3.0 description of this API",
             "/api/v1/random": {
                 "method": "GET",
                 "description": "Get random entropy bytes",
                 "params": {
                     "length": "Number of bytes (1-65536, default: 1024)",
-                    "type": "Output format: hex16, uint8, uint16 (default: hex16)",
+                    "type": "Output format: hex16, uint8, uint16, uint32 (default: hex16)",
+                    "endian": "Byte order for uint16/uint32: le (default), be",
                     "source": format!("Request from a specific source by name. Available: {}", source_names.join(", ")),
-                    "conditioning": "Conditioning mode: sha256 (default), vonneumann, raw",
+                    "conditioning": "Conditioning mode: sha256 (default), vonneumann, toeplitz, raw",
                 }
             },
+            "/api/v1/random/batch": {
+                "method": "POST",
+                "description": format!("Several draws in one request: {{\"requests\":[{{\"length\":32,\"type\":\"uint8\"}}]}}; items take the /api/v1/random params except source, up to {MAX_BATCH_ITEMS} items and {} bytes in total", state.max_batch_bytes),
+            },
             "/sources": {
                 "description": "List all active entropy sources with health metrics",
                 "params": {
                     "telemetry": "Include telemetry_v1 start/end report (true/false, default false)"
                 }
             },
+            "/sources/refresh": {
+                "method": "POST",
+                "description": "Re-detect hardware and rebuild the source set (requires --allow-refresh)",
+            },
             "/pool/status": {
                 "description": "Detailed pool status",
                 "params": {
                     "telemetry": "Include telemetry_v1 start/end report (true/false, default false)"
                 }
             },
-            "/health": "Health check",
+            "/health": "Health check (full pool report)",
+            "/ping": "Liveness check; returns \"ok\" without locking the pool",
+            "/livez": "Liveness probe (same as /ping)",
+            "/readyz": "Readiness probe; 503 until at least one source is healthy",
+            "/rpc": {
+                "method": "POST",
+                "description": format!("JSON-RPC 2.0; single calls or batched arrays, answered in order; a batch's random calls draw at most {} bytes in total", state.max_batch_bytes),
+                "methods": rpc::METHODS,
+            },
         },
         "examples": {
             "mixed_pool": "/api/v1/random?length=32&type=uint8",
@@ -316,28 +631,172 @@ async fn handle_index(State(state): State<Arc<AppState>>) -> Json<serde_json::Va
     }))
 }
 
-/// Build the axum router.
-fn build_router(pool: EntropyPool, allow_raw: bool) -> Router {
+/// Span for one request, carrying the `length` and `source` query params.
+fn request_span<B>(request: &axum::http::Request<B>) -> tracing::Span {
+    let query = request.uri().query().unwrap_or_default();
+    let param = |key: &str| {
+        query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .find_map(|(k, v)| (k == key).then_some(v))
+            .unwrap_or("-")
+    };
+    tracing::info_span!(
+        "request",
+        method = %request.method(),
+        path = %request.uri().path(),
+        length = %param("length"),
+        source = %param("source"),
+    )
+}
+
+/// Build the axum router for `config`; `host`, `port`, `prefill` and
+/// `require_sources` are [`run_server`]'s concern and ignored here.
+fn build_router(pool: impl Into<AsyncEntropyPool>, config: &ServerConfig) -> Router {
     let state = Arc::new(AppState {
-        pool: Mutex::new(pool),
-        allow_raw,
+        pool: pool.into(),
+        allow_raw: config.allow_raw,
+        refresh: config.refresh.clone(),
+        stream_health: Mutex::new(StreamHealth::new()),
+        max_batch_bytes: config.max_batch_bytes,
     });
 
-    Router::new()
+    let router = Router::new()
         .route("/", get(handle_index))
-        .route("/api/v1/random", get(handle_random))
+        .route("/openapi.json", get(openapi::handle_openapi))
+        .route("/api/v1/random", get(handle_random_http))
+        .route("/api/v1/random/batch", post(handle_random_batch))
         .route("/health", get(handle_health))
+        .route("/ping", get(handle_ping))
+        .route("/livez", get(handle_ping))
+        .route("/readyz", get(handle_readyz))
         .route("/sources", get(handle_sources))
+        .route("/sources/refresh", post(handle_refresh_sources))
         .route("/pool/status", get(handle_pool_status))
-        .with_state(state)
+        .route("/rpc", post(rpc::handle_rpc))
+        .with_state(state);
+
+    let router = if config.compress {
+        router.layer(compression::layer())
+    } else {
+        router
+    };
+    router.layer(
+        TraceLayer::new_for_http()
+            .make_span_with(request_span)
+            .on_response(
+                DefaultOnResponse::new()
+                    .level(tracing::Level::INFO)
+                    .latency_unit(LatencyUnit::Micros),
+            ),
+    )
+}
+
+/// Options for [`run_server`]. The default serves `127.0.0.1:8042` with
+/// every optional feature off.
+#[derive(Clone)]
+pub struct ServerConfig {
+    /// Bind address.
+    pub host: String,
+    pub port: u16,
+    /// Honor `?conditioning=` (including `raw`) on the random endpoints.
+    pub allow_raw: bool,
+    /// gzip/deflate JSON responses according to the client's
+    /// `Accept-Encoding` header.
+    pub compress: bool,
+    /// Enables `POST /sources/refresh`; refused with 403 when `None`.
+    pub refresh: Option<SourceDetector>,
+    /// Bytes of SHA-256 output kept ready in the background (see
+    /// [`EntropyPool::set_prefill`]); 0 is off.
+    pub prefill: usize,
+    /// Refuse to start with fewer healthy sources than this, counted after
+    /// one startup collection from every source.
+    pub require_sources: usize,
+    /// Total bytes one `POST /api/v1/random/batch` may draw.
+    pub max_batch_bytes: usize,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: 8042,
+            allow_raw: false,
+            compress: false,
+            refresh: None,
+            prefill: 0,
+            require_sources: 0,
+            max_batch_bytes: DEFAULT_MAX_BATCH_BYTES,
+        }
+    }
+}
+
+/// Run the HTTP entropy server with `config`.
+///
+/// With `require_sources` set, every source is first
+/// [warmed](EntropyPool::warm) once, and startup refuses to serve unless at
+/// least that many came back healthy with data. Otherwise the same warm-up
+/// runs in the background once listening, so `/readyz` turns ready when it
+/// finishes rather than waiting for the first request. A pool with no sources at all is served with a warning: output
+/// then comes from OS entropy alone (see [`HealthReport::degraded`]).
+///
+/// Returns (and logs) the error if the source requirement isn't met, the
+/// address cannot be bound, or serving fails.
+///
+pub async fn run_server(pool: EntropyPool, config: ServerConfig) -> std::io::Result<()> {
+    let require_sources = config.require_sources;
+    let pool =
+        tokio::task::spawn_blocking(move || check_sources(&pool, require_sources).map(|()| pool))
+            .await
+            .unwrap_or_else(|e| std::panic::resume_unwind(e.into_panic()))
+            .inspect_err(|e| tracing::error!(error = %e, "refusing to start"))?;
+    let pool = AsyncEntropyPool::new(pool);
+    if config.prefill > 0 {
+        pool.set_prefill(config.prefill);
+    }
+    let app = build_router(pool.clone(), &config);
+    let addr = format!("{}:{}", config.host, config.port);
+    let listener = tokio::net::TcpListener::bind(&addr)
+        .await
+        .inspect_err(|e| tracing::error!(%addr, error = %e, "failed to bind"))?;
+    tracing::info!(%addr, "listening");
+    if require_sources == 0 {
+        tokio::spawn(async move { pool.warm(STARTUP_SAMPLES, STARTUP_TIMEOUT_SECS).await });
+    }
+    axum::serve(listener, app)
+        .await
+        .inspect_err(|e| tracing::error!(error = %e, "server stopped"))
+}
+
+/// Sources that have collected data and were healthy on their last
+/// collection. A source never collected from counts as healthy in
+/// [`HealthReport::healthy`] but not here.
+fn serving_sources(report: &HealthReport) -> usize {
+    report
+        .sources
+        .iter()
+        .filter(|s| s.healthy && s.bytes > 0)
+        .count()
 }
 
-/// Run the HTTP entropy server.
-pub async fn run_server(pool: EntropyPool, host: &str, port: u16, allow_raw: bool) {
-    let app = build_router(pool, allow_raw);
-    let addr = format!("{host}:{port}");
-    let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
-    axum::serve(listener, app).await.unwrap();
+/// Startup validation for [`run_server`]. Blocks for the startup collection
+/// when `require_sources` is set.
+fn check_sources(pool: &EntropyPool, require_sources: usize) -> std::io::Result<()> {
+    if require_sources > 0 {
+        pool.warm(STARTUP_SAMPLES, STARTUP_TIMEOUT_SECS);
+    }
+    let report = pool.health_report();
+    let serving = serving_sources(&report);
+    if serving < require_sources {
+        return Err(std::io::Error::other(format!(
+            "need at least {require_sources} healthy entropy source(s), found {serving} of {}",
+            report.total
+        )));
+    }
+    if report.total == 0 {
+        tracing::warn!("no entropy sources; serving OS entropy only (degraded)");
+    }
+    Ok(())
 }
 
 // Simple hex encoding without external dep
@@ -349,7 +808,168 @@ mod hex {
 
 #[cfg(test)]
 mod tests {
-    use super::{DiagnosticsParams, include_telemetry};
+    use super::{
+        DEFAULT_MAX_BATCH_BYTES, DiagnosticsParams, Endian, EntropyPool, MAX_BATCH_ITEMS,
+        ServerConfig, SourceDetector, build_router, encode_data, include_telemetry,
+    };
+    use openentropy_core::{AsyncEntropyPool, ReplaySource};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// Serve `app` on an ephemeral port and return the full raw response.
+    async fn send(app: axum::Router, method: &str, path: &str, accept_encoding: &str) -> String {
+        send_body(app, method, path, accept_encoding, "").await
+    }
+
+    /// [`send`] with a request body.
+    async fn send_body(
+        app: axum::Router,
+        method: &str,
+        path: &str,
+        accept_encoding: &str,
+        body: &str,
+    ) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        let request = format!(
+            "{method} {path} HTTP/1.1\r\nHost: localhost\r\nAccept-Encoding: {accept_encoding}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response).await.unwrap();
+        String::from_utf8_lossy(&response).into_owned()
+    }
+
+    /// Serve the router on an ephemeral port and return the raw response head.
+    async fn fetch_headers(compress: bool, path: &str, accept_encoding: &str) -> String {
+        let app = build_router(
+            EntropyPool::new(Some(b"test")),
+            &ServerConfig {
+                compress,
+                ..ServerConfig::default()
+            },
+        );
+        let response = send(app, "GET", path, accept_encoding).await;
+        let end = response.find("\r\n\r\n").unwrap_or(response.len());
+        response[..end].to_ascii_lowercase()
+    }
+
+    #[tokio::test]
+    async fn run_server_returns_error_for_bound_port() {
+        let taken = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = taken.local_addr().unwrap().port();
+        let pool = EntropyPool::new(Some(b"test"));
+        let err = super::run_server(
+            pool,
+            ServerConfig {
+                port,
+                ..ServerConfig::default()
+            },
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::AddrInUse);
+    }
+
+    #[tokio::test]
+    async fn run_server_enforces_required_sources() {
+        // Both sources start out healthy; only collecting shows one is empty.
+        let mut pool = slow_pool(Duration::ZERO);
+        pool.add_source(Box::new(ReplaySource::from_bytes("empty", Vec::new())), 1.0);
+        assert_eq!(pool.health_report().healthy, 2);
+        let err = super::run_server(
+            pool,
+            ServerConfig {
+                port: 0,
+                require_sources: 2,
+                ..ServerConfig::default()
+            },
+        )
+        .await
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("need at least 2 healthy entropy source(s), found 1 of 2"),
+            "{err}"
+        );
+
+        assert!(super::check_sources(&slow_pool(Duration::ZERO), 1).is_ok());
+        assert!(super::check_sources(&EntropyPool::new(Some(b"test")), 0).is_ok());
+        assert!(super::check_sources(&EntropyPool::new(Some(b"test")), 1).is_err());
+    }
+
+    #[tokio::test]
+    async fn refresh_requires_opt_in() {
+        let app = build_router(EntropyPool::new(Some(b"test")), &ServerConfig::default());
+        let response = send(app, "POST", "/sources/refresh", "identity").await;
+        assert!(response.starts_with("HTTP/1.1 403"), "{response}");
+    }
+
+    #[tokio::test]
+    async fn refresh_reports_new_source_count() {
+        let detect: SourceDetector = Arc::new(|| {
+            openentropy_core::detect_available_sources()
+                .into_iter()
+                .filter(|s| s.name() == "clock_jitter")
+                .map(|s| (s, 1.0))
+                .collect()
+        });
+        let app = build_router(
+            EntropyPool::new(Some(b"test")),
+            &ServerConfig {
+                refresh: Some(detect),
+                ..ServerConfig::default()
+            },
+        );
+        let response = send(app, "POST", "/sources/refresh", "identity").await;
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+        assert!(response.ends_with(r#"{"sources":1}"#), "{response}");
+    }
+
+    #[test]
+    fn endian_swaps_multi_byte_values() {
+        let raw = [0x01, 0x02, 0x03, 0x04, 0x05];
+        let u16_le = encode_data(&raw, "uint16", Endian::Little);
+        let u16_be = encode_data(&raw, "uint16", Endian::Big);
+        assert_eq!(u16_le, serde_json::json!([0x0201, 0x0403]));
+        assert_eq!(u16_be, serde_json::json!([0x0102, 0x0304]));
+        assert_eq!(
+            encode_data(&raw, "uint32", Endian::Little),
+            serde_json::json!([0x0403_0201])
+        );
+        assert_eq!(
+            encode_data(&raw, "uint32", Endian::Big),
+            serde_json::json!([0x0102_0304])
+        );
+        assert_eq!(
+            encode_data(&raw, "uint8", Endian::Big),
+            encode_data(&raw, "uint8", Endian::Little)
+        );
+
+        assert_eq!(Endian::parse(None), Some(Endian::Little));
+        assert_eq!(Endian::parse(Some("be")), Some(Endian::Big));
+        assert_eq!(Endian::parse(Some("network")), None);
+    }
+
+    #[tokio::test]
+    async fn random_rejects_unknown_endian() {
+        let app = build_router(EntropyPool::new(Some(b"test")), &ServerConfig::default());
+        let response = send(
+            app,
+            "GET",
+            "/api/v1/random?type=uint16&endian=xx",
+            "identity",
+        )
+        .await;
+        assert!(response.starts_with("HTTP/1.1 400"), "{response}");
+    }
 
     #[test]
     fn telemetry_flag_defaults_to_false() {
@@ -359,4 +979,307 @@ mod tests {
             telemetry: Some(true),
         }));
     }
+
+    #[tokio::test]
+    async fn compress_flag_gzips_json_random() {
+        let head = fetch_headers(true, "/api/v1/random?length=4096&type=uint8", "gzip").await;
+        assert!(head.starts_with("http/1.1 200"), "{head}");
+        assert!(head.contains("content-encoding: gzip"), "{head}");
+
+        let head = fetch_headers(true, "/api/v1/random?length=4096&type=uint8", "deflate").await;
+        assert!(head.contains("content-encoding: deflate"), "{head}");
+
+        // Small bodies are not worth encoding.
+        let head = fetch_headers(true, "/api/v1/random?length=4&type=uint8", "gzip").await;
+        assert!(!head.contains("content-encoding"), "{head}");
+    }
+
+    #[tokio::test]
+    async fn responses_are_identity_without_compress_flag() {
+        let head = fetch_headers(false, "/api/v1/random?length=4096&type=uint8", "gzip").await;
+        assert!(head.starts_with("http/1.1 200"), "{head}");
+        assert!(!head.contains("content-encoding"), "{head}");
+    }
+
+    #[tokio::test]
+    async fn random_reports_stream_health_headers() {
+        let head = fetch_headers(false, "/api/v1/random?length=4096&type=uint8", "identity").await;
+        assert!(head.contains("x-openentropy-stream-bits: 32768"), "{head}");
+        for name in ["monobit", "runs", "byte-frequency"] {
+            assert!(
+                head.contains(&format!("x-openentropy-{name}-p: ")),
+                "{head}"
+            );
+        }
+
+        let head = fetch_headers(false, "/api/v1/random?length=4&type=uint8", "identity").await;
+        assert!(head.contains("x-openentropy-stream-bits: 32"), "{head}");
+        assert!(!head.contains("x-openentropy-monobit-p"), "{head}");
+    }
+
+    /// POST a JSON-RPC body to `/rpc` and parse the response body.
+    async fn rpc(body: &str) -> serde_json::Value {
+        let app = build_router(EntropyPool::new(Some(b"test")), &ServerConfig::default());
+        let response = send_body(app, "POST", "/rpc", "identity", body).await;
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+        let start = response.find("\r\n\r\n").unwrap() + 4;
+        serde_json::from_str(&response[start..]).unwrap()
+    }
+
+    #[tokio::test]
+    async fn rpc_batch_returns_ordered_responses() {
+        let responses = rpc(r#"[
+            {"jsonrpc":"2.0","method":"random","params":{"length":8,"type":"uint8"},"id":1},
+            {"jsonrpc":"2.0","method":"health","id":"h"}
+        ]"#)
+        .await;
+        let responses = responses.as_array().unwrap();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0]["id"], 1);
+        assert_eq!(responses[0]["result"]["length"], 8);
+        assert_eq!(responses[0]["result"]["data"].as_array().unwrap().len(), 8);
+        assert_eq!(responses[1]["id"], "h");
+        assert_eq!(responses[1]["result"]["sources_total"], 0);
+        assert!(responses[1]["result"]["status"].is_string());
+    }
+
+    #[tokio::test]
+    async fn rpc_reports_spec_errors() {
+        let response = rpc(r#"{"jsonrpc":"2.0","method":"nope","id":7}"#).await;
+        assert_eq!(response["error"]["code"], -32601);
+        assert_eq!(response["id"], 7);
+
+        let response = rpc(r#"{"jsonrpc":"2.0","method":"random","params":[8],"id":8}"#).await;
+        assert_eq!(response["error"]["code"], -32602);
+
+        let response = rpc("[").await;
+        assert_eq!(response["error"]["code"], -32700);
+        assert!(response["id"].is_null());
+
+        let response = rpc("[]").await;
+        assert_eq!(response["error"]["code"], -32600);
+
+        let calls = vec![r#"{"jsonrpc":"2.0","method":"health"}"#; MAX_BATCH_ITEMS + 1];
+        let response = rpc(&format!("[{}]", calls.join(","))).await;
+        assert_eq!(response["error"]["code"], -32600);
+        assert!(response["id"].is_null());
+    }
+
+    #[tokio::test]
+    async fn rpc_batch_shares_the_byte_budget() {
+        let app = build_router(
+            EntropyPool::new(Some(b"test")),
+            &ServerConfig {
+                max_batch_bytes: 40,
+                ..ServerConfig::default()
+            },
+        );
+        let call = |id: u32| {
+            format!(
+                r#"{{"jsonrpc":"2.0","method":"random","params":{{"length":32,"type":"uint8"}},"id":{id}}}"#
+            )
+        };
+        let body = format!("[{},{},{}]", call(1), call(2), call(3));
+        let response = send_body(app.clone(), "POST", "/rpc", "identity", &body).await;
+        let start = response.find("\r\n\r\n").unwrap() + 4;
+        let responses: serde_json::Value = serde_json::from_str(&response[start..]).unwrap();
+        assert_eq!(responses[0]["result"]["length"], 32);
+        assert_eq!(responses[1]["result"]["length"], 8);
+        assert_eq!(responses[2]["error"]["code"], -32000);
+        assert!(
+            responses[2]["error"]["message"]
+                .as_str()
+                .unwrap()
+                .contains("byte limit of 40"),
+            "{responses}"
+        );
+
+        // A lone call is not a batch and keeps the per-request cap.
+        let response = send_body(app, "POST", "/rpc", "identity", &call(4)).await;
+        assert!(response.contains(r#""length":32"#), "{response}");
+    }
+
+    #[tokio::test]
+    async fn rpc_notifications_get_no_response() {
+        let app = build_router(EntropyPool::new(Some(b"test")), &ServerConfig::default());
+        let body = r#"[{"jsonrpc":"2.0","method":"health"}]"#;
+        let response = send_body(app, "POST", "/rpc", "identity", body).await;
+        assert!(response.starts_with("HTTP/1.1 204"), "{response}");
+    }
+
+    /// POST a batch body to `/api/v1/random/batch` and parse the response body.
+    async fn batch(max_batch_bytes: usize, body: &str) -> Vec<serde_json::Value> {
+        batch_on(EntropyPool::new(Some(b"test")), max_batch_bytes, body).await
+    }
+
+    /// [`batch`] against `pool`.
+    async fn batch_on(
+        pool: EntropyPool,
+        max_batch_bytes: usize,
+        body: &str,
+    ) -> Vec<serde_json::Value> {
+        let app = build_router(
+            pool,
+            &ServerConfig {
+                max_batch_bytes,
+                ..ServerConfig::default()
+            },
+        );
+        let response = send_body(app, "POST", "/api/v1/random/batch", "identity", body).await;
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+        let start = response.find("\r\n\r\n").unwrap() + 4;
+        serde_json::from_str(&response[start..]).unwrap()
+    }
+
+    #[tokio::test]
+    async fn batch_returns_one_sized_response_per_request() {
+        let body = r#"{"requests":[
+            {"length":32,"type":"uint8"},
+            {"length":16,"type":"uint16","endian":"be"},
+            {"length":10}
+        ]}"#;
+        let responses = batch(DEFAULT_MAX_BATCH_BYTES, body).await;
+        assert_eq!(responses.len(), 3);
+        let expected = [("uint8", 32), ("uint16", 8), ("hex16", 5)];
+        for (response, (data_type, len)) in responses.iter().zip(expected) {
+            assert_eq!(response["success"], true, "{response}");
+            assert_eq!(response["type"], data_type);
+            assert_eq!(response["length"], len);
+            assert_eq!(response["data"].as_array().unwrap().len(), len);
+        }
+    }
+
+    #[tokio::test]
+    async fn batch_clamps_total_bytes_in_order() {
+        let body = r#"{"requests":[
+            {"length":32,"type":"uint8"},
+            {"length":32,"type":"uint8"},
+            {"length":32,"type":"uint8","endian":"middle"},
+            {"length":1,"type":"uint8"}
+        ]}"#;
+        let responses = batch(40, body).await;
+        assert_eq!(responses[0]["length"], 32);
+        assert_eq!(responses[1]["length"], 8);
+        assert_eq!(responses[2]["success"], false);
+        assert!(responses[2]["error"].as_str().unwrap().contains("endian"));
+        assert_eq!(responses[3]["success"], false);
+        assert!(responses[3]["error"].as_str().unwrap().contains("limit"));
+    }
+
+    #[tokio::test]
+    async fn batch_rejects_too_many_items() {
+        let items = vec![r#"{"length":0}"#; MAX_BATCH_ITEMS + 1].join(",");
+        let body = format!(r#"{{"requests":[{items}]}}"#);
+        let app = build_router(EntropyPool::new(Some(b"test")), &ServerConfig::default());
+        let response = send_body(app, "POST", "/api/v1/random/batch", "identity", &body).await;
+        assert!(response.starts_with("HTTP/1.1 400"), "{response}");
+        assert!(response.contains("exceeds the limit"), "{response}");
+
+        let items = vec![r#"{"length":0}"#; MAX_BATCH_ITEMS].join(",");
+        let body = format!(r#"{{"requests":[{items}]}}"#);
+        assert_eq!(
+            batch(DEFAULT_MAX_BATCH_BYTES, &body).await.len(),
+            MAX_BATCH_ITEMS
+        );
+    }
+
+    #[tokio::test]
+    async fn batch_fills_short_toeplitz_items() {
+        let body = r#"{"requests":[
+            {"length":4,"type":"uint8","conditioning":"toeplitz"},
+            {"length":1,"type":"uint8","conditioning":"toeplitz"}
+        ]}"#;
+        let mut pool = EntropyPool::new(Some(b"test"));
+        pool.add_source(
+            Box::new(openentropy_core::ReplaySource::from_seed("seeded", 7)),
+            1.0,
+        );
+        let responses = batch_on(pool, DEFAULT_MAX_BATCH_BYTES, body).await;
+        for (response, len) in responses.iter().zip([4, 1]) {
+            assert_eq!(response["success"], true, "{response}");
+            assert_eq!(response["length"], len);
+        }
+    }
+
+    /// A pool with one source whose collection blocks for `delay`, holding
+    /// its pool lock.
+    fn slow_pool(delay: Duration) -> EntropyPool {
+        let mut pool = EntropyPool::new(Some(b"test"));
+        pool.add_source(
+            Box::new(ReplaySource::from_seed("slow", 0).with_delay(delay)),
+            1.0,
+        );
+        pool
+    }
+
+    #[tokio::test]
+    async fn openapi_document_lists_random_length_param() {
+        let app = build_router(EntropyPool::new(Some(b"test")), &ServerConfig::default());
+        let response = send(app, "GET", "/openapi.json", "identity").await;
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+        let start = response.find("\r\n\r\n").unwrap() + 4;
+        let doc: serde_json::Value = serde_json::from_str(&response[start..]).unwrap();
+
+        assert!(doc["openapi"].as_str().unwrap().starts_with("3.0"));
+        let params = doc["paths"]["/api/v1/random"]["get"]["parameters"]
+            .as_array()
+            .unwrap();
+        let length = params.iter().find(|p| p["name"] == "length").unwrap();
+        assert_eq!(length["in"], "query");
+        assert_eq!(length["schema"]["maximum"], 65536);
+        for path in ["/health", "/sources", "/pool/status"] {
+            assert!(doc["paths"][path]["get"].is_object(), "{path}");
+        }
+    }
+
+    #[tokio::test]
+    async fn ping_and_livez_answer_ok() {
+        for path in ["/ping", "/livez"] {
+            let app = build_router(EntropyPool::new(Some(b"test")), &ServerConfig::default());
+            let response = send(app, "GET", path, "identity").await;
+            assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+            assert!(response.ends_with("ok"), "{response}");
+        }
+    }
+
+    #[tokio::test]
+    async fn livez_responds_while_collection_holds_the_pool() {
+        let app = build_router(slow_pool(Duration::from_secs(3)), &ServerConfig::default());
+        let busy = tokio::spawn(send(
+            app.clone(),
+            "GET",
+            "/api/v1/random?length=32",
+            "identity",
+        ));
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        let response = tokio::time::timeout(
+            Duration::from_secs(1),
+            send(app, "GET", "/livez", "identity"),
+        )
+        .await
+        .expect("/livez blocked behind the collection");
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+        assert!(!busy.is_finished());
+        busy.abort();
+    }
+
+    #[tokio::test]
+    async fn readyz_requires_a_healthy_source() {
+        let app = build_router(EntropyPool::new(Some(b"test")), &ServerConfig::default());
+        let response = send(app, "GET", "/readyz", "identity").await;
+        assert!(response.starts_with("HTTP/1.1 503"), "{response}");
+        assert!(response.contains(r#""ready":false"#), "{response}");
+
+        let pool = AsyncEntropyPool::new(slow_pool(Duration::ZERO));
+        let app = build_router(pool.clone(), &ServerConfig::default());
+        let response = send(app.clone(), "GET", "/readyz", "identity").await;
+        assert!(response.starts_with("HTTP/1.1 503"), "{response}");
+        assert!(response.contains(r#""sources_healthy":0"#), "{response}");
+
+        pool.collect_all().await;
+        let response = send(app, "GET", "/readyz", "identity").await;
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+        assert!(response.contains(r#""sources_healthy":1"#), "{response}");
+    }
 }
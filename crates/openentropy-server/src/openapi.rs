@@ -0,0 +1,319 @@
+//! OpenAPI 3.0 description of the HTTP API, served at `GET /openapi.json`.
+//!
+//! Hand-built JSON rather than derived from the handler types, so it must be
+//! kept in step with the `RandomParams`/response structs in `lib.rs` by hand.
+
+use axum::response::Json;
+use serde_json::{Value, json};
+
+/// `GET /openapi.json`.
+pub(crate) async fn handle_openapi() -> Json<Value> {
+    Json(document())
+}
+
+/// `telemetry` query parameter shared by `/sources` and `/pool/status`.
+fn telemetry_param() -> Value {
+    json!({
+        "name": "telemetry",
+        "in": "query",
+        "description": "Include a telemetry_v1 start/end report",
+        "schema": { "type": "boolean", "default": false },
+    })
+}
+
+/// Response object with a single JSON body.
+fn json_response(description: &str, schema: Value) -> Value {
+    json!({
+        "description": description,
+        "content": { "application/json": { "schema": schema } },
+    })
+}
+
+fn schema_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{name}") })
+}
+
+/// The full OpenAPI document.
+pub(crate) fn document() -> Value {
+    let p_header = |description: &str| {
+        json!({
+            "description": description,
+            "schema": { "type": "number", "minimum": 0, "maximum": 1 },
+        })
+    };
+
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "OpenEntropy Server",
+            "version": openentropy_core::VERSION,
+            "description": "Hardware entropy over HTTP (ANU QRNG API compatible).",
+        },
+        "paths": {
+            "/api/v1/random": {
+                "get": {
+                    "summary": "Get random entropy bytes",
+                    "parameters": [
+                        {
+                            "name": "length",
+                            "in": "query",
+                            "description": "Number of bytes; out-of-range values are clamped",
+                            "schema": { "type": "integer", "minimum": 1, "maximum": 65536, "default": 1024 },
+                        },
+                        {
+                            "name": "type",
+                            "in": "query",
+                            "description": "Encoding of `data`",
+                            "schema": { "type": "string", "enum": ["hex16", "uint8", "uint16", "uint32"], "default": "hex16" },
+                        },
+                        {
+                            "name": "endian",
+                            "in": "query",
+                            "description": "Byte order for uint16/uint32",
+                            "schema": { "type": "string", "enum": ["le", "be"], "default": "le" },
+                        },
+                        {
+                            "name": "conditioning",
+                            "in": "query",
+                            "description": "raw is honored only when the server runs with --allow-raw",
+                            "schema": { "type": "string", "enum": ["sha256", "vonneumann", "toeplitz", "raw"], "default": "sha256" },
+                        },
+                        {
+                            "name": "raw",
+                            "in": "query",
+                            "description": "Shorthand for conditioning=raw (requires --allow-raw)",
+                            "schema": { "type": "boolean", "default": false },
+                        },
+                        {
+                            "name": "source",
+                            "in": "query",
+                            "description": "Collect from one source by name (see /sources)",
+                            "schema": { "type": "string" },
+                        },
+                    ],
+                    "responses": {
+                        "200": {
+                            "description": "Random data",
+                            "headers": {
+                                "x-openentropy-stream-bits": {
+                                    "description": "Bits served since startup",
+                                    "schema": { "type": "integer" },
+                                },
+                                "x-openentropy-monobit-p": p_header("Running monobit p-value (omitted until enough data)"),
+                                "x-openentropy-runs-p": p_header("Running runs-test p-value (omitted until enough data)"),
+                                "x-openentropy-byte-frequency-p": p_header("Running byte-frequency p-value (omitted until enough data)"),
+                            },
+                            "content": { "application/json": { "schema": schema_ref("RandomResponse") } },
+                        },
+                        "400": json_response("Unknown source or invalid endian", schema_ref("RandomResponse")),
+                    },
+                },
+            },
+            "/api/v1/random/batch": {
+                "post": {
+                    "summary": "Several random draws in one request",
+                    "description": "Each item takes the /api/v1/random query parameters except `source`. Lengths are granted in order up to the server's total byte limit; the item crossing it is cut short and later items fail. At most 1024 items.",
+                    "requestBody": {
+                        "required": true,
+                        "content": { "application/json": { "schema": {
+                            "type": "object",
+                            "required": ["requests"],
+                            "properties": {
+                                "requests": {
+                                    "type": "array",
+                                    "maxItems": 1024,
+                                    "items": {
+                                        "type": "object",
+                                        "properties": {
+                                            "length": { "type": "integer", "minimum": 1, "maximum": 65536, "default": 1024 },
+                                            "type": { "type": "string", "enum": ["hex16", "uint8", "uint16", "uint32"], "default": "hex16" },
+                                            "endian": { "type": "string", "enum": ["le", "be"], "default": "le" },
+                                            "conditioning": { "type": "string", "enum": ["sha256", "vonneumann", "toeplitz", "raw"], "default": "sha256" },
+                                            "raw": { "type": "boolean", "default": false },
+                                        },
+                                    },
+                                },
+                            },
+                        } } },
+                    },
+                    "responses": {
+                        "200": json_response("One response per item, in order; failed items have success=false", json!({
+                            "type": "array",
+                            "items": schema_ref("RandomResponse"),
+                        })),
+                        "400": json_response("More than 1024 items", json!({
+                            "type": "object",
+                            "properties": { "error": { "type": "string" } },
+                        })),
+                    },
+                },
+            },
+            "/health": {
+                "get": {
+                    "summary": "Pool health summary",
+                    "responses": { "200": json_response("Health summary", schema_ref("HealthResponse")) },
+                },
+            },
+            "/ping": {
+                "get": {
+                    "summary": "Liveness check; answers without locking the pool",
+                    "responses": {
+                        "200": {
+                            "description": "Always `ok`",
+                            "content": { "text/plain": { "schema": { "type": "string" } } },
+                        },
+                    },
+                },
+            },
+            "/livez": {
+                "get": {
+                    "summary": "Liveness probe (same as /ping)",
+                    "responses": {
+                        "200": {
+                            "description": "Always `ok`",
+                            "content": { "text/plain": { "schema": { "type": "string" } } },
+                        },
+                    },
+                },
+            },
+            "/readyz": {
+                "get": {
+                    "summary": "Readiness probe",
+                    "responses": {
+                        "200": json_response("At least one source has collected data and is healthy", schema_ref("ReadyResponse")),
+                        "503": json_response("No source has collected healthy data yet", schema_ref("ReadyResponse")),
+                    },
+                },
+            },
+            "/sources": {
+                "get": {
+                    "summary": "Active entropy sources with health metrics",
+                    "parameters": [telemetry_param()],
+                    "responses": { "200": json_response("Source list", schema_ref("SourcesResponse")) },
+                },
+            },
+            "/sources/refresh": {
+                "post": {
+                    "summary": "Re-detect hardware and rebuild the source set",
+                    "responses": {
+                        "200": json_response("New source count", json!({
+                            "type": "object",
+                            "properties": { "sources": { "type": "integer" } },
+                        })),
+                        "403": json_response("Server started without --allow-refresh", json!({
+                            "type": "object",
+                            "properties": { "error": { "type": "string" } },
+                        })),
+                    },
+                },
+            },
+            "/pool/status": {
+                "get": {
+                    "summary": "Detailed pool status",
+                    "parameters": [telemetry_param()],
+                    "responses": { "200": json_response("Pool status", schema_ref("PoolStatus")) },
+                },
+            },
+            "/rpc": {
+                "post": {
+                    "summary": "JSON-RPC 2.0; single calls or batched arrays, answered in order",
+                    "description": "Batches hold at most 1024 calls; a larger batch gets a single -32600 error. A batch's `random` calls share the server's total byte limit for /api/v1/random/batch: the call crossing it is cut short and later ones fail with -32000.",
+                    "requestBody": {
+                        "required": true,
+                        "content": { "application/json": { "schema": { "type": "object" } } },
+                    },
+                    "responses": { "200": json_response("JSON-RPC response(s)", json!({})) },
+                },
+            },
+        },
+        "components": {
+            "schemas": {
+                "RandomResponse": {
+                    "type": "object",
+                    "required": ["type", "length", "data", "success", "conditioned"],
+                    "properties": {
+                        "type": { "type": "string" },
+                        "length": { "type": "integer", "description": "Number of elements in `data`" },
+                        "data": {
+                            "description": "Array of hex16 strings or integers, per `type`",
+                            "type": "array",
+                            "items": {},
+                        },
+                        "success": { "type": "boolean" },
+                        "conditioned": { "type": "boolean" },
+                        "source": { "type": "string" },
+                        "error": { "type": "string" },
+                    },
+                },
+                "HealthResponse": {
+                    "type": "object",
+                    "properties": {
+                        "status": { "type": "string", "enum": ["healthy", "degraded"] },
+                        "sources_healthy": { "type": "integer" },
+                        "sources_total": { "type": "integer" },
+                        "raw_bytes": { "type": "integer" },
+                        "output_bytes": { "type": "integer" },
+                    },
+                },
+                "ReadyResponse": {
+                    "type": "object",
+                    "properties": {
+                        "ready": { "type": "boolean" },
+                        "sources_healthy": { "type": "integer" },
+                        "sources_total": { "type": "integer" },
+                    },
+                },
+                "SourceEntry": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string" },
+                        "healthy": { "type": "boolean" },
+                        "bytes": { "type": "integer" },
+                        "entropy": { "type": "number" },
+                        "time": { "type": "number" },
+                        "failures": { "type": "integer" },
+                        "stuck": { "type": "boolean" },
+                        "typical_latency_ms": { "type": "number" },
+                        "cost_class": { "type": "string" },
+                    },
+                },
+                "SourcesResponse": {
+                    "type": "object",
+                    "properties": {
+                        "sources": { "type": "array", "items": schema_ref("SourceEntry") },
+                        "total": { "type": "integer" },
+                        "telemetry_v1": { "type": "object" },
+                    },
+                },
+                "PoolStatus": {
+                    "type": "object",
+                    "properties": {
+                        "healthy": { "type": "integer" },
+                        "total": { "type": "integer" },
+                        "raw_bytes": { "type": "integer" },
+                        "output_bytes": { "type": "integer" },
+                        "buffer_size": { "type": "integer" },
+                        "prefill_bytes": { "type": "integer" },
+                        "prefill_target": { "type": "integer" },
+                        "sources": {
+                            "type": "array",
+                            "items": {
+                                "type": "object",
+                                "properties": {
+                                    "name": { "type": "string" },
+                                    "healthy": { "type": "boolean" },
+                                    "bytes": { "type": "integer" },
+                                    "entropy": { "type": "number" },
+                                    "time": { "type": "number" },
+                                    "failures": { "type": "integer" },
+                                    "stuck": { "type": "boolean" },
+                                },
+                            },
+                        },
+                        "telemetry_v1": { "type": "object" },
+                    },
+                },
+            },
+        },
+    })
+}
@@ -0,0 +1,180 @@
+//! JSON-RPC 2.0 front end (`POST /rpc`).
+//!
+//! Each method maps its `params` object onto the query parameters of the
+//! matching GET endpoint and returns that endpoint's response body as the
+//! `result`. Batched calls run in order and their responses come back in
+//! request order; notifications (calls without an `id`) run but produce no
+//! response. A batch of more than [`MAX_BATCH_ITEMS`] calls is rejected
+//! whole as an invalid request, and its `random` calls share the
+//! `max_batch_bytes` budget of `POST /api/v1/random/batch`: the call that
+//! crosses it is cut short and later ones fail.
+
+use std::sync::Arc;
+
+use axum::{
+    body::Bytes,
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Json, Response},
+};
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::{Value, json};
+
+use super::{
+    AppState, MAX_BATCH_ITEMS, RandomParams, handle_health, handle_random, handle_sources,
+};
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+/// The handler rejected the call, e.g. an unknown `source`.
+const SERVER_ERROR: i64 = -32000;
+
+/// Methods accepted by `POST /rpc`.
+pub(crate) const METHODS: [&str; 3] = ["random", "health", "sources"];
+
+type RpcError = (i64, String);
+
+fn error_response(id: Value, code: i64, message: impl Into<String>) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "error": { "code": code, "message": message.into() },
+        "id": id,
+    })
+}
+
+/// Deserialize `params` into a handler's query struct; absent means defaults.
+fn parse_params<T: DeserializeOwned + Default>(params: Option<Value>) -> Result<T, RpcError> {
+    match params {
+        None | Some(Value::Null) => Ok(T::default()),
+        Some(params @ Value::Object(_)) => serde_json::from_value(params)
+            .map_err(|e| (INVALID_PARAMS, format!("Invalid params: {e}"))),
+        Some(_) => Err((
+            INVALID_PARAMS,
+            "Invalid params: expected an object".to_string(),
+        )),
+    }
+}
+
+fn to_result<T: Serialize>(body: T) -> Value {
+    serde_json::to_value(body).unwrap_or(Value::Null)
+}
+
+/// Run `method`. A `random` call draws at most `budget` bytes and spends
+/// what it draws.
+async fn call_method(
+    state: &Arc<AppState>,
+    method: &str,
+    params: Option<Value>,
+    budget: &mut usize,
+) -> Result<Value, RpcError> {
+    let limit = state.max_batch_bytes;
+    let state = State(Arc::clone(state));
+    match method {
+        "random" => {
+            let mut params: RandomParams = parse_params(params)?;
+            let length = params.length().min(*budget);
+            if length == 0 {
+                return Err((SERVER_ERROR, format!("batch byte limit of {limit} reached")));
+            }
+            params.length = Some(length);
+            let (status, Json(body)) = handle_random(state, Query(params)).await;
+            if status.is_success() {
+                *budget -= length;
+                Ok(to_result(body))
+            } else {
+                Err((SERVER_ERROR, body.error.unwrap_or_default()))
+            }
+        }
+        "health" => Ok(to_result(handle_health(state).await.0)),
+        "sources" => Ok(to_result(
+            handle_sources(state, Query(parse_params(params)?)).await.0,
+        )),
+        _ => Err((METHOD_NOT_FOUND, format!("Method not found: {method}"))),
+    }
+}
+
+/// Run one call against `budget` (see [`call_method`]). Returns `None` for
+/// notifications.
+async fn dispatch(state: &Arc<AppState>, call: Value, budget: &mut usize) -> Option<Value> {
+    let Value::Object(mut call) = call else {
+        return Some(error_response(
+            Value::Null,
+            INVALID_REQUEST,
+            "Invalid Request",
+        ));
+    };
+    let id = call.remove("id");
+    let method = match (call.remove("jsonrpc"), call.remove("method")) {
+        (Some(Value::String(v)), Some(Value::String(method))) if v == "2.0" => method,
+        _ => {
+            return Some(error_response(
+                id.unwrap_or(Value::Null),
+                INVALID_REQUEST,
+                "Invalid Request",
+            ));
+        }
+    };
+    let outcome = call_method(state, &method, call.remove("params"), budget).await;
+    let id = id?;
+    Some(match outcome {
+        Ok(result) => json!({ "jsonrpc": "2.0", "result": result, "id": id }),
+        Err((code, message)) => error_response(id, code, message),
+    })
+}
+
+pub(crate) async fn handle_rpc(State(state): State<Arc<AppState>>, body: Bytes) -> Response {
+    let request: Value = match serde_json::from_slice(&body) {
+        Ok(request) => request,
+        Err(e) => {
+            return Json(error_response(
+                Value::Null,
+                PARSE_ERROR,
+                format!("Parse error: {e}"),
+            ))
+            .into_response();
+        }
+    };
+
+    let Value::Array(calls) = request else {
+        // A single call is capped only like `GET /api/v1/random`.
+        let mut unlimited = usize::MAX;
+        return match dispatch(&state, request, &mut unlimited).await {
+            Some(response) => Json(response).into_response(),
+            None => StatusCode::NO_CONTENT.into_response(),
+        };
+    };
+    if calls.is_empty() {
+        return Json(error_response(
+            Value::Null,
+            INVALID_REQUEST,
+            "Invalid Request: empty batch",
+        ))
+        .into_response();
+    }
+    if calls.len() > MAX_BATCH_ITEMS {
+        return Json(error_response(
+            Value::Null,
+            INVALID_REQUEST,
+            format!(
+                "Invalid Request: batch of {} calls exceeds the limit of {MAX_BATCH_ITEMS}",
+                calls.len()
+            ),
+        ))
+        .into_response();
+    }
+
+    let mut budget = state.max_batch_bytes;
+    let mut responses = Vec::with_capacity(calls.len());
+    for call in calls {
+        if let Some(response) = dispatch(&state, call, &mut budget).await {
+            responses.push(response);
+        }
+    }
+    if responses.is_empty() {
+        StatusCode::NO_CONTENT.into_response()
+    } else {
+        Json(Value::Array(responses)).into_response()
+    }
+}
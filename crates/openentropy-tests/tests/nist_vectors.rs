@@ -0,0 +1,63 @@
+//! SP 800-22 reference vectors.
+//!
+//! `fixtures/{e,pi,sqrt2}.bin` hold the first 1,000,000 bits of the binary
+//! expansions used in SP 800-22 Rev. 1a Appendix B (integer-part bits
+//! included, as in NIST's `data.e`/`data.pi`/`data.sqrt2`), packed MSB-first
+//! into 125,000 bytes. They were generated with mpmath, e.g. for e:
+//! `int(floor(e * 2**(10**6 - 2))).to_bytes(125_000, "big")`.
+//!
+//! Expected p-values are the Appendix B table entries, published to six
+//! decimal places.
+
+use openentropy_tests::{
+    TestResult, block_frequency, cusum_test, dft_spectral, monobit_frequency, runs_test,
+};
+
+/// Published values are rounded to 1e-6; allow a little slack for that.
+const TOLERANCE: f64 = 1e-5;
+
+type Test = fn(&[u8]) -> TestResult;
+
+const TESTS: [(&str, Test); 5] = [
+    ("frequency", monobit_frequency),
+    ("block_frequency", block_frequency),
+    ("cusum_forward", cusum_test),
+    ("runs", runs_test),
+    ("fft", dft_spectral),
+];
+
+fn fixture(name: &str) -> Vec<u8> {
+    let path = format!("{}/tests/fixtures/{name}.bin", env!("CARGO_MANIFEST_DIR"));
+    let data = std::fs::read(&path).unwrap_or_else(|e| panic!("{path}: {e}"));
+    assert_eq!(data.len(), 125_000, "{path}");
+    data
+}
+
+/// Run every test on `name` and compare with `expected` (in `TESTS` order).
+fn check(name: &str, expected: [f64; 5]) {
+    let data = fixture(name);
+    for ((test, run), want) in TESTS.iter().zip(expected) {
+        let result = run(&data);
+        let got = result.p_value.expect("p-value");
+        assert!(
+            (got - want).abs() < TOLERANCE,
+            "{name} {test}: p={got:.6}, SP 800-22 gives {want:.6} ({})",
+            result.details
+        );
+    }
+}
+
+#[test]
+fn expansion_of_e() {
+    check("e", [0.953749, 0.211072, 0.669887, 0.561917, 0.847187]);
+}
+
+#[test]
+fn expansion_of_pi() {
+    check("pi", [0.578211, 0.380615, 0.628308, 0.419268, 0.010186]);
+}
+
+#[test]
+fn expansion_of_sqrt2() {
+    check("sqrt2", [0.811881, 0.833222, 0.879009, 0.313427, 0.581909]);
+}
@@ -7,7 +7,9 @@
 //!
 //! Plus a combined SHA-256 conditioned output (`get_random_bytes`) that mixes
 //! both sources. All raw sources produce bytes that can be further conditioned
-//! on the JS side or consumed directly.
+//! on the JS side or consumed directly. `get_random_bytes_with_status` also
+//! reports whether `crypto.getRandomValues()` was actually used, since without
+//! it the output silently rests on timing jitter alone.
 
 use sha2::{Digest, Sha256};
 use wasm_bindgen::prelude::*;
@@ -128,12 +130,18 @@ pub fn collect_timing_jitter(n_bytes: usize) -> Vec<u8> {
 /// timing-based sources.
 #[wasm_bindgen]
 pub fn collect_crypto_random(n_bytes: usize) -> Vec<u8> {
+    collect_crypto_random_checked(n_bytes).0
+}
+
+/// [`collect_crypto_random`] plus whether the crypto API supplied the bytes
+/// (`false` means the timing-jitter fallback did).
+fn collect_crypto_random_checked(n_bytes: usize) -> (Vec<u8>, bool) {
     let mut buf = vec![0u8; n_bytes];
     if !crypto_get_random(&mut buf) {
         // Fallback: fill with timing-based entropy if crypto API unavailable
-        return collect_timing_jitter(n_bytes);
+        return (collect_timing_jitter(n_bytes), false);
     }
-    buf
+    (buf, true)
 }
 
 // ---------------------------------------------------------------------------
@@ -148,12 +156,42 @@ pub fn collect_crypto_random(n_bytes: usize) -> Vec<u8> {
 /// applications that need high-quality random bytes.
 #[wasm_bindgen]
 pub fn get_random_bytes(n_bytes: usize) -> Vec<u8> {
+    conditioned_bytes(n_bytes).0
+}
+
+/// [`get_random_bytes`] with a report of which sources fed it.
+///
+/// Returns `{ bytes: Uint8Array, crypto_available: boolean, sources_used:
+/// string[] }`. `sources_used` is `["timing_jitter", "crypto"]` normally and
+/// `["timing_jitter"]` when `crypto.getRandomValues()` is missing or throws,
+/// in which case the output has no OS-entropy backing.
+#[wasm_bindgen]
+pub fn get_random_bytes_with_status(n_bytes: usize) -> JsValue {
+    let (bytes, crypto_available) = conditioned_bytes(n_bytes);
+    let sources_used = js_sys::Array::of1(&JsValue::from_str("timing_jitter"));
+    if crypto_available {
+        sources_used.push(&JsValue::from_str("crypto"));
+    }
+
+    let status = js_sys::Object::new();
+    let set = |key: &str, value: &JsValue| {
+        // Setting a plain property on a fresh object cannot fail.
+        let _ = js_sys::Reflect::set(&status, &JsValue::from_str(key), value);
+    };
+    set("bytes", &js_sys::Uint8Array::from(bytes.as_slice()).into());
+    set("crypto_available", &JsValue::from_bool(crypto_available));
+    set("sources_used", &sources_used.into());
+    status.into()
+}
+
+/// Conditioned output plus whether the crypto seed came from the crypto API.
+fn conditioned_bytes(n_bytes: usize) -> (Vec<u8>, bool) {
     let mut output = Vec::with_capacity(n_bytes);
     let mut counter: u64 = 0;
 
     // Collect raw material from both sources
     let timing = collect_timing_jitter(n_bytes.max(32));
-    let crypto = collect_crypto_random(32);
+    let (crypto, crypto_available) = collect_crypto_random_checked(32);
 
     // Initial state from crypto source
     let mut state: [u8; 32] = {
@@ -185,7 +223,7 @@ pub fn get_random_bytes(n_bytes: usize) -> Vec<u8> {
     }
 
     output.truncate(n_bytes);
-    output
+    (output, crypto_available)
 }
 
 /// Return the number of available entropy sources in this WASM environment.
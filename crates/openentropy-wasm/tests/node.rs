@@ -0,0 +1,26 @@
+//! wasm-bindgen tests, run under Node with
+//! `wasm-pack test --node crates/openentropy-wasm`.
+
+#![cfg(target_arch = "wasm32")]
+
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+fn field(obj: &JsValue, key: &str) -> JsValue {
+    js_sys::Reflect::get(obj, &JsValue::from_str(key)).unwrap()
+}
+
+#[wasm_bindgen_test]
+fn status_reports_crypto_available() {
+    let status = openentropy_wasm::get_random_bytes_with_status(64);
+    assert_eq!(field(&status, "crypto_available").as_bool(), Some(true));
+
+    let bytes = js_sys::Uint8Array::new(&field(&status, "bytes"));
+    assert_eq!(bytes.length(), 64);
+
+    let sources: Vec<String> = js_sys::Array::from(&field(&status, "sources_used"))
+        .iter()
+        .filter_map(|s| s.as_string())
+        .collect();
+    assert_eq!(sources, ["timing_jitter", "crypto"]);
+}
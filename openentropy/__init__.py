@@ -12,16 +12,29 @@ __author__ = "Amenti Labs"
 from openentropy.openentropy import (
     EntropyPool,
     detect_available_sources,
+    detect_available_sources_filtered,
     platform_info,
     detect_machine_info,
     run_all_tests,
+    run_all_tests_hex,
+    run_all_tests_base64,
+    run_all_tests_applicable,
+    run_all_tests_uniformity,
+    battery_report,
+    binary_matrix_rank,
+    lempel_ziv_complexity,
     calculate_quality_score,
+    combined_pvalue,
     condition,
     min_entropy_estimate,
+    min_entropy_estimate_hex,
+    min_entropy_estimate_base64,
     quick_min_entropy,
     quick_shannon,
     grade_min_entropy,
     quick_quality,
+    quick_quality_hex,
+    quick_quality_base64,
     version as _rust_version,
 )
 
@@ -35,16 +48,29 @@ def version() -> str:
 __all__ = [
     "EntropyPool",
     "detect_available_sources",
+    "detect_available_sources_filtered",
     "platform_info",
     "detect_machine_info",
     "run_all_tests",
+    "run_all_tests_hex",
+    "run_all_tests_base64",
+    "run_all_tests_applicable",
+    "run_all_tests_uniformity",
+    "battery_report",
+    "binary_matrix_rank",
+    "lempel_ziv_complexity",
     "calculate_quality_score",
+    "combined_pvalue",
     "condition",
     "min_entropy_estimate",
+    "min_entropy_estimate_hex",
+    "min_entropy_estimate_base64",
     "quick_min_entropy",
     "quick_shannon",
     "grade_min_entropy",
     "quick_quality",
+    "quick_quality_hex",
+    "quick_quality_base64",
     "version",
     "__version__",
     "__rust_backend__",
@@ -0,0 +1,19 @@
+"""detect_available_sources_filtered skips sources by requirement."""
+
+import pytest
+
+import openentropy
+
+
+def test_network_sources_excluded():
+    sources = openentropy.detect_available_sources_filtered(["network", "wifi"])
+    for s in sources:
+        assert "network" not in s["requirements"]
+        assert "wifi" not in s["requirements"]
+    names = {s["name"] for s in sources}
+    assert names.isdisjoint({"dns_timing", "tcp_connect_timing", "wifi_rssi"})
+
+
+def test_unknown_requirement_raises():
+    with pytest.raises(ValueError, match="invalid requirement"):
+        openentropy.detect_available_sources_filtered(["teleporter"])
@@ -0,0 +1,35 @@
+"""Hex/base64 entry points decode and match the raw-bytes functions."""
+
+import base64
+
+import pytest
+
+import openentropy
+
+DATA = bytes((i * 73 + 11) % 256 for i in range(4096))
+
+
+def test_run_all_tests_hex_and_base64_match_bytes():
+    expected = openentropy.run_all_tests(DATA)
+    assert openentropy.run_all_tests_hex(DATA.hex()) == expected
+    assert openentropy.run_all_tests_base64(base64.b64encode(DATA).decode()) == expected
+
+
+def test_estimators_match_bytes():
+    b64 = base64.b64encode(DATA).decode()
+    assert openentropy.min_entropy_estimate_hex(DATA.hex()) == openentropy.min_entropy_estimate(DATA)
+    assert openentropy.min_entropy_estimate_base64(b64) == openentropy.min_entropy_estimate(DATA)
+    assert openentropy.quick_quality_hex(DATA.hex().upper()) == openentropy.quick_quality(DATA)
+    assert openentropy.quick_quality_base64(b64.rstrip("=")) == openentropy.quick_quality(DATA)
+
+
+@pytest.mark.parametrize("bad", ["abc", "zz"])
+def test_malformed_hex_raises(bad):
+    with pytest.raises(ValueError, match="invalid hex"):
+        openentropy.run_all_tests_hex(bad)
+
+
+@pytest.mark.parametrize("bad", ["abcde", "ab=c", "ab$d"])
+def test_malformed_base64_raises(bad):
+    with pytest.raises(ValueError, match="invalid base64"):
+        openentropy.quick_quality_base64(bad)
@@ -0,0 +1,44 @@
+"""EntropyPool.get_ndarray returns numpy arrays of the requested dtype."""
+
+import pytest
+
+np = pytest.importorskip("numpy")
+
+import openentropy
+
+
+@pytest.fixture
+def pool():
+    return openentropy.EntropyPool(seed=b"ndarray")
+
+
+@pytest.mark.parametrize("dtype", ["uint8", "uint16", "uint32", "float64"])
+def test_dtype_and_length(pool, dtype):
+    arr = pool.get_ndarray(1000, dtype=dtype)
+    assert isinstance(arr, np.ndarray)
+    assert arr.dtype == np.dtype(dtype)
+    assert arr.shape == (1000,)
+
+
+def test_float64_in_unit_interval(pool):
+    arr = pool.get_ndarray(10_000, dtype="float64")
+    assert arr.min() >= 0.0
+    assert arr.max() < 1.0
+    assert 0.4 < arr.mean() < 0.6
+
+
+def test_integers_span_their_range(pool):
+    arr = pool.get_ndarray(10_000, dtype="uint16")
+    assert arr.max() > np.iinfo(np.uint16).max // 2
+    assert len(np.unique(arr)) > 5000
+
+
+def test_array_is_writable(pool):
+    arr = pool.get_ndarray(16)
+    arr[0] = 0
+    assert arr[0] == 0
+
+
+def test_rejects_unknown_dtype(pool):
+    with pytest.raises(ValueError):
+        pool.get_ndarray(16, dtype="int8")